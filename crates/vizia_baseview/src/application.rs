@@ -36,6 +36,7 @@ where
     window_scale_policy: WindowScalePolicy,
     on_idle: Option<Box<dyn Fn(&mut Context) + Send>>,
     ignore_default_theme: bool,
+    watch_stylesheets: bool,
 }
 
 impl<F> Application<F>
@@ -50,6 +51,7 @@ where
             window_scale_policy: WindowScalePolicy::SystemScaleFactor,
             on_idle: None,
             ignore_default_theme: false,
+            watch_stylesheets: false,
         }
     }
 
@@ -59,6 +61,15 @@ where
         self
     }
 
+    /// Reloads styles whenever the file of a stylesheet added to the application changes, so that a plugin UI can be
+    /// restyled while the host stays open.
+    ///
+    /// See [`Context::watch_stylesheets()`] for details.
+    pub fn watch_stylesheets(mut self) -> Self {
+        self.watch_stylesheets = true;
+        self
+    }
+
     /// Change the window's scale policy. Not part of [`new()`][Self::new] to keep the same
     /// signature as the winit backend. This should only be used for HiDPI scaling, use
     /// [`WindowDescription::scale_factor`] to set a separate arbitrary scale factor.
@@ -99,6 +110,7 @@ where
             self.app,
             self.on_idle,
             self.ignore_default_theme,
+            self.watch_stylesheets,
         );
 
         Ok(())
@@ -119,6 +131,7 @@ where
             self.app,
             self.on_idle,
             self.ignore_default_theme,
+            self.watch_stylesheets,
         )
     }
 
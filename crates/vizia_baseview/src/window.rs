@@ -127,6 +127,7 @@ impl ViziaWindow {
         app: F,
         on_idle: Option<Box<dyn Fn(&mut Context) + Send>>,
         ignore_default_theme: bool,
+        watch_stylesheets: bool,
     ) -> WindowHandle
     where
         P: HasRawWindowHandle,
@@ -157,6 +158,9 @@ impl ViziaWindow {
                 let mut cx = BackendContext::new(cx);
 
                 cx.set_event_proxy(Box::new(BaseviewProxy));
+                if watch_stylesheets {
+                    cx.context().watch_stylesheets();
+                }
                 ViziaWindow::new(cx, win_desc, scale_policy, window, Some(Box::new(app)), on_idle)
             },
         )
@@ -171,6 +175,7 @@ impl ViziaWindow {
         app: F,
         on_idle: Option<Box<dyn Fn(&mut Context) + Send>>,
         ignore_default_theme: bool,
+        watch_stylesheets: bool,
     ) where
         F: Fn(&mut Context),
         F: 'static + Send,
@@ -196,6 +201,9 @@ impl ViziaWindow {
                 let mut cx = BackendContext::new(cx);
 
                 cx.set_event_proxy(Box::new(BaseviewProxy));
+                if watch_stylesheets {
+                    cx.context().watch_stylesheets();
+                }
                 ViziaWindow::new(cx, win_desc, scale_policy, window, Some(Box::new(app)), on_idle)
             },
         )
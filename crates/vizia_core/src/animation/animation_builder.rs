@@ -1,6 +1,6 @@
 use crate::prelude::*;
 
-use vizia_style::{BorderWidth, Property};
+use vizia_style::{BorderColor, BorderWidth, BorderWidthValue, Property};
 
 pub struct AnimationBuilder<'a> {
     pub(crate) keyframes: Vec<KeyframeBuilder<'a>>,
@@ -40,6 +40,13 @@ impl<'a> KeyframeBuilder<'a> {
         Self { time, properties: Vec::new() }
     }
 
+    /// Sets the timing function used between this keyframe and the next.
+    pub fn timing_function(mut self, val: impl Into<EasingFunction>) -> Self {
+        self.properties.push(Property::AnimationTimingFunction(vec![val.into()]));
+
+        self
+    }
+
     // DISPLAY
 
     pub fn display(mut self, val: impl Into<Display>) -> Self {
@@ -100,12 +107,60 @@ impl<'a> KeyframeBuilder<'a> {
         self
     }
 
-    pub fn border_color(mut self, val: impl Into<Color>) -> Self {
+    pub fn border_top_width(mut self, val: impl Into<LengthOrPercentage>) -> Self {
+        self.properties.push(Property::BorderTopWidth(BorderWidthValue(val.into())));
+
+        self
+    }
+
+    pub fn border_right_width(mut self, val: impl Into<LengthOrPercentage>) -> Self {
+        self.properties.push(Property::BorderRightWidth(BorderWidthValue(val.into())));
+
+        self
+    }
+
+    pub fn border_bottom_width(mut self, val: impl Into<LengthOrPercentage>) -> Self {
+        self.properties.push(Property::BorderBottomWidth(BorderWidthValue(val.into())));
+
+        self
+    }
+
+    pub fn border_left_width(mut self, val: impl Into<LengthOrPercentage>) -> Self {
+        self.properties.push(Property::BorderLeftWidth(BorderWidthValue(val.into())));
+
+        self
+    }
+
+    pub fn border_color(mut self, val: impl Into<BorderColor>) -> Self {
         self.properties.push(Property::BorderColor(val.into()));
 
         self
     }
 
+    pub fn border_top_color(mut self, val: impl Into<Color>) -> Self {
+        self.properties.push(Property::BorderTopColor(val.into()));
+
+        self
+    }
+
+    pub fn border_right_color(mut self, val: impl Into<Color>) -> Self {
+        self.properties.push(Property::BorderRightColor(val.into()));
+
+        self
+    }
+
+    pub fn border_bottom_color(mut self, val: impl Into<Color>) -> Self {
+        self.properties.push(Property::BorderBottomColor(val.into()));
+
+        self
+    }
+
+    pub fn border_left_color(mut self, val: impl Into<Color>) -> Self {
+        self.properties.push(Property::BorderLeftColor(val.into()));
+
+        self
+    }
+
     pub fn border_top_left_radius(mut self, val: impl Into<LengthOrPercentage>) -> Self {
         self.properties.push(Property::CornerTopLeftRadius(val.into()));
 
@@ -192,6 +247,36 @@ impl<'a> KeyframeBuilder<'a> {
         self
     }
 
+    pub fn line_height(mut self, val: impl Into<LineHeight>) -> Self {
+        self.properties.push(Property::LineHeight(val.into()));
+
+        self
+    }
+
+    pub fn letter_spacing(mut self, val: impl Into<Length>) -> Self {
+        self.properties.push(Property::LetterSpacing(Spacing::Length(val.into())));
+
+        self
+    }
+
+    pub fn word_spacing(mut self, val: impl Into<Length>) -> Self {
+        self.properties.push(Property::WordSpacing(Spacing::Length(val.into())));
+
+        self
+    }
+
+    pub fn text_indent(mut self, val: impl Into<Length>) -> Self {
+        self.properties.push(Property::TextIndent(val.into()));
+
+        self
+    }
+
+    pub fn text_shadow(mut self, val: impl Into<Vec<TextShadow>>) -> Self {
+        self.properties.push(Property::TextShadow(val.into()));
+
+        self
+    }
+
     pub fn caret_color(mut self, val: impl Into<Color>) -> Self {
         self.properties.push(Property::CaretColor(val.into()));
 
@@ -0,0 +1,10 @@
+use crate::prelude::*;
+
+/// Events emitted to a view by the animations playing on it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnimationEvent {
+    /// Emitted when an animation starts a new iteration, with the index of the iteration.
+    Iteration(Animation, u32),
+    /// Emitted when an animation has finished playing.
+    Finished(Animation),
+}
@@ -3,14 +3,15 @@ use hashbrown::HashSet;
 
 use crate::prelude::*;
 
-use super::TimingFunction;
+use super::{AnimationEvent, PlaybackControl, TimingFunction};
 
 /// A keyframe in an animation state.
 #[derive(Debug, Clone)]
 pub(crate) struct Keyframe<T: Interpolator> {
     pub time: f32,
     pub value: T,
-    pub timing_function: TimingFunction,
+    /// The timing function used between this keyframe and the next, or linear if none is set.
+    pub timing_function: Option<TimingFunction>,
 }
 
 /// Represents an animation of a property with type `T`.
@@ -24,6 +25,18 @@ pub(crate) struct AnimationState<T: Interpolator> {
     pub duration: Duration,
     /// The delay before the animation starts.
     pub delay: Duration,
+    /// The number of times the animation plays.
+    pub iteration_count: AnimationIterationCount,
+    /// The direction in which each iteration of the animation plays.
+    pub direction: AnimationDirection,
+    /// Whether the animation applies its values before it starts and after it finishes.
+    pub fill_mode: AnimationFillMode,
+    /// The index of the current iteration of the animation.
+    pub iteration: u32,
+    /// Whether the animation is playing backwards.
+    pub reversed: bool,
+    /// The time at which the animation was paused.
+    pub paused_at: Option<Instant>,
     /// List of animation keyframes as (normalized time, value).
     pub keyframes: Vec<Keyframe<T>>,
     /// The output of value of the animation.
@@ -32,6 +45,8 @@ pub(crate) struct AnimationState<T: Interpolator> {
     pub persistent: bool,
     /// How far through the animation between 0.0 and 1.0.
     pub t: f32,
+    /// The normalized time within the current iteration, accounting for direction, used to sample the keyframes.
+    pub keyframe_t: f32,
 
     pub dt: f32,
 
@@ -57,10 +72,17 @@ where
             start_time: Instant::now(),
             duration: Duration::new(0, 0),
             delay: Duration::new(0, 0),
+            iteration_count: AnimationIterationCount::default(),
+            direction: AnimationDirection::default(),
+            fill_mode: AnimationFillMode::default(),
+            iteration: 0,
+            reversed: false,
+            paused_at: None,
             keyframes: Vec::new(),
             output: None,
             persistent: false,
             t: 0.0,
+            keyframe_t: 0.0,
             dt: 0.0,
             active: false,
             entities: HashSet::new(),
@@ -81,6 +103,12 @@ where
         self
     }
 
+    pub(crate) fn with_fill_mode(mut self, fill_mode: AnimationFillMode) -> Self {
+        self.fill_mode = fill_mode;
+
+        self
+    }
+
     pub(crate) fn with_keyframe(mut self, key: Keyframe<T>) -> Self {
         self.keyframes.push(key);
 
@@ -101,6 +129,196 @@ where
     pub(crate) fn is_transition(&self) -> bool {
         !(self.from_rule == usize::MAX && self.to_rule == usize::MAX)
     }
+
+    /// Returns the total number of iterations of the animation.
+    pub(crate) fn iterations(&self) -> f32 {
+        match self.iteration_count {
+            AnimationIterationCount::Count(count) => count,
+            // An infinite animation without a duration would never make any progress.
+            AnimationIterationCount::Infinite if self.duration.is_zero() => 1.0,
+            AnimationIterationCount::Infinite => f32::INFINITY,
+        }
+    }
+
+    /// Returns how far through the animation, in iterations, the animation is at the given time.
+    /// This is negative while the animation is delayed.
+    pub(crate) fn progress(&self, time: Instant) -> f32 {
+        let elapsed_time = self.paused_at.unwrap_or(time).duration_since(self.start_time);
+
+        if self.duration.is_zero() {
+            return if elapsed_time < self.delay {
+                -1.0
+            } else if self.reversed {
+                0.0
+            } else {
+                self.iterations()
+            };
+        }
+
+        let elapsed = elapsed_time.as_secs_f32() / self.duration.as_secs_f32();
+
+        if self.reversed {
+            -elapsed - self.dt
+        } else {
+            elapsed - self.dt
+        }
+    }
+
+    /// Sets how far through the animation, in iterations, the animation is at the given time.
+    pub(crate) fn set_progress(&mut self, time: Instant, progress: f32) {
+        self.start_time = time;
+        self.dt = -progress;
+        if self.paused_at.is_some() {
+            self.paused_at = Some(time);
+        }
+    }
+
+    /// Returns the keyframes either side of the given normalized time of an iteration, along with the time
+    /// between them after applying the timing function of the first keyframe.
+    pub(crate) fn segment_at(&self, t: f32) -> Option<(&Keyframe<T>, &Keyframe<T>, f32)> {
+        if self.keyframes.len() == 1 {
+            return Some((&self.keyframes[0], &self.keyframes[0], 0.0));
+        }
+
+        let mut i = 0;
+        while i < self.keyframes.len().checked_sub(2)? && self.keyframes[i + 1].time < t {
+            i += 1;
+        }
+
+        let start = self.keyframes.get(i)?;
+        let end = self.keyframes.get(i + 1)?;
+
+        let segment_time = if end.time > start.time {
+            ((t - start.time) / (end.time - start.time)).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+
+        let t = match &start.timing_function {
+            Some(timing_function) => timing_function.value(segment_time),
+            None => segment_time,
+        };
+
+        Some((start, end, t))
+    }
+
+    /// Returns the interpolated value of the animation at the given normalized time of an iteration.
+    pub(crate) fn value_at(&self, t: f32) -> Option<T> {
+        self.segment_at(t).map(|(start, end, t)| T::interpolate(&start.value, &end.value, t))
+    }
+
+    /// Updates the output of the animation for the given time, returning any events for the entities of the animation.
+    pub(crate) fn update(&mut self, time: Instant) -> Vec<AnimationEvent> {
+        let mut events = Vec::new();
+
+        let iterations = self.iterations();
+        let progress = self.progress(time);
+
+        // The animation is waiting for its delay.
+        if !self.reversed && progress < 0.0 {
+            self.t = 0.0;
+            self.keyframe_t = if self.is_iteration_forwards(0) { 0.0 } else { 1.0 };
+            self.output =
+                if matches!(self.fill_mode, AnimationFillMode::Backwards | AnimationFillMode::Both)
+                {
+                    self.value_at(self.keyframe_t)
+                } else {
+                    None
+                };
+
+            return events;
+        }
+
+        let finished = if self.reversed { progress <= 0.0 } else { progress >= iterations };
+        let progress = progress.clamp(0.0, iterations);
+
+        // The end of the last iteration belongs to that iteration rather than the start of the next.
+        let (iteration, iteration_time) = if finished && !self.reversed && iterations > 0.0 {
+            let iteration = iterations.ceil() - 1.0;
+            (iteration, progress - iteration)
+        } else {
+            (progress.floor(), progress.fract())
+        };
+
+        let iteration = iteration as u32;
+        if iteration != self.iteration {
+            self.iteration = iteration;
+            if !finished {
+                events.push(AnimationEvent::Iteration(self.id, iteration));
+            }
+        }
+
+        self.keyframe_t = if self.is_iteration_forwards(iteration) {
+            iteration_time
+        } else {
+            1.0 - iteration_time
+        };
+
+        self.output = self.value_at(self.keyframe_t);
+
+        if finished {
+            self.t = 1.0;
+            if matches!(self.fill_mode, AnimationFillMode::Forwards | AnimationFillMode::Both) {
+                self.persistent = true;
+            }
+            events.push(AnimationEvent::Finished(self.id));
+        } else if iterations.is_finite() {
+            self.t = (progress / iterations).min(1.0 - f32::EPSILON);
+        } else {
+            self.t = 0.0;
+        }
+
+        events
+    }
+
+    /// Applies a playback control to the animation at the given time.
+    pub(crate) fn control(&mut self, control: PlaybackControl, time: Instant) {
+        match control {
+            PlaybackControl::Pause => {
+                if self.paused_at.is_none() {
+                    self.paused_at = Some(time);
+                }
+            }
+
+            PlaybackControl::Resume => {
+                if let Some(paused_at) = self.paused_at.take() {
+                    self.start_time += time.duration_since(paused_at);
+                }
+            }
+
+            PlaybackControl::Seek(position) => {
+                let progress = if self.duration.is_zero() {
+                    0.0
+                } else {
+                    position.as_secs_f32() / self.duration.as_secs_f32()
+                };
+                self.set_progress(time, progress.min(self.iterations()));
+            }
+
+            PlaybackControl::Reverse => {
+                let progress = self.progress(time).clamp(0.0, self.iterations());
+                self.reversed = !self.reversed;
+                self.set_progress(time, progress);
+            }
+        }
+
+        // A finished animation held by its fill mode can be played again.
+        if self.t == 1.0 && self.persistent {
+            self.t = 0.0;
+        }
+
+        self.update(time);
+    }
+
+    // Returns true if the given iteration plays from the first keyframe to the last.
+    fn is_iteration_forwards(&self, iteration: u32) -> bool {
+        match self.direction {
+            AnimationDirection::Normal => true,
+            AnimationDirection::Reverse => false,
+            AnimationDirection::Alternate => iteration % 2 == 0,
+            AnimationDirection::AlternateReverse => iteration % 2 == 1,
+        }
+    }
 }
 
 impl<Prop> Default for AnimationState<Prop>
@@ -113,10 +331,17 @@ where
             start_time: Instant::now(),
             duration: Duration::new(0, 0),
             delay: Duration::new(0, 0),
+            iteration_count: AnimationIterationCount::default(),
+            direction: AnimationDirection::default(),
+            fill_mode: AnimationFillMode::default(),
+            iteration: 0,
+            reversed: false,
+            paused_at: None,
             keyframes: Vec::new(),
             output: None,
             persistent: true,
             t: 0.0,
+            keyframe_t: 0.0,
             dt: 0.0,
             active: false,
             entities: HashSet::new(),
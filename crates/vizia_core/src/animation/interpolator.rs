@@ -1,8 +1,10 @@
 use morphorm::Units;
 use vizia_style::{
-    Angle, BackgroundSize, ClipPath, Color, ColorStop, Display, Filter, FontSize, Gradient, Length,
-    LengthOrPercentage, LengthPercentageOrAuto, LengthValue, LineDirection, LinearGradient,
-    Opacity, PercentageOrNumber, Rect, Scale, Shadow, Transform, Translate, RGBA,
+    Angle, AngleOrPercentage, BackgroundSize, ClipPath, Color, ColorInterpolationMethod,
+    ColorSpace, ColorStop, ConicGradient, Display, Filter, FontSize, Gradient, GradientItem,
+    HorizontalPosition, Length, LengthOrPercentage, LengthPercentageOrAuto, LengthValue,
+    LineDirection, LineHeight, LinearGradient, Opacity, PercentageOrNumber, Position,
+    RadialGradient, Rect, Scale, Shadow, TextShadow, Transform, Translate, VerticalPosition, RGBA,
 };
 
 use skia_safe::Matrix;
@@ -69,21 +71,14 @@ impl Interpolator for Opacity {
 
 impl Interpolator for Color {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
-        let r = (end.r() as f64 - start.r() as f64).mul_add(t as f64, start.r() as f64) as u8;
-        let g = (end.g() as f64 - start.g() as f64).mul_add(t as f64, start.g() as f64) as u8;
-        let b = (end.b() as f64 - start.b() as f64).mul_add(t as f64, start.b() as f64) as u8;
-        let a = (end.a() as f64 - start.a() as f64).mul_add(t as f64, start.a() as f64) as u8;
-        Color::rgba(r, g, b, a)
+        Color::RGBA(RGBA::interpolate(&start.get_rgba(), &end.get_rgba(), t))
     }
 }
 
 impl Interpolator for RGBA {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
-        let r = (end.r() as f64 - start.r() as f64).mul_add(t as f64, start.r() as f64) as u8;
-        let g = (end.g() as f64 - start.g() as f64).mul_add(t as f64, start.g() as f64) as u8;
-        let b = (end.b() as f64 - start.b() as f64).mul_add(t as f64, start.b() as f64) as u8;
-        let a = (end.a() as f64 - start.a() as f64).mul_add(t as f64, start.a() as f64) as u8;
-        RGBA::rgba(r, g, b, a)
+        // Colors are interpolated in Oklab so that the perceived change in color is even over the transition.
+        ColorInterpolationMethod::from(ColorSpace::Oklab).mix(*start, *end, t)
     }
 }
 
@@ -93,6 +88,34 @@ impl Interpolator for Filter {
             (Filter::Blur(start), Filter::Blur(end)) => {
                 Filter::Blur(Length::interpolate(start, end, t))
             }
+            (Filter::Brightness(start), Filter::Brightness(end)) => {
+                Filter::Brightness(f32::interpolate(start, end, t))
+            }
+            (Filter::Contrast(start), Filter::Contrast(end)) => {
+                Filter::Contrast(f32::interpolate(start, end, t))
+            }
+            (Filter::Grayscale(start), Filter::Grayscale(end)) => {
+                Filter::Grayscale(f32::interpolate(start, end, t))
+            }
+            (Filter::HueRotate(start), Filter::HueRotate(end)) => {
+                Filter::HueRotate(Angle::interpolate(start, end, t))
+            }
+            (Filter::Invert(start), Filter::Invert(end)) => {
+                Filter::Invert(f32::interpolate(start, end, t))
+            }
+            (Filter::Opacity(start), Filter::Opacity(end)) => {
+                Filter::Opacity(f32::interpolate(start, end, t))
+            }
+            (Filter::Saturate(start), Filter::Saturate(end)) => {
+                Filter::Saturate(f32::interpolate(start, end, t))
+            }
+            (Filter::Sepia(start), Filter::Sepia(end)) => {
+                Filter::Sepia(f32::interpolate(start, end, t))
+            }
+            (Filter::DropShadow(start), Filter::DropShadow(end)) => {
+                Filter::DropShadow(TextShadow::interpolate(start, end, t))
+            }
+            _ => end.clone(),
         }
     }
 }
@@ -104,6 +127,10 @@ impl Interpolator for LengthValue {
                 LengthValue::Px(f32::interpolate(start_val, end_val, t))
             }
 
+            (LengthValue::Em(end_val), LengthValue::Em(start_val)) => {
+                LengthValue::Em(f32::interpolate(start_val, end_val, t))
+            }
+
             _ => LengthValue::default(),
         }
     }
@@ -199,19 +226,93 @@ impl Interpolator for Transform {
     }
 }
 
-// TODO: Split this into interpolated matrices for translation, rotation, scale, and skew
 impl Interpolator for Matrix {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
-        let mut transform = *start;
+        let start = DecomposedMatrix::new(start);
+        let end = DecomposedMatrix::new(end);
 
-        transform[0] = f32::interpolate(&start[0], &end[0], t);
-        transform[1] = f32::interpolate(&start[1], &end[1], t);
-        transform[2] = f32::interpolate(&start[2], &end[2], t);
-        transform[3] = f32::interpolate(&start[3], &end[3], t);
-        transform[4] = f32::interpolate(&start[4], &end[4], t);
-        transform[5] = f32::interpolate(&start[5], &end[5], t);
+        DecomposedMatrix::interpolate(&start, &end, t).as_matrix()
+    }
+}
 
-        transform
+// A 2D matrix decomposed into a translation, rotation, skew, and scale, which are applied in that order.
+// Interpolating the decomposed parts of two matrices, rather than the matrix components directly, avoids
+// distorting the transform part way through the interpolation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct DecomposedMatrix {
+    translate: (f32, f32),
+    rotate: f32,
+    skew: f32,
+    scale: (f32, f32),
+}
+
+impl DecomposedMatrix {
+    fn new(matrix: &Matrix) -> Self {
+        let (a, b) = (matrix.scale_x(), matrix.skew_y());
+        let (c, d) = (matrix.skew_x(), matrix.scale_y());
+
+        let scale_x = (a * a + b * b).sqrt();
+        if scale_x == 0.0 {
+            return Self {
+                translate: (matrix.translate_x(), matrix.translate_y()),
+                rotate: 0.0,
+                skew: 0.0,
+                scale: (0.0, d),
+            };
+        }
+
+        // The first column of the matrix is the rotated and scaled x axis.
+        let rotate = b.atan2(a);
+        let (sin, cos) = rotate.sin_cos();
+
+        // Unrotating the second column gives the skewed and scaled y axis. A negative determinant,
+        // i.e. a flip, results in a negative y scale.
+        let skew = cos * c + sin * d;
+        let scale_y = cos * d - sin * c;
+
+        Self {
+            translate: (matrix.translate_x(), matrix.translate_y()),
+            rotate,
+            skew: skew / scale_x,
+            scale: (scale_x, scale_y),
+        }
+    }
+
+    fn as_matrix(&self) -> Matrix {
+        let (sin, cos) = self.rotate.sin_cos();
+        let (scale_x, scale_y) = self.scale;
+        let skew = self.skew * scale_x;
+
+        Matrix::new_all(
+            cos * scale_x,
+            cos * skew - sin * scale_y,
+            self.translate.0,
+            sin * scale_x,
+            sin * skew + cos * scale_y,
+            self.translate.1,
+            0.0,
+            0.0,
+            1.0,
+        )
+    }
+}
+
+impl Interpolator for DecomposedMatrix {
+    fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        // Rotate the shortest way around.
+        let mut rotate_delta = end.rotate - start.rotate;
+        if rotate_delta > std::f32::consts::PI {
+            rotate_delta -= std::f32::consts::TAU;
+        } else if rotate_delta < -std::f32::consts::PI {
+            rotate_delta += std::f32::consts::TAU;
+        }
+
+        DecomposedMatrix {
+            translate: <(f32, f32)>::interpolate(&start.translate, &end.translate, t),
+            rotate: start.rotate + rotate_delta * t,
+            skew: f32::interpolate(&start.skew, &end.skew, t),
+            scale: <(f32, f32)>::interpolate(&start.scale, &end.scale, t),
+        }
     }
 }
 
@@ -261,6 +362,40 @@ impl Interpolator for Gradient {
                 Gradient::Linear(LinearGradient::interpolate(start_gradient, end_gradient, t))
             }
 
+            (
+                Gradient::RepeatingLinear(start_gradient),
+                Gradient::RepeatingLinear(end_gradient),
+            ) => Gradient::RepeatingLinear(LinearGradient::interpolate(
+                start_gradient,
+                end_gradient,
+                t,
+            )),
+
+            (Gradient::Radial(start_gradient), Gradient::Radial(end_gradient)) => {
+                Gradient::Radial(RadialGradient::interpolate(start_gradient, end_gradient, t))
+            }
+
+            (
+                Gradient::RepeatingRadial(start_gradient),
+                Gradient::RepeatingRadial(end_gradient),
+            ) => Gradient::RepeatingRadial(RadialGradient::interpolate(
+                start_gradient,
+                end_gradient,
+                t,
+            )),
+
+            (Gradient::Conic(start_gradient), Gradient::Conic(end_gradient)) => {
+                Gradient::Conic(ConicGradient::interpolate(start_gradient, end_gradient, t))
+            }
+
+            (Gradient::RepeatingConic(start_gradient), Gradient::RepeatingConic(end_gradient)) => {
+                Gradient::RepeatingConic(ConicGradient::interpolate(
+                    start_gradient,
+                    end_gradient,
+                    t,
+                ))
+            }
+
             _ => end.clone(),
         }
     }
@@ -280,35 +415,132 @@ impl Interpolator for LineDirection {
 
 impl Interpolator for LinearGradient {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
-        if start.stops.len() == end.stops.len() {
-            LinearGradient {
+        match interpolate_gradient_items(
+            &start.items,
+            &end.items,
+            t,
+            LengthOrPercentage::Percentage,
+        ) {
+            Some(items) => LinearGradient {
                 direction: LineDirection::interpolate(&start.direction, &end.direction, t),
-                stops: start
-                    .stops
-                    .iter()
-                    .zip(end.stops.iter())
-                    .enumerate()
-                    .map(|(index, (start_stop, end_stop))| {
-                        let num_stops = start.stops.len();
-                        let start_pos =
-                            start_stop.position.clone().unwrap_or(LengthOrPercentage::Percentage(
-                                index as f32 / (num_stops - 1) as f32 * 100.0,
-                            ));
-                        let end_pos =
-                            end_stop.position.clone().unwrap_or(LengthOrPercentage::Percentage(
-                                index as f32 / (num_stops - 1) as f32 * 100.0,
-                            ));
-                        ColorStop {
-                            color: Color::interpolate(&start_stop.color, &end_stop.color, t),
-                            position: Some(LengthOrPercentage::interpolate(
-                                &start_pos, &end_pos, t,
-                            )),
-                        }
-                    })
-                    .collect::<Vec<_>>(),
+                interpolation: end.interpolation,
+                items,
+            },
+
+            None => end.clone(),
+        }
+    }
+}
+
+impl Interpolator for RadialGradient {
+    fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        match interpolate_gradient_items(
+            &start.items,
+            &end.items,
+            t,
+            LengthOrPercentage::Percentage,
+        ) {
+            Some(items) => RadialGradient {
+                position: Position::interpolate(&start.position, &end.position, t),
+                interpolation: end.interpolation,
+                items,
+            },
+
+            None => end.clone(),
+        }
+    }
+}
+
+impl Interpolator for ConicGradient {
+    fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        match interpolate_gradient_items(&start.items, &end.items, t, AngleOrPercentage::Percentage)
+        {
+            Some(items) => ConicGradient {
+                angle: Angle::interpolate(&start.angle, &end.angle, t),
+                position: Position::interpolate(&start.position, &end.position, t),
+                interpolation: end.interpolation,
+                items,
+            },
+
+            None => end.clone(),
+        }
+    }
+}
+
+/// Interpolates the color stops and hints of two gradients, or returns `None` if they do not match.
+///
+/// Stops without a position are given an evenly spaced percentage so that they can be interpolated.
+fn interpolate_gradient_items<D: Interpolator + Clone>(
+    start: &[GradientItem<D>],
+    end: &[GradientItem<D>],
+    t: f32,
+    percentage: impl Fn(f32) -> D,
+) -> Option<Vec<GradientItem<D>>> {
+    if start.len() != end.len() {
+        return None;
+    }
+
+    let num_stops = start.iter().filter(|item| matches!(item, GradientItem::ColorStop(_))).count();
+    let default_position =
+        |index: usize| percentage(index as f32 / (num_stops.max(2) - 1) as f32 * 100.0);
+
+    let mut index = 0;
+    start
+        .iter()
+        .zip(end.iter())
+        .map(|items| match items {
+            (GradientItem::ColorStop(start_stop), GradientItem::ColorStop(end_stop)) => {
+                let start_pos =
+                    start_stop.position.clone().unwrap_or_else(|| default_position(index));
+                let end_pos = end_stop.position.clone().unwrap_or_else(|| default_position(index));
+                index += 1;
+
+                Some(GradientItem::ColorStop(ColorStop {
+                    color: Color::interpolate(&start_stop.color, &end_stop.color, t),
+                    position: Some(D::interpolate(&start_pos, &end_pos, t)),
+                }))
             }
-        } else {
-            end.clone()
+
+            (GradientItem::Hint(start_hint), GradientItem::Hint(end_hint)) => {
+                Some(GradientItem::Hint(D::interpolate(start_hint, end_hint, t)))
+            }
+
+            _ => None,
+        })
+        .collect()
+}
+
+impl Interpolator for AngleOrPercentage {
+    fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        match (start, end) {
+            (AngleOrPercentage::Angle(start_angle), AngleOrPercentage::Angle(end_angle)) => {
+                AngleOrPercentage::Angle(Angle::interpolate(start_angle, end_angle, t))
+            }
+
+            (AngleOrPercentage::Percentage(start_val), AngleOrPercentage::Percentage(end_val)) => {
+                AngleOrPercentage::Percentage(f32::interpolate(start_val, end_val, t))
+            }
+
+            _ => AngleOrPercentage::Percentage(
+                f32::interpolate(&start.to_turns(), &end.to_turns(), t) * 100.0,
+            ),
+        }
+    }
+}
+
+impl Interpolator for Position {
+    fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        Position {
+            x: HorizontalPosition::Length(LengthOrPercentage::interpolate(
+                &start.x.to_length_or_percentage(),
+                &end.x.to_length_or_percentage(),
+                t,
+            )),
+            y: VerticalPosition::Length(LengthOrPercentage::interpolate(
+                &start.y.to_length_or_percentage(),
+                &end.y.to_length_or_percentage(),
+                t,
+            )),
         }
     }
 }
@@ -326,6 +558,17 @@ impl Interpolator for Shadow {
     }
 }
 
+impl Interpolator for TextShadow {
+    fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        TextShadow {
+            x_offset: Length::interpolate(&start.x_offset, &end.x_offset, t),
+            y_offset: Length::interpolate(&start.y_offset, &end.y_offset, t),
+            blur_radius: Option::interpolate(&start.blur_radius, &end.blur_radius, t),
+            color: Option::interpolate(&start.color, &end.color, t),
+        }
+    }
+}
+
 impl<T: Interpolator + Clone + Default> Interpolator for Option<T> {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
         match (start, end) {
@@ -343,6 +586,20 @@ impl Interpolator for FontSize {
     }
 }
 
+impl Interpolator for LineHeight {
+    fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        match (start, end) {
+            (LineHeight::Number(s), LineHeight::Number(e)) => {
+                LineHeight::Number(f32::interpolate(s, e, t))
+            }
+            (LineHeight::Length(s), LineHeight::Length(e)) => {
+                LineHeight::Length(LengthOrPercentage::interpolate(s, e, t))
+            }
+            _ => end.clone(),
+        }
+    }
+}
+
 impl<T: Interpolator> Interpolator for Rect<T> {
     fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
         Rect(
@@ -362,3 +619,74 @@ impl Interpolator for ClipPath {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_matrix_eq(left: Matrix, right: Matrix) {
+        for i in 0..9 {
+            assert!((left[i] - right[i]).abs() < 1e-4, "{:?} != {:?}", left, right);
+        }
+    }
+
+    #[test]
+    fn decompose_matrix() {
+        let matrix = Matrix::translate((10.0, 20.0))
+            * Matrix::rotate_rad(0.5)
+            * Matrix::skew((0.3, 0.0))
+            * Matrix::scale((2.0, -3.0));
+
+        assert_matrix_eq(DecomposedMatrix::new(&matrix).as_matrix(), matrix);
+    }
+
+    #[test]
+    fn interpolate_matrix() {
+        let start = Matrix::scale((2.0, 2.0));
+        let end = Matrix::rotate_rad(std::f32::consts::FRAC_PI_2) * Matrix::scale((4.0, 4.0));
+
+        assert_matrix_eq(
+            Matrix::interpolate(&start, &end, 0.5),
+            Matrix::rotate_rad(std::f32::consts::FRAC_PI_4) * Matrix::scale((3.0, 3.0)),
+        );
+    }
+
+    #[test]
+    fn interpolate_color() {
+        // Interpolating in Oklab gives a perceptually even midpoint, which is darker than the sRGB midpoint.
+        assert_eq!(
+            Color::interpolate(&Color::black(), &Color::white(), 0.5),
+            Color::rgb(99, 99, 99)
+        );
+        assert_eq!(Color::interpolate(&Color::red(), &Color::blue(), 0.0), Color::red());
+        assert_eq!(Color::interpolate(&Color::red(), &Color::blue(), 1.0), Color::blue());
+
+        // Transparent colors do not darken the result.
+        assert_eq!(
+            Color::interpolate(&Color::white(), &Color::transparent(), 0.5),
+            Color::rgba(255, 255, 255, 128)
+        );
+    }
+
+    #[test]
+    fn interpolate_gradient() {
+        let start = Gradient::from("conic-gradient(red, blue)");
+        let end = Gradient::from("conic-gradient(blue 0%, red 50%)");
+
+        match Gradient::interpolate(&start, &end, 0.5) {
+            Gradient::Conic(gradient) => match &gradient.items[..] {
+                [GradientItem::ColorStop(first), GradientItem::ColorStop(last)] => {
+                    assert_eq!(first.color, Color::rgba(140, 83, 162, 255));
+                    assert_eq!(first.position, Some(AngleOrPercentage::Percentage(0.0)));
+                    assert_eq!(last.position, Some(AngleOrPercentage::Percentage(75.0)));
+                }
+                items => panic!("expected two color stops, found {:?}", items),
+            },
+            gradient => panic!("expected a conic gradient, found {:?}", gradient),
+        }
+
+        // Gradients with a different number of stops cannot be interpolated.
+        let end = Gradient::from("conic-gradient(blue, red, green)");
+        assert_eq!(Gradient::interpolate(&start, &end, 0.5), end);
+    }
+}
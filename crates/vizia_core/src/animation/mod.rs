@@ -4,6 +4,13 @@ pub use animation_id::{AnimId, Animation};
 mod animation_state;
 pub(crate) use animation_state::{AnimationState, Keyframe};
 
+mod animation_event;
+pub use animation_event::AnimationEvent;
+
+mod playback_options;
+pub(crate) use playback_options::PlaybackControl;
+pub use playback_options::PlaybackOptions;
+
 mod interpolator;
 pub(crate) use interpolator::Interpolator;
 
@@ -0,0 +1,70 @@
+use crate::prelude::*;
+
+use super::TimingFunction;
+
+/// Options used when playing an animation on a view.
+///
+/// # Example
+/// ```
+/// # use vizia_core::prelude::*;
+/// let options = PlaybackOptions::new(Duration::from_millis(500), Duration::ZERO)
+///     .iterations(AnimationIterationCount::Infinite)
+///     .direction(AnimationDirection::Alternate)
+///     .timing_function(EasingFunction::Steps { count: 4, position: StepPosition::JumpEnd });
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlaybackOptions {
+    pub(crate) duration: Duration,
+    pub(crate) delay: Duration,
+    pub(crate) iteration_count: AnimationIterationCount,
+    pub(crate) direction: AnimationDirection,
+    pub(crate) fill_mode: AnimationFillMode,
+    // Used by any keyframes of the animation which don't specify their own timing function.
+    pub(crate) timing_function: Option<TimingFunction>,
+}
+
+impl PlaybackOptions {
+    /// Creates new playback options for an animation with the given duration, for a single iteration, and delay.
+    pub fn new(duration: Duration, delay: Duration) -> Self {
+        Self { duration, delay, ..Default::default() }
+    }
+
+    /// Sets the number of times the animation plays.
+    pub fn iterations(mut self, iteration_count: impl Into<AnimationIterationCount>) -> Self {
+        self.iteration_count = iteration_count.into();
+
+        self
+    }
+
+    /// Sets the direction in which each iteration of the animation plays.
+    pub fn direction(mut self, direction: AnimationDirection) -> Self {
+        self.direction = direction;
+
+        self
+    }
+
+    /// Sets whether the animation applies its values to the view before it starts and after it finishes.
+    pub fn fill_mode(mut self, fill_mode: AnimationFillMode) -> Self {
+        self.fill_mode = fill_mode;
+
+        self
+    }
+
+    /// Sets the timing function used between keyframes of the animation which don't specify their own.
+    pub fn timing_function(mut self, timing_function: impl Into<EasingFunction>) -> Self {
+        self.timing_function = Some(timing_function.into().into());
+
+        self
+    }
+}
+
+/// Controls the playback of an animation which is already playing.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum PlaybackControl {
+    Pause,
+    Resume,
+    /// Jumps to the given time from the start of the animation, excluding the delay.
+    Seek(Duration),
+    /// Plays the animation in the opposite direction from its current position.
+    Reverse,
+}
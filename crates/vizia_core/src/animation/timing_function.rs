@@ -1,9 +1,11 @@
-#[derive(Debug, Clone, Copy)]
-pub(crate) struct TimingFunction {
-    x1: f32,
-    x2: f32,
-    y1: f32,
-    y2: f32,
+use vizia_style::{EasingFunction, LinearStop, StepPosition};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum TimingFunction {
+    CubicBezier { x1: f32, y1: f32, x2: f32, y2: f32 },
+    Steps { count: u32, position: StepPosition },
+    Linear(Vec<LinearStop>),
+    Spring { stiffness: f32, damping: f32, mass: f32 },
 }
 
 impl Default for TimingFunction {
@@ -30,18 +32,128 @@ impl TimingFunction {
     }
 }
 
+impl From<EasingFunction> for TimingFunction {
+    fn from(easing: EasingFunction) -> Self {
+        match easing {
+            EasingFunction::Linear => TimingFunction::linear(),
+            EasingFunction::Ease => TimingFunction::ease(),
+            EasingFunction::EaseIn => TimingFunction::ease_in(),
+            EasingFunction::EaseOut => TimingFunction::ease_out(),
+            EasingFunction::EaseInOut => TimingFunction::ease_in_out(),
+            EasingFunction::CubicBezier(x1, y1, x2, y2) => TimingFunction::new(x1, y1, x2, y2),
+            EasingFunction::Steps { count, position } => TimingFunction::Steps { count, position },
+            EasingFunction::LinearStops(stops) => TimingFunction::Linear(stops),
+            EasingFunction::Spring { stiffness, damping, mass } => {
+                TimingFunction::Spring { stiffness, damping, mass }
+            }
+        }
+    }
+}
+
 impl TimingFunction {
     pub fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
-        Self { x1, y1, x2, y2 }
+        Self::CubicBezier { x1, y1, x2, y2 }
     }
 
     pub fn value(&self, x: f32) -> f32 {
-        // Linear
-        if self.x1 == self.y1 && self.x2 == self.y2 {
+        match self {
+            TimingFunction::CubicBezier { x1, y1, x2, y2 } => {
+                // Linear
+                if x1 == y1 && x2 == y2 {
+                    return x;
+                }
+
+                Self::calc_bezier(Self::find_t_for_x(x, *x1, *x2), *y1, *y2)
+            }
+
+            TimingFunction::Steps { count, position } => Self::calc_steps(x, *count, *position),
+
+            TimingFunction::Linear(stops) => Self::calc_linear(x, stops),
+
+            TimingFunction::Spring { stiffness, damping, mass } => {
+                Self::calc_spring(x, *stiffness, *damping, *mass)
+            }
+        }
+    }
+
+    // See https://www.w3.org/TR/css-easing-1/#step-easing-algo
+    // The step count is clamped to the minimum allowed by CSS, which is two for `jump-none` and one otherwise, as
+    // timing functions constructed in code aren't validated by the parser.
+    fn calc_steps(x: f32, count: u32, position: StepPosition) -> f32 {
+        let count = count.max(if position == StepPosition::JumpNone { 2 } else { 1 });
+
+        let mut step = (x * count as f32).floor();
+        if matches!(position, StepPosition::JumpStart | StepPosition::JumpBoth) {
+            step += 1.0;
+        }
+
+        let jumps = match position {
+            StepPosition::JumpNone => count - 1,
+            StepPosition::JumpBoth => count + 1,
+            _ => count,
+        } as f32;
+
+        step.clamp(0.0, jumps) / jumps
+    }
+
+    // See https://www.w3.org/TR/css-easing-2/#linear-easing-function-output
+    fn calc_linear(x: f32, stops: &[LinearStop]) -> f32 {
+        if stops.len() < 2 {
+            return x;
+        }
+
+        // Find the stops either side of the input, extrapolating from the first or last pair of stops.
+        let i = stops.iter().rposition(|stop| stop.input <= x).unwrap_or(0).min(stops.len() - 2);
+        let (start, end) = (&stops[i], &stops[i + 1]);
+
+        if end.input <= start.input {
+            return if x < start.input { start.output } else { end.output };
+        }
+
+        start.output + (end.output - start.output) * (x - start.input) / (end.input - start.input)
+    }
+
+    // The position of a damped spring released from rest at zero towards a target of one.
+    // The input is scaled so that the spring has settled by the end of the animation.
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
+    fn calc_spring(x: f32, stiffness: f32, damping: f32, mass: f32) -> f32 {
+        if x >= 1.0 {
+            return 1.0;
+        }
+
+        // A spring without a finite, positive stiffness and mass never settles, so it falls back to linear.
+        if !(stiffness.is_finite() && damping.is_finite() && mass.is_finite())
+            || stiffness <= 0.0
+            || mass <= 0.0
+        {
+            return x;
+        }
+
+        // Undamped angular frequency and damping ratio.
+        let omega = (stiffness / mass).sqrt();
+        let zeta = damping / (2.0 * (stiffness * mass).sqrt());
+
+        // The spring has settled once the envelope of its oscillation decays below 0.1%.
+        let decay =
+            if zeta <= 1.0 { zeta * omega } else { omega * (zeta - (zeta * zeta - 1.0).sqrt()) };
+        // The negated comparison also rejects a NaN decay rate.
+        if !(decay > 0.0) {
             return x;
         }
+        let t = x * 1000f32.ln() / decay;
 
-        Self::calc_bezier(self.find_t_for_x(x), self.y1, self.y2)
+        if (zeta - 1.0).abs() < f32::EPSILON {
+            1.0 - (-omega * t).exp() * (1.0 + omega * t)
+        } else if zeta < 1.0 {
+            let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+            1.0 - (-zeta * omega * t).exp()
+                * ((omega_d * t).cos() + zeta * omega / omega_d * (omega_d * t).sin())
+        } else {
+            let root = (zeta * zeta - 1.0).sqrt();
+            let r1 = -omega * (zeta - root);
+            let r2 = -omega * (zeta + root);
+            1.0 + (r2 * (r1 * t).exp() - r1 * (r2 * t).exp()) / (r1 - r2)
+        }
     }
 
     fn calc_bezier(t: f32, a1: f32, a2: f32) -> f32 {
@@ -60,16 +172,16 @@ impl TimingFunction {
         3.0 * a(a1, a2) * t * t + 2.0 * b(a1, a2) * t + c(a1)
     }
 
-    fn find_t_for_x(&self, x: f32) -> f32 {
+    fn find_t_for_x(x: f32, x1: f32, x2: f32) -> f32 {
         let mut guess = x;
         let mut error = f32::MAX;
         for _ in 0..8 {
-            let pos = Self::calc_bezier(guess, self.x1, self.x2);
+            let pos = Self::calc_bezier(guess, x1, x2);
             error = pos - x;
             if error.abs() <= 0.0000001 {
                 return guess;
             }
-            let slope = Self::calc_bezier_slope(guess, self.x1, self.x2);
+            let slope = Self::calc_bezier_slope(guess, x1, x2);
             guess -= error / slope;
         }
         if error.abs() <= 0.0000001 {
@@ -83,6 +195,7 @@ impl TimingFunction {
 #[cfg(test)]
 mod tests {
     use super::TimingFunction;
+    use vizia_style::{LinearStop, StepPosition};
 
     #[test]
     fn linear() {
@@ -95,4 +208,97 @@ mod tests {
         let timing_func = TimingFunction::ease();
         assert_eq!(timing_func.value(0.25), 0.4085106);
     }
+
+    #[test]
+    fn steps() {
+        let timing_func = TimingFunction::Steps { count: 4, position: StepPosition::JumpEnd };
+        assert_eq!(timing_func.value(0.0), 0.0);
+        assert_eq!(timing_func.value(0.3), 0.25);
+        assert_eq!(timing_func.value(1.0), 1.0);
+
+        let timing_func = TimingFunction::Steps { count: 4, position: StepPosition::JumpStart };
+        assert_eq!(timing_func.value(0.0), 0.25);
+        assert_eq!(timing_func.value(0.8), 1.0);
+
+        let timing_func = TimingFunction::Steps { count: 3, position: StepPosition::JumpNone };
+        assert_eq!(timing_func.value(0.5), 0.5);
+        assert_eq!(timing_func.value(1.0), 1.0);
+
+        let timing_func = TimingFunction::Steps { count: 3, position: StepPosition::JumpBoth };
+        assert_eq!(timing_func.value(0.0), 0.25);
+        assert_eq!(timing_func.value(0.5), 0.5);
+    }
+
+    #[test]
+    fn steps_invalid_count() {
+        // A count below the minimum is treated as the minimum.
+        let timing_func = TimingFunction::Steps { count: 0, position: StepPosition::JumpNone };
+        assert_eq!(timing_func.value(0.0), 0.0);
+        assert_eq!(timing_func.value(0.5), 1.0);
+        assert_eq!(timing_func.value(1.0), 1.0);
+
+        let timing_func = TimingFunction::Steps { count: 1, position: StepPosition::JumpNone };
+        assert_eq!(timing_func.value(0.25), 0.0);
+        assert_eq!(timing_func.value(1.0), 1.0);
+
+        for position in [StepPosition::JumpStart, StepPosition::JumpEnd, StepPosition::JumpBoth] {
+            let timing_func = TimingFunction::Steps { count: 0, position };
+            let expected = TimingFunction::Steps { count: 1, position };
+            for x in [0.0, 0.5, 1.0] {
+                assert!(!timing_func.value(x).is_nan());
+                assert_eq!(timing_func.value(x), expected.value(x));
+            }
+        }
+    }
+
+    #[test]
+    fn linear_stops() {
+        let timing_func = TimingFunction::Linear(vec![
+            LinearStop::new(0.0, 0.0),
+            LinearStop::new(0.5, 0.75),
+            LinearStop::new(0.5, 0.25),
+            LinearStop::new(1.0, 1.0),
+        ]);
+        assert_eq!(timing_func.value(0.25), 0.375);
+        assert_eq!(timing_func.value(0.5), 0.25);
+        assert_eq!(timing_func.value(0.75), 0.625);
+        assert_eq!(timing_func.value(-0.5), -0.75);
+    }
+
+    #[test]
+    fn spring() {
+        for (stiffness, damping) in [(100.0, 5.0), (100.0, 20.0), (100.0, 40.0)] {
+            let timing_func = TimingFunction::Spring { stiffness, damping, mass: 1.0 };
+            assert_eq!(timing_func.value(0.0), 0.0);
+            assert!((timing_func.value(0.99) - 1.0).abs() < 0.01);
+            assert_eq!(timing_func.value(1.0), 1.0);
+        }
+
+        // An underdamped spring overshoots its target.
+        let timing_func = TimingFunction::Spring { stiffness: 100.0, damping: 5.0, mass: 1.0 };
+        assert!((0..100).any(|i| timing_func.value(i as f32 / 100.0) > 1.0));
+    }
+
+    #[test]
+    fn spring_invalid_parameters() {
+        // Springs which can't settle are treated as linear.
+        let springs = [
+            (-100.0, 10.0, 1.0),
+            (0.0, 10.0, 1.0),
+            (100.0, 10.0, 0.0),
+            (100.0, 10.0, -1.0),
+            (100.0, -10.0, 1.0),
+            (100.0, f32::NAN, 1.0),
+            (100.0, f32::INFINITY, 1.0),
+            (f32::INFINITY, 10.0, 1.0),
+            (100.0, 10.0, f32::NAN),
+        ];
+
+        for (stiffness, damping, mass) in springs {
+            let timing_func = TimingFunction::Spring { stiffness, damping, mass };
+            for x in [0.0, 0.25, 0.5, 0.75, 1.0] {
+                assert_eq!(timing_func.value(x), x);
+            }
+        }
+    }
 }
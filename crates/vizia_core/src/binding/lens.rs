@@ -130,6 +130,19 @@ pub trait LensExt: Lens {
         MapRef { id, lens: self, o: PhantomData }
     }
 
+    /// Like [`map_ref`](LensExt::map_ref), but the lens has no value when the closure returns `None`.
+    fn try_map_ref<O: 'static, F: 'static + Fn(&Self::Target) -> Option<&O>>(
+        self,
+        map: F,
+    ) -> TryMapRef<Self, O> {
+        let id = MAP_MANAGER.with_borrow_mut(|f| f.create());
+        let entity = CURRENT.with_borrow(|f| *f);
+        MAPS.with_borrow_mut(|f| {
+            f.insert(id, (entity, Box::new(TryMapRefState { closure: Rc::new(map) })))
+        });
+        TryMapRef { id, lens: self, o: PhantomData }
+    }
+
     fn unwrap<T: 'static>(self) -> Then<Self, UnwrapLens<T>>
     where
         Self: Lens<Target = Option<T>>,
@@ -156,6 +169,10 @@ pub struct MapRefState<T, O> {
     closure: Rc<dyn Fn(&T) -> &O>,
 }
 
+pub struct TryMapRefState<T, O> {
+    closure: Rc<dyn Fn(&T) -> Option<&O>>,
+}
+
 pub struct Map<L: Lens, O> {
     id: MapId,
     lens: L,
@@ -243,6 +260,51 @@ impl<L: Lens, O: 'static> Hash for MapRef<L, O> {
     }
 }
 
+pub struct TryMapRef<L: Lens, O> {
+    id: MapId,
+    lens: L,
+    o: PhantomData<O>,
+}
+
+impl<L: Lens, O: 'static> Copy for TryMapRef<L, O> {}
+
+impl<L: Lens, O: 'static> Clone for TryMapRef<L, O> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<L: Lens, O: 'static + Clone> Lens for TryMapRef<L, O> {
+    type Source = L::Source;
+    type Target = O;
+
+    fn view<'a>(&self, source: &'a Self::Source) -> Option<LensValue<'a, Self::Target>> {
+        let closure = MAPS.with_borrow(|f| {
+            let (_, any) = f.get(&self.id)?;
+            let TryMapRefState { closure } = any.downcast_ref()?;
+            Some(closure.clone())
+        })?;
+
+        match self.lens.view(source)? {
+            LensValue::Borrowed(target) => closure(target).map(LensValue::Borrowed),
+            LensValue::Owned(target) => closure(&target).cloned().map(LensValue::Owned),
+        }
+    }
+}
+
+impl<L: Lens, O: 'static> Debug for TryMapRef<L, O> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{:?}.try_map_ref(?)", self.lens))
+    }
+}
+
+impl<L: Lens, O: 'static> Hash for TryMapRef<L, O> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.lens.hash(state);
+        self.id.hash(state);
+    }
+}
+
 /// `Lens` composed of two lenses joined together
 #[derive(Hash)]
 pub struct Then<A, B> {
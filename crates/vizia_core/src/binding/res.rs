@@ -136,6 +136,9 @@ impl_res_simple!(TextDecorationLine);
 impl_res_clone!(TextStroke);
 impl_res_clone!(TextStrokeStyle);
 impl_res_simple!(Alignment);
+impl_res_simple!(ContainerType);
+impl_res_simple!(ColorModel);
+impl_res_clone!(ContainerName);
 
 impl<'i> ResGet<FontFamily<'i>> for FontFamily<'i> {
     fn get_ref<'a>(&'a self, _: &'a impl DataContext) -> Option<LensValue<'a, Self>> {
@@ -61,6 +61,11 @@ impl AccessNode {
         self.node_builder.set_text_selection(text_selection);
     }
 
+    /// Sets whether the node, such as a tree item, is expanded to show its children.
+    pub fn set_expanded(&mut self, expanded: bool) {
+        self.node_builder.set_expanded(expanded);
+    }
+
     /// Sets the accessibility bounds of the node. This is not the same as the layout bounds.
     pub fn set_bounds(&mut self, bounds: BoundingBox) {
         self.node_builder.set_bounds(Rect {
@@ -5,6 +5,7 @@ use vizia_window::{WindowDescription, WindowPosition};
 
 use super::EventProxy;
 use crate::{cache::CachedData, prelude::*, systems::*};
+use vizia_style::MediaState;
 
 #[cfg(feature = "clipboard")]
 use copypasta::ClipboardProvider;
@@ -81,6 +82,16 @@ impl BackendContext {
             .height
             .insert(window_entity, Units::Pixels(window_description.inner_size.height as f32));
 
+        self.0.style.set_media(
+            MediaState {
+                width: window_description.inner_size.width as f32,
+                height: window_description.inner_size.height as f32,
+                scale_factor: dpi_factor,
+                ..self.0.style.media
+            },
+            &self.0.tree,
+        );
+
         self.0.style.disabled.insert(window_entity, false);
 
         self.0.style.pseudo_classes.insert(window_entity, PseudoClassFlags::OVER);
@@ -127,6 +138,10 @@ impl BackendContext {
     /// Sets the scale factor used by the application.
     pub fn set_scale_factor(&mut self, scale: f64) {
         self.0.style.dpi_factor = scale;
+        self.0.style.set_media(
+            MediaState { scale_factor: scale as f32, ..self.0.style.media },
+            &self.0.tree,
+        );
     }
 
     /// Sets the size of the window.
@@ -145,6 +160,14 @@ impl BackendContext {
         let logical_height = self.0.style.physical_to_logical(physical_height);
         self.0.style.width.insert(window_entity, Units::Pixels(logical_width));
         self.0.style.height.insert(window_entity, Units::Pixels(logical_height));
+
+        // Media queries are evaluated against the size of the main window.
+        if window_entity == Entity::root() {
+            self.0.style.set_media(
+                MediaState { width: logical_width, height: logical_height, ..self.0.style.media },
+                &self.0.tree,
+            );
+        }
     }
 
     pub fn set_window_position(&mut self, window_entity: Entity, physical_x: f32, physical_y: f32) {
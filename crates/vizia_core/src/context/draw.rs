@@ -9,11 +9,10 @@ use skia_safe::{
 };
 use std::any::{Any, TypeId};
 use std::f32::consts::SQRT_2;
-use vizia_style::LengthPercentageOrAuto;
+use vizia_style::{resolve_color_stops, LengthPercentageOrAuto};
 
 use hashbrown::HashMap;
 
-use crate::animation::Interpolator;
 use crate::cache::CachedData;
 use crate::events::ViewHandler;
 use crate::model::ModelDataStore;
@@ -92,10 +91,10 @@ macro_rules! get_color_property {
     ) => {
         $(#[$meta])*
         pub fn $name(&self) -> Color {
-            if let Some(col) = self.style.$name.get(self.current) {
-                Color::rgba(col.r(), col.g(), col.b(), col.a())
-            } else {
-                Color::rgba(0, 0, 0, 0)
+            match self.style.$name.get(self.current) {
+                Some(Color::CurrentColor) => self.current_color(),
+                Some(col) => *col,
+                None => Color::rgba(0, 0, 0, 0),
             }
         }
     };
@@ -239,20 +238,14 @@ impl DrawContext<'_> {
 
         // Apply transform functions.
         if let Some(transforms) = self.style.transform.get(self.current) {
-            // Check if the transform is currently animating
-            // Get the animation state
-            // Manually interpolate the value to get the overall transform for the current frame
-            if let Some(animation_state) = self.style.transform.get_active_animation(self.current) {
-                if let Some(start) = animation_state.keyframes.first() {
-                    if let Some(end) = animation_state.keyframes.last() {
-                        let start_transform = start.value.as_transform(bounds, scale_factor);
-                        let end_transform = end.value.as_transform(bounds, scale_factor);
-                        let t = animation_state.t;
-                        let animated_transform =
-                            Matrix::interpolate(&start_transform, &end_transform, t);
-                        transform = transform * animated_transform;
-                    }
-                }
+            // Transform animations are interpolated between keyframes using the bounds of the view
+            if let Some(animation_state) = self
+                .style
+                .transform
+                .get_active_animation(self.current)
+                .filter(|animation_state| animation_state.output.is_some())
+            {
+                transform = transform * animation_state.as_transform(bounds, scale_factor);
             } else {
                 transform = transform * transforms.as_transform(bounds, scale_factor);
             }
@@ -321,10 +314,33 @@ impl DrawContext<'_> {
     }
 
     get_length_property!(
-        /// Returns the border width of the current view in physical pixels.
-        border_width
+        /// Returns the width of the top border of the current view in physical pixels.
+        border_top_width
     );
 
+    get_length_property!(
+        /// Returns the width of the right border of the current view in physical pixels.
+        border_right_width
+    );
+
+    get_length_property!(
+        /// Returns the width of the bottom border of the current view in physical pixels.
+        border_bottom_width
+    );
+
+    get_length_property!(
+        /// Returns the width of the left border of the current view in physical pixels.
+        border_left_width
+    );
+
+    /// Returns the width of the top border of the current view in physical pixels.
+    #[deprecated(
+        note = "use the per-side border width methods, such as `border_top_width`, instead"
+    )]
+    pub fn border_width(&self) -> f32 {
+        self.border_top_width()
+    }
+
     get_color_property!(
         /// Returns the outline color of the current view.
         outline_color
@@ -425,14 +441,78 @@ impl DrawContext<'_> {
     }
 
     get_color_property!(background_color);
-    get_color_property!(border_color);
 
-    /// Returns the border style of the current view.
+    get_color_property!(
+        /// Returns the color of the top border of the current view.
+        border_top_color
+    );
+
+    get_color_property!(
+        /// Returns the color of the right border of the current view.
+        border_right_color
+    );
+
+    get_color_property!(
+        /// Returns the color of the bottom border of the current view.
+        border_bottom_color
+    );
+
+    get_color_property!(
+        /// Returns the color of the left border of the current view.
+        border_left_color
+    );
+
+    /// Returns the color of the top border of the current view.
+    #[deprecated(
+        note = "use the per-side border color methods, such as `border_top_color`, instead"
+    )]
+    pub fn border_color(&self) -> Color {
+        self.border_top_color()
+    }
+
+    /// Returns the style of the top border of the current view.
+    pub fn border_top_style(&self) -> BorderStyleKeyword {
+        self.style.border_top_style.get(self.current).copied().unwrap_or_default()
+    }
+
+    /// Returns the style of the right border of the current view.
+    pub fn border_right_style(&self) -> BorderStyleKeyword {
+        self.style.border_right_style.get(self.current).copied().unwrap_or_default()
+    }
+
+    /// Returns the style of the bottom border of the current view.
+    pub fn border_bottom_style(&self) -> BorderStyleKeyword {
+        self.style.border_bottom_style.get(self.current).copied().unwrap_or_default()
+    }
+
+    /// Returns the style of the left border of the current view.
+    pub fn border_left_style(&self) -> BorderStyleKeyword {
+        self.style.border_left_style.get(self.current).copied().unwrap_or_default()
+    }
+
+    /// Returns the style of the top border of the current view.
+    #[deprecated(
+        note = "use the per-side border style methods, such as `border_top_style`, instead"
+    )]
     pub fn border_style(&self) -> BorderStyleKeyword {
-        self.style.border_style.get(self.current).copied().unwrap_or_default()
+        self.border_top_style()
+    }
+
+    /// Returns the value of `currentcolor` for the current view, which is its font color.
+    pub fn current_color(&self) -> Color {
+        self.style.current_color(self.tree, self.current)
     }
 
     get_color_property!(selection_color);
+
+    /// Returns the color of the selected text of the current view, if set by a `::selection` rule.
+    pub fn selection_font_color(&self) -> Option<Color> {
+        self.style
+            .selection_font_color
+            .get(self.current)
+            .map(|color| color.resolve(self.current_color()))
+    }
+
     get_color_property!(caret_color);
     get_color_property!(font_color);
 
@@ -461,7 +541,11 @@ impl DrawContext<'_> {
         self.style.shadow.get(self.current)
     }
 
-    pub fn backdrop_filter(&self) -> Option<&Filter> {
+    pub fn filter(&self) -> Option<&Vec<Filter>> {
+        self.style.filter.get(self.current)
+    }
+
+    pub fn backdrop_filter(&self) -> Option<&Vec<Filter>> {
         self.style.backdrop_filter.get(self.current)
     }
 
@@ -480,16 +564,6 @@ impl DrawContext<'_> {
         let corner_bottom_right_radius = self.corner_bottom_right_radius();
         let corner_bottom_left_radius = self.corner_bottom_left_radius();
 
-        let corner_top_left_shape = self.corner_top_left_shape();
-        let corner_top_right_shape = self.corner_top_right_shape();
-        let corner_bottom_right_shape = self.corner_bottom_right_shape();
-        let corner_bottom_left_shape = self.corner_bottom_left_shape();
-
-        let corner_top_left_smoothing = self.corner_top_left_smoothing();
-        let corner_top_right_smoothing = self.corner_top_right_smoothing();
-        let corner_bottom_right_smoothing = self.corner_bottom_right_smoothing();
-        let corner_bottom_left_smoothing = self.corner_bottom_left_smoothing();
-
         let rect: Rect = bounds.into();
 
         let mut rr = RRect::new_rect_radii(
@@ -504,31 +578,72 @@ impl DrawContext<'_> {
 
         rr = rr.with_outset(outset);
 
-        let x = rr.bounds().x();
-        let y = rr.bounds().y();
-        let width = rr.width();
-        let height = rr.height();
+        let bounds =
+            BoundingBox { x: rr.bounds().x(), y: rr.bounds().y(), w: rr.width(), h: rr.height() };
+
+        self.build_corner_path(
+            bounds,
+            [
+                rr.radii(Corner::UpperLeft).x,
+                rr.radii(Corner::UpperRight).x,
+                rr.radii(Corner::LowerRight).x,
+                rr.radii(Corner::LowerLeft).x,
+            ],
+        )
+    }
+
+    /// Get the vector path of the current view, inset by the given amounts on the top, right, bottom and left sides.
+    ///
+    /// The radius of each corner is reduced by the larger inset of its two sides, so the path follows the inner edge
+    /// of a border with these widths.
+    fn build_inset_path(&self, bounds: BoundingBox, insets: [f32; 4]) -> Path {
+        let [top, right, bottom, left] = insets;
+
+        let bounds = bounds.shrink_sides(left, top, right, bottom);
+        if bounds.w <= 0.0 || bounds.h <= 0.0 {
+            return Path::new();
+        }
+
+        self.build_corner_path(
+            bounds,
+            [
+                (self.corner_top_left_radius() - top.max(left)).max(0.0),
+                (self.corner_top_right_radius() - top.max(right)).max(0.0),
+                (self.corner_bottom_right_radius() - bottom.max(right)).max(0.0),
+                (self.corner_bottom_left_radius() - bottom.max(left)).max(0.0),
+            ],
+        )
+    }
+
+    // Builds the path of a box with the given top-left, top-right, bottom-right and bottom-left corner radii, using the
+    // corner shapes and smoothing of the current view.
+    fn build_corner_path(&self, bounds: BoundingBox, radii: [f32; 4]) -> Path {
+        let corner_top_left_shape = self.corner_top_left_shape();
+        let corner_top_right_shape = self.corner_top_right_shape();
+        let corner_bottom_right_shape = self.corner_bottom_right_shape();
+        let corner_bottom_left_shape = self.corner_bottom_left_shape();
+
+        let corner_top_left_smoothing = self.corner_top_left_smoothing();
+        let corner_top_right_smoothing = self.corner_top_right_smoothing();
+        let corner_bottom_right_smoothing = self.corner_bottom_right_smoothing();
+        let corner_bottom_left_smoothing = self.corner_bottom_left_smoothing();
+
+        let [top_left, top_right, bottom_right, bottom_left] = radii;
+
+        let x = bounds.x;
+        let y = bounds.y;
+        let width = bounds.w;
+        let height = bounds.h;
 
         //TODO: Cache the path and regenerate if the bounds change
         let mut path = Path::new();
 
-        if width == height
-            && corner_bottom_left_radius == width / 2.0
-            && corner_bottom_right_radius == width / 2.0
-            && corner_top_left_radius == height / 2.0
-            && corner_top_right_radius == height / 2.0
-        {
-            path.add_circle((width / 2.0, bounds.h / 2.0), width / 2.0, PathDirection::CW);
+        if width == height && radii.iter().all(|radius| *radius == width / 2.0) {
+            path.add_circle((width / 2.0, height / 2.0), width / 2.0, PathDirection::CW);
         } else {
-            let top_right = rr.radii(Corner::UpperRight).x;
-
             if top_right > 0.0 {
-                let (a, b, c, d, l, p, radius) = compute_smooth_corner(
-                    top_right,
-                    corner_top_right_smoothing,
-                    bounds.width(),
-                    bounds.height(),
-                );
+                let (a, b, c, d, l, p, radius) =
+                    compute_smooth_corner(top_right, corner_top_right_smoothing, width, height);
 
                 path.move_to((f32::max(width / 2.0, width - p), 0.0));
                 if corner_top_right_shape == CornerShape::Round {
@@ -558,7 +673,6 @@ impl DrawContext<'_> {
                     .line_to((width, height / 2.0));
             }
 
-            let bottom_right = rr.radii(Corner::LowerRight).x;
             if bottom_right > 0.0 {
                 let (a, b, c, d, l, p, radius) = compute_smooth_corner(
                     bottom_right,
@@ -593,7 +707,6 @@ impl DrawContext<'_> {
                 path.line_to((width, height)).line_to((width / 2.0, height));
             }
 
-            let bottom_left = rr.radii(Corner::LowerLeft).x;
             if bottom_left > 0.0 {
                 let (a, b, c, d, l, p, radius) =
                     compute_smooth_corner(bottom_left, corner_bottom_left_smoothing, width, height);
@@ -624,7 +737,6 @@ impl DrawContext<'_> {
                 path.line_to((0.0, height)).line_to((0.0, height / 2.0));
             }
 
-            let top_left = rr.radii(Corner::UpperLeft).x;
             if top_left > 0.0 {
                 let (a, b, c, d, l, p, radius) =
                     compute_smooth_corner(top_left, corner_top_left_smoothing, width, height);
@@ -657,8 +769,9 @@ impl DrawContext<'_> {
 
     /// Draw background color or background image (including gradients) for the current view.
     pub fn draw_background(&mut self, canvas: &Canvas) {
-        let border_width = self.border_width();
-        let path = self.build_path(self.bounds(), (-border_width / 2.0, -border_width / 2.0));
+        // The background extends halfway under the border on each side.
+        let path =
+            self.build_inset_path(self.bounds(), self.border_widths().map(|width| width / 2.0));
         let background_color = self.background_color();
         if background_color.a() != 0 {
             let mut paint = Paint::default();
@@ -675,37 +788,95 @@ impl DrawContext<'_> {
         self.draw_background_images(canvas, &path);
     }
 
+    // Returns the widths of the top, right, bottom and left borders of the current view.
+    fn border_widths(&self) -> [f32; 4] {
+        [
+            self.border_top_width(),
+            self.border_right_width(),
+            self.border_bottom_width(),
+            self.border_left_width(),
+        ]
+    }
+
     /// Draw the border of the current view.
+    ///
+    /// Each side of the border can have its own width, color, and style. Adjacent sides meet along a line from the
+    /// outer corner of the border towards its inner corner, following the shape of rounded and smoothed corners.
     pub fn draw_border(&mut self, canvas: &Canvas) {
-        let border_color = self.border_color();
-        let border_width = self.border_width();
-        let border_style = self.border_style();
+        let widths = self.border_widths();
+        let colors = [
+            self.border_top_color(),
+            self.border_right_color(),
+            self.border_bottom_color(),
+            self.border_left_color(),
+        ];
+        let styles = [
+            self.border_top_style(),
+            self.border_right_style(),
+            self.border_bottom_style(),
+            self.border_left_style(),
+        ];
+
+        let is_visible = |side: usize| {
+            widths[side] > 0.0 && colors[side].a() > 0 && styles[side] != BorderStyleKeyword::None
+        };
 
-        if border_width > 0.0 && border_color.a() > 0 && border_style != BorderStyleKeyword::None {
-            let bounds = self.bounds();
-            let path = self.build_path(bounds, (-border_width / 2.0, -border_width / 2.0));
-            let mut paint = Paint::default();
-            paint.set_style(PaintStyle::Stroke);
-            paint.set_color(border_color);
-            paint.set_stroke_width(border_width);
-            match border_style {
-                BorderStyleKeyword::Dashed => {
-                    paint.set_path_effect(PathEffect::dash(
-                        &[border_width * 2.0, border_width],
-                        0.0,
-                    ));
+        if !(0..4).any(is_visible) {
+            return;
+        }
+
+        let bounds = self.bounds();
+
+        // A border which is the same on every side is stroked as a single path so that dashes continue around the
+        // corners.
+        if widths.iter().all(|width| *width == widths[0])
+            && colors.iter().all(|color| *color == colors[0])
+            && styles.iter().all(|style| *style == styles[0])
+        {
+            let path = self.build_path(bounds, (-widths[0] / 2.0, -widths[0] / 2.0));
+            canvas.draw_path(&path, &border_paint(widths[0], colors[0], styles[0]));
+            return;
+        }
+
+        let outer_path = self.build_path(bounds, (0.0, 0.0));
+        let inner_path = self.build_inset_path(bounds, widths);
+        // Dashed and dotted sides are stroked along the middle of the border.
+        let middle_path = self.build_inset_path(bounds, widths.map(|width| width / 2.0));
+        let max_width = widths.iter().copied().fold(0.0, f32::max);
+
+        for (side, region) in border_regions(bounds, widths).iter().enumerate() {
+            if !is_visible(side) {
+                continue;
+            }
+
+            canvas.save();
+            canvas.clip_path(region, ClipOp::Intersect, true);
+            canvas.clip_path(&outer_path, ClipOp::Intersect, true);
+            canvas.clip_path(&inner_path, ClipOp::Difference, true);
+
+            match styles[side] {
+                BorderStyleKeyword::Solid => {
+                    let mut paint = Paint::default();
+                    paint.set_color(colors[side]);
+                    paint.set_anti_alias(true);
+                    canvas.draw_path(&outer_path, &paint);
                 }
 
-                BorderStyleKeyword::Dotted => {
-                    paint.set_path_effect(PathEffect::dash(&[0.0, border_width * 2.0], 0.0));
-                    paint.set_stroke_cap(skia_safe::PaintCap::Round);
+                BorderStyleKeyword::Dashed => {
+                    // Widen the stroke so that the dashes fill the border where it meets a wider side, relying on
+                    // the clip to trim them to the border.
+                    let mut paint = border_paint(widths[side], colors[side], styles[side]);
+                    paint.set_stroke_width(max_width * 2.0);
+                    canvas.draw_path(&middle_path, &paint);
                 }
 
-                _ => {}
+                style => {
+                    canvas
+                        .draw_path(&middle_path, &border_paint(widths[side], colors[side], style));
+                }
             }
 
-            paint.set_anti_alias(true);
-            canvas.draw_path(&path, &paint);
+            canvas.restore();
         }
     }
 
@@ -746,7 +917,7 @@ impl DrawContext<'_> {
             let path = self.build_path(bounds, (0.0, 0.0));
 
             for shadow in shadows.iter().rev() {
-                let shadow_color = shadow.color.unwrap_or_default();
+                let shadow_color = shadow.color.unwrap_or_default().resolve(self.current_color());
 
                 let shadow_x_offset = shadow.x_offset.to_px().unwrap_or(0.0) * self.scale_factor();
                 let shadow_y_offset = shadow.y_offset.to_px().unwrap_or(0.0) * self.scale_factor();
@@ -802,195 +973,174 @@ impl DrawContext<'_> {
         }
     }
 
-    /// Draw background images (including gradients) for the current view.
-    fn draw_background_images(&mut self, canvas: &Canvas, path: &Path) {
-        let bounds = self.bounds();
+    /// Returns a shader which paints the given gradient within the given bounds, or `None` if the gradient has no
+    /// color stops.
+    pub fn gradient_shader(&self, gradient: &Gradient, bounds: BoundingBox) -> Option<Shader> {
+        let scale_factor = self.scale_factor();
+        let repeating = gradient.is_repeating();
+        let tile_mode = if repeating { TileMode::Repeat } else { TileMode::Clamp };
+        let current_color = self.current_color();
+
+        match gradient {
+            Gradient::Linear(gradient) | Gradient::RepeatingLinear(gradient) => {
+                let (start, end, parent_length) = match gradient.direction {
+                    LineDirection::Horizontal(horizontal_keyword) => match horizontal_keyword {
+                        HorizontalPositionKeyword::Left => {
+                            (bounds.center_right(), bounds.center_left(), bounds.width())
+                        }
 
-        if let Some(images) = self.background_images() {
-            let image_sizes = self.background_size();
+                        HorizontalPositionKeyword::Right => {
+                            (bounds.center_left(), bounds.center_right(), bounds.width())
+                        }
+                    },
 
-            for (index, image) in images.iter().enumerate() {
-                match image {
-                    ImageOrGradient::Gradient(gradient) => match gradient {
-                        Gradient::Linear(linear_gradient) => {
-                            let (start, end, parent_length) = match linear_gradient.direction {
-                                LineDirection::Horizontal(horizontal_keyword) => {
-                                    match horizontal_keyword {
-                                        HorizontalPositionKeyword::Left => (
-                                            bounds.center_right(),
-                                            bounds.center_left(),
-                                            bounds.width(),
-                                        ),
-
-                                        HorizontalPositionKeyword::Right => (
-                                            bounds.center_left(),
-                                            bounds.center_right(),
-                                            bounds.width(),
-                                        ),
-                                    }
-                                }
+                    LineDirection::Vertical(vertical_keyword) => match vertical_keyword {
+                        VerticalPositionKeyword::Top => {
+                            (bounds.center_bottom(), bounds.center_top(), bounds.height())
+                        }
 
-                                LineDirection::Vertical(vertical_keyword) => match vertical_keyword
-                                {
-                                    VerticalPositionKeyword::Top => (
-                                        bounds.center_bottom(),
-                                        bounds.center_top(),
-                                        bounds.height(),
-                                    ),
-
-                                    VerticalPositionKeyword::Bottom => (
-                                        bounds.center_top(),
-                                        bounds.center_bottom(),
-                                        bounds.height(),
-                                    ),
-                                },
-
-                                LineDirection::Corner { horizontal, vertical } => {
-                                    match (horizontal, vertical) {
-                                        (
-                                            HorizontalPositionKeyword::Right,
-                                            VerticalPositionKeyword::Bottom,
-                                        ) => (
-                                            bounds.top_left(),
-                                            bounds.bottom_right(),
-                                            bounds.diagonal(),
-                                        ),
-
-                                        (
-                                            HorizontalPositionKeyword::Right,
-                                            VerticalPositionKeyword::Top,
-                                        ) => (
-                                            bounds.bottom_left(),
-                                            bounds.top_right(),
-                                            bounds.diagonal(),
-                                        ),
-
-                                        _ => (bounds.top_left(), bounds.bottom_right(), 0.0),
-                                    }
-                                }
+                        VerticalPositionKeyword::Bottom => {
+                            (bounds.center_top(), bounds.center_bottom(), bounds.height())
+                        }
+                    },
 
-                                LineDirection::Angle(angle) => {
-                                    let angle_rad = angle.to_radians();
-                                    let start_x =
-                                        bounds.x + ((angle_rad.sin() * bounds.w) - bounds.w) / -2.0;
-                                    let end_x =
-                                        bounds.x + ((angle_rad.sin() * bounds.w) + bounds.w) / 2.0;
-                                    let start_y =
-                                        bounds.y + ((angle_rad.cos() * bounds.h) + bounds.h) / 2.0;
-                                    let end_y =
-                                        bounds.y + ((angle_rad.cos() * bounds.h) - bounds.h) / -2.0;
+                    LineDirection::Corner { horizontal, vertical } => {
+                        match (horizontal, vertical) {
+                            (HorizontalPositionKeyword::Right, VerticalPositionKeyword::Bottom) => {
+                                (bounds.top_left(), bounds.bottom_right(), bounds.diagonal())
+                            }
 
-                                    let x = (end_x - start_x).abs();
-                                    let y = (end_y - start_y).abs();
+                            (HorizontalPositionKeyword::Right, VerticalPositionKeyword::Top) => {
+                                (bounds.bottom_left(), bounds.top_right(), bounds.diagonal())
+                            }
 
-                                    let dist = (x * x + y * y).sqrt();
+                            _ => (bounds.top_left(), bounds.bottom_right(), 0.0),
+                        }
+                    }
 
-                                    ((start_x, start_y), (end_x, end_y), dist)
-                                }
-                            };
-
-                            let num_stops = linear_gradient.stops.len();
-
-                            let mut stops = linear_gradient
-                                .stops
-                                .iter()
-                                .enumerate()
-                                .map(|(index, stop)| {
-                                    let pos = if let Some(pos) = &stop.position {
-                                        pos.to_pixels(parent_length, self.scale_factor())
-                                            / parent_length
-                                    } else {
-                                        index as f32 / (num_stops - 1) as f32
-                                    };
-                                    (pos, skia_safe::Color::from(stop.color))
-                                })
-                                .collect::<Vec<_>>();
-
-                            // Insert a stop at the front if the first stop is not at 0.
-                            if let Some(first) = stops.first() {
-                                if first.0 != 0.0 {
-                                    stops.insert(0, (0.0, first.1));
-                                }
-                            }
+                    LineDirection::Angle(angle) => {
+                        let angle_rad = angle.to_radians();
+                        let start_x = bounds.x + ((angle_rad.sin() * bounds.w) - bounds.w) / -2.0;
+                        let end_x = bounds.x + ((angle_rad.sin() * bounds.w) + bounds.w) / 2.0;
+                        let start_y = bounds.y + ((angle_rad.cos() * bounds.h) + bounds.h) / 2.0;
+                        let end_y = bounds.y + ((angle_rad.cos() * bounds.h) - bounds.h) / -2.0;
 
-                            // Insert a stop at the end if the last stop is not at 1.0.
-                            if let Some(last) = stops.last() {
-                                if last.0 != 1.0 {
-                                    stops.push((1.0, last.1));
-                                }
-                            }
+                        let x = (end_x - start_x).abs();
+                        let y = (end_y - start_y).abs();
 
-                            let (offsets, colors): (Vec<f32>, Vec<skia_safe::Color>) =
-                                stops.into_iter().unzip();
+                        let dist = (x * x + y * y).sqrt();
 
-                            let shader = Shader::linear_gradient(
-                                (Point::from(start), Point::from(end)),
-                                GradientShaderColors::Colors(&colors[..]),
-                                Some(&offsets[..]),
-                                TileMode::Clamp,
-                                None,
-                                None,
-                            );
+                        ((start_x, start_y), (end_x, end_y), dist)
+                    }
+                };
 
-                            let mut paint = Paint::default();
-                            paint.set_shader(shader);
+                let stops = resolve_color_stops(
+                    &gradient.items,
+                    &gradient.interpolation,
+                    current_color,
+                    |pos| pos.to_pixels(parent_length, scale_factor) / parent_length,
+                );
 
-                            canvas.draw_path(path, &paint);
-                        }
+                let (offsets, colors, (from, to)) = shader_stops(stops, repeating)?;
 
-                        Gradient::Radial(radial_gradient) => {
-                            let num_stops = radial_gradient.stops.len();
-
-                            let mut stops = radial_gradient
-                                .stops
-                                .iter()
-                                .enumerate()
-                                .map(|(index, stop)| {
-                                    let pos = if let Some(pos) = &stop.position {
-                                        pos.to_pixels(bounds.width(), self.scale_factor())
-                                            / bounds.width()
-                                    } else {
-                                        index as f32 / (num_stops - 1) as f32
-                                    };
-
-                                    (pos, skia_safe::Color::from(stop.color))
-                                })
-                                .collect::<Vec<_>>();
-
-                            // Insert a stop at the front if the first stop is not at 0.
-                            if let Some(first) = stops.first() {
-                                if first.0 != 0.0 {
-                                    stops.insert(0, (0.0, first.1));
-                                }
-                            }
+                let point_at = |t: f32| {
+                    Point::new(start.0 + (end.0 - start.0) * t, start.1 + (end.1 - start.1) * t)
+                };
 
-                            // Insert a stop at the end if the last stop is not at 1.0.
-                            if let Some(last) = stops.last() {
-                                if last.0 != 1.0 {
-                                    stops.push((1.0, last.1));
-                                }
-                            }
+                Shader::linear_gradient(
+                    (point_at(from), point_at(to)),
+                    GradientShaderColors::Colors(&colors[..]),
+                    Some(&offsets[..]),
+                    tile_mode,
+                    None,
+                    None,
+                )
+            }
+
+            Gradient::Radial(gradient) | Gradient::RepeatingRadial(gradient) => {
+                let center = gradient_center(&gradient.position, bounds, scale_factor);
+                let radius = bounds.w.max(bounds.h);
+
+                let stops = resolve_color_stops(
+                    &gradient.items,
+                    &gradient.interpolation,
+                    current_color,
+                    |pos| pos.to_pixels(bounds.width(), scale_factor) / bounds.width(),
+                );
+
+                let (offsets, colors, (from, to)) = shader_stops(stops, repeating)?;
+
+                if from == 0.0 {
+                    Shader::radial_gradient(
+                        center,
+                        radius * to,
+                        GradientShaderColors::Colors(&colors[..]),
+                        Some(&offsets[..]),
+                        tile_mode,
+                        None,
+                        None,
+                    )
+                } else {
+                    Shader::two_point_conical_gradient(
+                        center,
+                        radius * from,
+                        center,
+                        radius * to,
+                        GradientShaderColors::Colors(&colors[..]),
+                        Some(&offsets[..]),
+                        tile_mode,
+                        None,
+                        None,
+                    )
+                }
+            }
+
+            Gradient::Conic(gradient) | Gradient::RepeatingConic(gradient) => {
+                let center = gradient_center(&gradient.position, bounds, scale_factor);
+
+                let stops = resolve_color_stops(
+                    &gradient.items,
+                    &gradient.interpolation,
+                    current_color,
+                    |pos| pos.to_turns(),
+                );
+
+                let (offsets, colors, (from, to)) = shader_stops(stops, repeating)?;
 
-                            let (offsets, colors): (Vec<f32>, Vec<skia_safe::Color>) =
-                                stops.into_iter().unzip();
+                // A sweep gradient starts at the positive x-axis, while a conic gradient starts at the top.
+                let matrix = Matrix::rotate_deg_pivot(gradient.angle.to_degrees() - 90.0, center);
+
+                Shader::sweep_gradient(
+                    center,
+                    GradientShaderColors::Colors(&colors[..]),
+                    Some(&offsets[..]),
+                    tile_mode,
+                    Some((from * 360.0, to * 360.0)),
+                    None,
+                    Some(&matrix),
+                )
+            }
+
+            Gradient::None => None,
+        }
+    }
 
-                            let shader = Shader::radial_gradient(
-                                Point::from(bounds.center()),
-                                bounds.w.max(bounds.h),
-                                GradientShaderColors::Colors(&colors[..]),
-                                Some(&offsets[..]),
-                                TileMode::Clamp,
-                                None,
-                                None,
-                            );
+    /// Draw background images (including gradients) for the current view.
+    fn draw_background_images(&mut self, canvas: &Canvas, path: &Path) {
+        let bounds = self.bounds();
+
+        if let Some(images) = self.background_images() {
+            let image_sizes = self.background_size();
 
+            for (index, image) in images.iter().enumerate() {
+                match image {
+                    ImageOrGradient::Gradient(gradient) => {
+                        if let Some(shader) = self.gradient_shader(gradient, bounds) {
                             let mut paint = Paint::default();
                             paint.set_shader(shader);
                             canvas.draw_path(path, &paint);
                         }
-
-                        _ => {}
-                    },
+                    }
 
                     ImageOrGradient::Image(image_name) => {
                         if let Some(image_id) = self.resource_manager.image_ids.get(image_name) {
@@ -1195,6 +1345,110 @@ impl DataContext for DrawContext<'_> {
     }
 }
 
+// Returns the paint used to stroke a border with the given width, color, and style.
+fn border_paint(width: f32, color: Color, style: BorderStyleKeyword) -> Paint {
+    let mut paint = Paint::default();
+    paint.set_style(PaintStyle::Stroke);
+    paint.set_color(color);
+    paint.set_stroke_width(width);
+    match style {
+        BorderStyleKeyword::Dashed => {
+            paint.set_path_effect(PathEffect::dash(&[width * 2.0, width], 0.0));
+        }
+
+        BorderStyleKeyword::Dotted => {
+            paint.set_path_effect(PathEffect::dash(&[0.0, width * 2.0], 0.0));
+            paint.set_stroke_cap(skia_safe::PaintCap::Round);
+        }
+
+        _ => {}
+    }
+
+    paint.set_anti_alias(true);
+    paint
+}
+
+/// Returns the center of a radial or conic gradient within the bounds.
+fn gradient_center(position: &Position, bounds: BoundingBox, scale_factor: f32) -> Point {
+    Point::new(
+        bounds.x + position.x.to_length_or_percentage().to_pixels(bounds.w, scale_factor),
+        bounds.y + position.y.to_length_or_percentage().to_pixels(bounds.h, scale_factor),
+    )
+}
+
+/// Converts resolved color stops into the offsets and colors of a gradient shader, along with the range of the
+/// gradient line, as fractions, that the offsets span.
+fn shader_stops(
+    mut stops: Vec<(f32, RGBA)>,
+    repeating: bool,
+) -> Option<(Vec<f32>, Vec<skia_safe::Color>, (f32, f32))> {
+    let first = stops.first()?.0;
+    let last = stops.last()?.0;
+
+    // A repeating gradient repeats the span between its first and last stops, so the shader is fitted to that span.
+    if repeating && last > first {
+        let (offsets, colors) = stops
+            .into_iter()
+            .map(|(pos, color)| ((pos - first) / (last - first), skia_safe::Color::from(color)))
+            .unzip();
+
+        return Some((offsets, colors, (first, last)));
+    }
+
+    // Insert a stop at the front if the first stop is not at 0.
+    if first > 0.0 {
+        stops.insert(0, (0.0, stops[0].1));
+    }
+
+    // Insert a stop at the end if the last stop is not at 1.0.
+    if last < 1.0 {
+        stops.push((1.0, stops[stops.len() - 1].1));
+    }
+
+    let (offsets, colors) = stops
+        .into_iter()
+        .map(|(pos, color)| (pos.clamp(0.0, 1.0), skia_safe::Color::from(color)))
+        .unzip();
+
+    Some((offsets, colors, (0.0, 1.0)))
+}
+
+// Returns the regions of the top, right, bottom, and left borders of a view. The regions are divided by lines from
+// each corner towards the center of the view, at an angle which passes through the inner corner of the border.
+fn border_regions(bounds: BoundingBox, widths: [f32; 4]) -> [Path; 4] {
+    let [top, right, bottom, left] = widths;
+    let (center_x, center_y) = bounds.center();
+
+    let corners =
+        [bounds.top_left(), bounds.top_right(), bounds.bottom_right(), bounds.bottom_left()];
+    let directions = [(left, top), (-right, top), (-right, -bottom), (left, -bottom)];
+
+    let joins: [(f32, f32); 4] = std::array::from_fn(|corner| {
+        let (x, y) = corners[corner];
+        let (dx, dy) = directions[corner];
+        let tx = if dx != 0.0 { (center_x - x) / dx } else { f32::INFINITY };
+        let ty = if dy != 0.0 { (center_y - y) / dy } else { f32::INFINITY };
+        let t = tx.min(ty);
+        if t.is_finite() {
+            (x + dx * t, y + dy * t)
+        } else {
+            (x, y)
+        }
+    });
+
+    std::array::from_fn(|side| {
+        let next = (side + 1) % 4;
+        let mut path = Path::new();
+        path.move_to(corners[side])
+            .line_to(corners[next])
+            .line_to(joins[next])
+            .line_to((center_x, center_y))
+            .line_to(joins[side])
+            .close();
+        path
+    })
+}
+
 // Helper function for computing a rounded corner with variable smoothing
 fn compute_smooth_corner(
     corner_radius: f32,
@@ -1231,3 +1485,62 @@ fn compute_smooth_corner(
 
     (a, b, c, d, l, p, corner_radius)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::style_system;
+
+    #[test]
+    #[allow(deprecated)]
+    fn per_side_border_inputs() {
+        let cx = &mut Context::new();
+        cx.style.dpi_factor = 1.0;
+        cx.add_stylesheet(
+            r#"
+            .a {
+                border: 2px solid red;
+                border-right: 4px dashed blue;
+                border-bottom-width: 10%;
+                border-left-style: dotted;
+            }
+            "#,
+        )
+        .unwrap();
+
+        let entity = Element::new(cx).class("a").entity();
+        cx.needs_restyle(Entity::root());
+        style_system(cx);
+        cx.cache.set_bounds(entity, BoundingBox::from_min_max(0.0, 0.0, 100.0, 50.0));
+
+        let cx = DrawContext {
+            current: entity,
+            style: &cx.style,
+            cache: &cx.cache,
+            tree: &cx.tree,
+            data: &cx.data,
+            views: &mut cx.views,
+            resource_manager: &cx.resource_manager,
+            text_context: &mut cx.text_context,
+            modifiers: &cx.modifiers,
+            mouse: &cx.mouse,
+            windows: &mut cx.windows,
+        };
+
+        // Percentage widths are relative to the smaller dimension of the bounds.
+        assert_eq!(cx.border_widths(), [2.0, 4.0, 5.0, 2.0]);
+        assert_eq!(cx.border_top_color(), Color::rgb(255, 0, 0));
+        assert_eq!(cx.border_right_color(), Color::rgb(0, 0, 255));
+        assert_eq!(cx.border_bottom_color(), Color::rgb(255, 0, 0));
+        assert_eq!(cx.border_left_color(), Color::rgb(255, 0, 0));
+        assert_eq!(cx.border_top_style(), BorderStyleKeyword::Solid);
+        assert_eq!(cx.border_right_style(), BorderStyleKeyword::Dashed);
+        assert_eq!(cx.border_bottom_style(), BorderStyleKeyword::Solid);
+        assert_eq!(cx.border_left_style(), BorderStyleKeyword::Dotted);
+
+        // The deprecated getters return the values of the top border.
+        assert_eq!(cx.border_width(), 2.0);
+        assert_eq!(cx.border_color(), Color::rgb(255, 0, 0));
+        assert_eq!(cx.border_style(), BorderStyleKeyword::Solid);
+    }
+}
@@ -5,10 +5,12 @@ use std::error::Error;
 use std::rc::Rc;
 
 use hashbrown::{HashMap, HashSet};
-use vizia_storage::{LayoutTreeIterator, TreeIterator};
+use log::warn;
+use vizia_storage::{ChildIterator, LayoutTreeIterator, TreeIterator};
+use vizia_style::MediaState;
 use vizia_window::WindowPosition;
 
-use crate::animation::{AnimId, Interpolator};
+use crate::animation::{AnimId, PlaybackControl};
 use crate::cache::CachedData;
 use crate::events::{TimedEvent, TimedEventHandle, TimerState, ViewHandler};
 use crate::model::ModelDataStore;
@@ -226,12 +228,20 @@ impl<'a> EventContext<'a> {
         self.mouse
     }
 
+    /// Returns the nth child of the current view, ignoring content generated by `::before` and `::after` rules.
     pub fn nth_child(&self, n: usize) -> Option<Entity> {
-        self.tree.get_child(self.current, n)
+        self.children().nth(n)
     }
 
+    /// Returns the last child of the current view, ignoring content generated by `::before` and `::after` rules.
     pub fn last_child(&self) -> Option<Entity> {
-        self.tree.get_last_child(self.current).copied()
+        self.children().last()
+    }
+
+    // Returns an iterator over the children of the current view, excluding generated content.
+    fn children(&self) -> impl Iterator<Item = Entity> + '_ {
+        ChildIterator::new(self.tree, self.current)
+            .filter(|child| !self.style.generated_content.contains(*child))
     }
 
     pub fn with_current<T>(&mut self, entity: Entity, f: impl FnOnce(&mut Self) -> T) -> T {
@@ -357,20 +367,14 @@ impl<'a> EventContext<'a> {
 
         // Apply transform functions.
         if let Some(transforms) = self.style.transform.get(self.current) {
-            // Check if the transform is currently animating
-            // Get the animation state
-            // Manually interpolate the value to get the overall transform for the current frame
-            if let Some(animation_state) = self.style.transform.get_active_animation(self.current) {
-                if let Some(start) = animation_state.keyframes.first() {
-                    if let Some(end) = animation_state.keyframes.last() {
-                        let start_transform = start.value.as_transform(bounds, scale_factor);
-                        let end_transform = end.value.as_transform(bounds, scale_factor);
-                        let t = animation_state.t;
-                        let animated_transform =
-                            Matrix::interpolate(&start_transform, &end_transform, t);
-                        transform = transform * animated_transform;
-                    }
-                }
+            // Transform animations are interpolated between keyframes using the bounds of the view
+            if let Some(animation_state) = self
+                .style
+                .transform
+                .get_active_animation(self.current)
+                .filter(|animation_state| animation_state.output.is_some())
+            {
+                transform = transform * animation_state.as_transform(bounds, scale_factor);
             } else {
                 transform = transform * transforms.as_transform(bounds, scale_factor);
             }
@@ -384,7 +388,11 @@ impl<'a> EventContext<'a> {
     /// Trigger an animation with the given id to play on the current view.
     pub fn play_animation(&mut self, anim_id: impl AnimId, duration: Duration, delay: Duration) {
         if let Some(animation_id) = anim_id.get(self) {
-            self.style.enqueue_animation(self.current, animation_id, duration, delay);
+            self.style.enqueue_animation(
+                self.current,
+                animation_id,
+                PlaybackOptions::new(duration, delay),
+            );
         }
     }
 
@@ -398,11 +406,62 @@ impl<'a> EventContext<'a> {
     ) {
         if let Some(target_entity) = self.resolve_entity_identifier(target) {
             if let Some(animation_id) = anim_id.get(self) {
-                self.style.enqueue_animation(target_entity, animation_id, duration, delay)
+                self.style.enqueue_animation(
+                    target_entity,
+                    animation_id,
+                    PlaybackOptions::new(duration, delay),
+                )
             }
         }
     }
 
+    /// Trigger an animation with the given id to play on the current view with the given playback options,
+    /// such as the number of iterations, direction, and fill mode.
+    pub fn play_animation_with(&mut self, anim_id: impl AnimId, options: PlaybackOptions) {
+        if let Some(animation_id) = anim_id.get(self) {
+            self.style.enqueue_animation(self.current, animation_id, options);
+        }
+    }
+
+    /// Pauses an animation with the given id which is playing on the current view.
+    pub fn pause_animation(&mut self, anim_id: impl AnimId) {
+        self.control_animation(anim_id, PlaybackControl::Pause);
+    }
+
+    /// Resumes a paused animation with the given id on the current view.
+    pub fn resume_animation(&mut self, anim_id: impl AnimId) {
+        self.control_animation(anim_id, PlaybackControl::Resume);
+    }
+
+    /// Jumps to the given time, from the start of the first iteration, of an animation with the given id
+    /// which is playing on the current view.
+    pub fn seek_animation(&mut self, anim_id: impl AnimId, time: Duration) {
+        self.control_animation(anim_id, PlaybackControl::Seek(time));
+    }
+
+    /// Reverses the playback direction of an animation with the given id which is playing on the current view,
+    /// so that it plays back to its start from its current position.
+    pub fn reverse_animation(&mut self, anim_id: impl AnimId) {
+        self.control_animation(anim_id, PlaybackControl::Reverse);
+    }
+
+    /// Stops an animation with the given id which is playing on the current view.
+    pub fn stop_animation(&mut self, anim_id: impl AnimId) {
+        if let Some(animation_id) = anim_id.get(self) {
+            self.style.stop_animation(self.current, animation_id);
+            self.needs_redraw();
+            self.needs_relayout();
+        }
+    }
+
+    fn control_animation(&mut self, anim_id: impl AnimId, control: PlaybackControl) {
+        if let Some(animation_id) = anim_id.get(self) {
+            self.style.control_animation(self.current, animation_id, control);
+            self.needs_redraw();
+            self.needs_relayout();
+        }
+    }
+
     /// Returns true if the current view is currently animating with the given animation id.
     pub fn is_animating(&self, anim_id: impl AnimId) -> bool {
         if let Some(animation_id) = anim_id.get(self) {
@@ -702,6 +761,28 @@ impl<'a> EventContext<'a> {
         self.needs_restyle();
     }
 
+    /// Sets the value of an attribute of the current view, which can be matched by a CSS attribute selector.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let context = &mut Context::default();
+    /// # let mut cx = &mut EventContext::new(context);
+    /// cx.set_attr("data-state", "open");
+    /// ```
+    pub fn set_attr(&mut self, name: &str, value: impl ToString) {
+        self.style.set_attribute(self.current, name, value.to_string());
+
+        self.needs_restyle();
+    }
+
+    /// Removes an attribute from the current view.
+    pub fn remove_attr(&mut self, name: &str) {
+        self.style.remove_attribute(self.current, name);
+
+        self.needs_restyle();
+    }
+
     /// Returns a reference to the [Environment] model.
     pub fn environment(&self) -> &Environment {
         self.data::<Environment>().unwrap()
@@ -709,6 +790,9 @@ impl<'a> EventContext<'a> {
 
     /// Sets the current [theme mode](ThemeMode).
     pub fn set_theme_mode(&mut self, theme_mode: ThemeMode) {
+        let color_scheme = theme_mode.into();
+        self.style.set_media(MediaState { color_scheme, ..self.style.media }, self.tree);
+
         if !self.ignore_default_theme {
             match theme_mode {
                 ThemeMode::LightMode => {
@@ -722,6 +806,11 @@ impl<'a> EventContext<'a> {
         }
     }
 
+    /// Sets whether non-essential motion should be reduced, which is queried by the `prefers-reduced-motion` media feature.
+    pub(crate) fn set_reduced_motion(&mut self, reduced_motion: bool) {
+        self.style.set_media(MediaState { reduced_motion, ..self.style.media }, self.tree);
+    }
+
     /// Marks the current view as needing to be redrawn.
     pub fn needs_redraw(&mut self) {
         let parent_window = self.tree.get_parent_window(self.current).unwrap_or(Entity::root());
@@ -752,28 +841,63 @@ impl<'a> EventContext<'a> {
     }
 
     /// Reloads the stylesheets linked to the application.
-    pub fn reload_styles(&mut self) -> Result<(), std::io::Error> {
+    ///
+    /// Returns the diagnostics for any problems found while loading the stylesheets, including stylesheet files which
+    /// can't be read. The diagnostics are also passed to the handler set with
+    /// [`set_style_diagnostic_handler()`](crate::context::Context::set_style_diagnostic_handler), or logged as
+    /// warnings if no handler is set.
+    pub fn reload_styles(&mut self) -> Vec<StyleDiagnostic> {
         if self.resource_manager.themes.is_empty() && self.resource_manager.styles.is_empty() {
-            return Ok(());
+            return Vec::new();
         }
 
         self.style.remove_rules();
 
         self.style.clear_style_rules();
 
-        let mut overall_theme = String::new();
-
         // Reload built-in themes
         for theme in self.resource_manager.themes.iter() {
-            overall_theme += theme;
+            self.style.parse_theme(theme);
         }
 
-        for style_string in self.resource_manager.styles.iter().flat_map(|style| style.get_style())
-        {
-            overall_theme += &style_string;
+        let theme_diagnostics = self.style.diagnostics.len();
+
+        // User stylesheets are parsed separately so that urls within them can be resolved relative to their files.
+        for style in self.resource_manager.styles.iter() {
+            match style.get_style() {
+                Ok(style_string) => self.style.parse_stylesheet(&style_string, style.get_path()),
+                Err(error) => self
+                    .style
+                    .diagnostics
+                    .push(StyleDiagnostic::from_io_error(&error, style.get_path())),
+            }
         }
 
-        self.style.parse_theme(&overall_theme);
+        self.text_context.load_font_faces(&self.style.font_faces);
+
+        if let Some(watcher) = &self.resource_manager.stylesheet_watcher {
+            for path in self.style.imports.iter() {
+                watcher.watch(path.clone());
+            }
+        }
+
+        // The diagnostics of the built-in themes are only reported when the themes change.
+        let (theme_diagnostics, style_diagnostics) =
+            self.style.diagnostics.split_at(theme_diagnostics);
+        let diagnostics = if self.resource_manager.theme_diagnostics == theme_diagnostics {
+            style_diagnostics
+        } else {
+            self.resource_manager.theme_diagnostics = theme_diagnostics.to_vec();
+            &self.style.diagnostics
+        };
+
+        for diagnostic in diagnostics.iter() {
+            if let Some(handler) = &self.resource_manager.style_diagnostic_handler {
+                (handler)(diagnostic);
+            } else {
+                warn!("{}", diagnostic);
+            }
+        }
 
         for entity in self.tree.into_iter() {
             self.style.needs_restyle(entity);
@@ -782,7 +906,7 @@ impl<'a> EventContext<'a> {
             self.style.needs_text_update(entity);
         }
 
-        Ok(())
+        self.style.diagnostics.clone()
     }
 
     /// Spawns a thread and provides a [ContextProxy] for sending events back to the main UI thread.
@@ -1064,9 +1188,14 @@ impl<'a> EventContext<'a> {
 
     // FILTER
 
+    /// Sets the filter of the current view.
+    pub fn set_filter(&mut self, filter: impl Into<Vec<Filter>>) {
+        self.style.filter.insert(self.current, filter.into());
+    }
+
     /// Sets the backdrop filter of the current view.
-    pub fn set_backdrop_filter(&mut self, filter: Filter) {
-        self.style.backdrop_filter.insert(self.current, filter);
+    pub fn set_backdrop_filter(&mut self, filter: impl Into<Vec<Filter>>) {
+        self.style.backdrop_filter.insert(self.current, filter.into());
     }
 
     // BOX SHADOW
@@ -1142,10 +1271,33 @@ impl<'a> EventContext<'a> {
 
     // GETTERS
     get_length_property!(
-        /// Returns the border width of the current view in physical pixels.
-        border_width
+        /// Returns the width of the top border of the current view in physical pixels.
+        border_top_width
+    );
+
+    get_length_property!(
+        /// Returns the width of the right border of the current view in physical pixels.
+        border_right_width
     );
 
+    get_length_property!(
+        /// Returns the width of the bottom border of the current view in physical pixels.
+        border_bottom_width
+    );
+
+    get_length_property!(
+        /// Returns the width of the left border of the current view in physical pixels.
+        border_left_width
+    );
+
+    /// Returns the width of the top border of the current view in physical pixels.
+    #[deprecated(
+        note = "use the per-side border width methods, such as `border_top_width`, instead"
+    )]
+    pub fn border_width(&self) -> f32 {
+        self.border_top_width()
+    }
+
     /// Returns the font-size of the current view in physical pixels.
     pub fn font_size(&self) -> f32 {
         self.logical_to_physical(
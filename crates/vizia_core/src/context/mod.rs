@@ -17,6 +17,7 @@ use skia_safe::{
 use std::any::{Any, TypeId};
 use std::cell::RefCell;
 use std::collections::{BinaryHeap, VecDeque};
+use std::path::Path;
 use std::rc::Rc;
 use std::sync::Mutex;
 use vizia_id::IdManager;
@@ -44,10 +45,11 @@ use crate::{cache::CachedData, resource::ImageOrSvg};
 
 use crate::model::ModelDataStore;
 use crate::prelude::*;
-use crate::resource::ResourceManager;
+use crate::resource::{ResourceManager, StylesheetWatcher};
 use crate::text::TextContext;
 use vizia_input::MouseState;
 use vizia_storage::{ChildIterator, LayoutTreeIterator};
+use vizia_style::MediaState;
 
 static DEFAULT_LAYOUT: &str = include_str!("../../resources/themes/default_layout.css");
 static DARK_THEME: &str = include_str!("../../resources/themes/dark_theme.css");
@@ -189,6 +191,7 @@ impl Context {
                     asset_provider,
                     text_bounds: Default::default(),
                     text_paragraphs: Default::default(),
+                    font_faces: Default::default(),
                 }
             },
 
@@ -555,6 +558,22 @@ impl Context {
         );
     }
 
+    /// Loads a font file and adds it to the application.
+    ///
+    /// The font can then be selected with the `font-family`, `font-weight`, and `font-slant` properties, using the
+    /// family name, weight, and slant stored in the font file. Fonts can also be loaded by stylesheets with a
+    /// `@font-face` rule.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// cx.add_font_file("assets/fonts/Inter-Bold.ttf").expect("Failed to load font");
+    /// ```
+    pub fn add_font_file(&mut self, path: impl AsRef<Path>) -> Result<(), FontError> {
+        self.text_context.add_font_file(path.as_ref())
+    }
+
     /// Sets the global default font for the application.
     pub fn set_default_font(&mut self, names: &[&str]) {
         self.style.default_font = names
@@ -568,15 +587,87 @@ impl Context {
     pub(crate) fn add_theme(&mut self, theme: &str) {
         self.resource_manager.themes.push(theme.to_owned());
 
-        EventContext::new(self).reload_styles().expect("Failed to reload styles");
+        EventContext::new(self).reload_styles();
     }
 
-    pub fn add_stylesheet(&mut self, style: impl IntoCssStr) -> Result<(), std::io::Error> {
+    /// Adds a stylesheet to the application, which can be a css string or the path of a css file.
+    ///
+    /// Returns an error if the stylesheet file can't be read. Otherwise returns the diagnostics for any problems found
+    /// while loading the stylesheets of the application, such as invalid selectors, unknown properties, or invalid
+    /// property values.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// let diagnostics = cx.add_stylesheet(".foo { widht: 10px; }").unwrap();
+    /// for diagnostic in diagnostics {
+    ///     println!("{}", diagnostic);
+    /// }
+    /// ```
+    pub fn add_stylesheet(
+        &mut self,
+        style: impl IntoCssStr,
+    ) -> Result<Vec<StyleDiagnostic>, std::io::Error> {
+        style.get_style()?;
+
+        if let (Some(watcher), Some(path)) =
+            (&self.resource_manager.stylesheet_watcher, style.get_path())
+        {
+            watcher.watch(path.to_path_buf());
+        }
+
         self.resource_manager.styles.push(Box::new(style));
 
-        EventContext::new(self).reload_styles().expect("Failed to reload styles");
+        Ok(EventContext::new(self).reload_styles())
+    }
 
-        Ok(())
+    /// Returns the diagnostics for any problems found while parsing the stylesheets of the application when they
+    /// were last loaded.
+    pub fn style_diagnostics(&self) -> &[StyleDiagnostic] {
+        &self.style.diagnostics
+    }
+
+    /// Sets a handler which receives the diagnostics of the stylesheets of the application whenever they are loaded,
+    /// instead of the diagnostics being logged as warnings.
+    ///
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// cx.set_style_diagnostic_handler(|diagnostic| {
+    ///     eprintln!("{}", diagnostic);
+    /// });
+    /// ```
+    pub fn set_style_diagnostic_handler<F: 'static + Fn(&StyleDiagnostic)>(&mut self, handler: F) {
+        self.resource_manager.style_diagnostic_handler = Some(Box::new(handler));
+    }
+
+    /// Watches the files of the stylesheets of the application, including those added later and the files they
+    /// import, and reloads styles whenever one of them changes.
+    ///
+    /// Changes are debounced so that saving a file causes a single reload. Running animations and transitions keep
+    /// playing across a reload, and the diagnostics of the reloaded stylesheets are passed to the handler set with
+    /// [`set_style_diagnostic_handler()`](Self::set_style_diagnostic_handler), or logged as warnings. Stylesheets
+    /// added as strings are not watched.
+    ///
+    /// The watcher sends events to the event loop, so it requires a windowing backend such as winit or baseview.
+    pub fn watch_stylesheets(&mut self) {
+        if self.resource_manager.stylesheet_watcher.is_some() {
+            return;
+        }
+
+        let paths = self
+            .resource_manager
+            .styles
+            .iter()
+            .filter_map(|style| style.get_path())
+            .map(Path::to_path_buf)
+            .chain(self.style.imports.iter().cloned())
+            .collect();
+
+        self.resource_manager.stylesheet_watcher =
+            Some(StylesheetWatcher::new(paths, self.get_proxy()));
     }
 
     /// Remove all user themes from the application.
@@ -585,9 +676,15 @@ impl Context {
 
         self.add_theme(DEFAULT_LAYOUT);
         self.add_theme(MARKDOWN);
+        let environment = self.data::<Environment>().expect("Failed to get environment");
+        let theme_mode = environment.theme.get_current_theme();
+        self.style.set_media(
+            MediaState { color_scheme: theme_mode.into(), ..self.style.media },
+            &self.tree,
+        );
+
         if !self.ignore_default_theme {
-            let environment = self.data::<Environment>().expect("Failed to get environment");
-            match environment.theme.get_current_theme() {
+            match theme_mode {
                 ThemeMode::LightMode => self.add_theme(LIGHT_THEME),
                 ThemeMode::DarkMode => self.add_theme(DARK_THEME),
             }
@@ -882,6 +979,7 @@ impl Context {
 
 pub(crate) enum InternalEvent {
     Redraw,
+    ReloadStyles,
     LoadImage { path: String, image: Mutex<Option<skia_safe::Image>>, policy: ImageRetentionPolicy },
 }
 
@@ -3,6 +3,7 @@ use crate::prelude::*;
 
 use unic_langid::LanguageIdentifier;
 use vizia_derive::Lens;
+use vizia_style::{ColorScheme, MediaState};
 use web_time::Duration;
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
@@ -12,6 +13,15 @@ pub enum ThemeMode {
     LightMode,
 }
 
+impl From<ThemeMode> for ColorScheme {
+    fn from(theme_mode: ThemeMode) -> Self {
+        match theme_mode {
+            ThemeMode::LightMode => ColorScheme::Light,
+            ThemeMode::DarkMode => ColorScheme::Dark,
+        }
+    }
+}
+
 use crate::{context::EventContext, events::Event};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -54,6 +64,8 @@ pub struct Environment {
     pub locale: LanguageIdentifier,
     /// Current application and system theme.
     pub theme: Theme,
+    /// Whether non-essential motion should be reduced.
+    pub reduced_motion: bool,
     /// The timer used to blink the caret of a textbox.
     pub(crate) caret_timer: Timer,
 }
@@ -66,10 +78,58 @@ impl Environment {
                 cx.emit(TextEvent::ToggleCaret);
             }
         });
-        Self { locale, theme: Theme::default(), caret_timer }
+        let reduced_motion = system_reduced_motion();
+        cx.style.set_media(MediaState { reduced_motion, ..cx.style.media }, &cx.tree);
+
+        Self { locale, theme: Theme::default(), reduced_motion, caret_timer }
     }
 }
 
+/// Returns whether the system is set to reduce non-essential motion.
+#[cfg(target_os = "windows")]
+fn system_reduced_motion() -> bool {
+    use windows_sys::Win32::UI::WindowsAndMessaging::{
+        SystemParametersInfoW, SPI_GETCLIENTAREAANIMATION,
+    };
+
+    let mut animations_enabled = 1;
+    // SAFETY: `SPI_GETCLIENTAREAANIMATION` writes a `BOOL` to the given pointer.
+    let result = unsafe {
+        SystemParametersInfoW(
+            SPI_GETCLIENTAREAANIMATION,
+            0,
+            &mut animations_enabled as *mut i32 as *mut _,
+            0,
+        )
+    };
+
+    result != 0 && animations_enabled == 0
+}
+
+/// Returns whether the system is set to reduce non-essential motion.
+#[cfg(target_os = "macos")]
+fn system_reduced_motion() -> bool {
+    std::process::Command::new("defaults")
+        .args(["read", "com.apple.universalaccess", "reduceMotion"])
+        .output()
+        .is_ok_and(|output| String::from_utf8_lossy(&output.stdout).trim() == "1")
+}
+
+/// Returns whether the system is set to reduce non-essential motion.
+#[cfg(target_os = "linux")]
+fn system_reduced_motion() -> bool {
+    std::process::Command::new("gsettings")
+        .args(["get", "org.gnome.desktop.interface", "enable-animations"])
+        .output()
+        .is_ok_and(|output| String::from_utf8_lossy(&output.stdout).trim() == "false")
+}
+
+/// Returns whether the system is set to reduce non-essential motion.
+#[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+fn system_reduced_motion() -> bool {
+    false
+}
+
 /// Events for setting the state in the [Environment].
 pub enum EnvironmentEvent {
     /// Set the locale used for the whole application.
@@ -81,6 +141,9 @@ pub enum EnvironmentEvent {
     UseSystemLocale,
     /// Alternate between dark and light theme modes.
     ToggleThemeMode,
+    /// Set whether non-essential motion should be reduced, which can be queried by stylesheets with the
+    /// `prefers-reduced-motion` media feature.
+    SetReducedMotion(bool),
 }
 
 impl Model for Environment {
@@ -94,7 +157,7 @@ impl Model for Environment {
                 theme.clone_into(&mut self.theme.app_theme);
 
                 cx.set_theme_mode(self.theme.get_current_theme());
-                cx.reload_styles().unwrap();
+                cx.reload_styles();
             }
 
             EnvironmentEvent::UseSystemLocale => {
@@ -111,7 +174,12 @@ impl Model for Environment {
                 self.theme.app_theme = AppTheme::BuiltIn(theme_mode);
 
                 cx.set_theme_mode(theme_mode);
-                cx.reload_styles().unwrap();
+                cx.reload_styles();
+            }
+
+            EnvironmentEvent::SetReducedMotion(reduced_motion) => {
+                self.reduced_motion = reduced_motion;
+                cx.set_reduced_motion(reduced_motion);
             }
         });
 
@@ -120,7 +188,7 @@ impl Model for Environment {
                 self.theme.sys_theme = Some(*theme);
                 if self.theme.app_theme == AppTheme::System {
                     cx.set_theme_mode(*theme);
-                    cx.reload_styles().unwrap();
+                    cx.reload_styles();
                 }
             }
             _ => (),
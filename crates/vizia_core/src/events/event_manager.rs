@@ -56,6 +56,9 @@ impl EventManager {
                 // Handle internal events.
                 event.take(|internal_event, _| match internal_event {
                     InternalEvent::Redraw => cx.needs_redraw(Entity::root()),
+                    InternalEvent::ReloadStyles => {
+                        EventContext::new(cx).reload_styles();
+                    }
                     InternalEvent::LoadImage { path, image, policy } => {
                         if let Some(image) = image.lock().unwrap().take() {
                             ResourceContext::new(cx).load_image(path, image, policy);
@@ -510,7 +513,7 @@ fn internal_state_updates(cx: &mut Context, window_event: &WindowEvent, meta: &m
             }
 
             if *code == Code::F5 {
-                EventContext::new(cx).reload_styles().unwrap();
+                EventContext::new(cx).reload_styles();
             }
 
             if *code == Code::Tab {
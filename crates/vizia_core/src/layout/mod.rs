@@ -27,6 +27,12 @@
 //! - `Auto` - The spacing is determined by the corresponding `child_space` of the parent. So `left` would be determined by the parent `padding_left` etc.
 //!
 //! # Child Space
+//!
+//! # Grid
+//! A view with a `Grid` layout type positions its children into cells defined by the `grid-columns` and `grid-rows` tracks,
+//! which can be specified in pixels, stretch, percentage or auto units, e.g. `grid-columns: 100px 1s 1s`.
+//! Children are placed in the grid with the zero-based `column-start` and `row-start` properties and can span multiple cells
+//! with the `column-span` and `row-span` properties.
 pub(crate) mod cache;
 pub(crate) mod node;
 
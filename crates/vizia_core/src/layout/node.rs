@@ -148,18 +148,36 @@ impl Node for Entity {
                 child_space_y += val;
             }
 
-            let border_width = store
-                .border_width
+            let border_left = store
+                .border_left_width
+                .get(*self)
+                .cloned()
+                .unwrap_or_default()
+                .to_pixels(0.0, store.scale_factor());
+            let border_right = store
+                .border_right_width
+                .get(*self)
+                .cloned()
+                .unwrap_or_default()
+                .to_pixels(0.0, store.scale_factor());
+            let border_top = store
+                .border_top_width
+                .get(*self)
+                .cloned()
+                .unwrap_or_default()
+                .to_pixels(0.0, store.scale_factor());
+            let border_bottom = store
+                .border_bottom_width
                 .get(*self)
                 .cloned()
                 .unwrap_or_default()
                 .to_pixels(0.0, store.scale_factor());
 
-            child_space_x += 2.0 * border_width;
-            child_space_y += 2.0 * border_width;
+            child_space_x += border_left + border_right;
+            child_space_y += border_top + border_bottom;
 
-            p_left += border_width;
-            p_top += border_width;
+            p_left += border_left;
+            p_top += border_top;
 
             let text_width = match (
                 store.text_wrap.get(*self).copied().unwrap_or(true),
@@ -318,7 +336,7 @@ impl Node for Entity {
     }
 
     fn border_left(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.border_width.get(*self).map(|border_width| match border_width {
+        store.border_left_width.get(*self).map(|border_width| match border_width {
             LengthOrPercentage::Length(val) => {
                 Units::Pixels(store.logical_to_physical(val.to_px().unwrap_or_default()))
             }
@@ -327,7 +345,7 @@ impl Node for Entity {
     }
 
     fn border_right(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.border_width.get(*self).map(|border_width| match border_width {
+        store.border_right_width.get(*self).map(|border_width| match border_width {
             LengthOrPercentage::Length(val) => {
                 Units::Pixels(store.logical_to_physical(val.to_px().unwrap_or_default()))
             }
@@ -336,7 +354,7 @@ impl Node for Entity {
     }
 
     fn border_top(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.border_width.get(*self).map(|border_width| match border_width {
+        store.border_top_width.get(*self).map(|border_width| match border_width {
             LengthOrPercentage::Length(val) => {
                 Units::Pixels(store.logical_to_physical(val.to_px().unwrap_or_default()))
             }
@@ -345,7 +363,7 @@ impl Node for Entity {
     }
 
     fn border_bottom(&self, store: &Self::Store) -> Option<morphorm::Units> {
-        store.border_width.get(*self).map(|border_width| match border_width {
+        store.border_bottom_width.get(*self).map(|border_width| match border_width {
             LengthOrPercentage::Length(val) => {
                 Units::Pixels(store.logical_to_physical(val.to_px().unwrap_or_default()))
             }
@@ -392,4 +410,44 @@ impl Node for Entity {
             t => t,
         })
     }
+
+    fn grid_columns(&self, store: &Self::Store) -> Option<Vec<Units>> {
+        store.grid_columns.get(*self).map(|columns| {
+            columns
+                .iter()
+                .map(|column| match column {
+                    Units::Pixels(val) => Units::Pixels(store.logical_to_physical(*val)),
+                    t => *t,
+                })
+                .collect()
+        })
+    }
+
+    fn grid_rows(&self, store: &Self::Store) -> Option<Vec<Units>> {
+        store.grid_rows.get(*self).map(|rows| {
+            rows.iter()
+                .map(|row| match row {
+                    Units::Pixels(val) => Units::Pixels(store.logical_to_physical(*val)),
+                    t => *t,
+                })
+                .collect()
+        })
+    }
+
+    fn column_start(&self, store: &Self::Store) -> Option<usize> {
+        store.column_start.get(*self).copied()
+    }
+
+    // A span of zero is treated as a span of one.
+    fn column_span(&self, store: &Self::Store) -> Option<usize> {
+        store.column_span.get(*self).map(|span| (*span).max(1))
+    }
+
+    fn row_start(&self, store: &Self::Store) -> Option<usize> {
+        store.row_start.get(*self).copied()
+    }
+
+    fn row_span(&self, store: &Self::Store) -> Option<usize> {
+        store.row_span.get(*self).map(|span| (*span).max(1))
+    }
 }
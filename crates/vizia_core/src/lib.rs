@@ -55,14 +55,16 @@ pub mod backend {
 pub mod prelude {
     pub use super::binding::{
         Binding, Data, Index, Lens, LensExt, LensValue, Map, MapRef, Res, ResGet, StaticLens, Then,
-        UnwrapLens, Wrapper,
+        TryMapRef, UnwrapLens, Wrapper,
     };
 
     pub use super::impl_res_simple;
 
     pub use crate::model::Model;
 
-    pub use super::animation::{Animation, AnimationBuilder, KeyframeBuilder};
+    pub use super::animation::{
+        Animation, AnimationBuilder, AnimationEvent, KeyframeBuilder, PlaybackOptions,
+    };
     pub use super::context::{
         AccessContext, AccessNode, Context, ContextProxy, DataContext, DrawContext, EmitContext,
         EventContext, ProxyEmitError, WindowState,
@@ -78,7 +80,7 @@ pub mod prelude {
         AbilityModifiers, AccessibilityModifiers, ActionModifiers, LayoutModifiers,
         LinearGradientBuilder, ShadowBuilder, StyleModifiers, TextModifiers,
     };
-    pub use super::resource::{ImageId, ImageRetentionPolicy};
+    pub use super::resource::{FontError, ImageId, ImageRetentionPolicy};
     pub use super::util::{IntoCssStr, CSS};
     pub use super::view::{Handle, View};
     pub use super::views::*;
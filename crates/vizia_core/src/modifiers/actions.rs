@@ -73,6 +73,9 @@ pub(crate) struct ActionsModel {
     pub(crate) on_geo_changed: Option<Box<dyn Fn(&mut EventContext, GeoChanged) + Send + Sync>>,
     pub(crate) on_drag_start: Option<Box<dyn Fn(&mut EventContext) + Send + Sync>>,
     pub(crate) on_drop: Option<Box<dyn Fn(&mut EventContext, DropData) + Send + Sync>>,
+    pub(crate) on_animation_iteration:
+        Option<Box<dyn Fn(&mut EventContext, Animation, u32) + Send + Sync>>,
+    pub(crate) on_animation_end: Option<Box<dyn Fn(&mut EventContext, Animation) + Send + Sync>>,
 }
 
 impl ActionsModel {
@@ -93,6 +96,8 @@ impl ActionsModel {
             on_geo_changed: None,
             on_drag_start: None,
             on_drop: None,
+            on_animation_iteration: None,
+            on_animation_end: None,
         }
     }
 }
@@ -161,6 +166,34 @@ impl Model for ActionsModel {
             ActionsEvent::OnDrop(on_drop) => {
                 self.on_drop = Some(on_drop);
             }
+
+            ActionsEvent::OnAnimationIteration(on_animation_iteration) => {
+                self.on_animation_iteration = Some(on_animation_iteration);
+            }
+
+            ActionsEvent::OnAnimationEnd(on_animation_end) => {
+                self.on_animation_end = Some(on_animation_end);
+            }
+        });
+
+        event.map(|animation_event, meta| {
+            if meta.target != cx.current() {
+                return;
+            }
+
+            match animation_event {
+                AnimationEvent::Iteration(animation, iteration) => {
+                    if let Some(action) = &self.on_animation_iteration {
+                        (action)(cx, *animation, *iteration);
+                    }
+                }
+
+                AnimationEvent::Finished(animation) => {
+                    if let Some(action) = &self.on_animation_end {
+                        (action)(cx, *animation);
+                    }
+                }
+            }
         });
 
         event.map(|window_event, meta| match window_event {
@@ -307,6 +340,8 @@ pub(crate) enum ActionsEvent {
     OnGeoChanged(Box<dyn Fn(&mut EventContext, GeoChanged) + Send + Sync>),
     OnDragStart(Box<dyn Fn(&mut EventContext) + Send + Sync>),
     OnDrop(Box<dyn Fn(&mut EventContext, DropData) + Send + Sync>),
+    OnAnimationIteration(Box<dyn Fn(&mut EventContext, Animation, u32) + Send + Sync>),
+    OnAnimationEnd(Box<dyn Fn(&mut EventContext, Animation) + Send + Sync>),
 }
 
 /// Modifiers which add an action callback to a view.
@@ -488,6 +523,31 @@ pub trait ActionModifiers<V> {
     fn on_drop<F>(self, action: F) -> Self
     where
         F: 'static + Fn(&mut EventContext, DropData) + Send + Sync;
+
+    /// Adds a callback which is performed when an animation playing on the view starts a new iteration.
+    /// The callback is provided with the animation and the index of the new iteration.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx).on_animation_iteration(|_, _, iteration| debug!("Started iteration {}", iteration));
+    /// ```
+    fn on_animation_iteration<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, Animation, u32) + Send + Sync;
+
+    /// Adds a callback which is performed when an animation playing on the view finishes.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use vizia_core::prelude::*;
+    /// # let mut cx = &mut Context::default();
+    /// Element::new(cx).on_animation_end(|_, _| debug!("Animation finished!"));
+    /// ```
+    fn on_animation_end<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, Animation) + Send + Sync;
 }
 
 // If the entity doesn't have an `ActionsModel` then add one to the entity
@@ -802,4 +862,34 @@ impl<V: View> ActionModifiers<V> for Handle<'_, V> {
 
         self
     }
+
+    fn on_animation_iteration<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, Animation, u32) + Send + Sync,
+    {
+        build_action_model(self.cx, self.entity);
+
+        self.cx.emit_custom(
+            Event::new(ActionsEvent::OnAnimationIteration(Box::new(action)))
+                .target(self.entity)
+                .origin(self.entity),
+        );
+
+        self
+    }
+
+    fn on_animation_end<F>(self, action: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, Animation) + Send + Sync,
+    {
+        build_action_model(self.cx, self.entity);
+
+        self.cx.emit_custom(
+            Event::new(ActionsEvent::OnAnimationEnd(Box::new(action)))
+                .target(self.entity)
+                .origin(self.entity),
+        );
+
+        self
+    }
 }
@@ -407,6 +407,65 @@ pub trait LayoutModifiers: internal::Modifiable {
 
         self
     }
+
+    modifier!(
+        /// Sets the column tracks of a view with a `Grid` layout type.
+        ///
+        /// Each track can be specified in `Units::Pixels(...)`, `Units::Percentage(...)`, `Units::Stretch(...)` or `Units::Auto`.
+        ///
+        /// # Example
+        /// ```
+        /// # use vizia_core::prelude::*;
+        /// # let cx = &mut Context::default();
+        /// HStack::new(cx, |cx| {
+        ///     Label::new(cx, "Name");
+        ///     Element::new(cx).column_start(1);
+        /// })
+        /// .layout_type(LayoutType::Grid)
+        /// .grid_columns(vec![Pixels(100.0), Stretch(1.0)])
+        /// .grid_rows(vec![Auto]);
+        /// ```
+        grid_columns,
+        Vec<Units>,
+        SystemFlags::RELAYOUT
+    );
+
+    modifier!(
+        /// Sets the row tracks of a view with a `Grid` layout type.
+        ///
+        /// Each track can be specified in `Units::Pixels(...)`, `Units::Percentage(...)`, `Units::Stretch(...)` or `Units::Auto`.
+        grid_rows,
+        Vec<Units>,
+        SystemFlags::RELAYOUT
+    );
+
+    modifier!(
+        /// Sets the index of the column, starting from zero, in which the view is placed when its parent has a `Grid` layout type.
+        column_start,
+        usize,
+        SystemFlags::RELAYOUT
+    );
+
+    modifier!(
+        /// Sets the number of columns spanned by the view when its parent has a `Grid` layout type.
+        column_span,
+        usize,
+        SystemFlags::RELAYOUT
+    );
+
+    modifier!(
+        /// Sets the index of the row, starting from zero, in which the view is placed when its parent has a `Grid` layout type.
+        row_start,
+        usize,
+        SystemFlags::RELAYOUT
+    );
+
+    modifier!(
+        /// Sets the number of rows spanned by the view when its parent has a `Grid` layout type.
+        row_span,
+        usize,
+        SystemFlags::RELAYOUT
+    );
 }
 
 impl<V: View> LayoutModifiers for Handle<'_, V> {}
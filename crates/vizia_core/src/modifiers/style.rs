@@ -85,6 +85,37 @@ pub trait StyleModifiers: internal::Modifiable {
         self
     }
 
+    /// Sets the value of an attribute of the view.
+    ///
+    /// The attribute can be referenced by a CSS attribute selector.
+    /// # Example
+    /// ```
+    /// # use vizia_core::prelude::*;
+    /// # let cx = &mut Context::default();
+    /// Element::new(cx).attr("orientation", "vertical");
+    /// ```
+    /// css
+    /// ```css
+    /// element[orientation="vertical"] {
+    ///     width: 10px;
+    /// }
+    ///```
+    fn attr<U: ToString>(mut self, name: &str, value: impl Res<U>) -> Self {
+        let name = name.to_owned();
+        let entity = self.entity();
+        let current = self.current();
+        self.context().with_current(current, |cx| {
+            value.set_or_bind(cx, entity, move |cx, value| {
+                let value = value.get(cx).to_string();
+                cx.style.set_attribute(entity, &name, value);
+
+                cx.needs_restyle(entity);
+            });
+        });
+
+        self
+    }
+
     // PseudoClassFlags
     // TODO: Should these have their own modifiers trait?
 
@@ -202,6 +233,21 @@ pub trait StyleModifiers: internal::Modifiable {
         SystemFlags::RESTYLE
     );
 
+    modifier!(
+        /// Sets whether the view is a container, whose size can be queried by the rules within `@container` blocks
+        /// which apply to its descendants.
+        container_type,
+        ContainerType,
+        SystemFlags::RESTYLE
+    );
+
+    modifier!(
+        /// Sets the names of the view as a container, which `@container` blocks can use to query it specifically.
+        container_name,
+        ContainerName,
+        SystemFlags::RESTYLE
+    );
+
     modifier!(
         /// Sets whether the view should be positioned and rendered.
         ///
@@ -295,8 +341,24 @@ pub trait StyleModifiers: internal::Modifiable {
         SystemFlags::REDRAW
     );
 
+    /// Sets the filter applied to the view and its children, such as `Filter::Grayscale(1.0)` or a chain of filters.
+    fn filter<U: Into<Vec<Filter>>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        self.context().with_current(current, |cx| {
+            value.set_or_bind(cx, entity, move |cx, v| {
+                let value = v.get(cx).into();
+                cx.style.filter.insert(cx.current, value);
+
+                cx.needs_redraw(entity);
+            });
+        });
+
+        self
+    }
+
     /// Sets the backdrop filter for the view.
-    fn backdrop_filter<U: Into<Filter>>(mut self, value: impl Res<U>) -> Self {
+    fn backdrop_filter<U: Into<Vec<Filter>>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
         let current = self.current();
         self.context().with_current(current, |cx| {
@@ -404,23 +466,144 @@ pub trait StyleModifiers: internal::Modifiable {
     }
 
     // Border Properties
+    /// Sets the border width for all four sides of the view.
+    fn border_width<U: Into<LengthOrPercentage>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        self.context().with_current(current, |cx| {
+            value.set_or_bind(cx, entity, move |cx, v| {
+                let value = v.get(cx).into();
+                cx.style.border_top_width.insert(cx.current, value.clone());
+                cx.style.border_right_width.insert(cx.current, value.clone());
+                cx.style.border_bottom_width.insert(cx.current, value.clone());
+                cx.style.border_left_width.insert(cx.current, value);
+
+                cx.style.needs_relayout();
+                cx.needs_redraw(entity);
+            });
+        });
+
+        self
+    }
+
+    modifier!(
+        /// Sets the width of the top border of the view.
+        border_top_width,
+        LengthOrPercentage,
+        SystemFlags::RELAYOUT | SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the width of the right border of the view.
+        border_right_width,
+        LengthOrPercentage,
+        SystemFlags::RELAYOUT | SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the width of the bottom border of the view.
+        border_bottom_width,
+        LengthOrPercentage,
+        SystemFlags::RELAYOUT | SystemFlags::REDRAW
+    );
+
     modifier!(
-        /// Sets the border width of the view.
-        border_width,
+        /// Sets the width of the left border of the view.
+        border_left_width,
         LengthOrPercentage,
         SystemFlags::RELAYOUT | SystemFlags::REDRAW
     );
 
+    /// Sets the border color for all four sides of the view.
+    fn border_color<U: Into<Color>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        self.context().with_current(current, |cx| {
+            value.set_or_bind(cx, entity, move |cx, v| {
+                let value = v.get(cx).into();
+                cx.style.border_top_color.insert(cx.current, value);
+                cx.style.border_right_color.insert(cx.current, value);
+                cx.style.border_bottom_color.insert(cx.current, value);
+                cx.style.border_left_color.insert(cx.current, value);
+
+                cx.needs_redraw(entity);
+            });
+        });
+
+        self
+    }
+
+    modifier!(
+        /// Sets the color of the top border of the view.
+        border_top_color,
+        Color,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the color of the right border of the view.
+        border_right_color,
+        Color,
+        SystemFlags::REDRAW
+    );
+
     modifier!(
-        /// Sets the border color of the view.
-        border_color,
+        /// Sets the color of the bottom border of the view.
+        border_bottom_color,
         Color,
         SystemFlags::REDRAW
     );
 
     modifier!(
-        /// Sets the border color of the view.
-        border_style,
+        /// Sets the color of the left border of the view.
+        border_left_color,
+        Color,
+        SystemFlags::REDRAW
+    );
+
+    /// Sets the border style for all four sides of the view.
+    fn border_style<U: Into<BorderStyleKeyword>>(mut self, value: impl Res<U>) -> Self {
+        let entity = self.entity();
+        let current = self.current();
+        self.context().with_current(current, |cx| {
+            value.set_or_bind(cx, entity, move |cx, v| {
+                let value = v.get(cx).into();
+                cx.style.border_top_style.insert(cx.current, value);
+                cx.style.border_right_style.insert(cx.current, value);
+                cx.style.border_bottom_style.insert(cx.current, value);
+                cx.style.border_left_style.insert(cx.current, value);
+
+                cx.needs_redraw(entity);
+            });
+        });
+
+        self
+    }
+
+    modifier!(
+        /// Sets the style of the top border of the view.
+        border_top_style,
+        BorderStyleKeyword,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the style of the right border of the view.
+        border_right_style,
+        BorderStyleKeyword,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the style of the bottom border of the view.
+        border_bottom_style,
+        BorderStyleKeyword,
+        SystemFlags::REDRAW
+    );
+
+    modifier!(
+        /// Sets the style of the left border of the view.
+        border_left_style,
         BorderStyleKeyword,
         SystemFlags::REDRAW
     );
@@ -687,7 +870,8 @@ impl<V: View> StyleModifiers for Handle<'_, V> {}
 #[derive(Debug, Clone)]
 pub struct LinearGradientBuilder {
     direction: LineDirection,
-    stops: Vec<ColorStop<LengthOrPercentage>>,
+    interpolation: ColorInterpolationMethod,
+    items: Vec<GradientItem<LengthOrPercentage>>,
 }
 
 impl Default for LinearGradientBuilder {
@@ -698,19 +882,41 @@ impl Default for LinearGradientBuilder {
 
 impl LinearGradientBuilder {
     pub fn new() -> Self {
-        LinearGradientBuilder { direction: LineDirection::default(), stops: Vec::new() }
+        LinearGradientBuilder {
+            direction: LineDirection::default(),
+            interpolation: ColorInterpolationMethod::default(),
+            items: Vec::new(),
+        }
     }
 
     pub fn with_direction(direction: impl Into<LineDirection>) -> Self {
-        LinearGradientBuilder { direction: direction.into(), stops: Vec::new() }
+        LinearGradientBuilder { direction: direction.into(), ..Self::new() }
     }
 
     fn build(self) -> Gradient {
-        Gradient::Linear(LinearGradient { direction: self.direction, stops: self.stops })
+        Gradient::Linear(LinearGradient {
+            direction: self.direction,
+            interpolation: self.interpolation,
+            items: self.items,
+        })
+    }
+
+    /// Sets the color space in which the colors of the gradient are interpolated.
+    pub fn interpolation(mut self, interpolation: impl Into<ColorInterpolationMethod>) -> Self {
+        self.interpolation = interpolation.into();
+
+        self
     }
 
     pub fn add_stop(mut self, stop: impl Into<ColorStop<LengthOrPercentage>>) -> Self {
-        self.stops.push(stop.into());
+        self.items.push(GradientItem::ColorStop(stop.into()));
+
+        self
+    }
+
+    /// Adds a color hint, which is the position at which the color is halfway between the stops either side.
+    pub fn add_hint(mut self, hint: impl Into<LengthOrPercentage>) -> Self {
+        self.items.push(GradientItem::Hint(hint.into()));
 
         self
     }
@@ -60,6 +60,13 @@ pub trait TextModifiers: internal::Modifiable {
         SystemFlags::REFLOW
     );
 
+    modifier!(
+        /// Sets the OpenType font features, such as ligatures or tabular numbers, that should be used by the view.
+        font_feature_settings,
+        Vec<FontFeature>,
+        SystemFlags::REFLOW
+    );
+
     /// Sets the text color of the view.
     fn color<U: Clone + Into<Color>>(mut self, value: impl Res<U>) -> Self {
         let entity = self.entity();
@@ -164,6 +171,48 @@ pub trait TextModifiers: internal::Modifiable {
         TextStrokeStyle,
         SystemFlags::REFLOW
     );
+
+    modifier!(
+        /// Sets the height of each line of text, either as a multiple of the font size or as a length.
+        line_height,
+        LineHeight,
+        SystemFlags::REFLOW
+    );
+
+    modifier!(
+        /// Sets the additional space between letters of the text.
+        letter_spacing,
+        Length,
+        SystemFlags::REFLOW
+    );
+
+    modifier!(
+        /// Sets the additional space between words of the text.
+        word_spacing,
+        Length,
+        SystemFlags::REFLOW
+    );
+
+    modifier!(
+        /// Sets the indentation of the first line of text.
+        text_indent,
+        Length,
+        SystemFlags::REFLOW
+    );
+
+    modifier!(
+        /// Sets the shadows drawn behind the text.
+        text_shadow,
+        Vec<TextShadow>,
+        SystemFlags::REFLOW
+    );
+
+    modifier!(
+        /// Sets the capitalization of the text.
+        text_transform,
+        TextTransform,
+        SystemFlags::REFLOW
+    );
 }
 
 impl<V> TextModifiers for Handle<'_, V> {}
@@ -1,16 +1,20 @@
 //! Resource management for fonts, themes, images, and translations.
 
 mod image_id;
+mod watcher;
 
 pub use image_id::ImageId;
 use vizia_id::{GenerationalId, IdManager};
+pub(crate) use watcher::StylesheetWatcher;
 
 use crate::context::ResourceContext;
 use crate::entity::Entity;
 use crate::prelude::IntoCssStr;
+use crate::style::StyleDiagnostic;
 // use crate::view::Canvas;
 use fluent_bundle::{FluentBundle, FluentResource};
 use hashbrown::{HashMap, HashSet};
+use std::path::PathBuf;
 use unic_langid::LanguageIdentifier;
 
 pub(crate) enum ImageOrSvg {
@@ -33,6 +37,41 @@ pub enum ImageRetentionPolicy {
     DropWhenNoObservers,
 }
 
+/// Errors that might occur when loading a font file.
+#[derive(Debug)]
+pub enum FontError {
+    /// The font file could not be read.
+    Io(std::io::Error),
+    /// The font file at the given path does not contain a supported font.
+    InvalidData(PathBuf),
+}
+
+impl std::fmt::Display for FontError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FontError::Io(err) => write!(f, "Failed to read font file: {}", err),
+            FontError::InvalidData(path) => {
+                write!(f, "The file {} does not contain a supported font", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for FontError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FontError::Io(err) => Some(err),
+            FontError::InvalidData(_) => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for FontError {
+    fn from(err: std::io::Error) -> Self {
+        FontError::Io(err)
+    }
+}
+
 #[doc(hidden)]
 #[derive(Default)]
 pub struct ResourceManager {
@@ -48,6 +87,14 @@ pub struct ResourceManager {
     pub language: LanguageIdentifier,
 
     pub image_loader: Option<Box<dyn Fn(&mut ResourceContext, &str)>>,
+
+    // Receives the diagnostics of stylesheets when styles are reloaded, instead of them being logged.
+    pub(crate) style_diagnostic_handler: Option<Box<dyn Fn(&StyleDiagnostic)>>,
+    // The diagnostics of the built-in themes when they were last reported.
+    pub(crate) theme_diagnostics: Vec<StyleDiagnostic>,
+
+    // Reloads styles when the files of stylesheets change, if enabled with `Context::watch_stylesheets()`.
+    pub(crate) stylesheet_watcher: Option<StylesheetWatcher>,
 }
 
 impl ResourceManager {
@@ -121,6 +168,9 @@ impl ResourceManager {
 
             language: locale,
             image_loader: default_image_loader,
+            style_diagnostic_handler: None,
+            theme_diagnostics: Vec::new(),
+            stylesheet_watcher: None,
         }
     }
 
@@ -0,0 +1,138 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+
+use hashbrown::HashMap;
+
+use crate::context::{ContextProxy, InternalEvent};
+
+// How often the modification times of the watched files are checked.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+// How long the watched files must be left unchanged before styles are reloaded, so that an editor which writes a file
+// in several steps only causes a single reload.
+const DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// Watches the files of stylesheets for changes and reloads styles when they are modified.
+///
+/// The watcher polls the modification times of the files on a background thread and stops when it is dropped.
+pub(crate) struct StylesheetWatcher {
+    paths: Arc<Mutex<Vec<PathBuf>>>,
+    running: Arc<AtomicBool>,
+}
+
+impl StylesheetWatcher {
+    /// Starts watching the given stylesheet files, sending an event through the proxy to reload styles whenever they
+    /// change.
+    pub fn new(paths: Vec<PathBuf>, mut proxy: ContextProxy) -> Self {
+        let paths = Arc::new(Mutex::new(paths));
+        let running = Arc::new(AtomicBool::new(true));
+
+        let watched_paths = paths.clone();
+        let is_running = running.clone();
+        std::thread::spawn(move || {
+            let mut modified_times = HashMap::new();
+            let mut changed_at = None;
+
+            while is_running.load(Ordering::Relaxed) {
+                for path in watched_paths.lock().unwrap().iter() {
+                    let modified = modified_time(path);
+                    // The first modification time of a file is only recorded, as it was loaded when it was added.
+                    if let Some(previous) = modified_times.insert(path.clone(), modified) {
+                        if previous != modified {
+                            changed_at = Some(Instant::now());
+                        }
+                    }
+                }
+
+                if changed_at.is_some_and(|time: Instant| time.elapsed() >= DEBOUNCE) {
+                    changed_at = None;
+                    // Stop watching if the event loop has closed or doesn't support proxying events.
+                    if proxy.emit(InternalEvent::ReloadStyles).is_err() {
+                        break;
+                    }
+                }
+
+                std::thread::sleep(POLL_INTERVAL);
+            }
+        });
+
+        Self { paths, running }
+    }
+
+    /// Adds a stylesheet file to be watched.
+    pub fn watch(&self, path: PathBuf) {
+        let mut paths = self.paths.lock().unwrap();
+        if !paths.contains(&path) {
+            paths.push(path);
+        }
+    }
+}
+
+impl Drop for StylesheetWatcher {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+    }
+}
+
+// Returns the time a file was last modified, or `None` if it doesn't exist. A file being removed or created also
+// counts as a change.
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::EventProxy;
+    use crate::entity::Entity;
+    use crate::events::Event;
+    use std::fs::File;
+    use std::sync::mpsc::{channel, Sender};
+
+    struct TestProxy(Sender<Event>);
+
+    impl EventProxy for TestProxy {
+        fn send(&self, event: Event) -> Result<(), ()> {
+            self.0.send(event).map_err(|_| ())
+        }
+
+        fn make_clone(&self) -> Box<dyn EventProxy> {
+            Box::new(TestProxy(self.0.clone()))
+        }
+    }
+
+    #[test]
+    fn reload_on_change() {
+        let path = std::env::temp_dir().join(format!("vizia-watcher-{}.css", std::process::id()));
+        let file = File::create(&path).unwrap();
+        file.set_modified(SystemTime::UNIX_EPOCH).unwrap();
+
+        let (sender, receiver) = channel();
+        let proxy = ContextProxy {
+            current: Entity::root(),
+            event_proxy: Some(Box::new(TestProxy(sender))),
+        };
+        let watcher = StylesheetWatcher::new(vec![path.clone()], proxy);
+
+        // Wait for the initial modification time to be recorded before changing it.
+        std::thread::sleep(POLL_INTERVAL * 2);
+        assert!(receiver.try_recv().is_err());
+
+        file.set_modified(SystemTime::now()).unwrap();
+
+        let mut event = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+        let mut reloaded = false;
+        event.take(|internal_event: InternalEvent, _| {
+            reloaded = matches!(internal_event, InternalEvent::ReloadStyles);
+        });
+        assert!(reloaded);
+
+        // Only a single reload is sent for a change.
+        std::thread::sleep(DEBOUNCE * 2);
+        assert!(receiver.try_recv().is_err());
+
+        drop(watcher);
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -1,4 +1,6 @@
-use crate::animation::{AnimationState, Interpolator};
+use crate::animation::{
+    AnimationEvent, AnimationState, Interpolator, PlaybackControl, PlaybackOptions,
+};
 use crate::prelude::*;
 use vizia_storage::{SparseSet, SparseSetGeneric, SparseSetIndex};
 
@@ -287,6 +289,13 @@ where
         self.animations.insert(animation, animation_description);
     }
 
+    /// Removes an animation, returning its description if it existed.
+    ///
+    /// Entities which are already playing the animation continue to do so.
+    pub(crate) fn remove_animation(&mut self, animation: Animation) -> Option<AnimationState<T>> {
+        self.animations.remove(animation)
+    }
+
     pub(crate) fn insert_rule(&mut self, rule: Rule, value: T) {
         self.shared_data.insert(rule, value);
     }
@@ -312,8 +321,7 @@ where
         entity: Entity,
         animation: Animation,
         start_time: Instant,
-        duration: Duration,
-        delay: Duration,
+        options: &PlaybackOptions,
     ) {
         let entity_index = entity.index();
 
@@ -326,108 +334,90 @@ where
             self.inline_data.sparse.resize(entity_index + 1, InlineIndex::null());
         }
 
-        if entity_index < self.inline_data.sparse.len() {
-            let active_anim_index = self.inline_data.sparse[entity_index].anim_index as usize;
-            if active_anim_index < self.active_animations.len() {
-                let anim_state = &mut self.active_animations[active_anim_index];
-                if anim_state.id == animation {
-                    anim_state.active = true;
-                    anim_state.t = 0.0;
-                    anim_state.start_time = start_time;
-                    anim_state.output = Some(
-                        self.animations
-                            .get(animation)
-                            .cloned()
-                            .unwrap()
-                            .keyframes
-                            .first()
-                            .unwrap()
-                            .value
-                            .clone(),
-                    );
-                } else {
-                    anim_state.output = Some(
-                        self.animations
-                            .get(animation)
-                            .cloned()
-                            .unwrap()
-                            .keyframes
-                            .first()
-                            .unwrap()
-                            .value
-                            .clone(),
-                    );
-                    anim_state.entities.remove(&entity);
-                }
+        // Unlink the entity from any animation it is already playing
+        let active_anim_index = self.inline_data.sparse[entity_index].anim_index as usize;
+        if active_anim_index < self.active_animations.len() {
+            let anim_state = &mut self.active_animations[active_anim_index];
+            anim_state.entities.remove(&entity);
+            if anim_state.entities.is_empty() {
+                anim_state.t = 1.0;
+                anim_state.persistent = false;
             }
+        }
 
-            // Safe to unwrap because already checked that the animation exists
-            let mut anim_state = self.animations.get(animation).cloned().unwrap();
-            anim_state.duration = duration;
-            anim_state.delay = delay;
-            anim_state.dt = delay.as_secs_f32() / duration.as_secs_f32();
-            anim_state.output = Some(
-                self.animations
-                    .get(animation)
-                    .cloned()
-                    .unwrap()
-                    .keyframes
-                    .first()
-                    .unwrap()
-                    .value
-                    .clone(),
-            );
-            anim_state.play(entity);
-            self.inline_data.sparse[entity_index].anim_index = self.active_animations.len() as u32;
-            self.active_animations.push(anim_state);
+        // Safe to unwrap because already checked that the animation exists
+        let mut anim_state = self.animations.get(animation).cloned().unwrap();
+        anim_state.duration = options.duration;
+        anim_state.delay = options.delay;
+        anim_state.dt = options.delay.as_secs_f32() / options.duration.as_secs_f32();
+        anim_state.iteration_count = options.iteration_count;
+        anim_state.direction = options.direction;
+        anim_state.fill_mode = options.fill_mode;
+        // The timing function of the playback options applies to keyframes which don't specify their own.
+        if let Some(timing_function) = &options.timing_function {
+            for keyframe in anim_state.keyframes.iter_mut() {
+                keyframe.timing_function.get_or_insert_with(|| timing_function.clone());
+            }
         }
+        anim_state.play(entity);
+        anim_state.start_time = start_time;
+        anim_state.update(start_time);
+        self.inline_data.sparse[entity_index].anim_index = self.active_animations.len() as u32;
+        self.active_animations.push(anim_state);
     }
 
-    pub fn tick(&mut self, time: Instant) -> Vec<Entity> {
-        self.remove_innactive_animations();
-
-        if self.has_animations() {
-            for state in self.active_animations.iter_mut() {
-                // If the animation is already finished then skip
-                if state.t == 1.0 {
-                    continue;
-                }
-
-                if state.keyframes.len() == 1 {
-                    state.output = Some(state.keyframes[0].value.clone());
-                    continue;
-                }
+    /// Pauses, resumes, seeks or reverses the given animation if it is the active animation of the entity.
+    pub(crate) fn control_animation(
+        &mut self,
+        entity: Entity,
+        animation: Animation,
+        control: PlaybackControl,
+        time: Instant,
+    ) {
+        if !self.has_active_animation(entity, animation) {
+            return;
+        }
 
-                let elapsed_time = time.duration_since(state.start_time);
-                let mut normalised_time =
-                    (elapsed_time.as_secs_f32() / state.duration.as_secs_f32()) - state.dt;
+        let anim_index = self.inline_data.sparse[entity.index()].anim_index as usize;
+        self.active_animations[anim_index].control(control, time);
+    }
 
-                normalised_time = normalised_time.clamp(0.0, 1.0);
+    /// Updates the active animations for the given time, returning the entities with animated values which
+    /// need to be updated. Events for any animations which started a new iteration or finished are added to `events`.
+    pub fn tick(
+        &mut self,
+        time: Instant,
+        events: &mut Vec<(Entity, AnimationEvent)>,
+    ) -> Vec<Entity> {
+        // Entities of animations which finished on the previous tick return to their unanimated values
+        let mut entities = self
+            .active_animations
+            .iter()
+            .filter(|state| state.t == 1.0 && !state.persistent)
+            .flat_map(|state| state.entities.iter().copied())
+            .collect::<Vec<Entity>>();
 
-                let mut i = 0;
-                while i < state.keyframes.len() - 1 && state.keyframes[i + 1].time < normalised_time
-                {
-                    i += 1;
-                }
-                let start = &state.keyframes[i];
-                let end = &state.keyframes[i + 1];
+        self.remove_innactive_animations();
 
-                let normalised_elapsed_time =
-                    (normalised_time - start.time) / (end.time - start.time);
+        for state in self.active_animations.iter_mut() {
+            // If the animation is already finished or paused then skip
+            if state.t == 1.0 || state.paused_at.is_some() {
+                continue;
+            }
 
-                state.t = normalised_time;
+            let animation_events = state.update(time);
 
-                let timing_t = start.timing_function.value(normalised_elapsed_time);
-                state.output = Some(T::interpolate(&start.value, &end.value, timing_t));
+            // Transitions are played by the style system and don't emit events
+            if !state.is_transition() {
+                for entity in state.entities.iter() {
+                    events.extend(animation_events.iter().map(|event| (*entity, *event)));
+                }
             }
 
-            self.active_animations
-                .iter()
-                .flat_map(|state| state.entities.clone())
-                .collect::<Vec<Entity>>()
-        } else {
-            Vec::new()
+            entities.extend(state.entities.iter().copied());
         }
+
+        entities
     }
 
     // Returns true if the given entity is linked to an active animation
@@ -469,16 +459,6 @@ where
         }
     }
 
-    pub fn has_animations(&self) -> bool {
-        for state in self.active_animations.iter() {
-            if state.t < 1.0 {
-                return true;
-            }
-        }
-
-        false
-    }
-
     pub fn has_active_animation(&self, entity: Entity, animation: Animation) -> bool {
         let entity_index = entity.index();
         if entity_index < self.inline_data.sparse.len() {
@@ -493,6 +473,26 @@ where
         false
     }
 
+    /// Stops the given animation if it is the active animation of the entity.
+    pub(crate) fn stop_animation(&mut self, entity: Entity, animation: Animation) {
+        if !self.has_active_animation(entity, animation) {
+            return;
+        }
+
+        let entity_index = entity.index();
+        let anim_index = self.inline_data.sparse[entity_index].anim_index as usize;
+        self.inline_data.sparse[entity_index].anim_index = u32::MAX;
+
+        let anim_state = &mut self.active_animations[anim_index];
+        anim_state.entities.remove(&entity);
+        if anim_state.entities.is_empty() {
+            anim_state.t = 1.0;
+            anim_state.persistent = false;
+        }
+
+        self.remove_innactive_animations();
+    }
+
     // Returns a reference to any inline data on the entity if it exists.
     // pub fn get_inline(&self, entity: Entity) -> Option<&T> {
     //     let entity_index = entity.index();
@@ -558,7 +558,10 @@ where
             let animation_index = self.inline_data.sparse[entity_index].anim_index as usize;
 
             if animation_index < self.active_animations.len() {
-                return self.active_animations[animation_index].get_output();
+                // A delayed animation without a backwards fill has no output until it starts
+                if let Some(output) = self.active_animations[animation_index].get_output() {
+                    return Some(output);
+                }
             }
 
             let data_index = self.inline_data.sparse[entity_index].data_index;
@@ -607,8 +610,13 @@ where
                     let rule_data_index = shared_data_index.data_index as usize;
 
                     if current_anim_state.is_transition() {
-                        // Skip if the transition hasn't changed
-                        if current_anim_state.to_rule != rule_data_index {
+                        if current_anim_state.to_rule == DataIndex::null().index() {
+                            // The rules were reloaded so continue the transition towards the new value
+                            current_anim_state.to_rule = rule_data_index;
+                            current_anim_state.keyframes.last_mut().unwrap().value =
+                                self.shared_data.dense[rule_data_index].value.clone();
+                        } else if current_anim_state.to_rule != rule_data_index {
+                            // Skip if the transition hasn't changed
                             if rule_data_index == current_anim_state.from_rule {
                                 // Transitioning back to previous rule
                                 current_anim_state.from_rule = current_anim_state.to_rule;
@@ -670,8 +678,7 @@ where
                             entity,
                             rule_animation,
                             Instant::now(),
-                            duration,
-                            delay,
+                            &PlaybackOptions::new(duration, delay),
                         );
                     }
                     //}
@@ -716,6 +723,14 @@ where
 
         self.shared_data.clear();
 
+        // Active transitions refer to rule data which no longer exists, so they are retargeted when next linked
+        for anim_state in self.active_animations.iter_mut() {
+            if anim_state.is_transition() {
+                anim_state.from_rule = DataIndex::null().index();
+                anim_state.to_rule = DataIndex::null().index();
+            }
+        }
+
         for index in self.inline_data.sparse.iter_mut() {
             if !index.data_index.is_inline() {
                 index.data_index = DataIndex::null();
@@ -727,6 +742,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::animation::{Keyframe, TimingFunction};
 
     // DataIndex tests
 
@@ -795,4 +811,114 @@ mod tests {
         animatable_storage.insert(Entity::root(), 5.0);
         //assert_eq!(animatable_storage.entity_indices.first().unwrap().data_index, DataIndex::inline(0));
     }
+
+    // Animation tests
+
+    fn linear_animation(animation: Animation) -> AnimationState<f32> {
+        let timing_function = Some(TimingFunction::linear());
+        AnimationState::new(animation)
+            .with_keyframe(Keyframe {
+                time: 0.0,
+                value: 0.0,
+                timing_function: timing_function.clone(),
+            })
+            .with_keyframe(Keyframe { time: 1.0, value: 10.0, timing_function })
+    }
+
+    /// Test that an alternating animation plays every other iteration backwards and returns to the
+    /// unanimated value when finished.
+    #[test]
+    fn alternate_iterations() {
+        let mut animatable_storage = AnimatableSet::default();
+        let animation = Animation::new(0, 0);
+        animatable_storage.insert_animation(animation, linear_animation(animation));
+        animatable_storage.insert(Entity::root(), 20.0);
+
+        let start = Instant::now();
+        let options = PlaybackOptions::new(Duration::from_secs(1), Duration::ZERO)
+            .iterations(2.0)
+            .direction(AnimationDirection::Alternate);
+        animatable_storage.play_animation(Entity::root(), animation, start, &options);
+
+        let mut events = Vec::new();
+        animatable_storage.tick(start + Duration::from_millis(1250), &mut events);
+        assert_eq!(animatable_storage.get(Entity::root()), Some(&7.5));
+        assert_eq!(events, vec![(Entity::root(), AnimationEvent::Iteration(animation, 1))]);
+
+        animatable_storage.tick(start + Duration::from_secs(3), &mut events);
+        assert_eq!(animatable_storage.get(Entity::root()), Some(&0.0));
+        assert_eq!(events.last(), Some(&(Entity::root(), AnimationEvent::Finished(animation))));
+
+        animatable_storage.tick(start + Duration::from_secs(4), &mut events);
+        assert_eq!(animatable_storage.get(Entity::root()), Some(&20.0));
+    }
+
+    /// Test that a paused animation holds its value until resumed and that an animation which fills forwards
+    /// holds its final value.
+    #[test]
+    fn pause_and_fill_forwards() {
+        let mut animatable_storage = AnimatableSet::default();
+        let animation = Animation::new(0, 0);
+        animatable_storage.insert_animation(animation, linear_animation(animation));
+        animatable_storage.insert(Entity::root(), 20.0);
+
+        let start = Instant::now();
+        let options = PlaybackOptions::new(Duration::from_secs(1), Duration::ZERO)
+            .fill_mode(AnimationFillMode::Forwards);
+        animatable_storage.play_animation(Entity::root(), animation, start, &options);
+
+        let mut events = Vec::new();
+        animatable_storage.control_animation(
+            Entity::root(),
+            animation,
+            PlaybackControl::Pause,
+            start + Duration::from_millis(500),
+        );
+        animatable_storage.tick(start + Duration::from_secs(2), &mut events);
+        assert_eq!(animatable_storage.get(Entity::root()), Some(&5.0));
+
+        animatable_storage.control_animation(
+            Entity::root(),
+            animation,
+            PlaybackControl::Resume,
+            start + Duration::from_secs(2),
+        );
+        animatable_storage.tick(start + Duration::from_millis(2250), &mut events);
+        assert_eq!(animatable_storage.get(Entity::root()), Some(&7.5));
+
+        animatable_storage.tick(start + Duration::from_secs(3), &mut events);
+        animatable_storage.tick(start + Duration::from_secs(4), &mut events);
+        assert_eq!(animatable_storage.get(Entity::root()), Some(&10.0));
+    }
+
+    /// Test that a transition which is playing when the rules are reloaded continues towards the reloaded value
+    /// of the rule.
+    #[test]
+    fn transition_after_clear_rules() {
+        let mut animatable_storage = AnimatableSet::default();
+        let rule1 = Rule::new(0, 0);
+        let rule2 = Rule::new(1, 0);
+        let animation = Animation::new(0, 0);
+
+        animatable_storage.insert_rule(rule1, 0.0);
+        animatable_storage.insert_rule(rule2, 10.0);
+        animatable_storage.insert_animation(
+            animation,
+            linear_animation(animation).with_duration(Duration::from_secs(1)),
+        );
+        animatable_storage.insert_transition(rule2, animation);
+
+        animatable_storage.link(Entity::root(), &[rule1]);
+        animatable_storage.link(Entity::root(), &[rule2]);
+        assert!(animatable_storage.has_active_animation(Entity::root(), animation));
+
+        animatable_storage.clear_rules();
+        animatable_storage.insert_rule(rule1, 0.0);
+        animatable_storage.insert_rule(rule2, 20.0);
+        animatable_storage.link(Entity::root(), &[rule2]);
+
+        let anim_state = animatable_storage.get_active_animation(Entity::root()).unwrap();
+        assert_eq!(anim_state.keyframes.first().unwrap().value, 0.0);
+        assert_eq!(anim_state.keyframes.last().unwrap().value, 20.0);
+    }
 }
@@ -0,0 +1,131 @@
+use hashbrown::HashMap;
+use vizia_style::substitute_variables;
+
+/// The computed custom properties (`--*`) of an entity.
+///
+/// Custom properties are inherited, so the computed properties of an entity include those of its parent.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct CustomProperties(HashMap<String, String>);
+
+impl CustomProperties {
+    /// Computes the custom properties of an entity from its declarations, given in order of precedence,
+    /// and the custom properties of its parent.
+    pub fn compute<'a>(
+        declarations: impl IntoIterator<Item = &'a (String, String)>,
+        parent: Option<&CustomProperties>,
+    ) -> Self {
+        let mut declared = HashMap::new();
+        for (name, value) in declarations {
+            declared.entry(name.as_str()).or_insert(value.as_str());
+        }
+
+        let mut properties = parent.cloned().unwrap_or_default();
+        for name in declared.keys() {
+            match resolve(name, &declared, parent, &mut Vec::new()) {
+                Some(value) => properties.0.insert(name.to_string(), value),
+                // A property which references an undefined or cyclic variable is invalid.
+                None => properties.0.remove(*name),
+            };
+        }
+
+        properties
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0.get(name).map(|value| value.as_str())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Substitutes any `var()` references in the given css value with the values of these custom properties.
+    pub fn substitute(&self, value: &str) -> Option<String> {
+        substitute_variables(value, &mut |name: &str| self.get(name).map(|value| value.to_owned()))
+    }
+}
+
+// Resolves the value of a custom property, substituting any references to other custom properties.
+fn resolve(
+    name: &str,
+    declared: &HashMap<&str, &str>,
+    parent: Option<&CustomProperties>,
+    stack: &mut Vec<String>,
+) -> Option<String> {
+    let value = match declared.get(name) {
+        Some(value) => *value,
+        None => return parent?.get(name).map(|value| value.to_owned()),
+    };
+
+    if !value.contains("var(") {
+        return Some(value.to_owned());
+    }
+
+    if stack.iter().any(|visited| visited == name) {
+        return None;
+    }
+
+    stack.push(name.to_owned());
+    let resolved =
+        substitute_variables(value, &mut |name: &str| resolve(name, declared, parent, &mut *stack));
+    stack.pop();
+
+    resolved
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn declarations(list: &[(&str, &str)]) -> Vec<(String, String)> {
+        list.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect()
+    }
+
+    #[test]
+    fn inherit_from_parent() {
+        let parent = CustomProperties::compute(
+            &declarations(&[("--size", "10px"), ("--color", "red")]),
+            None,
+        );
+        let child = CustomProperties::compute(&declarations(&[("--color", "blue")]), Some(&parent));
+
+        assert_eq!(child.get("--size"), Some("10px"));
+        assert_eq!(child.get("--color"), Some("blue"));
+        assert_eq!(parent.get("--color"), Some("red"));
+    }
+
+    #[test]
+    fn first_declaration_wins() {
+        let properties = CustomProperties::compute(
+            &declarations(&[("--color", "blue"), ("--color", "red")]),
+            None,
+        );
+
+        assert_eq!(properties.get("--color"), Some("blue"));
+    }
+
+    #[test]
+    fn resolve_references() {
+        let parent = CustomProperties::compute(&declarations(&[("--base", "4px")]), None);
+        let child = CustomProperties::compute(
+            &declarations(&[("--gap", "calc(var(--base) * 2)"), ("--padding", "var(--gap)")]),
+            Some(&parent),
+        );
+
+        assert_eq!(child.get("--gap"), Some("calc(4px * 2)"));
+        assert_eq!(child.get("--padding"), Some("calc(4px * 2)"));
+        assert_eq!(child.substitute("var(--base) var(--missing, 1px)"), Some("4px 1px".into()));
+    }
+
+    #[test]
+    fn cyclic_references_are_invalid() {
+        let properties = CustomProperties::compute(
+            &declarations(&[("--a", "var(--b)"), ("--b", "var(--a)"), ("--c", "1px")]),
+            None,
+        );
+
+        assert_eq!(properties.get("--a"), None);
+        assert_eq!(properties.get("--b"), None);
+        assert_eq!(properties.get("--c"), Some("1px"));
+    }
+}
@@ -0,0 +1,138 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use vizia_style::{CustomParseError, Error, Location};
+
+/// A problem found while loading a stylesheet, such as an invalid selector, an unknown property, or an invalid
+/// property value.
+///
+/// Diagnostics for the stylesheets of an application are returned by
+/// [`add_stylesheet()`](crate::context::Context::add_stylesheet) and
+/// [`reload_styles()`](crate::context::EventContext::reload_styles), and can be retrieved with
+/// [`style_diagnostics()`](crate::context::Context::style_diagnostics).
+#[derive(Debug, Clone, PartialEq)]
+pub struct StyleDiagnostic {
+    /// The path of the stylesheet, or `None` if the stylesheet was added as a string.
+    pub path: Option<PathBuf>,
+    /// The line number, starting from 1, or 0 if the diagnostic applies to the whole stylesheet.
+    pub line: u32,
+    /// The column number, starting from 1, or 0 if the diagnostic applies to the whole stylesheet.
+    pub column: u32,
+    /// The name of the property the diagnostic refers to, if any.
+    pub property: Option<String>,
+    /// A description of the problem.
+    pub reason: String,
+}
+
+impl StyleDiagnostic {
+    pub(crate) fn from_error(error: &Error<CustomParseError>, path: Option<&Path>) -> Self {
+        let property = match &error.kind {
+            CustomParseError::UnknownProperty(name)
+            | CustomParseError::InvalidPropertyValue(name) => Some(name.to_string()),
+            _ => None,
+        };
+
+        let (line, column) =
+            error.location.as_ref().map_or((0, 0), |location| (location.line + 1, location.column));
+
+        Self {
+            path: path.map(Path::to_path_buf),
+            line,
+            column,
+            property,
+            reason: error.kind.to_string(),
+        }
+    }
+
+    pub(crate) fn new(path: Option<&Path>, location: Location, reason: String) -> Self {
+        Self {
+            path: path.map(Path::to_path_buf),
+            line: location.line + 1,
+            column: location.column,
+            property: None,
+            reason,
+        }
+    }
+
+    pub(crate) fn from_io_error(error: &std::io::Error, path: Option<&Path>) -> Self {
+        Self {
+            path: path.map(Path::to_path_buf),
+            line: 0,
+            column: 0,
+            property: None,
+            reason: format!("Failed to read stylesheet: {}", error),
+        }
+    }
+}
+
+impl fmt::Display for StyleDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.path {
+            Some(path) => write!(f, "{}", path.display())?,
+            None => f.write_str("<stylesheet>")?,
+        }
+
+        if self.line > 0 {
+            write!(f, ":{}:{}", self.line, self.column)?;
+        }
+
+        write!(f, ": {}", self.reason)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vizia_style::ErrorLocation;
+
+    #[test]
+    fn diagnostic_from_error() {
+        let error = Error {
+            kind: CustomParseError::InvalidPropertyValue("width".into()),
+            location: Some(ErrorLocation { filename: String::new(), line: 4, column: 11 }),
+        };
+
+        let diagnostic = StyleDiagnostic::from_error(&error, Some(Path::new("theme.css")));
+
+        assert_eq!(
+            diagnostic,
+            StyleDiagnostic {
+                path: Some(PathBuf::from("theme.css")),
+                line: 5,
+                column: 11,
+                property: Some(String::from("width")),
+                reason: String::from("Invalid value for property: width"),
+            }
+        );
+        assert_eq!(diagnostic.to_string(), "theme.css:5:11: Invalid value for property: width");
+    }
+
+    #[test]
+    fn diagnostic_without_location() {
+        let error = std::io::Error::new(std::io::ErrorKind::NotFound, "not found");
+        let diagnostic = StyleDiagnostic::from_io_error(&error, None);
+
+        assert_eq!(diagnostic.property, None);
+        assert_eq!(diagnostic.to_string(), "<stylesheet>: Failed to read stylesheet: not found");
+    }
+
+    #[test]
+    fn theme_diagnostics_reported_once() {
+        use crate::prelude::*;
+        use std::{cell::Cell, rc::Rc};
+
+        let cx = &mut Context::new();
+        let reported = Rc::new(Cell::new(0));
+        let counter = reported.clone();
+        cx.set_style_diagnostic_handler(move |_| counter.set(counter.get() + 1));
+
+        cx.add_theme(".a { widht: 10px; }");
+        assert_eq!(reported.get(), 1);
+
+        // Reloading styles reports the diagnostics of user stylesheets, but not those of unchanged themes.
+        let diagnostics = cx.add_stylesheet(".b { heigth: 10px; }").unwrap();
+        assert_eq!(reported.get(), 2);
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(cx.style_diagnostics(), diagnostics);
+    }
+}
@@ -28,6 +28,186 @@
 //! ```
 //! Rules defined in css can apply to many views but are overridden by inline properties on a view.
 //!
+//! ### Attribute Selectors
+//! Attributes set on a view with the [`attr()`](crate::modifiers::StyleModifiers::attr) modifier can be matched
+//! by attribute selectors, such as `[orientation]`, `[orientation="vertical"]` or `[data-state^=open]`.
+//! The value of an attribute can be bound to a lens, in which case the view is restyled when the value changes.
+//! ```
+//! # use vizia_core::prelude::*;
+//! # let cx = &mut Context::default();
+//! Element::new(cx).attr("orientation", "vertical");
+//! ```
+//! ```css
+//! element[orientation="vertical"] {
+//!     width: 10px;
+//! }
+//! ```
+//!
+//! ### Generated Content
+//! Rules with a `::before` or `::after` pseudo-element and a `content` property generate an anonymous child view
+//! at the start or end of each matched view, which is laid out and drawn like a label. The content can contain
+//! strings, including escaped icon codepoints such as `"\e900"`, the value of an attribute of the matched view with
+//! `attr()`, and the value of a counter with `counter()`. Counters are created with `counter-reset` and incremented
+//! with `counter-increment`.
+//! ```css
+//! list {
+//!     counter-reset: item;
+//! }
+//!
+//! list > label::before {
+//!     counter-increment: item;
+//!     content: counter(item) ". ";
+//! }
+//!
+//! textbox[data-unit]::after {
+//!     content: attr(data-unit);
+//! }
+//! ```
+//!
+//! ### Text Pseudo-Elements
+//! The selected text of a textbox is styled by the `background-color` and `color` properties of `::selection`
+//! rules, while the placeholder of a textbox and the bullets of markdown lists are styled by `::placeholder` and
+//! `::marker` rules respectively.
+//! ```css
+//! textbox::selection {
+//!     background-color: #6464c888;
+//!     color: white;
+//! }
+//!
+//! textbox::placeholder {
+//!     color: gray;
+//!     font-style: italic;
+//! }
+//!
+//! .li::marker {
+//!     color: #3f7de1;
+//! }
+//! ```
+//!
+//! ### Custom Properties
+//! Rules can declare custom properties, with names beginning with `--`, which are inherited by descendant views.
+//! The value of a custom property can be used in any other property with `var()`, along with an optional fallback value.
+//! ```css
+//! .container {
+//!     --accent: #3d7eff;
+//! }
+//!
+//! .foo {
+//!     background-color: var(--accent, red);
+//! }
+//! ```
+//!
+//! ### Colors
+//! Colors can be specified in the `lab()`, `lch()`, `oklab()` and `oklch()` color spaces, mixed with `color-mix()`,
+//! or derived from another color with the relative color syntax, which makes the channels of the origin color
+//! available as keywords. The `currentcolor` keyword resolves to the `color` of the view. Color transitions and
+//! animations are interpolated in Oklab.
+//! ```css
+//! button {
+//!     background-color: var(--accent);
+//!     border-color: currentcolor;
+//! }
+//!
+//! button:hover {
+//!     background-color: color-mix(in oklab, var(--accent), white 20%);
+//! }
+//!
+//! button:active {
+//!     background-color: oklch(from var(--accent) calc(l - 0.1) c h);
+//! }
+//! ```
+//!
+//! ### Media Queries
+//! Rules within an `@media` block only apply while its media query matches. Media queries can test the logical
+//! `width` and `height` of the main window, its `resolution` (scale factor), its `orientation`, and the
+//! `prefers-color-scheme` and `prefers-reduced-motion` preferences, and are re-evaluated when these change.
+//! ```css
+//! @media (max-width: 600px) {
+//!     .sidebar {
+//!         display: none;
+//!     }
+//! }
+//!
+//! @media (prefers-color-scheme: dark) and (min-resolution: 2dppx) {
+//!     .logo {
+//!         background-image: url("logo-dark@2x.png");
+//!     }
+//! }
+//! ```
+//!
+//! ### Container Queries
+//! Views with a `container-type` of `size` or `inline-size` are containers, which can be queried by the rules within
+//! an `@container` block. Each rule applies to a view only while the condition of the block matches the logical
+//! size of the nearest ancestor container, optionally restricted to containers with a given `container-name`.
+//! Container queries can test the `width` of a container, as well as the `height` and `orientation` of containers
+//! with a `container-type` of `size`, and are re-evaluated after layout when the size of a container changes.
+//! ```css
+//! .panel {
+//!     container: panel / inline-size;
+//! }
+//!
+//! @container panel (max-width: 400px) {
+//!     .panel .details {
+//!         display: none;
+//!     }
+//! }
+//! ```
+//!
+//! ### Font Faces
+//! Fonts can be loaded by a stylesheet with an `@font-face` rule, which adds a font file as a face of a font family
+//! for a range of weights and a slant. The url of a font file is resolved relative to the stylesheet file, if the
+//! stylesheet was loaded from a path. Text using the family is drawn with the face which best matches its
+//! `font-weight` and `font-slant`. Fonts can also be loaded in code with
+//! [`add_font_file()`](crate::context::Context::add_font_file()).
+//! ```css
+//! @font-face {
+//!     font-family: "Inter";
+//!     src: url("fonts/Inter-Variable.ttf");
+//!     font-weight: 100 900;
+//! }
+//!
+//! @font-face {
+//!     font-family: "Inter";
+//!     src: url("fonts/Inter-Italic.ttf"), local("Inter Italic");
+//!     font-style: italic;
+//! }
+//!
+//! .title {
+//!     font-family: "Inter";
+//!     font-weight: bold;
+//! }
+//! ```
+//!
+//! ### Animations
+//! Keyframe animations defined with `@keyframes` can be played with the `animation` property, or its `animation-name`,
+//! `animation-duration`, `animation-delay`, `animation-timing-function`, `animation-iteration-count`,
+//! `animation-direction` and `animation-fill-mode` longhands. An animation starts when a rule naming it begins to
+//! match a view, such as a `:hover` rule, and stops when the rule no longer matches.
+//! ```css
+//! @keyframes pulse {
+//!     from { opacity: 1.0; }
+//!     to { opacity: 0.5; }
+//! }
+//!
+//! .foo:hover {
+//!     animation: pulse 200ms ease-out infinite alternate;
+//! }
+//! ```
+//!
+//! As well as the keyword and `cubic-bezier()` easing functions, transitions and animations support `steps()`,
+//! `linear()` with multiple stops, and a non-standard `spring(stiffness, damping, mass)` function. A keyframe can set
+//! its own `animation-timing-function`, which is used until the next keyframe.
+//! ```css
+//! @keyframes walk {
+//!     from { transform: translateX(0px); animation-timing-function: steps(8, jump-none); }
+//!     to { transform: translateX(-256px); }
+//! }
+//!
+//! .panel {
+//!     transition: left 400ms spring(170, 26);
+//! }
+//! ```
+//!
 //! ### Adding Stylesheets
 //! To add a css string to an application, use [`add_theme()`](crate::context::Context::add_theme()) on [`Context`].
 //! This can be used with the `include_str!()` macro to embed an external stylesheet file into the application binary when compiled.
@@ -50,6 +230,9 @@
 //!
 //! To add an external css stylesheet which is read from a file at runtime, use [`add_stylesheet()`](crate::context::Context::add_stylesheet()) on [`Context`].
 //! Stylesheets added this way can be hot-reloaded by pressing the F5 key in the application window.
+//! They can also be reloaded automatically whenever their files change by calling
+//! [`watch_stylesheets()`](crate::context::Context::watch_stylesheets()), or the `watch_stylesheets()` method of the
+//! winit and baseview applications. Running animations and transitions keep playing when styles are reloaded.
 //!
 //! ```
 //! # use vizia_core::prelude::*;
@@ -59,29 +242,64 @@
 //!
 //! Element::new(cx).class("foo");
 //! ```
+//!
+//! ### Imports and Cascade Layers
+//! A stylesheet loaded from a file can include other stylesheets with `@import` rules, which must come before any
+//! other rules except `@layer` statements. The url of an import is resolved relative to the importing stylesheet, and
+//! an import which would cause a cycle is skipped.
+//!
+//! Rules can be placed into cascade layers with `@layer` blocks or `@import url(...) layer(name)`. Rules in a later
+//! layer override rules in an earlier layer regardless of specificity, and rules which are not in a layer override
+//! all layers. Layers are ordered by when they are first declared, so a stylesheet can declare the order up front with
+//! a statement such as `@layer design-system, app;`. The rules of the built-in themes are in the `vizia` layer, which
+//! comes before all other layers.
+//!
+//! ```css
+//! @layer design-system, app;
+//! @import url("design-system.css") layer(design-system);
+//!
+//! @layer app {
+//!     button {
+//!         background-color: red;
+//!     }
+//! }
+//! ```
+//!
+//! ### Diagnostics
+//! Invalid selectors, unknown properties, and invalid property values are skipped when a stylesheet is parsed, without
+//! affecting the rest of the stylesheet. Each problem is described by a [`StyleDiagnostic`], with the path, line,
+//! and column of the problem within the stylesheet. Diagnostics can be retrieved with
+//! [`style_diagnostics()`](crate::context::Context::style_diagnostics()), and are logged as warnings unless a handler
+//! is set with [`set_style_diagnostic_handler()`](crate::context::Context::set_style_diagnostic_handler()).
 
 use hashbrown::{HashMap, HashSet};
 use indexmap::IndexMap;
 use log::warn;
 use std::fmt::Debug;
 use std::ops::{Deref, DerefMut, Range};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
 
 use crate::prelude::*;
 
 pub use vizia_style::{
-    Alignment, Angle, BackgroundImage, BackgroundSize, BorderStyleKeyword, ClipPath, Color,
-    CornerShape, CssRule, CursorIcon, Display, Filter, FontFamily, FontSize, FontSlant,
-    FontVariation, FontWeight, FontWeightKeyword, FontWidth, GenericFontFamily, Gradient,
-    HorizontalPosition, HorizontalPositionKeyword, Length, LengthOrPercentage, LengthValue,
-    LineClamp, LineDirection, LinearGradient, Matrix, Opacity, Overflow, PointerEvents, Position,
-    PositionType, Scale, Shadow, TextAlign, TextDecorationLine, TextDecorationStyle, TextOverflow,
-    TextStroke, TextStrokeStyle, Transform, Transition, Translate, VerticalPosition,
-    VerticalPositionKeyword, Visibility, RGBA,
+    Alignment, Angle, AngleOrPercentage, AnimationDirection, AnimationFillMode,
+    AnimationIterationCount, BackgroundImage, BackgroundSize, BorderStyleKeyword, ClipPath, Color,
+    ColorInterpolationMethod, ColorSpace, ConicGradient, ContainerName, ContainerType, CornerShape,
+    CssRule, CursorIcon, Display, EasingFunction, Filter, FontFamily, FontFeature, FontSize,
+    FontSlant, FontVariation, FontWeight, FontWeightKeyword, FontWidth, GenericFontFamily,
+    Gradient, GradientItem, HorizontalPosition, HorizontalPositionKeyword, HueInterpolationMethod,
+    Length, LengthOrPercentage, LengthValue, LineClamp, LineDirection, LineHeight, LinearGradient,
+    LinearStop, Matrix, Opacity, Overflow, PointerEvents, Position, PositionType, RadialGradient,
+    Scale, Shadow, Spacing, StepPosition, TextAlign, TextDecorationLine, TextDecorationStyle,
+    TextOverflow, TextShadow, TextStroke, TextStrokeStyle, TextTransform, Transform, Transition,
+    Translate, VerticalPosition, VerticalPositionKeyword, Visibility, RGBA,
 };
 
 use vizia_style::{
-    BlendMode, EasingFunction, KeyframeSelector, ParserOptions, Property, SelectorList, Selectors,
-    StyleSheet,
+    BlendMode, ContainerQuery, Content, CounterChange, ImportRule, KeyframeSelector, LayerName,
+    MediaQueryList, MediaState, ParserOptions, Property, PseudoElement, SelectorList, Selectors,
+    StyleSheet, ToCss,
 };
 
 mod rule;
@@ -93,9 +311,18 @@ pub(crate) use pseudoclass::*;
 mod transform;
 pub(crate) use transform::*;
 
-use crate::animation::{AnimationState, Interpolator, Keyframe, TimingFunction};
+mod custom_property;
+pub(crate) use custom_property::*;
+
+mod diagnostic;
+pub use diagnostic::*;
+
+use crate::animation::{
+    AnimationState, Interpolator, Keyframe, PlaybackControl, PlaybackOptions, TimingFunction,
+};
 use crate::storage::animatable_set::AnimatableSet;
 use crate::storage::style_set::StyleSet;
+use crate::text::FontFaceDescriptor;
 use bitflags::bitflags;
 use vizia_id::IdManager;
 use vizia_storage::SparseSet;
@@ -188,6 +415,41 @@ impl DerefMut for Bloom {
     }
 }
 
+/// The name of the cascade layer containing the rules of the built-in themes.
+pub(crate) const THEME_LAYER: &str = "vizia";
+
+// The context which the rules of a stylesheet are added in.
+#[derive(Clone, Copy)]
+struct RuleScope<'a> {
+    // The path of the stylesheet, against which the urls of imports and font faces are resolved.
+    path: Option<&'a Path>,
+    // The canonical paths of the stylesheet and those which imported it, used to detect import cycles.
+    importers: &'a [PathBuf],
+    // The media query lists of the `@media` blocks and imports containing the rules.
+    media: &'a [MediaQueryList],
+    // The queries of the `@container` blocks containing the rules.
+    container: &'a [ContainerQuery],
+    // The full name of the cascade layer containing the rules.
+    layer: Option<&'a str>,
+}
+
+// Returns the canonical form of a path so that the same file imported through different paths can be recognized.
+fn canonical_path(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+// A rule containing the resolved variable declarations of a rule for a given set of values.
+pub(crate) struct ResolvedRule {
+    source: Rule,
+    values: Vec<Option<String>>,
+    // Whether each variable declaration of the source rule was resolved to a valid property.
+    parsed: Vec<bool>,
+    // Transitions declared using variables.
+    transitions: Vec<Transition>,
+    // The number of entities which are linked to the rule.
+    users: usize,
+}
+
 /// Stores the style properties of all entities in the application.
 #[derive(Default)]
 pub struct Style {
@@ -197,16 +459,64 @@ pub struct Style {
     pub(crate) animation_manager: IdManager<Animation>,
     pub(crate) animations: HashMap<String, Animation>,
     // List of animations to be started on the next frame
-    pub(crate) pending_animations: Vec<(Entity, Animation, Duration, Duration)>,
+    pub(crate) pending_animations: Vec<(Entity, Animation, PlaybackOptions)>,
 
     // List of rules
     pub(crate) rules: IndexMap<Rule, SelectorList<Selectors>>,
 
+    // Custom Properties
+    pub(crate) custom_property_rules: HashMap<Rule, Vec<(String, String)>>,
+    pub(crate) custom_properties: SparseSet<CustomProperties>,
+    // Declarations which contain `var()` references, resolved against the custom properties of each entity.
+    pub(crate) variable_declarations: HashMap<Rule, Vec<(String, String)>>,
+    pub(crate) variable_transitions: HashMap<Rule, Vec<Transition>>,
+    // Rules containing the resolved variable declarations of a rule for a given set of values.
+    pub(crate) resolved_rules: HashMap<(Rule, Vec<Option<String>>), Rule>,
+    pub(crate) resolved_rule_data: HashMap<Rule, ResolvedRule>,
+    // Resolved rules which no longer have any users, which are reused for other values of the same source rule.
+    pub(crate) unused_resolved_rules: Vec<Rule>,
+    // The resolved rules each entity is linked to.
+    pub(crate) entity_resolved_rules: SparseSet<Vec<Rule>>,
+
+    // Media Queries
+    // The media query lists of the `@media` blocks containing a rule, all of which must match for the rule to apply.
+    pub(crate) media_rules: HashMap<Rule, Vec<MediaQueryList>>,
+    // Rules within `@media` blocks whose media queries don't currently match.
+    pub(crate) inactive_rules: HashSet<Rule>,
+    // The state of the window and environment which media queries are evaluated against.
+    pub(crate) media: MediaState,
+
+    // Container Queries
+    // The queries of the `@container` blocks containing a rule, all of which must match for the rule to apply.
+    pub(crate) container_rules: HashMap<Rule, Vec<ContainerQuery>>,
+
+    // Cascade Layers
+    // The full names of cascade layers in the order they were first declared, where the name of a nested layer is
+    // the path of its ancestors separated by dots.
+    pub(crate) layers: Vec<String>,
+    // The cascade layer of each rule within a layer, as an index into `layers`.
+    pub(crate) rule_layers: HashMap<Rule, usize>,
+    // The precedence of each layer in `layers`, with higher values overriding lower ones.
+    layer_order: Vec<usize>,
+
+    // Rules with a `::selection` selector, whose properties apply to the selected text of an entity.
+    pub(crate) selection_rules: HashSet<Rule>,
+
+    // Stylesheet files imported by `@import` rules.
+    pub(crate) imports: Vec<PathBuf>,
+
+    // Font faces declared by `@font-face` rules, which are loaded when styles are reloaded.
+    pub(crate) font_faces: Vec<FontFaceDescriptor>,
+
+    // Problems found while parsing the current stylesheets.
+    pub(crate) diagnostics: Vec<StyleDiagnostic>,
+
     pub(crate) default_font: Vec<FamilyOwned>,
 
     // CSS Selector Properties
     pub(crate) ids: SparseSet<String>,
     pub(crate) classes: SparseSet<HashSet<String>>,
+    pub(crate) attributes: SparseSet<HashMap<String, String>>,
     pub(crate) pseudo_classes: SparseSet<PseudoClassFlags>,
     pub(crate) disabled: StyleSet<bool>,
     pub(crate) abilities: SparseSet<Abilities>,
@@ -238,7 +548,8 @@ pub struct Style {
     pub(crate) overflowy: StyleSet<Overflow>,
 
     // Filters
-    pub(crate) backdrop_filter: AnimatableSet<Filter>,
+    pub(crate) filter: AnimatableSet<Vec<Filter>>,
+    pub(crate) backdrop_filter: AnimatableSet<Vec<Filter>>,
 
     pub(crate) blend_mode: StyleSet<BlendMode>,
 
@@ -249,10 +560,23 @@ pub struct Style {
     pub(crate) rotate: AnimatableSet<Angle>,
     pub(crate) scale: AnimatableSet<Scale>,
 
-    // Border
-    pub(crate) border_width: AnimatableSet<LengthOrPercentage>,
-    pub(crate) border_color: AnimatableSet<Color>,
-    pub(crate) border_style: StyleSet<BorderStyleKeyword>,
+    // Border Width
+    pub(crate) border_top_width: AnimatableSet<LengthOrPercentage>,
+    pub(crate) border_right_width: AnimatableSet<LengthOrPercentage>,
+    pub(crate) border_bottom_width: AnimatableSet<LengthOrPercentage>,
+    pub(crate) border_left_width: AnimatableSet<LengthOrPercentage>,
+
+    // Border Color
+    pub(crate) border_top_color: AnimatableSet<Color>,
+    pub(crate) border_right_color: AnimatableSet<Color>,
+    pub(crate) border_bottom_color: AnimatableSet<Color>,
+    pub(crate) border_left_color: AnimatableSet<Color>,
+
+    // Border Style
+    pub(crate) border_top_style: StyleSet<BorderStyleKeyword>,
+    pub(crate) border_right_style: StyleSet<BorderStyleKeyword>,
+    pub(crate) border_bottom_style: StyleSet<BorderStyleKeyword>,
+    pub(crate) border_left_style: StyleSet<BorderStyleKeyword>,
 
     // Corner Shape
     pub(crate) corner_top_left_shape: StyleSet<CornerShape>,
@@ -307,8 +631,17 @@ pub struct Style {
     pub(crate) font_slant: StyleSet<FontSlant>,
     pub(crate) font_width: StyleSet<FontWidth>,
     pub(crate) font_variation_settings: StyleSet<Vec<FontVariation>>,
+    pub(crate) font_feature_settings: StyleSet<Vec<FontFeature>>,
+    pub(crate) line_height: AnimatableSet<LineHeight>,
+    pub(crate) letter_spacing: AnimatableSet<Length>,
+    pub(crate) word_spacing: AnimatableSet<Length>,
+    pub(crate) text_indent: AnimatableSet<Length>,
+    pub(crate) text_shadow: AnimatableSet<Vec<TextShadow>>,
+    pub(crate) text_transform: StyleSet<TextTransform>,
     pub(crate) caret_color: AnimatableSet<Color>,
     pub(crate) selection_color: AnimatableSet<Color>,
+    // The text color of selected text, set by the `color` property of a `::selection` rule.
+    pub(crate) selection_font_color: AnimatableSet<Color>,
 
     pub(crate) fill: AnimatableSet<Color>,
 
@@ -317,6 +650,28 @@ pub struct Style {
 
     pub(crate) pointer_events: StyleSet<PointerEvents>,
 
+    // Generated Content
+    pub(crate) content: StyleSet<Content>,
+    pub(crate) counter_reset: StyleSet<Vec<CounterChange>>,
+    pub(crate) counter_increment: StyleSet<Vec<CounterChange>>,
+    // The pseudo-element which each generated content entity was created for.
+    pub(crate) generated_content: SparseSet<PseudoElement>,
+    // The pseudo-element which each view part, such as the placeholder of a textbox, is styled by.
+    pub(crate) pseudo_element_views: SparseSet<PseudoElement>,
+    // Views which render selectable text, and so are styled by `::selection` rules.
+    pub(crate) selectable_text: HashSet<Entity>,
+
+    // Animations
+    pub(crate) animation_name: StyleSet<Vec<Option<String>>>,
+    pub(crate) animation_duration: StyleSet<Vec<Duration>>,
+    pub(crate) animation_delay: StyleSet<Vec<Duration>>,
+    pub(crate) animation_timing_function: StyleSet<Vec<EasingFunction>>,
+    pub(crate) animation_iteration_count: StyleSet<Vec<AnimationIterationCount>>,
+    pub(crate) animation_direction: StyleSet<Vec<AnimationDirection>>,
+    pub(crate) animation_fill_mode: StyleSet<Vec<AnimationFillMode>>,
+    // Keyframe animations currently played on each entity due to its `animation-name`, with their playback options.
+    pub(crate) css_animations: SparseSet<Vec<(Animation, PlaybackOptions)>>,
+
     // LAYOUT
 
     // Display
@@ -330,6 +685,18 @@ pub struct Style {
 
     pub(crate) alignment: StyleSet<Alignment>,
 
+    // Grid
+    pub(crate) grid_columns: StyleSet<Vec<Units>>,
+    pub(crate) grid_rows: StyleSet<Vec<Units>>,
+    pub(crate) column_start: StyleSet<usize>,
+    pub(crate) column_span: StyleSet<usize>,
+    pub(crate) row_start: StyleSet<usize>,
+    pub(crate) row_span: StyleSet<usize>,
+
+    // Containers
+    pub(crate) container_type: StyleSet<ContainerType>,
+    pub(crate) container_name: StyleSet<ContainerName>,
+
     // Spacing
     pub(crate) left: AnimatableSet<Units>,
     pub(crate) right: AnimatableSet<Units>,
@@ -378,6 +745,86 @@ pub struct Style {
     pub(crate) dpi_factor: f64,
 }
 
+/// Invokes the given macro with the names of every animatable property set of [`Style`], each followed by whether an
+/// animation of the property needs a `redraw`, a `reflow` of the text, or a `relayout` of the view.
+macro_rules! animatable_sets {
+    ($callback:ident) => {
+        $callback!(
+            opacity => redraw,
+            clip_path => redraw,
+            filter => redraw,
+            backdrop_filter => redraw,
+            transform => redraw,
+            transform_origin => redraw,
+            translate => redraw,
+            rotate => redraw,
+            scale => redraw,
+            border_top_width => relayout,
+            border_right_width => relayout,
+            border_bottom_width => relayout,
+            border_left_width => relayout,
+            border_top_color => redraw,
+            border_right_color => redraw,
+            border_bottom_color => redraw,
+            border_left_color => redraw,
+            corner_top_left_radius => redraw,
+            corner_top_right_radius => redraw,
+            corner_bottom_left_radius => redraw,
+            corner_bottom_right_radius => redraw,
+            corner_top_left_smoothing => redraw,
+            corner_top_right_smoothing => redraw,
+            corner_bottom_left_smoothing => redraw,
+            corner_bottom_right_smoothing => redraw,
+            outline_width => redraw,
+            outline_color => redraw,
+            outline_offset => redraw,
+            background_color => redraw,
+            background_image => redraw,
+            background_size => redraw,
+            shadow => redraw,
+            underline_color => reflow,
+            overline_color => reflow,
+            strikethrough_color => reflow,
+            font_color => reflow,
+            font_size => reflow,
+            line_height => reflow,
+            letter_spacing => reflow,
+            word_spacing => reflow,
+            text_indent => reflow,
+            text_shadow => reflow,
+            caret_color => redraw,
+            selection_color => redraw,
+            selection_font_color => redraw,
+            fill => redraw,
+            display => relayout,
+            left => relayout,
+            right => relayout,
+            top => relayout,
+            bottom => relayout,
+            padding_left => relayout,
+            padding_right => relayout,
+            padding_top => relayout,
+            padding_bottom => relayout,
+            vertical_gap => relayout,
+            horizontal_gap => relayout,
+            vertical_scroll => relayout,
+            horizontal_scroll => relayout,
+            width => relayout,
+            height => relayout,
+            min_width => relayout,
+            max_width => relayout,
+            min_height => relayout,
+            max_height => relayout,
+            min_horizontal_gap => relayout,
+            max_horizontal_gap => relayout,
+            min_vertical_gap => relayout,
+            max_vertical_gap => relayout
+        )
+    };
+}
+
+pub(crate) use animatable_sets;
+
 impl Style {
     pub fn scale_factor(&self) -> f32 {
         self.dpi_factor as f32
@@ -393,9 +840,114 @@ impl Style {
         physical / self.dpi_factor as f32
     }
 
+    /// Returns the value of `currentcolor` for the given entity, which is its font color.
+    pub(crate) fn current_color(&self, tree: &Tree<Entity>, entity: Entity) -> Color {
+        // A font color of `currentcolor` behaves like `inherit`, so resolve it from the ancestors.
+        entity
+            .parent_iter(tree)
+            .filter_map(|ancestor| self.font_color.get(ancestor))
+            .find(|color| **color != Color::CurrentColor)
+            .copied()
+            .unwrap_or(Color::black())
+    }
+
+    /// Returns the nearest ancestor of an entity which is a container that can be queried by the given query.
+    pub(crate) fn query_container(
+        &self,
+        tree: &Tree<Entity>,
+        entity: Entity,
+        query: &ContainerQuery,
+    ) -> Option<Entity> {
+        entity.parent_iter(tree).skip(1).find(|ancestor| self.is_queried_by(*ancestor, query))
+    }
+
+    // Returns true if the given entity is a container with a name matching the given query.
+    fn is_queried_by(&self, container: Entity, query: &ContainerQuery) -> bool {
+        let is_container =
+            self.container_type.get(container).is_some_and(|ty| *ty != ContainerType::Normal);
+
+        is_container
+            && query.name.as_ref().map_or(true, |name| {
+                self.container_name.get(container).is_some_and(|names| names.contains(name))
+            })
+    }
+
+    /// Returns true if the given query matches a container with the given bounds.
+    pub(crate) fn container_query_matches(
+        &self,
+        container: Entity,
+        query: &ContainerQuery,
+        bounds: BoundingBox,
+    ) -> bool {
+        // Only the width of an `inline-size` container can be queried.
+        let height = match self.container_type.get(container) {
+            Some(ContainerType::Size) => Some(self.physical_to_logical(bounds.h)),
+            _ => None,
+        };
+
+        query.matches(self.physical_to_logical(bounds.w), height)
+    }
+
+    /// Returns true if any container query which can query the given container matches differently at its old and
+    /// new bounds, in which case the descendants of the container need to be restyled.
+    pub(crate) fn crosses_container_breakpoint(
+        &self,
+        container: Entity,
+        old_bounds: BoundingBox,
+        new_bounds: BoundingBox,
+    ) -> bool {
+        self.container_rules.values().flatten().any(|query| {
+            self.is_queried_by(container, query)
+                && self.container_query_matches(container, query, old_bounds)
+                    != self.container_query_matches(container, query, new_bounds)
+        })
+    }
+
     pub(crate) fn remove_rules(&mut self) {
         self.rule_manager.reset();
         self.rules.clear();
+        self.custom_property_rules.clear();
+        self.variable_declarations.clear();
+        self.variable_transitions.clear();
+        self.resolved_rules.clear();
+        self.resolved_rule_data.clear();
+        self.unused_resolved_rules.clear();
+        self.entity_resolved_rules.clear();
+        self.media_rules.clear();
+        self.inactive_rules.clear();
+        self.container_rules.clear();
+        self.layers.clear();
+        self.rule_layers.clear();
+        self.layer_order.clear();
+        self.selection_rules.clear();
+        self.imports.clear();
+        self.font_faces.clear();
+        self.diagnostics.clear();
+    }
+
+    /// Sets the state which media queries are evaluated against, restyling every entity in the tree if this changes
+    /// which rules apply.
+    pub(crate) fn set_media(&mut self, media: MediaState, tree: &Tree<Entity>) {
+        self.media = media;
+        if self.update_media_rules() {
+            for entity in tree.into_iter() {
+                self.needs_restyle(entity);
+            }
+        }
+    }
+
+    // Determines which rules within `@media` blocks apply, returning true if this has changed.
+    fn update_media_rules(&mut self) -> bool {
+        let inactive_rules = self
+            .media_rules
+            .iter()
+            .filter(|(_, queries)| !queries.iter().all(|query| query.matches(&self.media)))
+            .map(|(rule, _)| *rule)
+            .collect::<HashSet<_>>();
+
+        let changed = inactive_rules != self.inactive_rules;
+        self.inactive_rules = inactive_rules;
+        changed
     }
 
     pub(crate) fn get_animation(&self, name: &str) -> Option<&Animation> {
@@ -413,8 +965,9 @@ impl Style {
             animation_id: Animation,
             time: f32,
             value: T,
+            timing_function: &Option<TimingFunction>,
         ) {
-            let keyframe = Keyframe { time, value, timing_function: TimingFunction::linear() };
+            let keyframe = Keyframe { time, value, timing_function: timing_function.clone() };
 
             if let Some(anim_state) = storage.get_animation_mut(animation_id) {
                 anim_state.keyframes.push(keyframe)
@@ -424,57 +977,251 @@ impl Style {
             }
         }
 
+        // The timing function of a keyframe applies to the segment between it and the next keyframe.
+        let timing_function = properties.iter().find_map(|property| match property {
+            Property::AnimationTimingFunction(timing_functions) => {
+                timing_functions.first().cloned().map(TimingFunction::from)
+            }
+            _ => None,
+        });
+
         for property in properties.iter() {
             match property {
                 // DISPLAY
                 Property::Display(value) => {
-                    insert_keyframe(&mut self.display, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.display,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 Property::Opacity(value) => {
-                    insert_keyframe(&mut self.opacity, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.opacity,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 Property::ClipPath(value) => {
-                    insert_keyframe(&mut self.clip_path, animation_id, time, value.clone());
+                    insert_keyframe(
+                        &mut self.clip_path,
+                        animation_id,
+                        time,
+                        value.clone(),
+                        &timing_function,
+                    );
+                }
+
+                // FILTER
+                Property::Filter(value) => {
+                    insert_keyframe(
+                        &mut self.filter,
+                        animation_id,
+                        time,
+                        value.clone(),
+                        &timing_function,
+                    );
+                }
+
+                Property::BackdropFilter(value) => {
+                    insert_keyframe(
+                        &mut self.backdrop_filter,
+                        animation_id,
+                        time,
+                        value.clone(),
+                        &timing_function,
+                    );
                 }
 
                 // TRANSFORM
                 Property::Transform(value) => {
-                    insert_keyframe(&mut self.transform, animation_id, time, value.clone());
+                    insert_keyframe(
+                        &mut self.transform,
+                        animation_id,
+                        time,
+                        value.clone(),
+                        &timing_function,
+                    );
                 }
 
                 Property::TransformOrigin(transform_origin) => {
                     let x = transform_origin.x.to_length_or_percentage();
                     let y = transform_origin.y.to_length_or_percentage();
                     let value = Translate { x, y };
-                    insert_keyframe(&mut self.transform_origin, animation_id, time, value);
+                    insert_keyframe(
+                        &mut self.transform_origin,
+                        animation_id,
+                        time,
+                        value,
+                        &timing_function,
+                    );
                 }
 
                 Property::Translate(value) => {
-                    insert_keyframe(&mut self.translate, animation_id, time, value.clone());
+                    insert_keyframe(
+                        &mut self.translate,
+                        animation_id,
+                        time,
+                        value.clone(),
+                        &timing_function,
+                    );
                 }
 
                 Property::Rotate(value) => {
-                    insert_keyframe(&mut self.rotate, animation_id, time, *value);
+                    insert_keyframe(&mut self.rotate, animation_id, time, *value, &timing_function);
                 }
 
                 Property::Scale(value) => {
-                    insert_keyframe(&mut self.scale, animation_id, time, *value);
+                    insert_keyframe(&mut self.scale, animation_id, time, *value, &timing_function);
                 }
 
                 // BORDER
                 Property::BorderWidth(value) => {
                     insert_keyframe(
-                        &mut self.border_width,
+                        &mut self.border_top_width,
+                        animation_id,
+                        time,
+                        value.top.0.clone(),
+                        &timing_function,
+                    );
+                    insert_keyframe(
+                        &mut self.border_right_width,
+                        animation_id,
+                        time,
+                        value.right.0.clone(),
+                        &timing_function,
+                    );
+                    insert_keyframe(
+                        &mut self.border_bottom_width,
+                        animation_id,
+                        time,
+                        value.bottom.0.clone(),
+                        &timing_function,
+                    );
+                    insert_keyframe(
+                        &mut self.border_left_width,
                         animation_id,
                         time,
                         value.left.0.clone(),
+                        &timing_function,
+                    );
+                }
+
+                Property::BorderTopWidth(value) => {
+                    insert_keyframe(
+                        &mut self.border_top_width,
+                        animation_id,
+                        time,
+                        value.0.clone(),
+                        &timing_function,
+                    );
+                }
+
+                Property::BorderRightWidth(value) => {
+                    insert_keyframe(
+                        &mut self.border_right_width,
+                        animation_id,
+                        time,
+                        value.0.clone(),
+                        &timing_function,
+                    );
+                }
+
+                Property::BorderBottomWidth(value) => {
+                    insert_keyframe(
+                        &mut self.border_bottom_width,
+                        animation_id,
+                        time,
+                        value.0.clone(),
+                        &timing_function,
+                    );
+                }
+
+                Property::BorderLeftWidth(value) => {
+                    insert_keyframe(
+                        &mut self.border_left_width,
+                        animation_id,
+                        time,
+                        value.0.clone(),
+                        &timing_function,
                     );
                 }
 
                 Property::BorderColor(value) => {
-                    insert_keyframe(&mut self.border_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.border_top_color,
+                        animation_id,
+                        time,
+                        value.top,
+                        &timing_function,
+                    );
+                    insert_keyframe(
+                        &mut self.border_right_color,
+                        animation_id,
+                        time,
+                        value.right,
+                        &timing_function,
+                    );
+                    insert_keyframe(
+                        &mut self.border_bottom_color,
+                        animation_id,
+                        time,
+                        value.bottom,
+                        &timing_function,
+                    );
+                    insert_keyframe(
+                        &mut self.border_left_color,
+                        animation_id,
+                        time,
+                        value.left,
+                        &timing_function,
+                    );
+                }
+
+                Property::BorderTopColor(value) => {
+                    insert_keyframe(
+                        &mut self.border_top_color,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
+                }
+
+                Property::BorderRightColor(value) => {
+                    insert_keyframe(
+                        &mut self.border_right_color,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
+                }
+
+                Property::BorderBottomColor(value) => {
+                    insert_keyframe(
+                        &mut self.border_bottom_color,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
+                }
+
+                Property::BorderLeftColor(value) => {
+                    insert_keyframe(
+                        &mut self.border_left_color,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 Property::CornerTopLeftRadius(value) => {
@@ -483,6 +1230,7 @@ impl Style {
                         animation_id,
                         time,
                         value.clone(),
+                        &timing_function,
                     );
                 }
 
@@ -492,6 +1240,7 @@ impl Style {
                         animation_id,
                         time,
                         value.clone(),
+                        &timing_function,
                     );
                 }
 
@@ -501,6 +1250,7 @@ impl Style {
                         animation_id,
                         time,
                         value.clone(),
+                        &timing_function,
                     );
                 }
 
@@ -510,6 +1260,7 @@ impl Style {
                         animation_id,
                         time,
                         value.clone(),
+                        &timing_function,
                     );
                 }
 
@@ -520,20 +1271,39 @@ impl Style {
                         animation_id,
                         time,
                         value.left.0.clone(),
+                        &timing_function,
                     );
                 }
 
                 Property::OutlineColor(value) => {
-                    insert_keyframe(&mut self.outline_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.outline_color,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 Property::OutlineOffset(value) => {
-                    insert_keyframe(&mut self.outline_offset, animation_id, time, value.clone());
+                    insert_keyframe(
+                        &mut self.outline_offset,
+                        animation_id,
+                        time,
+                        value.clone(),
+                        &timing_function,
+                    );
                 }
 
                 // BACKGROUND
                 Property::BackgroundColor(value) => {
-                    insert_keyframe(&mut self.background_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.background_color,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 Property::BackgroundImage(images) => {
@@ -549,141 +1319,359 @@ impl Style {
                             }
                         })
                         .collect::<Vec<_>>();
-                    insert_keyframe(&mut self.background_image, animation_id, time, images);
+                    insert_keyframe(
+                        &mut self.background_image,
+                        animation_id,
+                        time,
+                        images,
+                        &timing_function,
+                    );
                 }
 
                 Property::BackgroundSize(value) => {
-                    insert_keyframe(&mut self.background_size, animation_id, time, value.clone());
+                    insert_keyframe(
+                        &mut self.background_size,
+                        animation_id,
+                        time,
+                        value.clone(),
+                        &timing_function,
+                    );
                 }
 
                 // BOX SHADOW
                 Property::Shadow(value) => {
-                    insert_keyframe(&mut self.shadow, animation_id, time, value.clone());
+                    insert_keyframe(
+                        &mut self.shadow,
+                        animation_id,
+                        time,
+                        value.clone(),
+                        &timing_function,
+                    );
                 }
 
                 // TEXT
                 Property::FontColor(value) => {
-                    insert_keyframe(&mut self.font_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.font_color,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 Property::FontSize(value) => {
-                    insert_keyframe(&mut self.font_size, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.font_size,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
+                }
+
+                Property::LineHeight(value) => {
+                    insert_keyframe(
+                        &mut self.line_height,
+                        animation_id,
+                        time,
+                        value.clone(),
+                        &timing_function,
+                    );
+                }
+
+                Property::LetterSpacing(value) => {
+                    insert_keyframe(
+                        &mut self.letter_spacing,
+                        animation_id,
+                        time,
+                        value.clone().into(),
+                        &timing_function,
+                    );
+                }
+
+                Property::WordSpacing(value) => {
+                    insert_keyframe(
+                        &mut self.word_spacing,
+                        animation_id,
+                        time,
+                        value.clone().into(),
+                        &timing_function,
+                    );
+                }
+
+                Property::TextIndent(value) => {
+                    insert_keyframe(
+                        &mut self.text_indent,
+                        animation_id,
+                        time,
+                        value.clone(),
+                        &timing_function,
+                    );
+                }
+
+                Property::TextShadow(value) => {
+                    insert_keyframe(
+                        &mut self.text_shadow,
+                        animation_id,
+                        time,
+                        value.clone(),
+                        &timing_function,
+                    );
                 }
 
                 Property::CaretColor(value) => {
-                    insert_keyframe(&mut self.caret_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.caret_color,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 Property::SelectionColor(value) => {
-                    insert_keyframe(&mut self.selection_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.selection_color,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 // SPACE
                 Property::Left(value) => {
-                    insert_keyframe(&mut self.left, animation_id, time, *value);
+                    insert_keyframe(&mut self.left, animation_id, time, *value, &timing_function);
                 }
 
                 Property::Right(value) => {
-                    insert_keyframe(&mut self.right, animation_id, time, *value);
+                    insert_keyframe(&mut self.right, animation_id, time, *value, &timing_function);
                 }
 
                 Property::Top(value) => {
-                    insert_keyframe(&mut self.top, animation_id, time, *value);
+                    insert_keyframe(&mut self.top, animation_id, time, *value, &timing_function);
                 }
 
                 Property::Bottom(value) => {
-                    insert_keyframe(&mut self.bottom, animation_id, time, *value);
+                    insert_keyframe(&mut self.bottom, animation_id, time, *value, &timing_function);
                 }
 
                 // Padding
                 Property::PaddingLeft(value) => {
-                    insert_keyframe(&mut self.padding_left, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.padding_left,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 Property::PaddingRight(value) => {
-                    insert_keyframe(&mut self.padding_right, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.padding_right,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 Property::PaddingTop(value) => {
-                    insert_keyframe(&mut self.padding_top, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.padding_top,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 Property::PaddingBottom(value) => {
-                    insert_keyframe(&mut self.padding_bottom, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.padding_bottom,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 Property::HorizontalGap(value) => {
-                    insert_keyframe(&mut self.horizontal_gap, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.horizontal_gap,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 Property::VerticalGap(value) => {
-                    insert_keyframe(&mut self.vertical_gap, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.vertical_gap,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 Property::Gap(value) => {
-                    insert_keyframe(&mut self.horizontal_gap, animation_id, time, *value);
-                    insert_keyframe(&mut self.vertical_gap, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.horizontal_gap,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
+                    insert_keyframe(
+                        &mut self.vertical_gap,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 // GAP CONSSTRAINTS
                 Property::MinGap(value) => {
-                    insert_keyframe(&mut self.min_horizontal_gap, animation_id, time, *value);
-                    insert_keyframe(&mut self.min_vertical_gap, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.min_horizontal_gap,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
+                    insert_keyframe(
+                        &mut self.min_vertical_gap,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 Property::MaxGap(value) => {
-                    insert_keyframe(&mut self.max_horizontal_gap, animation_id, time, *value);
-                    insert_keyframe(&mut self.max_vertical_gap, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.max_horizontal_gap,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
+                    insert_keyframe(
+                        &mut self.max_vertical_gap,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 Property::MinHorizontalGap(value) => {
-                    insert_keyframe(&mut self.min_horizontal_gap, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.min_horizontal_gap,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 Property::MaxHorizontalGap(value) => {
-                    insert_keyframe(&mut self.max_horizontal_gap, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.max_horizontal_gap,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 Property::MinVerticalGap(value) => {
-                    insert_keyframe(&mut self.min_vertical_gap, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.min_vertical_gap,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 Property::MaxVerticalGap(value) => {
-                    insert_keyframe(&mut self.max_vertical_gap, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.max_vertical_gap,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 // SIZE
                 Property::Width(value) => {
-                    insert_keyframe(&mut self.width, animation_id, time, *value);
+                    insert_keyframe(&mut self.width, animation_id, time, *value, &timing_function);
                 }
 
                 Property::Height(value) => {
-                    insert_keyframe(&mut self.height, animation_id, time, *value);
+                    insert_keyframe(&mut self.height, animation_id, time, *value, &timing_function);
                 }
 
                 // SIZE CONSTRAINTS
                 Property::MinWidth(value) => {
-                    insert_keyframe(&mut self.min_width, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.min_width,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 Property::MaxWidth(value) => {
-                    insert_keyframe(&mut self.max_width, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.max_width,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 Property::MinHeight(value) => {
-                    insert_keyframe(&mut self.min_height, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.min_height,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 Property::MaxHeight(value) => {
-                    insert_keyframe(&mut self.max_height, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.max_height,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 Property::UnderlineColor(value) => {
-                    insert_keyframe(&mut self.underline_color, animation_id, time, *value);
+                    insert_keyframe(
+                        &mut self.underline_color,
+                        animation_id,
+                        time,
+                        *value,
+                        &timing_function,
+                    );
                 }
 
                 Property::Fill(value) => {
-                    insert_keyframe(&mut self.fill, animation_id, time, *value);
+                    insert_keyframe(&mut self.fill, animation_id, time, *value, &timing_function);
                 }
 
                 _ => {}
@@ -704,10 +1692,9 @@ impl Style {
         &mut self,
         entity: Entity,
         animation: Animation,
-        duration: Duration,
-        delay: Duration,
+        options: PlaybackOptions,
     ) {
-        self.pending_animations.push((entity, animation, duration, delay));
+        self.pending_animations.push((entity, animation, options));
     }
 
     pub(crate) fn play_pending_animations(&mut self) {
@@ -715,8 +1702,8 @@ impl Style {
 
         let pending_animations = self.pending_animations.drain(..).collect::<Vec<_>>();
 
-        for (entity, animation, duration, delay) in pending_animations {
-            self.play_animation(entity, animation, start_time + delay, duration, delay)
+        for (entity, animation, options) in pending_animations {
+            self.play_animation(entity, animation, start_time, options)
         }
     }
 
@@ -725,188 +1712,629 @@ impl Style {
         entity: Entity,
         animation: Animation,
         start_time: Instant,
-        duration: Duration,
-        delay: Duration,
+        options: PlaybackOptions,
     ) {
-        self.display.play_animation(entity, animation, start_time, duration, delay);
-        self.opacity.play_animation(entity, animation, start_time, duration, delay);
-        self.clip_path.play_animation(entity, animation, start_time, duration, delay);
-
-        self.transform.play_animation(entity, animation, start_time, duration, delay);
-        self.transform_origin.play_animation(entity, animation, start_time, duration, delay);
-        self.translate.play_animation(entity, animation, start_time, duration, delay);
-        self.rotate.play_animation(entity, animation, start_time, duration, delay);
-        self.scale.play_animation(entity, animation, start_time, duration, delay);
-
-        self.border_width.play_animation(entity, animation, start_time, duration, delay);
-        self.border_color.play_animation(entity, animation, start_time, duration, delay);
-
-        self.corner_top_left_radius.play_animation(entity, animation, start_time, duration, delay);
-        self.corner_top_right_radius.play_animation(entity, animation, start_time, duration, delay);
-        self.corner_bottom_left_radius
-            .play_animation(entity, animation, start_time, duration, delay);
-        self.corner_bottom_right_radius
-            .play_animation(entity, animation, start_time, duration, delay);
-
-        self.outline_width.play_animation(entity, animation, start_time, duration, delay);
-        self.outline_color.play_animation(entity, animation, start_time, duration, delay);
-        self.outline_offset.play_animation(entity, animation, start_time, duration, delay);
-
-        self.background_color.play_animation(entity, animation, start_time, duration, delay);
-        self.background_image.play_animation(entity, animation, start_time, duration, delay);
-        self.background_size.play_animation(entity, animation, start_time, duration, delay);
-
-        self.shadow.play_animation(entity, animation, start_time, duration, delay);
-
-        self.font_color.play_animation(entity, animation, start_time, duration, delay);
-        self.font_size.play_animation(entity, animation, start_time, duration, delay);
-        self.caret_color.play_animation(entity, animation, start_time, duration, delay);
-        self.selection_color.play_animation(entity, animation, start_time, duration, delay);
-
-        self.left.play_animation(entity, animation, start_time, duration, delay);
-        self.right.play_animation(entity, animation, start_time, duration, delay);
-        self.top.play_animation(entity, animation, start_time, duration, delay);
-        self.bottom.play_animation(entity, animation, start_time, duration, delay);
-
-        self.padding_left.play_animation(entity, animation, start_time, duration, delay);
-        self.padding_right.play_animation(entity, animation, start_time, duration, delay);
-        self.padding_top.play_animation(entity, animation, start_time, duration, delay);
-        self.padding_bottom.play_animation(entity, animation, start_time, duration, delay);
-        self.horizontal_gap.play_animation(entity, animation, start_time, duration, delay);
-        self.vertical_gap.play_animation(entity, animation, start_time, duration, delay);
-
-        self.width.play_animation(entity, animation, start_time, duration, delay);
-        self.height.play_animation(entity, animation, start_time, duration, delay);
-
-        self.min_width.play_animation(entity, animation, start_time, duration, delay);
-        self.max_width.play_animation(entity, animation, start_time, duration, delay);
-        self.min_height.play_animation(entity, animation, start_time, duration, delay);
-        self.max_height.play_animation(entity, animation, start_time, duration, delay);
-
-        self.min_horizontal_gap.play_animation(entity, animation, start_time, duration, delay);
-        self.max_horizontal_gap.play_animation(entity, animation, start_time, duration, delay);
-        self.min_vertical_gap.play_animation(entity, animation, start_time, duration, delay);
-        self.max_vertical_gap.play_animation(entity, animation, start_time, duration, delay);
-
-        self.underline_color.play_animation(entity, animation, start_time, duration, delay);
-
-        self.fill.play_animation(entity, animation, start_time, duration, delay);
+        macro_rules! play_animation {
+            ($($set:ident => $kind:ident),+) => {
+                $(self.$set.play_animation(entity, animation, start_time, &options);)+
+            };
+        }
+
+        animatable_sets!(play_animation);
+    }
+
+    pub(crate) fn stop_animation(&mut self, entity: Entity, animation: Animation) {
+        self.pending_animations.retain(|(e, anim, _)| *e != entity || *anim != animation);
+
+        macro_rules! stop_animation {
+            ($($set:ident => $kind:ident),+) => {
+                $(self.$set.stop_animation(entity, animation);)+
+            };
+        }
+
+        animatable_sets!(stop_animation);
+    }
+
+    /// Removes the keyframes of an animation, so that it can be redefined without restarting the entities which
+    /// are playing it.
+    pub(crate) fn remove_animation(&mut self, animation: Animation) {
+        macro_rules! remove_animation {
+            ($($set:ident => $kind:ident),+) => {
+                $(self.$set.remove_animation(animation);)+
+            };
+        }
+
+        animatable_sets!(remove_animation);
+    }
+
+    pub(crate) fn control_animation(
+        &mut self,
+        entity: Entity,
+        animation: Animation,
+        control: PlaybackControl,
+    ) {
+        let time = Instant::now();
+
+        macro_rules! control_animation {
+            ($($set:ident => $kind:ident),+) => {
+                $(self.$set.control_animation(entity, animation, control, time);)+
+            };
+        }
+
+        animatable_sets!(control_animation);
+    }
+
+    /// Starts the keyframe animations named by the `animation-name` of an entity which are not already playing,
+    /// and stops those which are no longer named. Animations whose playback options change are restarted.
+    pub(crate) fn update_css_animations(&mut self, entity: Entity) {
+        let names = self.animation_name.get(entity).cloned().unwrap_or_default();
+        let durations = self.animation_duration.get(entity).cloned().unwrap_or_default();
+        let delays = self.animation_delay.get(entity).cloned().unwrap_or_default();
+        let timing_functions =
+            self.animation_timing_function.get(entity).cloned().unwrap_or_default();
+        let iteration_counts =
+            self.animation_iteration_count.get(entity).cloned().unwrap_or_default();
+        let directions = self.animation_direction.get(entity).cloned().unwrap_or_default();
+        let fill_modes = self.animation_fill_mode.get(entity).cloned().unwrap_or_default();
+
+        // Values of the other `animation-*` properties are repeated to match the number of names.
+        fn nth<T: Clone>(values: &[T], index: usize) -> Option<T> {
+            values.get(index % values.len().max(1)).cloned()
+        }
+
+        let mut animations = Vec::new();
+        for (index, name) in names.iter().enumerate() {
+            let Some(animation) = name.as_ref().and_then(|name| self.animations.get(name)) else {
+                continue;
+            };
+
+            let duration = nth(&durations, index).unwrap_or_default();
+            let delay = nth(&delays, index).unwrap_or_default();
+            let timing_function = nth(&timing_functions, index).unwrap_or(EasingFunction::Ease);
+            let iteration_count = nth(&iteration_counts, index).unwrap_or_default();
+            let direction = nth(&directions, index).unwrap_or_default();
+            let fill_mode = nth(&fill_modes, index).unwrap_or_default();
+
+            // An animation with a zero duration has no visible effect unless it fills forwards.
+            if duration.is_zero()
+                && !matches!(fill_mode, AnimationFillMode::Forwards | AnimationFillMode::Both)
+            {
+                continue;
+            }
+
+            animations.push((
+                *animation,
+                PlaybackOptions::new(duration, delay)
+                    .iterations(iteration_count)
+                    .direction(direction)
+                    .fill_mode(fill_mode)
+                    .timing_function(timing_function),
+            ));
+        }
+
+        let previous = self.css_animations.remove(entity).unwrap_or_default();
+
+        for playing in previous.iter() {
+            if !animations.contains(playing) {
+                self.stop_animation(entity, playing.0);
+            }
+        }
+
+        for (animation, options) in animations.iter() {
+            if !previous.iter().any(|(anim, opts)| anim == animation && opts == options) {
+                self.enqueue_animation(entity, *animation, options.clone());
+            }
+        }
+
+        if !animations.is_empty() {
+            self.css_animations.insert(entity, animations);
+        }
     }
 
     pub(crate) fn is_animating(&self, entity: Entity, animation: Animation) -> bool {
-        self.display.has_active_animation(entity, animation)
-            | self.opacity.has_active_animation(entity, animation)
-            | self.clip_path.has_active_animation(entity, animation)
-            | self.transform.has_active_animation(entity, animation)
-            | self.transform_origin.has_active_animation(entity, animation)
-            | self.translate.has_active_animation(entity, animation)
-            | self.rotate.has_active_animation(entity, animation)
-            | self.scale.has_active_animation(entity, animation)
-            | self.border_width.has_active_animation(entity, animation)
-            | self.border_color.has_active_animation(entity, animation)
-            | self.corner_top_left_radius.has_active_animation(entity, animation)
-            | self.corner_top_right_radius.has_active_animation(entity, animation)
-            | self.corner_bottom_left_radius.has_active_animation(entity, animation)
-            | self.corner_bottom_right_radius.has_active_animation(entity, animation)
-            | self.outline_width.has_active_animation(entity, animation)
-            | self.outline_color.has_active_animation(entity, animation)
-            | self.outline_offset.has_active_animation(entity, animation)
-            | self.background_color.has_active_animation(entity, animation)
-            | self.background_image.has_active_animation(entity, animation)
-            | self.background_size.has_active_animation(entity, animation)
-            | self.shadow.has_active_animation(entity, animation)
-            | self.font_color.has_active_animation(entity, animation)
-            | self.font_size.has_active_animation(entity, animation)
-            | self.caret_color.has_active_animation(entity, animation)
-            | self.selection_color.has_active_animation(entity, animation)
-            | self.left.has_active_animation(entity, animation)
-            | self.right.has_active_animation(entity, animation)
-            | self.top.has_active_animation(entity, animation)
-            | self.bottom.has_active_animation(entity, animation)
-            | self.padding_left.has_active_animation(entity, animation)
-            | self.padding_right.has_active_animation(entity, animation)
-            | self.padding_top.has_active_animation(entity, animation)
-            | self.padding_bottom.has_active_animation(entity, animation)
-            | self.horizontal_gap.has_active_animation(entity, animation)
-            | self.vertical_gap.has_active_animation(entity, animation)
-            | self.width.has_active_animation(entity, animation)
-            | self.height.has_active_animation(entity, animation)
-            | self.min_width.has_active_animation(entity, animation)
-            | self.max_width.has_active_animation(entity, animation)
-            | self.min_height.has_active_animation(entity, animation)
-            | self.max_height.has_active_animation(entity, animation)
-            | self.min_horizontal_gap.has_active_animation(entity, animation)
-            | self.max_horizontal_gap.has_active_animation(entity, animation)
-            | self.min_vertical_gap.has_active_animation(entity, animation)
-            | self.max_vertical_gap.has_active_animation(entity, animation)
-            | self.underline_color.has_active_animation(entity, animation)
-            | self.fill.has_active_animation(entity, animation)
+        macro_rules! is_animating {
+            ($($set:ident => $kind:ident),+) => {
+                $(self.$set.has_active_animation(entity, animation))|+
+            };
+        }
+
+        animatable_sets!(is_animating)
     }
 
+    /// Parses a built-in theme, whose rules are added to the [`THEME_LAYER`] cascade layer so that they are overridden
+    /// by the stylesheets of the application.
     pub(crate) fn parse_theme(&mut self, stylesheet: &str) {
-        if let Ok(stylesheet) = StyleSheet::parse(stylesheet, ParserOptions::new()) {
-            let rules = stylesheet.rules.0;
+        self.parse_stylesheet_in(
+            stylesheet,
+            RuleScope {
+                path: None,
+                importers: &[],
+                media: &[],
+                container: &[],
+                layer: Some(THEME_LAYER),
+            },
+        );
 
-            for rule in rules {
-                match rule {
-                    CssRule::Style(style_rule) => {
-                        let rule_id = self.rule_manager.create();
+        self.update_media_rules();
+        self.update_layer_order();
+    }
 
-                        let selectors = style_rule.selectors;
+    /// Parses a stylesheet loaded from the given path, against which the urls of any imports and font faces are
+    /// resolved.
+    ///
+    /// Any problems found while parsing the stylesheet are added to the diagnostics of the style.
+    pub(crate) fn parse_stylesheet(&mut self, stylesheet: &str, path: Option<&Path>) {
+        let importers = path.map(canonical_path).into_iter().collect::<Vec<_>>();
 
-                        self.rules.insert(rule_id, selectors);
+        self.parse_stylesheet_in(
+            stylesheet,
+            RuleScope { path, importers: &importers, media: &[], container: &[], layer: None },
+        );
 
-                        for property in style_rule.declarations.declarations {
-                            match property {
-                                Property::Transition(transitions) => {
-                                    for transition in transitions.iter() {
-                                        self.insert_transition(rule_id, transition);
-                                    }
+        self.update_media_rules();
+        self.update_layer_order();
+    }
+
+    // Parses a stylesheet, adding its rules within the given scope.
+    fn parse_stylesheet_in(&mut self, stylesheet: &str, scope: RuleScope) {
+        let warnings = Arc::new(RwLock::new(Vec::new()));
+        let mut options = ParserOptions::new();
+        options.filename = scope.path.map(|path| path.display().to_string()).unwrap_or_default();
+        options.warnings = Some(warnings.clone());
+
+        match StyleSheet::parse(stylesheet, options) {
+            Ok(stylesheet) => {
+                self.parse_rules(stylesheet.rules.0, scope);
+            }
+
+            Err(error) => {
+                self.diagnostics.push(StyleDiagnostic::from_error(&error, scope.path));
+            }
+        }
+
+        if let Ok(warnings) = warnings.read() {
+            self.diagnostics.extend(
+                warnings.iter().map(|warning| StyleDiagnostic::from_error(warning, scope.path)),
+            );
+        }
+    }
+
+    // Parses the stylesheet imported by an `@import` rule, whose url is resolved against the importing stylesheet.
+    fn import_stylesheet(&mut self, import_rule: ImportRule, scope: RuleScope) {
+        let Some(path) = scope.path else {
+            self.diagnostics.push(StyleDiagnostic::new(
+                None,
+                import_rule.loc,
+                String::from("@import is only supported in stylesheets loaded from files"),
+            ));
+            return;
+        };
+
+        let url = Path::new(import_rule.url.as_ref());
+        let import_path = path.parent().map_or_else(|| url.to_path_buf(), |dir| dir.join(url));
+
+        let canonical_import_path = canonical_path(&import_path);
+        if scope.importers.contains(&canonical_import_path) {
+            self.diagnostics.push(StyleDiagnostic::new(
+                Some(path),
+                import_rule.loc,
+                format!("Import cycle: {} is already being imported", import_path.display()),
+            ));
+            return;
+        }
+
+        let stylesheet = match std::fs::read_to_string(&import_path) {
+            Ok(stylesheet) => stylesheet,
+            Err(error) => {
+                self.diagnostics.push(StyleDiagnostic::new(
+                    Some(path),
+                    import_rule.loc,
+                    format!("Failed to import {}: {}", import_path.display(), error),
+                ));
+                return;
+            }
+        };
+
+        let layer = match &import_rule.layer {
+            Some(name) => Some(self.declare_layer(scope.layer, name.as_ref())),
+            None => scope.layer.map(str::to_owned),
+        };
+
+        let mut media = scope.media.to_vec();
+        if !import_rule.media.0.is_empty() {
+            media.push(import_rule.media);
+        }
+
+        let mut importers = scope.importers.to_vec();
+        importers.push(canonical_import_path);
+
+        self.imports.push(import_path.clone());
+
+        self.parse_stylesheet_in(
+            &stylesheet,
+            RuleScope {
+                path: Some(&import_path),
+                importers: &importers,
+                media: &media,
+                container: scope.container,
+                layer: layer.as_deref(),
+            },
+        );
+    }
+
+    // Declares a cascade layer within the given parent layer, or a new anonymous layer if it has no name, returning
+    // the full name of the layer. Layers are ordered by when they are first declared.
+    fn declare_layer(&mut self, parent: Option<&str>, name: Option<&LayerName>) -> String {
+        let name = match name {
+            Some(name) => name.as_string(),
+            None => format!("<anonymous-{}>", self.layers.len()),
+        };
+
+        let full_name = match parent {
+            Some(parent) => format!("{}.{}", parent, name),
+            None => name,
+        };
+
+        self.layer_index(&full_name);
+
+        full_name
+    }
+
+    // Returns the index of the cascade layer with the given full name, adding it if it hasn't been declared.
+    fn layer_index(&mut self, name: &str) -> usize {
+        if let Some(index) = self.layers.iter().position(|layer| layer == name) {
+            return index;
+        }
+
+        // Parent layers are declared before the layers nested within them.
+        if let Some((parent, _)) = name.rsplit_once('.') {
+            self.layer_index(parent);
+        }
+
+        self.layers.push(name.to_owned());
+        self.layers.len() - 1
+    }
+
+    // Determines the precedence of the cascade layers. Sibling layers are ordered by when they were first declared,
+    // and the rules of a layer override those of the layers nested within it.
+    fn update_layer_order(&mut self) {
+        fn visit(
+            layers: &[String],
+            parent: Option<&str>,
+            layer_order: &mut [usize],
+            next: &mut usize,
+        ) {
+            for (index, name) in layers.iter().enumerate() {
+                if name.rsplit_once('.').map(|(parent, _)| parent) == parent {
+                    visit(layers, Some(name), layer_order, next);
+                    layer_order[index] = *next;
+                    *next += 1;
+                }
+            }
+        }
+
+        let mut layer_order = vec![0; self.layers.len()];
+        visit(&self.layers, None, &mut layer_order, &mut 0);
+        self.layer_order = layer_order;
+    }
+
+    /// Returns the precedence of the cascade layer of a rule, where rules which aren't in a layer override those
+    /// which are.
+    pub(crate) fn layer_precedence(&self, rule: Rule) -> usize {
+        self.rule_layers
+            .get(&rule)
+            .and_then(|layer| self.layer_order.get(*layer))
+            .copied()
+            .unwrap_or(usize::MAX)
+    }
+
+    // Adds the given rules of a stylesheet within the given scope.
+    fn parse_rules(&mut self, rules: Vec<CssRule>, scope: RuleScope) {
+        for rule in rules {
+            match rule {
+                CssRule::Style(style_rule) => {
+                    let rule_id = self.rule_manager.create();
+
+                    let selectors = style_rule.selectors;
+
+                    if selectors.slice().iter().all(|selector| {
+                        selector.pseudo_element() == Some(&PseudoElement::Selection)
+                    }) {
+                        self.selection_rules.insert(rule_id);
+                    }
+
+                    self.rules.insert(rule_id, selectors);
+
+                    if !scope.media.is_empty() {
+                        self.media_rules.insert(rule_id, scope.media.to_vec());
+                    }
+
+                    if !scope.container.is_empty() {
+                        self.container_rules.insert(rule_id, scope.container.to_vec());
+                    }
+
+                    if let Some(layer) = scope.layer {
+                        let layer = self.layer_index(layer);
+                        self.rule_layers.insert(rule_id, layer);
+                    }
+
+                    let mut rule_transitions = Vec::new();
+
+                    for property in style_rule.declarations.declarations {
+                        match property {
+                            Property::Transition(transitions) => {
+                                for transition in transitions.iter() {
+                                    self.insert_transition(rule_id, transition);
                                 }
 
-                                _ => {
-                                    self.insert_property(rule_id, property);
+                                rule_transitions.extend(transitions);
+                            }
+
+                            // Later declarations take precedence over earlier ones.
+                            Property::Custom(custom) => {
+                                self.custom_property_rules.entry(rule_id).or_default().insert(
+                                    0,
+                                    (custom.name.to_string(), custom.value.to_css_string()),
+                                );
+                            }
+
+                            Property::Unparsed(unparsed) if unparsed.value.has_variables() => {
+                                self.variable_declarations.entry(rule_id).or_default().push((
+                                    unparsed.name.to_string(),
+                                    unparsed.value.to_css_string(),
+                                ));
+                            }
+
+                            _ => {
+                                // A later declaration overrides earlier declarations of the same property which
+                                // reference variables.
+                                if let Some(declarations) =
+                                    self.variable_declarations.get_mut(&rule_id)
+                                {
+                                    declarations.retain(|(name, _)| name != property.name());
+                                    if declarations.is_empty() {
+                                        self.variable_declarations.remove(&rule_id);
+                                    }
                                 }
+
+                                self.insert_property(rule_id, property);
                             }
                         }
                     }
 
-                    CssRule::Keyframes(keyframes_rule) => {
-                        let name = keyframes_rule.name.as_string();
+                    if self.variable_declarations.contains_key(&rule_id) {
+                        self.variable_transitions.insert(rule_id, rule_transitions);
+                    }
+                }
 
-                        let animation_id = self.animation_manager.create();
+                CssRule::Keyframes(keyframes_rule) => {
+                    let name = keyframes_rule.name.as_string();
 
-                        for keyframes in keyframes_rule.keyframes {
-                            for selector in keyframes.selectors.iter() {
-                                let time = match selector {
-                                    KeyframeSelector::From => 0.0,
-                                    KeyframeSelector::To => 1.0,
-                                    KeyframeSelector::Percentage(percentage) => {
-                                        percentage.0 / 100.0
-                                    }
-                                };
+                    // Redefining an animation keeps its id so that entities which are playing it aren't restarted.
+                    let animation_id = match self.animations.get(&name) {
+                        Some(&animation_id) => {
+                            self.remove_animation(animation_id);
+                            animation_id
+                        }
+                        None => self.animation_manager.create(),
+                    };
+
+                    for keyframes in keyframes_rule.keyframes {
+                        for selector in keyframes.selectors.iter() {
+                            let time = match selector {
+                                KeyframeSelector::From => 0.0,
+                                KeyframeSelector::To => 1.0,
+                                KeyframeSelector::Percentage(percentage) => percentage.0 / 100.0,
+                            };
+
+                            self.add_keyframe(
+                                animation_id,
+                                time,
+                                &keyframes.declarations.declarations,
+                            );
+                        }
+                    }
+
+                    self.animations.insert(name, animation_id);
+                }
+
+                CssRule::Media(media_rule) => {
+                    let mut media = scope.media.to_vec();
+                    media.push(media_rule.query);
+                    self.parse_rules(media_rule.rules.0, RuleScope { media: &media, ..scope });
+                }
+
+                CssRule::Container(container_rule) => {
+                    let mut container = scope.container.to_vec();
+                    container.push(container_rule.query);
+                    self.parse_rules(
+                        container_rule.rules.0,
+                        RuleScope { container: &container, ..scope },
+                    );
+                }
+
+                CssRule::FontFace(font_face_rule) => {
+                    if let Some(mut font_face) = FontFaceDescriptor::from_rule(font_face_rule) {
+                        if let Some(path) = scope.path {
+                            font_face.resolve_paths(path);
+                        }
+
+                        self.font_faces.push(font_face);
+                    }
+                }
+
+                CssRule::Import(import_rule) => {
+                    self.import_stylesheet(import_rule, scope);
+                }
+
+                CssRule::LayerStatement(layer_statement) => {
+                    for name in layer_statement.names.iter() {
+                        self.declare_layer(scope.layer, Some(name));
+                    }
+                }
+
+                CssRule::LayerBlock(layer_block) => {
+                    let layer = self.declare_layer(scope.layer, layer_block.name.as_ref());
+                    self.parse_rules(
+                        layer_block.rules.0,
+                        RuleScope { layer: Some(&layer), ..scope },
+                    );
+                }
+
+                _ => {}
+            }
+        }
+    }
+
+    /// Returns a rule containing the declarations of the given rule which reference custom properties,
+    /// resolved against the given custom property values.
+    pub(crate) fn resolve_variables(
+        &mut self,
+        rule_id: Rule,
+        custom_properties: &CustomProperties,
+    ) -> Option<Rule> {
+        let declarations = self.variable_declarations.get(&rule_id)?.clone();
+
+        let values = declarations
+            .iter()
+            .map(|(_, value)| custom_properties.substitute(value))
+            .collect::<Vec<_>>();
+
+        if let Some(resolved_rule) = self.resolved_rules.get(&(rule_id, values.clone())) {
+            return Some(*resolved_rule);
+        }
+
+        let mut properties = Vec::new();
+        let mut transitions = Vec::new();
+        let mut parsed = vec![false; declarations.len()];
+        for (index, ((name, _), value)) in declarations.iter().zip(values.iter()).enumerate() {
+            // Declarations referencing undefined variables without a fallback are ignored.
+            if let Some(value) = value {
+                match Property::parse_string(name, value) {
+                    Ok(Property::Transition(resolved)) => {
+                        transitions.extend(resolved);
+                        parsed[index] = true;
+                    }
+                    Ok(Property::Unparsed(_)) | Err(_) => {
+                        warn!("Failed to parse resolved property: {}: {}", name, value);
+                    }
+                    Ok(property) => {
+                        properties.push(property);
+                        parsed[index] = true;
+                    }
+                }
+            }
+        }
+
+        // An unused resolved rule of the same source rule is reused if the same declarations are valid, so that all
+        // of its properties are overwritten with the new values.
+        let reusable = self.unused_resolved_rules.iter().position(|rule| {
+            self.resolved_rule_data.get(rule).is_some_and(|data| {
+                data.source == rule_id && data.parsed == parsed && data.transitions == transitions
+            })
+        });
+
+        let resolved_rule = if let Some(position) = reusable {
+            let resolved_rule = self.unused_resolved_rules.swap_remove(position);
+            if let Some(data) = self.resolved_rule_data.get_mut(&resolved_rule) {
+                let previous_values = std::mem::replace(&mut data.values, values.clone());
+                self.resolved_rules.remove(&(rule_id, previous_values));
+            }
+
+            for property in properties {
+                self.insert_property(resolved_rule, property);
+            }
+
+            resolved_rule
+        } else {
+            let resolved_rule = self.rule_manager.create();
+
+            if self.selection_rules.contains(&rule_id) {
+                self.selection_rules.insert(resolved_rule);
+            }
+
+            for property in properties {
+                self.insert_property(resolved_rule, property);
+            }
+
+            let rule_transitions = self.variable_transitions.get(&rule_id).into_iter().flatten();
+            for transition in
+                rule_transitions.chain(transitions.iter()).cloned().collect::<Vec<_>>()
+            {
+                self.insert_transition(resolved_rule, &transition);
+            }
+
+            self.resolved_rule_data.insert(
+                resolved_rule,
+                ResolvedRule {
+                    source: rule_id,
+                    values: values.clone(),
+                    parsed,
+                    transitions,
+                    users: 0,
+                },
+            );
+
+            resolved_rule
+        };
+
+        self.resolved_rules.insert((rule_id, values), resolved_rule);
+
+        Some(resolved_rule)
+    }
+
+    /// Sets the resolved rules the entity is linked to. Resolved rules which are no longer linked to any entity can be
+    /// reused for other values.
+    pub(crate) fn set_resolved_rules(&mut self, entity: Entity, rules: Vec<Rule>) {
+        for rule in rules.iter() {
+            if let Some(data) = self.resolved_rule_data.get_mut(rule) {
+                if data.users == 0 {
+                    self.unused_resolved_rules.retain(|unused| unused != rule);
+                }
 
-                                self.add_keyframe(
-                                    animation_id,
-                                    time,
-                                    &keyframes.declarations.declarations,
-                                );
-                            }
-                        }
+                data.users += 1;
+            }
+        }
 
-                        self.animations.insert(name, animation_id);
-                    }
+        let previous = self.entity_resolved_rules.remove(entity).unwrap_or_default();
+        if !rules.is_empty() {
+            self.entity_resolved_rules.insert(entity, rules);
+        }
 
-                    _ => {}
+        for rule in previous {
+            if let Some(data) = self.resolved_rule_data.get_mut(&rule) {
+                data.users -= 1;
+                if data.users == 0 {
+                    self.unused_resolved_rules.push(rule);
                 }
             }
-        } else {
-            println!("Failed to parse stylesheet");
         }
     }
 
     fn insert_transition(&mut self, rule_id: Rule, transition: &Transition) {
         let animation = self.animation_manager.create();
+
+        // Transitions of a `::selection` rule animate the colors of the selected text.
+        if self.selection_rules.contains(&rule_id) {
+            match transition.property.as_ref() {
+                "background-color" => {
+                    self.selection_color
+                        .insert_animation(animation, self.add_transition(transition));
+                    self.selection_color.insert_transition(rule_id, animation);
+                }
+
+                "color" => {
+                    self.selection_font_color
+                        .insert_animation(animation, self.add_transition(transition));
+                    self.selection_font_color.insert_transition(rule_id, animation);
+                }
+
+                _ => {}
+            }
+
+            return;
+        }
+
         match transition.property.as_ref() {
             "display" => {
                 self.display.insert_animation(animation, self.add_transition(transition));
@@ -923,6 +2351,16 @@ impl Style {
                 self.clip_path.insert_transition(rule_id, animation);
             }
 
+            "filter" => {
+                self.filter.insert_animation(animation, self.add_transition(transition));
+                self.filter.insert_transition(rule_id, animation);
+            }
+
+            "backdrop-filter" => {
+                self.backdrop_filter.insert_animation(animation, self.add_transition(transition));
+                self.backdrop_filter.insert_transition(rule_id, animation);
+            }
+
             "transform" => {
                 self.transform.insert_animation(animation, self.add_transition(transition));
                 self.transform.insert_transition(rule_id, animation);
@@ -949,20 +2387,128 @@ impl Style {
             }
 
             "border" => {
-                self.border_width.insert_animation(animation, self.add_transition(transition));
-                self.border_width.insert_transition(rule_id, animation);
-                self.border_color.insert_animation(animation, self.add_transition(transition));
-                self.border_color.insert_transition(rule_id, animation);
+                self.border_top_width.insert_animation(animation, self.add_transition(transition));
+                self.border_top_width.insert_transition(rule_id, animation);
+                self.border_right_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_width.insert_transition(rule_id, animation);
+                self.border_bottom_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_width.insert_transition(rule_id, animation);
+                self.border_left_width.insert_animation(animation, self.add_transition(transition));
+                self.border_left_width.insert_transition(rule_id, animation);
+                self.border_top_color.insert_animation(animation, self.add_transition(transition));
+                self.border_top_color.insert_transition(rule_id, animation);
+                self.border_right_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_color.insert_transition(rule_id, animation);
+                self.border_bottom_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_color.insert_transition(rule_id, animation);
+                self.border_left_color.insert_animation(animation, self.add_transition(transition));
+                self.border_left_color.insert_transition(rule_id, animation);
+            }
+
+            "border-top" => {
+                self.border_top_width.insert_animation(animation, self.add_transition(transition));
+                self.border_top_width.insert_transition(rule_id, animation);
+                self.border_top_color.insert_animation(animation, self.add_transition(transition));
+                self.border_top_color.insert_transition(rule_id, animation);
+            }
+
+            "border-right" => {
+                self.border_right_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_width.insert_transition(rule_id, animation);
+                self.border_right_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_color.insert_transition(rule_id, animation);
+            }
+
+            "border-bottom" => {
+                self.border_bottom_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_width.insert_transition(rule_id, animation);
+                self.border_bottom_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_color.insert_transition(rule_id, animation);
+            }
+
+            "border-left" => {
+                self.border_left_width.insert_animation(animation, self.add_transition(transition));
+                self.border_left_width.insert_transition(rule_id, animation);
+                self.border_left_color.insert_animation(animation, self.add_transition(transition));
+                self.border_left_color.insert_transition(rule_id, animation);
             }
 
             "border-width" => {
-                self.border_width.insert_animation(animation, self.add_transition(transition));
-                self.border_width.insert_transition(rule_id, animation);
+                self.border_top_width.insert_animation(animation, self.add_transition(transition));
+                self.border_top_width.insert_transition(rule_id, animation);
+                self.border_right_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_width.insert_transition(rule_id, animation);
+                self.border_bottom_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_width.insert_transition(rule_id, animation);
+                self.border_left_width.insert_animation(animation, self.add_transition(transition));
+                self.border_left_width.insert_transition(rule_id, animation);
+            }
+
+            "border-top-width" => {
+                self.border_top_width.insert_animation(animation, self.add_transition(transition));
+                self.border_top_width.insert_transition(rule_id, animation);
+            }
+
+            "border-right-width" => {
+                self.border_right_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_width.insert_transition(rule_id, animation);
+            }
+
+            "border-bottom-width" => {
+                self.border_bottom_width
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_width.insert_transition(rule_id, animation);
+            }
+
+            "border-left-width" => {
+                self.border_left_width.insert_animation(animation, self.add_transition(transition));
+                self.border_left_width.insert_transition(rule_id, animation);
             }
 
             "border-color" => {
-                self.border_color.insert_animation(animation, self.add_transition(transition));
-                self.border_color.insert_transition(rule_id, animation);
+                self.border_top_color.insert_animation(animation, self.add_transition(transition));
+                self.border_top_color.insert_transition(rule_id, animation);
+                self.border_right_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_color.insert_transition(rule_id, animation);
+                self.border_bottom_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_color.insert_transition(rule_id, animation);
+                self.border_left_color.insert_animation(animation, self.add_transition(transition));
+                self.border_left_color.insert_transition(rule_id, animation);
+            }
+
+            "border-top-color" => {
+                self.border_top_color.insert_animation(animation, self.add_transition(transition));
+                self.border_top_color.insert_transition(rule_id, animation);
+            }
+
+            "border-right-color" => {
+                self.border_right_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_right_color.insert_transition(rule_id, animation);
+            }
+
+            "border-bottom-color" => {
+                self.border_bottom_color
+                    .insert_animation(animation, self.add_transition(transition));
+                self.border_bottom_color.insert_transition(rule_id, animation);
+            }
+
+            "border-left-color" => {
+                self.border_left_color.insert_animation(animation, self.add_transition(transition));
+                self.border_left_color.insert_transition(rule_id, animation);
             }
 
             "corner-radius" => {
@@ -1056,6 +2602,31 @@ impl Style {
                 self.font_size.insert_transition(rule_id, animation);
             }
 
+            "line-height" => {
+                self.line_height.insert_animation(animation, self.add_transition(transition));
+                self.line_height.insert_transition(rule_id, animation);
+            }
+
+            "letter-spacing" => {
+                self.letter_spacing.insert_animation(animation, self.add_transition(transition));
+                self.letter_spacing.insert_transition(rule_id, animation);
+            }
+
+            "word-spacing" => {
+                self.word_spacing.insert_animation(animation, self.add_transition(transition));
+                self.word_spacing.insert_transition(rule_id, animation);
+            }
+
+            "text-indent" => {
+                self.text_indent.insert_animation(animation, self.add_transition(transition));
+                self.text_indent.insert_transition(rule_id, animation);
+            }
+
+            "text-shadow" => {
+                self.text_shadow.insert_animation(animation, self.add_transition(transition));
+                self.text_shadow.insert_transition(rule_id, animation);
+            }
+
             "caret-color" => {
                 self.caret_color.insert_animation(animation, self.add_transition(transition));
                 self.caret_color.insert_transition(rule_id, animation);
@@ -1190,6 +2761,23 @@ impl Style {
     }
 
     fn insert_property(&mut self, rule_id: Rule, property: Property) {
+        // Rules for the `::selection` pseudo-element only style the colors of the selected text.
+        if self.selection_rules.contains(&rule_id) {
+            match property {
+                Property::BackgroundColor(color) => {
+                    self.selection_color.insert_rule(rule_id, color);
+                }
+
+                Property::FontColor(color) => {
+                    self.selection_font_color.insert_rule(rule_id, color);
+                }
+
+                property => warn!("Unsupported property in ::selection rule: {:?}", property),
+            }
+
+            return;
+        }
+
         match property {
             // Display
             Property::Display(display) => {
@@ -1212,6 +2800,10 @@ impl Style {
             }
 
             // Filters
+            Property::Filter(filter) => {
+                self.filter.insert_rule(rule_id, filter);
+            }
+
             Property::BackdropFilter(filter) => {
                 self.backdrop_filter.insert_rule(rule_id, filter);
             }
@@ -1235,6 +2827,106 @@ impl Style {
                 self.alignment.insert_rule(rule_id, alignment);
             }
 
+            // Animations
+            Property::Animation(animations) => {
+                self.animation_name.insert_rule(
+                    rule_id,
+                    animations.iter().map(|animation| animation.name.as_string()).collect(),
+                );
+                self.animation_duration.insert_rule(
+                    rule_id,
+                    animations.iter().map(|animation| animation.duration).collect(),
+                );
+                self.animation_delay.insert_rule(
+                    rule_id,
+                    animations.iter().map(|animation| animation.delay).collect(),
+                );
+                self.animation_timing_function.insert_rule(
+                    rule_id,
+                    animations.iter().map(|animation| animation.timing_function.clone()).collect(),
+                );
+                self.animation_iteration_count.insert_rule(
+                    rule_id,
+                    animations.iter().map(|animation| animation.iteration_count).collect(),
+                );
+                self.animation_direction.insert_rule(
+                    rule_id,
+                    animations.iter().map(|animation| animation.direction).collect(),
+                );
+                self.animation_fill_mode.insert_rule(
+                    rule_id,
+                    animations.iter().map(|animation| animation.fill_mode).collect(),
+                );
+            }
+
+            Property::AnimationName(names) => {
+                self.animation_name
+                    .insert_rule(rule_id, names.iter().map(|name| name.as_string()).collect());
+            }
+
+            Property::AnimationDuration(durations) => {
+                self.animation_duration.insert_rule(rule_id, durations);
+            }
+
+            Property::AnimationDelay(delays) => {
+                self.animation_delay.insert_rule(rule_id, delays);
+            }
+
+            Property::AnimationTimingFunction(timing_functions) => {
+                self.animation_timing_function.insert_rule(rule_id, timing_functions);
+            }
+
+            Property::AnimationIterationCount(iteration_counts) => {
+                self.animation_iteration_count.insert_rule(rule_id, iteration_counts);
+            }
+
+            Property::AnimationDirection(directions) => {
+                self.animation_direction.insert_rule(rule_id, directions);
+            }
+
+            Property::AnimationFillMode(fill_modes) => {
+                self.animation_fill_mode.insert_rule(rule_id, fill_modes);
+            }
+
+            // Grid
+            Property::GridColumns(columns) => {
+                self.grid_columns.insert_rule(rule_id, columns);
+            }
+
+            Property::GridRows(rows) => {
+                self.grid_rows.insert_rule(rule_id, rows);
+            }
+
+            Property::ColumnStart(start) => {
+                self.column_start.insert_rule(rule_id, start);
+            }
+
+            Property::ColumnSpan(span) => {
+                self.column_span.insert_rule(rule_id, span);
+            }
+
+            Property::RowStart(start) => {
+                self.row_start.insert_rule(rule_id, start);
+            }
+
+            Property::RowSpan(span) => {
+                self.row_span.insert_rule(rule_id, span);
+            }
+
+            // Container
+            Property::Container(container) => {
+                self.container_name.insert_rule(rule_id, container.name);
+                self.container_type.insert_rule(rule_id, container.container_type);
+            }
+
+            Property::ContainerName(container_name) => {
+                self.container_name.insert_rule(rule_id, container_name);
+            }
+
+            Property::ContainerType(container_type) => {
+                self.container_type.insert_rule(rule_id, container_type);
+            }
+
             // Space
             Property::Space(space) => {
                 self.left.insert_rule(rule_id, space);
@@ -1372,29 +3064,154 @@ impl Style {
             // Border
             Property::Border(border) => {
                 if let Some(border_color) = border.color {
-                    self.border_color.insert_rule(rule_id, border_color);
+                    self.border_top_color.insert_rule(rule_id, border_color);
+                    self.border_right_color.insert_rule(rule_id, border_color);
+                    self.border_bottom_color.insert_rule(rule_id, border_color);
+                    self.border_left_color.insert_rule(rule_id, border_color);
                 }
 
                 if let Some(border_width) = border.width {
-                    self.border_width.insert_rule(rule_id, border_width.into());
+                    let border_width: LengthOrPercentage = border_width.into();
+                    self.border_top_width.insert_rule(rule_id, border_width.clone());
+                    self.border_right_width.insert_rule(rule_id, border_width.clone());
+                    self.border_bottom_width.insert_rule(rule_id, border_width.clone());
+                    self.border_left_width.insert_rule(rule_id, border_width);
                 }
 
                 if let Some(border_style) = border.style {
-                    self.border_style.insert_rule(rule_id, border_style.top);
+                    self.border_top_style.insert_rule(rule_id, border_style.top);
+                    self.border_right_style.insert_rule(rule_id, border_style.right);
+                    self.border_bottom_style.insert_rule(rule_id, border_style.bottom);
+                    self.border_left_style.insert_rule(rule_id, border_style.left);
                 }
             }
 
-            // Border
+            Property::BorderTop(border) => {
+                if let Some(border_color) = border.color {
+                    self.border_top_color.insert_rule(rule_id, border_color);
+                }
+
+                if let Some(border_width) = border.width {
+                    self.border_top_width.insert_rule(rule_id, border_width.into());
+                }
+
+                if let Some(border_style) = border.style {
+                    self.border_top_style.insert_rule(rule_id, border_style.top);
+                }
+            }
+
+            Property::BorderRight(border) => {
+                if let Some(border_color) = border.color {
+                    self.border_right_color.insert_rule(rule_id, border_color);
+                }
+
+                if let Some(border_width) = border.width {
+                    self.border_right_width.insert_rule(rule_id, border_width.into());
+                }
+
+                if let Some(border_style) = border.style {
+                    self.border_right_style.insert_rule(rule_id, border_style.top);
+                }
+            }
+
+            Property::BorderBottom(border) => {
+                if let Some(border_color) = border.color {
+                    self.border_bottom_color.insert_rule(rule_id, border_color);
+                }
+
+                if let Some(border_width) = border.width {
+                    self.border_bottom_width.insert_rule(rule_id, border_width.into());
+                }
+
+                if let Some(border_style) = border.style {
+                    self.border_bottom_style.insert_rule(rule_id, border_style.top);
+                }
+            }
+
+            Property::BorderLeft(border) => {
+                if let Some(border_color) = border.color {
+                    self.border_left_color.insert_rule(rule_id, border_color);
+                }
+
+                if let Some(border_width) = border.width {
+                    self.border_left_width.insert_rule(rule_id, border_width.into());
+                }
+
+                if let Some(border_style) = border.style {
+                    self.border_left_style.insert_rule(rule_id, border_style.top);
+                }
+            }
+
+            // Border Width
             Property::BorderWidth(border_width) => {
-                self.border_width.insert_rule(rule_id, border_width.top.0);
+                self.border_top_width.insert_rule(rule_id, border_width.top.0);
+                self.border_right_width.insert_rule(rule_id, border_width.right.0);
+                self.border_bottom_width.insert_rule(rule_id, border_width.bottom.0);
+                self.border_left_width.insert_rule(rule_id, border_width.left.0);
+            }
+
+            Property::BorderTopWidth(border_width) => {
+                self.border_top_width.insert_rule(rule_id, border_width.0);
+            }
+
+            Property::BorderRightWidth(border_width) => {
+                self.border_right_width.insert_rule(rule_id, border_width.0);
             }
 
+            Property::BorderBottomWidth(border_width) => {
+                self.border_bottom_width.insert_rule(rule_id, border_width.0);
+            }
+
+            Property::BorderLeftWidth(border_width) => {
+                self.border_left_width.insert_rule(rule_id, border_width.0);
+            }
+
+            // Border Color
             Property::BorderColor(color) => {
-                self.border_color.insert_rule(rule_id, color);
+                self.border_top_color.insert_rule(rule_id, color.top);
+                self.border_right_color.insert_rule(rule_id, color.right);
+                self.border_bottom_color.insert_rule(rule_id, color.bottom);
+                self.border_left_color.insert_rule(rule_id, color.left);
+            }
+
+            Property::BorderTopColor(color) => {
+                self.border_top_color.insert_rule(rule_id, color);
+            }
+
+            Property::BorderRightColor(color) => {
+                self.border_right_color.insert_rule(rule_id, color);
             }
 
+            Property::BorderBottomColor(color) => {
+                self.border_bottom_color.insert_rule(rule_id, color);
+            }
+
+            Property::BorderLeftColor(color) => {
+                self.border_left_color.insert_rule(rule_id, color);
+            }
+
+            // Border Style
             Property::BorderStyle(style) => {
-                self.border_style.insert_rule(rule_id, style.top);
+                self.border_top_style.insert_rule(rule_id, style.top);
+                self.border_right_style.insert_rule(rule_id, style.right);
+                self.border_bottom_style.insert_rule(rule_id, style.bottom);
+                self.border_left_style.insert_rule(rule_id, style.left);
+            }
+
+            Property::BorderTopStyle(style) => {
+                self.border_top_style.insert_rule(rule_id, style);
+            }
+
+            Property::BorderRightStyle(style) => {
+                self.border_right_style.insert_rule(rule_id, style);
+            }
+
+            Property::BorderBottomStyle(style) => {
+                self.border_bottom_style.insert_rule(rule_id, style);
+            }
+
+            Property::BorderLeftStyle(style) => {
+                self.border_left_style.insert_rule(rule_id, style);
             }
 
             // Border Radius
@@ -1475,7 +3292,7 @@ impl Style {
             }
 
             // Font Slant
-            Property::FontSlant(font_slant) => {
+            Property::FontSlant(font_slant) | Property::FontStyle(font_slant) => {
                 self.font_slant.insert_rule(rule_id, font_slant);
             }
 
@@ -1489,6 +3306,41 @@ impl Style {
                 self.font_variation_settings.insert_rule(rule_id, font_variation_settings);
             }
 
+            // Font Feature Settings
+            Property::FontFeatureSettings(font_feature_settings) => {
+                self.font_feature_settings.insert_rule(rule_id, font_feature_settings);
+            }
+
+            // Line Height
+            Property::LineHeight(line_height) => {
+                self.line_height.insert_rule(rule_id, line_height);
+            }
+
+            // Letter Spacing
+            Property::LetterSpacing(letter_spacing) => {
+                self.letter_spacing.insert_rule(rule_id, letter_spacing.into());
+            }
+
+            // Word Spacing
+            Property::WordSpacing(word_spacing) => {
+                self.word_spacing.insert_rule(rule_id, word_spacing.into());
+            }
+
+            // Text Indent
+            Property::TextIndent(text_indent) => {
+                self.text_indent.insert_rule(rule_id, text_indent);
+            }
+
+            // Text Shadow
+            Property::TextShadow(text_shadow) => {
+                self.text_shadow.insert_rule(rule_id, text_shadow);
+            }
+
+            // Text Transform
+            Property::TextTransform(text_transform) => {
+                self.text_transform.insert_rule(rule_id, text_transform);
+            }
+
             // Caret Color
             Property::CaretColor(caret_color) => {
                 self.caret_color.insert_rule(rule_id, caret_color);
@@ -1609,15 +3461,26 @@ impl Style {
                 self.pointer_events.insert_rule(rule_id, pointer_events);
             }
 
+            // Generated Content
+            Property::Content(content) => {
+                self.content.insert_rule(rule_id, content);
+            }
+
+            Property::CounterReset(counter_reset) => {
+                self.counter_reset.insert_rule(rule_id, counter_reset);
+            }
+
+            Property::CounterIncrement(counter_increment) => {
+                self.counter_increment.insert_rule(rule_id, counter_increment);
+            }
+
             // Unparsed. TODO: Log the error.
             Property::Unparsed(unparsed) => {
                 warn!("Unparsed: {}", unparsed.name);
             }
 
-            // TODO: Custom property support
-            Property::Custom(custom) => {
-                warn!("Custom Property: {}", custom.name);
-            }
+            // Custom properties are stored per rule by `parse_rules` and computed during restyle.
+            Property::Custom(_) => {}
             Property::TextOverflow(text_overflow) => {
                 self.text_overflow.insert_rule(rule_id, text_overflow);
             }
@@ -1649,25 +3512,40 @@ impl Style {
         &self,
         transition: &Transition,
     ) -> AnimationState<T> {
-        let timing_function = transition
-            .timing_function
-            .map(|easing| match easing {
-                EasingFunction::Linear => TimingFunction::linear(),
-                EasingFunction::Ease => TimingFunction::ease(),
-                EasingFunction::EaseIn => TimingFunction::ease_in(),
-                EasingFunction::EaseOut => TimingFunction::ease_out(),
-                EasingFunction::EaseInOut => TimingFunction::ease_in_out(),
-                EasingFunction::CubicBezier(x1, y1, x2, y2) => TimingFunction::new(x1, y1, x2, y2),
-            })
-            .unwrap_or_default();
+        let timing_function =
+            Some(transition.timing_function.clone().map(TimingFunction::from).unwrap_or_default());
 
         AnimationState::new(Animation::null())
             .with_duration(transition.duration)
             .with_delay(transition.delay.unwrap_or_default())
-            .with_keyframe(Keyframe { time: 0.0, value: Default::default(), timing_function })
+            // Transitions hold their starting value while delayed.
+            .with_fill_mode(AnimationFillMode::Backwards)
+            .with_keyframe(Keyframe {
+                time: 0.0,
+                value: Default::default(),
+                timing_function: timing_function.clone(),
+            })
             .with_keyframe(Keyframe { time: 1.0, value: Default::default(), timing_function })
     }
 
+    /// Sets the value of an attribute of the given entity, which can be matched by an attribute selector.
+    pub(crate) fn set_attribute(&mut self, entity: Entity, name: &str, value: String) {
+        if let Some(attributes) = self.attributes.get_mut(entity) {
+            attributes.insert(name.to_owned(), value);
+        } else {
+            let mut attributes = HashMap::new();
+            attributes.insert(name.to_owned(), value);
+            self.attributes.insert(entity, attributes);
+        }
+    }
+
+    /// Removes an attribute from the given entity.
+    pub(crate) fn remove_attribute(&mut self, entity: Entity, name: &str) {
+        if let Some(attributes) = self.attributes.get_mut(entity) {
+            attributes.remove(name);
+        }
+    }
+
     // Add style data for the given entity.
     pub fn add(&mut self, entity: Entity) {
         self.pseudo_classes.insert(entity, PseudoClassFlags::VALID);
@@ -1681,7 +3559,11 @@ impl Style {
     // Remove style data for the given entity.
     pub fn remove(&mut self, entity: Entity) {
         self.ids.remove(entity);
+        self.custom_properties.remove(entity);
+        self.set_resolved_rules(entity, Vec::new());
+        self.css_animations.remove(entity);
         self.classes.remove(entity);
+        self.attributes.remove(entity);
         self.pseudo_classes.remove(entity);
         self.disabled.remove(entity);
         self.abilities.remove(entity);
@@ -1709,7 +3591,8 @@ impl Style {
         self.overflowx.remove(entity);
         self.overflowy.remove(entity);
 
-        // Backdrop Filter
+        // Filters
+        self.filter.remove(entity);
         self.backdrop_filter.remove(entity);
 
         // Blend Mode
@@ -1723,9 +3606,18 @@ impl Style {
         self.scale.remove(entity);
 
         // Border
-        self.border_width.remove(entity);
-        self.border_color.remove(entity);
-        self.border_style.remove(entity);
+        self.border_top_width.remove(entity);
+        self.border_right_width.remove(entity);
+        self.border_bottom_width.remove(entity);
+        self.border_left_width.remove(entity);
+        self.border_top_color.remove(entity);
+        self.border_right_color.remove(entity);
+        self.border_bottom_color.remove(entity);
+        self.border_left_color.remove(entity);
+        self.border_top_style.remove(entity);
+        self.border_right_style.remove(entity);
+        self.border_bottom_style.remove(entity);
+        self.border_left_style.remove(entity);
 
         // Corner Shape
         self.corner_bottom_left_shape.remove(entity);
@@ -1767,12 +3659,20 @@ impl Style {
         self.font_family.remove(entity);
         self.font_color.remove(entity);
         self.font_size.remove(entity);
+        self.line_height.remove(entity);
+        self.letter_spacing.remove(entity);
+        self.word_spacing.remove(entity);
+        self.text_indent.remove(entity);
+        self.text_shadow.remove(entity);
+        self.text_transform.remove(entity);
+        self.font_feature_settings.remove(entity);
         self.font_weight.remove(entity);
         self.font_slant.remove(entity);
         self.font_width.remove(entity);
         self.font_variation_settings.remove(entity);
         self.caret_color.remove(entity);
         self.selection_color.remove(entity);
+        self.selection_font_color.remove(entity);
         self.text_decoration_line.remove(entity);
         self.text_stroke_width.remove(entity);
         self.text_stroke_style.remove(entity);
@@ -1782,6 +3682,14 @@ impl Style {
 
         self.pointer_events.remove(entity);
 
+        // Generated Content
+        self.content.remove(entity);
+        self.counter_reset.remove(entity);
+        self.counter_increment.remove(entity);
+        self.generated_content.remove(entity);
+        self.pseudo_element_views.remove(entity);
+        self.selectable_text.remove(&entity);
+
         // Layout Type
         self.layout_type.remove(entity);
 
@@ -1790,6 +3698,27 @@ impl Style {
 
         self.alignment.remove(entity);
 
+        // Animations
+        self.animation_name.remove(entity);
+        self.animation_duration.remove(entity);
+        self.animation_delay.remove(entity);
+        self.animation_timing_function.remove(entity);
+        self.animation_iteration_count.remove(entity);
+        self.animation_direction.remove(entity);
+        self.animation_fill_mode.remove(entity);
+
+        // Grid
+        self.grid_columns.remove(entity);
+        self.grid_rows.remove(entity);
+        self.column_start.remove(entity);
+        self.column_span.remove(entity);
+        self.row_start.remove(entity);
+        self.row_span.remove(entity);
+
+        // Containers
+        self.container_type.remove(entity);
+        self.container_name.remove(entity);
+
         // Space
         self.left.remove(entity);
         self.right.remove(entity);
@@ -1871,7 +3800,8 @@ impl Style {
         // Clipping
         self.clip_path.clear_rules();
 
-        // Backdrop Filer
+        // Filters
+        self.filter.clear_rules();
         self.backdrop_filter.clear_rules();
 
         // Blend Mode
@@ -1888,9 +3818,18 @@ impl Style {
         self.overflowy.clear_rules();
 
         // Border
-        self.border_width.clear_rules();
-        self.border_color.clear_rules();
-        self.border_style.clear_rules();
+        self.border_top_width.clear_rules();
+        self.border_right_width.clear_rules();
+        self.border_bottom_width.clear_rules();
+        self.border_left_width.clear_rules();
+        self.border_top_color.clear_rules();
+        self.border_right_color.clear_rules();
+        self.border_bottom_color.clear_rules();
+        self.border_left_color.clear_rules();
+        self.border_top_style.clear_rules();
+        self.border_right_style.clear_rules();
+        self.border_bottom_style.clear_rules();
+        self.border_left_style.clear_rules();
 
         // Corner Shape
         self.corner_bottom_left_shape.clear_rules();
@@ -1926,6 +3865,27 @@ impl Style {
         self.position_type.clear_rules();
         self.alignment.clear_rules();
 
+        // Animations
+        self.animation_name.clear_rules();
+        self.animation_duration.clear_rules();
+        self.animation_delay.clear_rules();
+        self.animation_timing_function.clear_rules();
+        self.animation_iteration_count.clear_rules();
+        self.animation_direction.clear_rules();
+        self.animation_fill_mode.clear_rules();
+
+        // Grid
+        self.grid_columns.clear_rules();
+        self.grid_rows.clear_rules();
+        self.column_start.clear_rules();
+        self.column_span.clear_rules();
+        self.row_start.clear_rules();
+        self.row_span.clear_rules();
+
+        // Containers
+        self.container_type.clear_rules();
+        self.container_name.clear_rules();
+
         // Space
         self.left.clear_rules();
         self.right.clear_rules();
@@ -1969,8 +3929,16 @@ impl Style {
         self.font_slant.clear_rules();
         self.font_color.clear_rules();
         self.font_size.clear_rules();
+        self.line_height.clear_rules();
+        self.letter_spacing.clear_rules();
+        self.word_spacing.clear_rules();
+        self.text_indent.clear_rules();
+        self.text_shadow.clear_rules();
+        self.text_transform.clear_rules();
+        self.font_feature_settings.clear_rules();
         self.font_variation_settings.clear_rules();
         self.selection_color.clear_rules();
+        self.selection_font_color.clear_rules();
         self.caret_color.clear_rules();
         self.text_decoration_line.clear_rules();
         self.text_stroke_width.clear_rules();
@@ -1980,8 +3948,263 @@ impl Style {
 
         self.pointer_events.clear_rules();
 
+        self.content.clear_rules();
+        self.counter_reset.clear_rules();
+        self.counter_increment.clear_rules();
+
         self.name.clear_rules();
 
         self.fill.clear_rules();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule_precedences(style: &Style) -> Vec<usize> {
+        style.rules.keys().map(|rule| style.layer_precedence(*rule)).collect()
+    }
+
+    #[test]
+    fn per_side_borders() {
+        let mut style = Style::default();
+        style.parse_stylesheet(
+            r#"
+            .a {
+                border: 1px solid red;
+                border-width: 1px 2px 3px 4px;
+                border-left-color: blue;
+                border-bottom: dashed green;
+            }
+            "#,
+            None,
+        );
+
+        let rules = style.rules.keys().copied().collect::<Vec<_>>();
+        let entity = Entity::root();
+        style.border_right_width.link(entity, &rules);
+        style.border_left_width.link(entity, &rules);
+        style.border_top_color.link(entity, &rules);
+        style.border_bottom_color.link(entity, &rules);
+        style.border_left_color.link(entity, &rules);
+        style.border_top_style.link(entity, &rules);
+        style.border_bottom_style.link(entity, &rules);
+
+        assert_eq!(style.border_right_width.get(entity), Some(&Length::px(2.0).into()));
+        assert_eq!(style.border_left_width.get(entity), Some(&Length::px(4.0).into()));
+        assert_eq!(style.border_top_color.get(entity), Some(&Color::rgb(255, 0, 0)));
+        assert_eq!(style.border_bottom_color.get(entity), Some(&Color::rgb(0, 128, 0)));
+        assert_eq!(style.border_left_color.get(entity), Some(&Color::rgb(0, 0, 255)));
+        assert_eq!(style.border_top_style.get(entity), Some(&BorderStyleKeyword::Solid));
+        assert_eq!(style.border_bottom_style.get(entity), Some(&BorderStyleKeyword::Dashed));
+    }
+
+    #[test]
+    fn border_shorthand_expansion() {
+        let mut style = Style::default();
+        style.parse_stylesheet(
+            r#"
+            .a {
+                border-width: 1px 2px;
+                border-color: red blue green;
+                border-style: solid dashed dotted none;
+            }
+            "#,
+            None,
+        );
+
+        let rules = style.rules.keys().copied().collect::<Vec<_>>();
+        let entity = Entity::root();
+        style.border_top_width.link(entity, &rules);
+        style.border_right_width.link(entity, &rules);
+        style.border_bottom_width.link(entity, &rules);
+        style.border_left_width.link(entity, &rules);
+        style.border_top_color.link(entity, &rules);
+        style.border_right_color.link(entity, &rules);
+        style.border_bottom_color.link(entity, &rules);
+        style.border_left_color.link(entity, &rules);
+        style.border_top_style.link(entity, &rules);
+        style.border_right_style.link(entity, &rules);
+        style.border_bottom_style.link(entity, &rules);
+        style.border_left_style.link(entity, &rules);
+
+        // Two values set the vertical and horizontal sides.
+        assert_eq!(style.border_top_width.get(entity), Some(&Length::px(1.0).into()));
+        assert_eq!(style.border_right_width.get(entity), Some(&Length::px(2.0).into()));
+        assert_eq!(style.border_bottom_width.get(entity), Some(&Length::px(1.0).into()));
+        assert_eq!(style.border_left_width.get(entity), Some(&Length::px(2.0).into()));
+
+        // Three values set the top, horizontal and bottom sides.
+        assert_eq!(style.border_top_color.get(entity), Some(&Color::rgb(255, 0, 0)));
+        assert_eq!(style.border_right_color.get(entity), Some(&Color::rgb(0, 0, 255)));
+        assert_eq!(style.border_bottom_color.get(entity), Some(&Color::rgb(0, 128, 0)));
+        assert_eq!(style.border_left_color.get(entity), Some(&Color::rgb(0, 0, 255)));
+
+        // Four values set each side in clockwise order from the top.
+        assert_eq!(style.border_top_style.get(entity), Some(&BorderStyleKeyword::Solid));
+        assert_eq!(style.border_right_style.get(entity), Some(&BorderStyleKeyword::Dashed));
+        assert_eq!(style.border_bottom_style.get(entity), Some(&BorderStyleKeyword::Dotted));
+        assert_eq!(style.border_left_style.get(entity), Some(&BorderStyleKeyword::None));
+    }
+
+    #[test]
+    fn typography() {
+        let mut style = Style::default();
+        style.parse_stylesheet(
+            r#"
+            .a {
+                line-height: 1.5;
+                letter-spacing: 2px;
+                word-spacing: normal;
+                text-transform: uppercase;
+                text-shadow: 1px 1px red, 0px 0px 4px;
+                font-feature-settings: "liga" off;
+            }
+            "#,
+            None,
+        );
+
+        let rules = style.rules.keys().copied().collect::<Vec<_>>();
+        let entity = Entity::root();
+        style.line_height.link(entity, &rules);
+        style.letter_spacing.link(entity, &rules);
+        style.word_spacing.link(entity, &rules);
+        style.text_transform.link(entity, &rules);
+        style.text_shadow.link(entity, &rules);
+        style.font_feature_settings.link(entity, &rules);
+
+        assert_eq!(style.line_height.get(entity), Some(&LineHeight::Number(1.5)));
+        assert_eq!(style.letter_spacing.get(entity), Some(&Length::px(2.0)));
+        assert_eq!(style.word_spacing.get(entity), Some(&Length::zero()));
+        assert_eq!(style.text_transform.get(entity), Some(&TextTransform::Uppercase));
+        assert_eq!(style.text_shadow.get(entity).map(Vec::len), Some(2));
+        assert_eq!(
+            style.font_feature_settings.get(entity),
+            Some(&vec![FontFeature::new("liga", 0)])
+        );
+    }
+
+    #[test]
+    fn selection_rules() {
+        let mut style = Style::default();
+        style.parse_stylesheet(
+            r#"
+            textbox::selection {
+                background-color: red;
+                color: white;
+            }
+            textbox {
+                font-style: italic;
+            }
+            "#,
+            None,
+        );
+
+        let rules = style.rules.keys().copied().collect::<Vec<_>>();
+        let entity = Entity::root();
+        style.selection_color.link(entity, &rules);
+        style.selection_font_color.link(entity, &rules);
+        style.background_color.link(entity, &rules);
+        style.font_color.link(entity, &rules);
+        style.font_slant.link(entity, &rules);
+
+        assert_eq!(style.selection_color.get(entity), Some(&Color::rgb(255, 0, 0)));
+        assert_eq!(style.selection_font_color.get(entity), Some(&Color::rgb(255, 255, 255)));
+        // The properties of a `::selection` rule only apply to the selected text.
+        assert_eq!(style.background_color.get(entity), None);
+        assert_eq!(style.font_color.get(entity), None);
+        assert_eq!(style.font_slant.get(entity), Some(&FontSlant::Italic));
+    }
+
+    #[test]
+    fn container_queries() {
+        let mut style = Style { dpi_factor: 1.0, ..Default::default() };
+        style.parse_stylesheet(
+            r#"
+            .panel {
+                container: panel / inline-size;
+            }
+            @container panel (min-width: 400px) {
+                .a {}
+            }
+            @container (height > 100px) {
+                .b {}
+            }
+            "#,
+            None,
+        );
+
+        let rules = style.rules.keys().copied().collect::<Vec<_>>();
+        let container = Entity::root();
+        style.container_type.link(container, &rules[..1]);
+        style.container_name.link(container, &rules[..1]);
+
+        let bounds = |w: f32| BoundingBox { w, h: 300.0, ..Default::default() };
+        let width_query = &style.container_rules[&rules[1]][0];
+        let height_query = &style.container_rules[&rules[2]][0];
+
+        assert!(style.is_queried_by(container, width_query));
+        assert!(style.container_query_matches(container, width_query, bounds(500.0)));
+        assert!(!style.container_query_matches(container, width_query, bounds(300.0)));
+        // The height of an `inline-size` container can't be queried.
+        assert!(!style.container_query_matches(container, height_query, bounds(500.0)));
+
+        assert!(style.crosses_container_breakpoint(container, bounds(300.0), bounds(500.0)));
+        assert!(!style.crosses_container_breakpoint(container, bounds(500.0), bounds(600.0)));
+    }
+
+    #[test]
+    fn layer_order() {
+        let mut style = Style::default();
+        style.parse_theme(".a {}");
+        style.parse_stylesheet(
+            r#"
+            @layer base, components;
+            @layer components {
+                .b {}
+            }
+            @layer base {
+                .c {}
+                @layer nested {
+                    .d {}
+                }
+            }
+            .e {}
+            "#,
+            None,
+        );
+
+        assert_eq!(style.layers, vec!["vizia", "base", "components", "base.nested"]);
+        // The theme layer is declared first, nested layers are overridden by their parent, and unlayered rules
+        // override all layers.
+        assert_eq!(rule_precedences(&style), vec![0, 3, 2, 1, usize::MAX]);
+    }
+
+    #[test]
+    fn import_cycle() {
+        let dir = std::env::temp_dir().join(format!("vizia-import-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let app_path = dir.join("app.css");
+        let base_path = dir.join("base.css");
+        let app = "@import url(base.css) layer(base);\n.app {}";
+        std::fs::write(&app_path, app).unwrap();
+        std::fs::write(&base_path, "@import \"app.css\";\n.base {}").unwrap();
+
+        let mut style = Style::default();
+        style.parse_stylesheet(app, Some(&app_path));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(style.imports, vec![base_path]);
+        assert_eq!(style.layers, vec!["base"]);
+        // The imported rules come before the rules of the importing stylesheet.
+        assert_eq!(rule_precedences(&style), vec![0, usize::MAX]);
+
+        assert_eq!(style.diagnostics.len(), 1);
+        assert_eq!(style.diagnostics[0].path.as_deref(), Some(dir.join("base.css").as_path()));
+        assert_eq!(style.diagnostics[0].line, 1);
+        assert!(style.diagnostics[0].reason.starts_with("Import cycle"));
+    }
+}
@@ -1,6 +1,7 @@
 use skia_safe::Matrix;
 use vizia_style::{Angle, Scale, Transform, Translate};
 
+use crate::animation::{AnimationState, Interpolator};
 use crate::layout::BoundingBox;
 
 /// Trait for converting a transform definition into a `Matrix`.
@@ -38,74 +39,176 @@ impl IntoTransform for Vec<Transform> {
     fn as_transform(&self, bounds: BoundingBox, scale_factor: f32) -> Matrix {
         let mut result = Matrix::new_identity();
         for transform in self.iter() {
-            let t = match transform {
-                Transform::Translate(translate) => {
-                    let tx = translate.0.to_pixels(bounds.w, scale_factor);
-                    let ty = translate.1.to_pixels(bounds.h, scale_factor);
-
-                    Matrix::translate((tx, ty))
-                }
+            result = result * ResolvedTransform::new(transform, bounds, scale_factor).as_matrix();
+        }
 
-                Transform::TranslateX(x) => {
-                    let tx = x.to_pixels(bounds.w, scale_factor);
+        result
+    }
+}
 
-                    Matrix::translate((tx, 0.0))
-                }
+impl IntoTransform for AnimationState<Vec<Transform>> {
+    fn as_transform(&self, bounds: BoundingBox, scale_factor: f32) -> Matrix {
+        // Percentage translations depend on the bounds of the view, so the keyframes of a transform animation are
+        // interpolated when the transform is computed rather than when the animation is ticked.
+        match self.segment_at(self.keyframe_t) {
+            Some((start, end, t)) => {
+                interpolate_transforms(&start.value, &end.value, t, bounds, scale_factor)
+            }
+            None => Matrix::new_identity(),
+        }
+    }
+}
 
-                Transform::TranslateY(y) => {
-                    let ty = y.to_pixels(bounds.h, scale_factor);
+/// Interpolates between two lists of transform functions.
+///
+/// If the functions of both lists match then each pair of functions is interpolated separately, with any missing
+/// functions of the shorter list treated as the identity. Otherwise the matrices of the lists are decomposed and interpolated.
+fn interpolate_transforms(
+    start: &[Transform],
+    end: &[Transform],
+    t: f32,
+    bounds: BoundingBox,
+    scale_factor: f32,
+) -> Matrix {
+    let start = start
+        .iter()
+        .map(|transform| ResolvedTransform::new(transform, bounds, scale_factor))
+        .collect::<Vec<_>>();
+    let end = end
+        .iter()
+        .map(|transform| ResolvedTransform::new(transform, bounds, scale_factor))
+        .collect::<Vec<_>>();
+
+    let matching = start
+        .iter()
+        .zip(end.iter())
+        .all(|(start, end)| std::mem::discriminant(start) == std::mem::discriminant(end));
+
+    if matching {
+        let mut result = Matrix::new_identity();
+        for i in 0..start.len().max(end.len()) {
+            let (start, end) = match (start.get(i), end.get(i)) {
+                (Some(start), Some(end)) => (*start, *end),
+                (Some(start), None) => (*start, start.identity()),
+                (None, Some(end)) => (end.identity(), *end),
+                (None, None) => break,
+            };
 
-                    Matrix::translate((0.0, ty))
-                }
+            result = result * ResolvedTransform::interpolate(&start, &end, t).as_matrix();
+        }
 
-                Transform::Scale(scale) => {
-                    let sx = scale.0.to_factor();
-                    let sy = scale.1.to_factor();
+        result
+    } else {
+        let start_matrix = start
+            .iter()
+            .fold(Matrix::new_identity(), |result, transform| result * transform.as_matrix());
+        let end_matrix = end
+            .iter()
+            .fold(Matrix::new_identity(), |result, transform| result * transform.as_matrix());
+
+        Matrix::interpolate(&start_matrix, &end_matrix, t)
+    }
+}
 
-                    Matrix::scale((sx, sy))
-                }
+// A transform function with its lengths resolved to pixels, its scales to factors, and its angles to radians.
+// Translations, scales and skews along a single axis are resolved to their two dimensional equivalents so that,
+// for example, a `translateX()` can be interpolated with a `translate()`.
+#[derive(Debug, Clone, Copy)]
+enum ResolvedTransform {
+    Translate(f32, f32),
+    Scale(f32, f32),
+    Rotate(f32),
+    Skew(f32, f32),
+    Matrix(Matrix),
+}
 
-                Transform::ScaleX(x) => {
-                    let sx = x.to_factor();
+impl ResolvedTransform {
+    fn new(transform: &Transform, bounds: BoundingBox, scale_factor: f32) -> Self {
+        match transform {
+            Transform::Translate(translate) => ResolvedTransform::Translate(
+                translate.0.to_pixels(bounds.w, scale_factor),
+                translate.1.to_pixels(bounds.h, scale_factor),
+            ),
 
-                    Matrix::scale((sx, 1.0))
-                }
+            Transform::TranslateX(x) => {
+                ResolvedTransform::Translate(x.to_pixels(bounds.w, scale_factor), 0.0)
+            }
 
-                Transform::ScaleY(y) => {
-                    let sy = y.to_factor();
+            Transform::TranslateY(y) => {
+                ResolvedTransform::Translate(0.0, y.to_pixels(bounds.h, scale_factor))
+            }
 
-                    Matrix::scale((1.0, sy))
-                }
+            Transform::Scale(scale) => {
+                ResolvedTransform::Scale(scale.0.to_factor(), scale.1.to_factor())
+            }
 
-                Transform::Rotate(angle) => Matrix::rotate_rad(angle.to_radians()),
+            Transform::ScaleX(x) => ResolvedTransform::Scale(x.to_factor(), 1.0),
 
-                Transform::Skew(x, y) => {
-                    let cx = x.to_radians().tan();
-                    let cy = y.to_radians().tan();
+            Transform::ScaleY(y) => ResolvedTransform::Scale(1.0, y.to_factor()),
 
-                    Matrix::skew((cx, cy))
-                }
+            Transform::Rotate(angle) => ResolvedTransform::Rotate(angle.to_radians()),
 
-                Transform::SkewX(angle) => {
-                    let cx = angle.to_radians().tan();
+            Transform::Skew(x, y) => ResolvedTransform::Skew(x.to_radians(), y.to_radians()),
 
-                    Matrix::skew((cx, 0.0))
-                }
+            Transform::SkewX(angle) => ResolvedTransform::Skew(angle.to_radians(), 0.0),
 
-                Transform::SkewY(angle) => {
-                    let cy = angle.to_radians().tan();
+            Transform::SkewY(angle) => ResolvedTransform::Skew(0.0, angle.to_radians()),
 
-                    Matrix::skew((0.0, cy))
-                }
+            Transform::Matrix(matrix) => ResolvedTransform::Matrix(Matrix::new_all(
+                matrix.a, matrix.c, matrix.e, matrix.b, matrix.d, matrix.f, 0.0, 0.0, 1.0,
+            )),
+        }
+    }
 
-                Transform::Matrix(matrix) => Matrix::new_all(
-                    matrix.a, matrix.c, matrix.e, matrix.b, matrix.d, matrix.f, 0.0, 0.0, 1.0,
-                ),
-            };
+    // Returns the identity function of the same type.
+    fn identity(&self) -> Self {
+        match self {
+            ResolvedTransform::Translate(..) => ResolvedTransform::Translate(0.0, 0.0),
+            ResolvedTransform::Scale(..) => ResolvedTransform::Scale(1.0, 1.0),
+            ResolvedTransform::Rotate(..) => ResolvedTransform::Rotate(0.0),
+            ResolvedTransform::Skew(..) => ResolvedTransform::Skew(0.0, 0.0),
+            ResolvedTransform::Matrix(..) => ResolvedTransform::Matrix(Matrix::new_identity()),
+        }
+    }
 
-            result = result * t;
+    fn as_matrix(&self) -> Matrix {
+        match self {
+            ResolvedTransform::Translate(x, y) => Matrix::translate((*x, *y)),
+            ResolvedTransform::Scale(x, y) => Matrix::scale((*x, *y)),
+            ResolvedTransform::Rotate(angle) => Matrix::rotate_rad(*angle),
+            ResolvedTransform::Skew(x, y) => Matrix::skew((x.tan(), y.tan())),
+            ResolvedTransform::Matrix(matrix) => *matrix,
         }
+    }
+}
 
-        result
+impl Interpolator for ResolvedTransform {
+    fn interpolate(start: &Self, end: &Self, t: f32) -> Self {
+        match (start, end) {
+            (ResolvedTransform::Translate(x1, y1), ResolvedTransform::Translate(x2, y2)) => {
+                ResolvedTransform::Translate(
+                    f32::interpolate(x1, x2, t),
+                    f32::interpolate(y1, y2, t),
+                )
+            }
+
+            (ResolvedTransform::Scale(x1, y1), ResolvedTransform::Scale(x2, y2)) => {
+                ResolvedTransform::Scale(f32::interpolate(x1, x2, t), f32::interpolate(y1, y2, t))
+            }
+
+            (ResolvedTransform::Rotate(angle1), ResolvedTransform::Rotate(angle2)) => {
+                ResolvedTransform::Rotate(f32::interpolate(angle1, angle2, t))
+            }
+
+            (ResolvedTransform::Skew(x1, y1), ResolvedTransform::Skew(x2, y2)) => {
+                ResolvedTransform::Skew(f32::interpolate(x1, x2, t), f32::interpolate(y1, y2, t))
+            }
+
+            _ => ResolvedTransform::Matrix(Matrix::interpolate(
+                &start.as_matrix(),
+                &end.as_matrix(),
+                t,
+            )),
+        }
     }
 }
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::style::animatable_sets;
 
 pub(crate) fn animation_system(cx: &mut Context) -> bool {
     cx.style.play_pending_animations();
@@ -10,73 +11,24 @@ pub(crate) fn animation_system(cx: &mut Context) -> bool {
     let mut redraw_entities = Vec::new();
     let mut reflow_entities = Vec::new();
     let mut relayout_entities = Vec::new();
+    let mut animation_events = Vec::new();
+
+    macro_rules! tick {
+        ($($set:ident => $kind:ident),+) => {
+            $(tick!(@$kind cx.style.$set.tick(time, &mut animation_events));)+
+        };
+        (@redraw $entities:expr) => {
+            redraw_entities.extend($entities)
+        };
+        (@reflow $entities:expr) => {
+            reflow_entities.extend($entities)
+        };
+        (@relayout $entities:expr) => {
+            relayout_entities.extend($entities)
+        };
+    }
 
-    // Properties which affect rendering
-    // Opacity
-    redraw_entities.extend(cx.style.opacity.tick(time));
-    // Corner Colour
-    redraw_entities.extend(cx.style.border_color.tick(time));
-    // Corner Radius
-    redraw_entities.extend(cx.style.corner_top_left_radius.tick(time));
-    redraw_entities.extend(cx.style.corner_top_right_radius.tick(time));
-    redraw_entities.extend(cx.style.corner_bottom_left_radius.tick(time));
-    redraw_entities.extend(cx.style.corner_bottom_right_radius.tick(time));
-    // Background
-    redraw_entities.extend(cx.style.background_color.tick(time));
-    redraw_entities.extend(cx.style.background_image.tick(time));
-    redraw_entities.extend(cx.style.background_size.tick(time));
-    // Box Shadow
-    redraw_entities.extend(cx.style.shadow.tick(time));
-    // Transform
-    redraw_entities.extend(cx.style.transform.tick(time));
-    redraw_entities.extend(cx.style.transform_origin.tick(time));
-    redraw_entities.extend(cx.style.translate.tick(time));
-    redraw_entities.extend(cx.style.rotate.tick(time));
-    redraw_entities.extend(cx.style.scale.tick(time));
-    // Outline
-    redraw_entities.extend(cx.style.outline_color.tick(time));
-    redraw_entities.extend(cx.style.outline_offset.tick(time));
-    redraw_entities.extend(cx.style.outline_width.tick(time));
-    // Clip Path
-    redraw_entities.extend(cx.style.clip_path.tick(time));
-
-    redraw_entities.extend(cx.style.fill.tick(time));
-
-    // Font Color
-    reflow_entities.extend(cx.style.font_color.tick(time));
-    // Font Size
-    reflow_entities.extend(cx.style.font_size.tick(time));
-
-    // Properties which affect layout
-    relayout_entities.extend(cx.style.display.tick(time));
-    // Border Width
-    relayout_entities.extend(cx.style.border_width.tick(time));
-    // Space
-    relayout_entities.extend(cx.style.left.tick(time));
-    relayout_entities.extend(cx.style.right.tick(time));
-    relayout_entities.extend(cx.style.top.tick(time));
-    relayout_entities.extend(cx.style.bottom.tick(time));
-    // Size
-    relayout_entities.extend(cx.style.width.tick(time));
-    relayout_entities.extend(cx.style.height.tick(time));
-    // Min/Max Size
-    relayout_entities.extend(cx.style.max_width.tick(time));
-    relayout_entities.extend(cx.style.max_height.tick(time));
-    relayout_entities.extend(cx.style.min_width.tick(time));
-    relayout_entities.extend(cx.style.min_height.tick(time));
-    // Min/Max Gap
-    relayout_entities.extend(cx.style.max_horizontal_gap.tick(time));
-    relayout_entities.extend(cx.style.max_vertical_gap.tick(time));
-    relayout_entities.extend(cx.style.min_horizontal_gap.tick(time));
-    relayout_entities.extend(cx.style.min_vertical_gap.tick(time));
-    // Row/Col Between
-    relayout_entities.extend(cx.style.vertical_gap.tick(time));
-    relayout_entities.extend(cx.style.horizontal_gap.tick(time));
-    // Child Space
-    relayout_entities.extend(cx.style.padding_left.tick(time));
-    relayout_entities.extend(cx.style.padding_right.tick(time));
-    relayout_entities.extend(cx.style.padding_top.tick(time));
-    relayout_entities.extend(cx.style.padding_bottom.tick(time));
+    animatable_sets!(tick);
 
     if !relayout_entities.is_empty() {
         cx.style.system_flags.set(SystemFlags::RELAYOUT, true);
@@ -90,5 +42,16 @@ pub(crate) fn animation_system(cx: &mut Context) -> bool {
         cx.style.text_construction.insert(*entity).unwrap();
     }
 
+    // Each property of an animation emits the same events so only send each event once.
+    let mut sent_events = Vec::new();
+    for (entity, event) in animation_events {
+        if !sent_events.contains(&(entity, event)) {
+            sent_events.push((entity, event));
+            cx.event_queue.push_back(
+                Event::new(event).target(entity).origin(entity).propagate(Propagation::Direct),
+            );
+        }
+    }
+
     !redraw_entities.is_empty() | !relayout_entities.is_empty() | !reflow_entities.is_empty()
 }
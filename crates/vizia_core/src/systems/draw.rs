@@ -1,7 +1,8 @@
-use crate::{animation::Interpolator, cache::CachedData, prelude::*};
+use crate::{cache::CachedData, prelude::*};
 use morphorm::Node;
 use skia_safe::{
-    canvas::SaveLayerRec, ClipOp, ImageFilter, Matrix, Paint, Rect, SamplingOptions, Surface,
+    canvas::SaveLayerRec, color_filters, image_filters, ClipOp, ImageFilter, Matrix, Paint, Rect,
+    SamplingOptions, Surface,
 };
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
@@ -51,24 +52,14 @@ pub(crate) fn transform_system(cx: &mut Context) {
 
                 // Apply transform functions.
                 if let Some(transforms) = cx.style.transform.get(entity) {
-                    // Check if the transform is currently animating
-                    // Get the animation state
-                    // Manually interpolate the value to get the overall transform for the current frame
-                    if let Some(animation_state) = cx.style.transform.get_active_animation(entity) {
-                        if let Some(start) = animation_state.keyframes.first() {
-                            if let Some(end) = animation_state.keyframes.last() {
-                                let start_transform =
-                                    start.value.as_transform(bounds, scale_factor);
-                                let end_transform = end.value.as_transform(bounds, scale_factor);
-                                let t = animation_state.t;
-                                let animated_transform = skia_safe::Matrix::interpolate(
-                                    &start_transform,
-                                    &end_transform,
-                                    t,
-                                );
-                                transform = transform * animated_transform;
-                            }
-                        }
+                    // Transform animations are interpolated between keyframes using the bounds of the view
+                    if let Some(animation_state) = cx
+                        .style
+                        .transform
+                        .get_active_animation(entity)
+                        .filter(|animation_state| animation_state.output.is_some())
+                    {
+                        transform = transform * animation_state.as_transform(bounds, scale_factor);
                     } else {
                         transform = transform * transforms.as_transform(bounds, scale_factor);
                     }
@@ -279,35 +270,42 @@ fn draw_entity(
         return;
     }
 
-    let backdrop_filter = cx.backdrop_filter();
+    let filter = cx.filter().filter(|filter| !filter.is_empty());
+    let backdrop_filter = cx.backdrop_filter().filter(|filter| !filter.is_empty());
     let blend_mode = cx.style.blend_mode.get(current).copied().unwrap_or_default();
 
     canvas.save();
-    let layer_count =
-        if cx.opacity() != 1.0 || backdrop_filter.is_some() || blend_mode != BlendMode::Normal {
-            let mut paint = Paint::default();
-            paint.set_alpha_f(cx.opacity());
-            paint.set_blend_mode(blend_mode.into());
+    let layer_count = if cx.opacity() != 1.0
+        || filter.is_some()
+        || backdrop_filter.is_some()
+        || blend_mode != BlendMode::Normal
+    {
+        let mut paint = Paint::default();
+        paint.set_alpha_f(cx.opacity());
+        paint.set_blend_mode(blend_mode.into());
+
+        // Drop shadows without a color use the color of the text.
+        let current_color = cx.current_color();
+
+        if let Some(filter) = filter {
+            paint.set_image_filter(image_filter(filter, None, current_color, cx.scale_factor()));
+        }
 
+        let backdrop = backdrop_filter.and_then(|backdrop_filter| {
             let rect: Rect = cx.bounds().into();
-            let mut filter = ImageFilter::crop(rect, None, None).unwrap();
-
-            let slr = if let Some(backdrop_filter) = backdrop_filter {
-                match backdrop_filter {
-                    Filter::Blur(radius) => {
-                        let sigma = radius.to_px().unwrap() * cx.scale_factor() / 2.0;
-                        filter = filter.blur(None, (sigma, sigma), None).unwrap();
-                        SaveLayerRec::default().paint(&paint).backdrop(&filter)
-                    }
-                }
-            } else {
-                SaveLayerRec::default().paint(&paint)
-            };
+            let crop = ImageFilter::crop(rect, None, None);
+            image_filter(backdrop_filter, crop, current_color, cx.scale_factor())
+        });
 
-            Some(canvas.save_layer(&slr))
-        } else {
-            None
-        };
+        let mut slr = SaveLayerRec::default().paint(&paint);
+        if let Some(backdrop) = &backdrop {
+            slr = slr.backdrop(backdrop);
+        }
+
+        Some(canvas.save_layer(&slr))
+    } else {
+        None
+    };
 
     if let Some(transform) = cx.cache.transform.get(current) {
         canvas.set_matrix(&(transform.into()));
@@ -352,6 +350,43 @@ fn draw_entity(
     cx.current = current;
 }
 
+/// Builds an image filter which applies the given filters in order to the output of `input`, or to the source
+/// content if `input` is `None`.
+fn image_filter(
+    filters: &[Filter],
+    input: Option<ImageFilter>,
+    current_color: Color,
+    scale_factor: f32,
+) -> Option<ImageFilter> {
+    filters.iter().fold(input, |input, filter| match filter {
+        Filter::Blur(radius) => {
+            let sigma = radius.to_px().unwrap_or_default() * scale_factor / 2.0;
+            image_filters::blur((sigma, sigma), None, input, None)
+        }
+
+        Filter::DropShadow(shadow) => {
+            let x = shadow.x_offset.to_px().unwrap_or_default() * scale_factor;
+            let y = shadow.y_offset.to_px().unwrap_or_default() * scale_factor;
+            let sigma = shadow.blur_radius.as_ref().and_then(Length::to_px).unwrap_or_default()
+                * scale_factor
+                / 2.0;
+            let color =
+                skia_safe::Color::from(shadow.color.unwrap_or_default().resolve(current_color));
+            image_filters::drop_shadow((x, y), (sigma, sigma), color, None, input, None)
+        }
+
+        _ => filter.color_matrix().and_then(|matrix| {
+            image_filters::color_filter(color_filters::matrix_row_major(&matrix, None), input, None)
+        }),
+    })
+}
+
+// Returns the distance a blur with the given radius extends beyond the content it blurs. Blurs are drawn with a
+// standard deviation of half the blur radius, and a gaussian blur is negligible beyond three standard deviations.
+fn blur_extent(blur_radius: f32) -> f32 {
+    3.0 * blur_radius / 2.0
+}
+
 // Must be called after transform and clipping systems to be valid.
 pub(crate) fn draw_bounds(
     style: &Style,
@@ -375,7 +410,7 @@ pub(crate) fn draw_bounds(
             if let Some(blur_radius) =
                 shadow.blur_radius.as_ref().map(|br| br.clone().to_px().unwrap() * scale_factor)
             {
-                shadow_bounds = shadow_bounds.expand(blur_radius);
+                shadow_bounds = shadow_bounds.expand(blur_extent(blur_radius));
             }
 
             if let Some(spread_radius) =
@@ -419,6 +454,31 @@ pub(crate) fn draw_bounds(
         }
     }
 
+    // Blurs and drop shadows draw outside of the content of the view and its children.
+    if let Some(filters) = style.filter.get(entity) {
+        let scale_factor = style.scale_factor();
+        for filter in filters {
+            match filter {
+                Filter::Blur(radius) => {
+                    dirty_bounds = dirty_bounds
+                        .expand(blur_extent(radius.to_px().unwrap_or_default() * scale_factor));
+                }
+
+                Filter::DropShadow(shadow) => {
+                    let x = shadow.x_offset.to_px().unwrap_or_default() * scale_factor;
+                    let y = shadow.y_offset.to_px().unwrap_or_default() * scale_factor;
+                    let blur_radius =
+                        shadow.blur_radius.as_ref().and_then(Length::to_px).unwrap_or_default()
+                            * scale_factor;
+                    dirty_bounds = dirty_bounds
+                        .union(&dirty_bounds.offset(x, y).expand(blur_extent(blur_radius)));
+                }
+
+                _ => {}
+            }
+        }
+    }
+
     let z_index = style.z_index.get(entity).copied().unwrap_or_default();
 
     let parent = tree
@@ -458,3 +518,61 @@ impl PartialEq for ZEntity {
 }
 
 impl Eq for ZEntity {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blur_draw_bounds() {
+        let cx = &mut Context::new();
+        cx.style.dpi_factor = 1.0;
+
+        let blurred = Element::new(cx).entity();
+        let drop_shadow = Element::new(cx).entity();
+        let box_shadow = Element::new(cx).entity();
+
+        let bounds = BoundingBox::from_min_max(100.0, 100.0, 200.0, 200.0);
+        for entity in [blurred, drop_shadow, box_shadow] {
+            cx.cache.set_bounds(entity, bounds);
+        }
+
+        cx.style.filter.insert(blurred, vec![Filter::Blur(Length::px(10.0))]);
+        cx.style.filter.insert(
+            drop_shadow,
+            vec![Filter::DropShadow(TextShadow::new(
+                Length::px(5.0),
+                Length::px(0.0),
+                Some(Length::px(10.0)),
+                None,
+            ))],
+        );
+        cx.style.shadow.insert(
+            box_shadow,
+            vec![Shadow::new(
+                Length::px(5.0),
+                Length::px(0.0),
+                Some(Length::px(10.0)),
+                None,
+                None,
+                false,
+            )],
+        );
+
+        // A blur radius of 10px has a standard deviation of 5px, which extends 15px beyond the content.
+        assert_eq!(
+            draw_bounds(&cx.style, &cx.cache, &cx.tree, blurred),
+            BoundingBox::from_min_max(85.0, 85.0, 215.0, 215.0)
+        );
+
+        assert_eq!(
+            draw_bounds(&cx.style, &cx.cache, &cx.tree, drop_shadow),
+            BoundingBox::from_min_max(90.0, 85.0, 220.0, 215.0)
+        );
+
+        assert_eq!(
+            draw_bounds(&cx.style, &cx.cache, &cx.tree, box_shadow),
+            BoundingBox::from_min_max(90.0, 85.0, 220.0, 215.0)
+        );
+    }
+}
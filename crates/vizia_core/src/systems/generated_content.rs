@@ -0,0 +1,141 @@
+use crate::prelude::*;
+use vizia_style::{Content, ContentItem, PseudoElement};
+
+/// An anonymous view holding the content generated by a `::before` or `::after` pseudo-element.
+pub(crate) struct GeneratedContent;
+
+impl View for GeneratedContent {}
+
+// A counter in scope while iterating the tree, along with the depth of the view which created it.
+struct Counter {
+    name: String,
+    value: i32,
+    depth: usize,
+}
+
+/// Keeps generated content at the start or end of its originating view and computes its text from the
+/// `content` property, resolving any `attr()` and `counter()` functions.
+pub(crate) fn generated_content_system(cx: &mut Context, redraw_entities: &mut Vec<Entity>) {
+    if cx.style.generated_content.is_empty() {
+        return;
+    }
+
+    // Children added after the generated content of a view are moved so that `::before` content is always
+    // the first child and `::after` content is always the last child.
+    let generated = cx
+        .tree
+        .into_iter()
+        .filter_map(|entity| {
+            cx.style
+                .generated_content
+                .get(entity)
+                .map(|pseudo_element| (entity, pseudo_element.clone()))
+        })
+        .collect::<Vec<_>>();
+
+    for (entity, pseudo_element) in generated {
+        match pseudo_element {
+            PseudoElement::Before => {
+                if cx.tree.get_prev_sibling(entity).is_some() {
+                    cx.tree.set_first_child(entity).unwrap();
+                    cx.style.needs_relayout();
+                }
+            }
+
+            PseudoElement::After => {
+                let last_child = cx
+                    .tree
+                    .get_parent(entity)
+                    .and_then(|parent| cx.tree.get_last_child(parent).copied());
+                if let Some(last_child) = last_child.filter(|last_child| *last_child != entity) {
+                    cx.tree.set_next_sibling(last_child, entity).unwrap();
+                    cx.style.needs_relayout();
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    // Counters are scoped to the view which creates them, its following siblings, and their descendants.
+    let mut counters: Vec<Counter> = Vec::new();
+    let mut ancestors: Vec<Entity> = Vec::new();
+
+    for entity in cx.tree.into_iter() {
+        if cx.tree.is_ignored(entity) {
+            continue;
+        }
+
+        let parent = cx.tree.get_layout_parent(entity);
+        while ancestors.last().is_some_and(|ancestor| Some(*ancestor) != parent) {
+            ancestors.pop();
+        }
+
+        let depth = ancestors.len();
+        ancestors.push(entity);
+        counters.retain(|counter| counter.depth <= depth);
+
+        if let Some(counter_reset) = cx.style.counter_reset.get(entity) {
+            for change in counter_reset {
+                // A counter reset by a previous sibling is replaced rather than nested.
+                counters.retain(|counter| counter.name != change.name || counter.depth != depth);
+                counters.push(Counter {
+                    name: change.name.clone(),
+                    value: change.value.unwrap_or(0),
+                    depth,
+                });
+            }
+        }
+
+        if let Some(counter_increment) = cx.style.counter_increment.get(entity) {
+            for change in counter_increment {
+                let value = change.value.unwrap_or(1);
+                if let Some(counter) =
+                    counters.iter_mut().rev().find(|counter| counter.name == change.name)
+                {
+                    counter.value += value;
+                } else {
+                    counters.push(Counter { name: change.name.clone(), value, depth });
+                }
+            }
+        }
+
+        if !cx.style.generated_content.contains(entity) {
+            continue;
+        }
+
+        let mut text = String::new();
+        if let Some(Content::Items(items)) = cx.style.content.get(entity) {
+            for item in items {
+                match item {
+                    ContentItem::String(string) => text.push_str(string),
+
+                    ContentItem::Attr(name) => {
+                        if let Some(value) = parent
+                            .and_then(|parent| cx.style.attributes.get(parent))
+                            .and_then(|attributes| attributes.get(name))
+                        {
+                            text.push_str(value);
+                        }
+                    }
+
+                    ContentItem::Counter(name) => {
+                        let value = counters
+                            .iter()
+                            .rev()
+                            .find(|counter| counter.name == *name)
+                            .map_or(0, |counter| counter.value);
+                        text.push_str(&value.to_string());
+                    }
+                }
+            }
+        }
+
+        if cx.style.text.get(entity) != Some(&text) {
+            cx.style.text.insert(entity, text);
+            cx.style.needs_text_update(entity);
+            cx.style.needs_relayout();
+            redraw_entities.push(entity);
+        }
+    }
+}
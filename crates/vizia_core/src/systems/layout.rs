@@ -30,6 +30,9 @@ pub(crate) fn layout_system(cx: &mut Context) {
 
         let iter = LayoutTreeIterator::full(cx.tree);
 
+        // Containers whose size crosses a breakpoint of a container query.
+        let mut containers = Vec::new();
+
         for entity in iter {
             cx.current = entity;
             if cx.style.display.get(entity).copied().unwrap_or_default() == Display::None {
@@ -69,6 +72,12 @@ pub(crate) fn layout_system(cx: &mut Context) {
 
                         let new_bounds = BoundingBox { x, y, w, h };
 
+                        if (w != bounds.w || h != bounds.h)
+                            && cx.style.crosses_container_breakpoint(entity, *bounds, new_bounds)
+                        {
+                            containers.push(entity);
+                        }
+
                         // if new_bounds != *bounds && *bounds != BoundingBox::default() {
                         //     cx.needs_redraw();
                         // }
@@ -102,6 +111,14 @@ pub(crate) fn layout_system(cx: &mut Context) {
             }
         }
 
+        // The rules within `@container` blocks which apply to the descendants of a container can change when its
+        // size changes, which takes effect when the next restyle is performed.
+        for container in containers {
+            for descendant in LayoutTreeIterator::subtree(cx.tree, container).skip(1) {
+                cx.style.restyle.insert(descendant).unwrap();
+            }
+        }
+
         // A relayout, retransform, or reclip, can cause the element under the cursor to change. So we push a mouse move event here to force
         // a new event cycle and the hover system to trigger.
         if let Some(proxy) = &cx.event_proxy {
@@ -156,3 +173,35 @@ fn visit_entity(cx: &mut EventContext, entity: Entity, event: &mut Event) {
         cx.views.insert(entity, view);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_placement() {
+        let cx = &mut Context::new();
+        cx.style.dpi_factor = 1.0;
+
+        let grid = VStack::new(cx, |_| {})
+            .layout_type(LayoutType::Grid)
+            .width(Pixels(300.0))
+            .height(Pixels(100.0))
+            .grid_columns(vec![Pixels(100.0); 3])
+            .grid_rows(vec![Pixels(50.0); 2])
+            .entity();
+        let child = cx.with_current(grid, |cx| {
+            Element::new(cx).column_start(1).column_span(2).row_start(1).entity()
+        });
+
+        layout_system(cx);
+
+        // The child fills the cells from the second column and row, spanning two columns.
+        let grid_bounds = cx.cache.get_bounds(grid);
+        let bounds = cx.cache.get_bounds(child);
+        assert_eq!(
+            (bounds.x - grid_bounds.x, bounds.y - grid_bounds.y, bounds.w, bounds.h),
+            (100.0, 50.0, 200.0, 50.0)
+        );
+    }
+}
@@ -2,6 +2,7 @@ pub(crate) mod accessibility;
 pub(crate) mod animation;
 pub(crate) mod binding;
 pub(crate) mod draw;
+pub(crate) mod generated_content;
 pub mod hover;
 pub(crate) mod image;
 pub(crate) mod layout;
@@ -12,6 +13,7 @@ pub(crate) use accessibility::*;
 pub(crate) use animation::*;
 pub(crate) use binding::*;
 pub(crate) use draw::*;
+pub(crate) use generated_content::*;
 pub use hover::*;
 pub(crate) use layout::*;
 pub(crate) use style::*;
@@ -1,6 +1,10 @@
-use crate::{events::ViewHandler, prelude::*};
-use hashbrown::HashMap;
-use vizia_storage::{LayoutParentIterator, TreeBreadthIterator};
+use crate::{
+    events::ViewHandler,
+    prelude::*,
+    systems::{generated_content_system, GeneratedContent},
+};
+use hashbrown::{HashMap, HashSet};
+use vizia_storage::{ChildIterator, LayoutParentIterator, TreeBreadthIterator};
 use vizia_style::{
     matches_selector,
     selectors::{
@@ -10,7 +14,8 @@ use vizia_style::{
         parser::{Component, NthType},
         OpaqueElement, SelectorImpl,
     },
-    Element, MatchingContext, MatchingMode, PseudoClass, QuirksMode, SelectorIdent, Selectors,
+    Content, Element, MatchingContext, MatchingMode, PseudoClass, PseudoElement, QuirksMode,
+    SelectorIdent, SelectorList, Selectors,
 };
 
 /// A node used for style matching.
@@ -20,6 +25,8 @@ pub(crate) struct Node<'s, 't, 'v> {
     store: &'s Style,
     tree: &'t Tree<Entity>,
     views: &'v HashMap<Entity, Box<dyn ViewHandler>>,
+    // The pseudo-element of the entity being matched, if matching rules for its generated content.
+    pseudo_element: Option<PseudoElement>,
 }
 
 impl std::fmt::Debug for Node<'_, '_, '_> {
@@ -54,29 +61,52 @@ impl Element for Node<'_, '_, '_> {
             store: self.store,
             tree: self.tree,
             views: self.views,
+            pseudo_element: None,
         })
     }
 
+    fn pseudo_element_originating_element(&self) -> Option<Self> {
+        Some(Node { pseudo_element: None, ..self.clone() })
+    }
+
+    // Generated content is skipped when matching siblings so that it doesn't affect selectors such as `:first-child`.
     fn prev_sibling_element(&self) -> Option<Self> {
-        self.tree.get_prev_layout_sibling(self.entity).map(|parent| Node {
-            entity: parent,
+        let mut sibling = self.tree.get_prev_layout_sibling(self.entity);
+        while let Some(generated) =
+            sibling.filter(|sibling| self.store.generated_content.contains(*sibling))
+        {
+            sibling = self.tree.get_prev_layout_sibling(generated);
+        }
+
+        sibling.map(|sibling| Node {
+            entity: sibling,
             store: self.store,
             tree: self.tree,
             views: self.views,
+            pseudo_element: None,
         })
     }
 
     fn next_sibling_element(&self) -> Option<Self> {
-        self.tree.get_next_layout_sibling(self.entity).map(|parent| Node {
-            entity: parent,
+        let mut sibling = self.tree.get_next_layout_sibling(self.entity);
+        while let Some(generated) =
+            sibling.filter(|sibling| self.store.generated_content.contains(*sibling))
+        {
+            sibling = self.tree.get_next_layout_sibling(generated);
+        }
+
+        sibling.map(|sibling| Node {
+            entity: sibling,
             store: self.store,
             tree: self.tree,
             views: self.views,
+            pseudo_element: None,
         })
     }
 
     fn is_empty(&self) -> bool {
-        !self.tree.has_children(self.entity)
+        ChildIterator::new(self.tree, self.entity)
+            .all(|child| self.store.generated_content.contains(child))
     }
 
     fn is_root(&self) -> bool {
@@ -111,7 +141,7 @@ impl Element for Node<'_, '_, '_> {
     }
 
     fn is_pseudo_element(&self) -> bool {
-        false
+        self.pseudo_element.is_some()
     }
 
     fn is_same_type(&self, other: &Self) -> bool {
@@ -157,18 +187,27 @@ impl Element for Node<'_, '_, '_> {
     fn attr_matches(
         &self,
         _ns: &NamespaceConstraint<&<Self::Impl as SelectorImpl>::NamespaceUrl>,
-        _local_name: &<Self::Impl as SelectorImpl>::LocalName,
-        _operation: &AttrSelectorOperation<&<Self::Impl as SelectorImpl>::AttrValue>,
+        local_name: &<Self::Impl as SelectorImpl>::LocalName,
+        operation: &AttrSelectorOperation<&<Self::Impl as SelectorImpl>::AttrValue>,
     ) -> bool {
+        if let Some(value) = self
+            .store
+            .attributes
+            .get(self.entity)
+            .and_then(|attributes| attributes.get(&local_name.0))
+        {
+            return operation.eval_str(value);
+        }
+
         false
     }
 
     fn match_pseudo_element(
         &self,
-        _pe: &<Self::Impl as SelectorImpl>::PseudoElement,
+        pe: &<Self::Impl as SelectorImpl>::PseudoElement,
         _context: &mut MatchingContext<'_, Self::Impl>,
     ) -> bool {
-        false
+        self.pseudo_element.as_ref() == Some(pe)
     }
 
     fn match_non_ts_pseudo_class(
@@ -254,6 +293,7 @@ pub(crate) fn inline_inheritance_system(cx: &mut Context, redraw_entities: &mut
             if cx.style.disabled.inherit_inline(entity, parent)
                 | cx.style.caret_color.inherit_inline(entity, parent)
                 | cx.style.selection_color.inherit_inline(entity, parent)
+                | cx.style.selection_font_color.inherit_inline(entity, parent)
             {
                 redraw_entities.push(entity);
             }
@@ -268,6 +308,13 @@ pub(crate) fn inline_inheritance_system(cx: &mut Context, redraw_entities: &mut
                 | cx.style.text_stroke_width.inherit_inline(entity, parent)
                 | cx.style.text_stroke_style.inherit_inline(entity, parent)
                 | cx.style.font_variation_settings.inherit_inline(entity, parent)
+                | cx.style.font_feature_settings.inherit_inline(entity, parent)
+                | cx.style.line_height.inherit_inline(entity, parent)
+                | cx.style.letter_spacing.inherit_inline(entity, parent)
+                | cx.style.word_spacing.inherit_inline(entity, parent)
+                | cx.style.text_indent.inherit_inline(entity, parent)
+                | cx.style.text_shadow.inherit_inline(entity, parent)
+                | cx.style.text_transform.inherit_inline(entity, parent)
             {
                 cx.style.needs_text_update(entity);
             }
@@ -289,12 +336,20 @@ pub(crate) fn shared_inheritance_system(cx: &mut Context, redraw_entities: &mut
                 | cx.style.text_stroke_width.inherit_shared(entity, parent)
                 | cx.style.text_stroke_style.inherit_shared(entity, parent)
                 | cx.style.font_variation_settings.inherit_shared(entity, parent)
+                | cx.style.font_feature_settings.inherit_shared(entity, parent)
+                | cx.style.line_height.inherit_shared(entity, parent)
+                | cx.style.letter_spacing.inherit_shared(entity, parent)
+                | cx.style.word_spacing.inherit_shared(entity, parent)
+                | cx.style.text_indent.inherit_shared(entity, parent)
+                | cx.style.text_shadow.inherit_shared(entity, parent)
+                | cx.style.text_transform.inherit_shared(entity, parent)
             {
                 cx.style.needs_text_update(entity);
             }
 
             if cx.style.caret_color.inherit_shared(entity, parent)
                 | cx.style.selection_color.inherit_shared(entity, parent)
+                | cx.style.selection_font_color.inherit_shared(entity, parent)
             {
                 redraw_entities.push(entity);
             }
@@ -340,6 +395,10 @@ fn link_style_data(
         should_redraw = true;
     }
 
+    if style.filter.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
     if style.backdrop_filter.link(entity, matched_rules) {
         should_redraw = true;
     }
@@ -427,16 +486,55 @@ fn link_style_data(
     }
 
     // Border
-    if style.border_width.link(entity, matched_rules) {
+    if style.border_top_width.link(entity, matched_rules) {
         should_relayout = true;
         should_redraw = true;
     }
 
-    if style.border_color.link(entity, matched_rules) {
+    if style.border_right_width.link(entity, matched_rules) {
+        should_relayout = true;
         should_redraw = true;
     }
 
-    if style.border_style.link(entity, matched_rules) {
+    if style.border_bottom_width.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.border_left_width.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.border_top_color.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_right_color.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_bottom_color.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_left_color.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_top_style.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_right_style.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_bottom_style.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
+    if style.border_left_style.link(entity, matched_rules) {
         should_redraw = true;
     }
 
@@ -501,6 +599,41 @@ fn link_style_data(
         should_redraw = true;
     }
 
+    // Grid
+    if style.grid_columns.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.grid_rows.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.column_start.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.column_span.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.row_start.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    if style.row_span.link(entity, matched_rules) {
+        should_relayout = true;
+        should_redraw = true;
+    }
+
+    // Containers
+    style.container_type.link(entity, matched_rules);
+    style.container_name.link(entity, matched_rules);
+
     // Background
     if style.background_color.link(entity, matched_rules) {
         should_redraw = true;
@@ -556,6 +689,47 @@ fn link_style_data(
         should_reflow = true;
     }
 
+    if style.font_feature_settings.link(entity, matched_rules) {
+        should_redraw = true;
+        should_relayout = true;
+        should_reflow = true;
+    }
+
+    if style.line_height.link(entity, matched_rules) {
+        should_redraw = true;
+        should_relayout = true;
+        should_reflow = true;
+    }
+
+    if style.letter_spacing.link(entity, matched_rules) {
+        should_redraw = true;
+        should_relayout = true;
+        should_reflow = true;
+    }
+
+    if style.word_spacing.link(entity, matched_rules) {
+        should_redraw = true;
+        should_relayout = true;
+        should_reflow = true;
+    }
+
+    if style.text_indent.link(entity, matched_rules) {
+        should_redraw = true;
+        should_relayout = true;
+        should_reflow = true;
+    }
+
+    if style.text_shadow.link(entity, matched_rules) {
+        should_redraw = true;
+        should_reflow = true;
+    }
+
+    if style.text_transform.link(entity, matched_rules) {
+        should_redraw = true;
+        should_relayout = true;
+        should_reflow = true;
+    }
+
     if style.text_wrap.link(entity, matched_rules) {
         should_redraw = true;
         should_relayout = true;
@@ -581,6 +755,10 @@ fn link_style_data(
         should_redraw = true;
     }
 
+    if style.selection_font_color.link(entity, matched_rules) {
+        should_redraw = true;
+    }
+
     if style.caret_color.link(entity, matched_rules) {
         should_redraw = true;
     }
@@ -698,6 +876,24 @@ fn link_style_data(
         should_redraw = true;
     }
 
+    // Generated Content
+    style.content.link(entity, matched_rules);
+    style.counter_reset.link(entity, matched_rules);
+    style.counter_increment.link(entity, matched_rules);
+
+    // Animations
+    let mut should_animate = style.animation_name.link(entity, matched_rules);
+    should_animate |= style.animation_duration.link(entity, matched_rules);
+    should_animate |= style.animation_delay.link(entity, matched_rules);
+    should_animate |= style.animation_timing_function.link(entity, matched_rules);
+    should_animate |= style.animation_iteration_count.link(entity, matched_rules);
+    should_animate |= style.animation_direction.link(entity, matched_rules);
+    should_animate |= style.animation_fill_mode.link(entity, matched_rules);
+
+    if should_animate {
+        style.update_css_animations(entity);
+    }
+
     //
     if should_relayout {
         style.system_flags.set(SystemFlags::RELAYOUT, true);
@@ -718,39 +914,138 @@ fn link_style_data(
     }
 }
 
+// Computes the custom properties of an entity and returns the rules to link to the entity, which includes
+// a resolved rule before each matched rule containing declarations with `var()` references.
+fn resolve_custom_properties(
+    style: &mut Style,
+    tree: &Tree<Entity>,
+    entity: Entity,
+    matched_rules: &[Rule],
+) -> Vec<Rule> {
+    let custom_properties = CustomProperties::compute(
+        matched_rules.iter().filter_map(|rule| style.custom_property_rules.get(rule)).flatten(),
+        tree.get_layout_parent(entity).and_then(|parent| style.custom_properties.get(parent)),
+    );
+
+    let mut rules = Vec::with_capacity(matched_rules.len());
+    let mut resolved_rules = Vec::new();
+    for rule in matched_rules {
+        if let Some(resolved_rule) = style.resolve_variables(*rule, &custom_properties) {
+            rules.push(resolved_rule);
+            resolved_rules.push(resolved_rule);
+        }
+
+        rules.push(*rule);
+    }
+
+    style.set_resolved_rules(entity, resolved_rules);
+
+    if custom_properties.is_empty() {
+        style.custom_properties.remove(entity);
+    } else {
+        style.custom_properties.insert(entity, custom_properties);
+    }
+
+    rules
+}
+
 /// Compute a list of matching style rules for a given entity.
 pub(crate) fn compute_matched_rules(
     cx: &Context,
     entity: Entity,
     matched_rules: &mut Vec<(Rule, u32)>,
+) {
+    match_rules(cx, entity, None, matched_rules);
+}
+
+/// Compute a list of matching style rules for a pseudo-element of a given entity, such as `::before`.
+pub(crate) fn compute_pseudo_element_rules(
+    cx: &Context,
+    entity: Entity,
+    pseudo_element: &PseudoElement,
+    matched_rules: &mut Vec<(Rule, u32)>,
+) {
+    match_rules(cx, entity, Some(pseudo_element), matched_rules);
+}
+
+fn match_rules(
+    cx: &Context,
+    entity: Entity,
+    pseudo_element: Option<&PseudoElement>,
+    matched_rules: &mut Vec<(Rule, u32)>,
 ) {
     for (rule, selector_list) in cx.style.rules.iter() {
-        let mut cache = SelectorCaches::default();
-        let mut context = MatchingContext::new(
-            MatchingMode::Normal,
-            None,
-            &mut cache,
-            QuirksMode::NoQuirks,
-            NeedsSelectorFlags::No,
-            MatchingForInvalidation::No,
-        );
-
-        for selector in selector_list.slice() {
-            let matches = matches_selector(
+        if let Some(specificity) = match_rule(cx, entity, *rule, selector_list, pseudo_element) {
+            matched_rules.push((*rule, specificity));
+        }
+    }
+
+    sort_matched_rules(cx, matched_rules);
+}
+
+// Returns the specificity of the first selector of the rule which matches the entity, or `None` if the rule doesn't
+// apply to the entity.
+fn match_rule(
+    cx: &Context,
+    entity: Entity,
+    rule: Rule,
+    selector_list: &SelectorList<Selectors>,
+    pseudo_element: Option<&PseudoElement>,
+) -> Option<u32> {
+    // Rules within `@media` blocks which don't match are skipped.
+    if cx.style.inactive_rules.contains(&rule) {
+        return None;
+    }
+
+    // Rules within `@container` blocks only apply if the queried containers have a matching size.
+    if let Some(queries) = cx.style.container_rules.get(&rule) {
+        let matches = queries.iter().all(|query| {
+            cx.style.query_container(&cx.tree, entity, query).is_some_and(|container| {
+                cx.style.container_query_matches(container, query, cx.cache.get_bounds(container))
+            })
+        });
+
+        if !matches {
+            return None;
+        }
+    }
+
+    let mut cache = SelectorCaches::default();
+    let mut context = MatchingContext::new(
+        MatchingMode::Normal,
+        None,
+        &mut cache,
+        QuirksMode::NoQuirks,
+        NeedsSelectorFlags::No,
+        MatchingForInvalidation::No,
+    );
+
+    selector_list
+        .slice()
+        .iter()
+        .filter(|selector| selector.pseudo_element() == pseudo_element)
+        .find(|&selector| {
+            matches_selector(
                 selector,
                 0,
                 None,
-                &Node { entity, store: &cx.style, tree: &cx.tree, views: &cx.views },
+                &Node {
+                    entity,
+                    store: &cx.style,
+                    tree: &cx.tree,
+                    views: &cx.views,
+                    pseudo_element: pseudo_element.cloned(),
+                },
                 &mut context,
-            );
-            if matches {
-                matched_rules.push((*rule, selector.specificity()));
-                break;
-            }
-        }
-    }
+            )
+        })
+        .map(|selector| selector.specificity())
+}
 
-    matched_rules.sort_by_cached_key(|(_, s)| *s);
+// Sorts matched rules from highest to lowest precedence. Rules in cascade layers with a higher precedence override
+// rules with a higher specificity.
+fn sort_matched_rules(cx: &Context, matched_rules: &mut [(Rule, u32)]) {
+    matched_rules.sort_by_cached_key(|(rule, s)| (cx.style.layer_precedence(*rule), *s));
     matched_rules.reverse();
 }
 
@@ -778,6 +1073,10 @@ fn has_same_selector(cx: &Context, entity1: Entity, entity2: Entity) -> bool {
         }
     }
 
+    if cx.style.attributes.get(entity1) != cx.style.attributes.get(entity2) {
+        return false;
+    }
+
     if let Some(psudeo_class_flag1) = cx.style.pseudo_classes.get(entity1) {
         if let Some(psudeo_class_flag2) = cx.style.pseudo_classes.get(entity2) {
             if psudeo_class_flag2.bits() != psudeo_class_flag1.bits() {
@@ -789,6 +1088,55 @@ fn has_same_selector(cx: &Context, entity1: Entity, entity2: Entity) -> bool {
     true
 }
 
+// Returns true if the given rule declares the `content` property, either directly or using `var()`.
+fn declares_content(style: &Style, rule: Rule) -> bool {
+    style.content.get_shared(rule).is_some()
+        || style
+            .variable_declarations
+            .get(&rule)
+            .is_some_and(|declarations| declarations.iter().any(|(name, _)| name == "content"))
+}
+
+// Creates, links, or removes the entities holding the generated content of the given pseudo-elements.
+fn restyle_generated_content(
+    cx: &mut Context,
+    pseudo_elements: HashSet<(Entity, PseudoElement)>,
+    redraw_entities: &mut Vec<Entity>,
+) {
+    for (entity, pseudo_element) in pseudo_elements {
+        let mut matched_rules = Vec::new();
+        compute_pseudo_element_rules(cx, entity, &pseudo_element, &mut matched_rules);
+        let matched_rules = matched_rules.iter().map(|(rule, _)| *rule).collect::<Vec<_>>();
+
+        let generated = ChildIterator::new(&cx.tree, entity)
+            .find(|child| cx.style.generated_content.get(*child) == Some(&pseudo_element));
+
+        // Content is only generated if one of the matched rules declares it.
+        if !matched_rules.iter().any(|rule| declares_content(&cx.style, *rule)) {
+            if let Some(generated) = generated {
+                cx.remove(generated);
+            }
+
+            continue;
+        }
+
+        let generated = generated.unwrap_or_else(|| {
+            let generated =
+                cx.with_current(entity, |cx| GeneratedContent.build(cx, |_| {}).entity());
+            cx.style.generated_content.insert(generated, pseudo_element.clone());
+            cx.style.abilities.insert(generated, Abilities::empty());
+            generated
+        });
+
+        let rules = resolve_custom_properties(&mut cx.style, &cx.tree, generated, &matched_rules);
+        link_style_data(&mut cx.style, &cx.tree, generated, redraw_entities, &rules);
+
+        if matches!(cx.style.content.get(generated), None | Some(Content::None)) {
+            cx.remove(generated);
+        }
+    }
+}
+
 pub(crate) struct MatchedRulesCache {
     pub entity: Entity,
     pub rules: Vec<(Rule, u32)>,
@@ -806,12 +1154,40 @@ pub(crate) fn style_system(cx: &mut Context) {
         let mut parent = None;
         let mut cache: Vec<MatchedRulesCache> = Vec::with_capacity(50);
 
+        // The rules which can generate content, which are matched against each restyled view to determine whether
+        // its `::before` and `::after` pseudo-elements need to be restyled.
+        let generated_content_rules = cx
+            .style
+            .rules
+            .iter()
+            .filter(|(rule, selector_list)| {
+                declares_content(&cx.style, **rule)
+                    && selector_list.slice().iter().any(|selector| {
+                        matches!(
+                            selector.pseudo_element(),
+                            Some(PseudoElement::Before | PseudoElement::After)
+                        )
+                    })
+            })
+            .map(|(rule, _)| *rule)
+            .collect::<Vec<_>>();
+        let mut pseudo_elements = HashSet::new();
+
         // Restyle the entire application.
         for entity in iterator {
             if !cx.style.restyle.contains(entity) {
                 continue;
             }
 
+            // Generated content is styled by the pseudo-element rules of the view it was generated for.
+            if let Some(pseudo_element) = cx.style.generated_content.get(entity) {
+                if let Some(parent) = cx.tree.get_parent(entity) {
+                    pseudo_elements.insert((parent, pseudo_element.clone()));
+                }
+
+                continue;
+            }
+
             let mut matched_rules = Vec::with_capacity(50);
 
             let current_parent = cx.tree.get_layout_parent(entity);
@@ -863,18 +1239,70 @@ pub(crate) fn style_system(cx: &mut Context) {
                 cache.push(MatchedRulesCache { entity, rules: matched_rules.clone() });
             }
 
-            if !matched_rules.is_empty() {
-                link_style_data(
-                    &mut cx.style,
-                    &cx.tree,
+            // View parts, such as the placeholder of a textbox, are also styled by the pseudo-element rules of the
+            // view they belong to, which cascade with the rules matching the part itself.
+            if let Some(pseudo_element) = cx.style.pseudo_element_views.get(entity) {
+                if let Some(owner) = cx.tree.get_layout_parent(entity) {
+                    let len = matched_rules.len();
+                    compute_pseudo_element_rules(cx, owner, pseudo_element, &mut matched_rules);
+                    if matched_rules.len() > len {
+                        sort_matched_rules(cx, &mut matched_rules);
+                    }
+                }
+            }
+
+            // The selected text of views which render selectable text is styled by their `::selection` rules.
+            if !cx.style.selection_rules.is_empty() && cx.style.selectable_text.contains(&entity) {
+                let len = matched_rules.len();
+                compute_pseudo_element_rules(
+                    cx,
                     entity,
-                    &mut redraw_entities,
-                    &matched_rules.iter().map(|(rule, _)| *rule).collect::<Vec<_>>(),
+                    &PseudoElement::Selection,
+                    &mut matched_rules,
                 );
+                if matched_rules.len() > len {
+                    sort_matched_rules(cx, &mut matched_rules);
+                }
+            }
+
+            let rules = resolve_custom_properties(
+                &mut cx.style,
+                &cx.tree,
+                entity,
+                &matched_rules.iter().map(|(rule, _)| *rule).collect::<Vec<_>>(),
+            );
+
+            if !rules.is_empty() {
+                link_style_data(&mut cx.style, &cx.tree, entity, &mut redraw_entities, &rules);
+            }
+
+            // Pseudo-elements are only restyled if they already have generated content or a rule which can generate
+            // content matches them.
+            for pseudo_element in [PseudoElement::Before, PseudoElement::After] {
+                let has_generated_content = !cx.style.generated_content.is_empty()
+                    && ChildIterator::new(&cx.tree, entity).any(|child| {
+                        cx.style.generated_content.get(child) == Some(&pseudo_element)
+                    });
+
+                if has_generated_content
+                    || generated_content_rules.iter().any(|rule| {
+                        cx.style.rules.get(rule).is_some_and(|selector_list| {
+                            match_rule(cx, entity, *rule, selector_list, Some(&pseudo_element))
+                                .is_some()
+                        })
+                    })
+                {
+                    pseudo_elements.insert((entity, pseudo_element));
+                }
             }
         }
+
+        restyle_generated_content(cx, pseudo_elements, &mut redraw_entities);
+
         cx.style.restyle.clear();
 
+        generated_content_system(cx, &mut redraw_entities);
+
         shared_inheritance_system(cx, &mut redraw_entities);
 
         for entity in redraw_entities {
@@ -882,3 +1310,243 @@ pub(crate) fn style_system(cx: &mut Context) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Builds a view with the given class and a placeholder part, returning both entities.
+    fn view_with_placeholder(cx: &mut Context, class: &str) -> (Entity, Entity) {
+        let owner = Element::new(cx).class(class).selectable_text().entity();
+        let part = cx.with_current(owner, |cx| {
+            Element::new(cx)
+                .class("placeholder")
+                .pseudo_element(PseudoElement::Placeholder)
+                .entity()
+        });
+
+        (owner, part)
+    }
+
+    #[test]
+    fn pseudo_element_cascade() {
+        let cx = &mut Context::new();
+        cx.add_stylesheet(
+            r#"
+            @layer base {
+                .a::selection { background-color: red; }
+                .a::placeholder { color: red; }
+            }
+            .a { selection-color: blue; }
+            .placeholder { color: blue; }
+            .b::selection { background-color: red; }
+            .b::placeholder { color: red; }
+            "#,
+        )
+        .unwrap();
+
+        let (a, a_placeholder) = view_with_placeholder(cx, "a");
+        let (b, b_placeholder) = view_with_placeholder(cx, "b");
+        let unselectable = Element::new(cx).class("b").entity();
+        cx.needs_restyle(Entity::root());
+        style_system(cx);
+
+        // Unlayered rules override the pseudo-element rules of a cascade layer.
+        assert_eq!(cx.style.selection_color.get(a), Some(&Color::blue()));
+        assert_eq!(cx.style.font_color.get(a_placeholder), Some(&Color::blue()));
+
+        // Pseudo-element rules with a higher specificity override the rules of the part itself.
+        assert_eq!(cx.style.selection_color.get(b), Some(&Color::red()));
+        assert_eq!(cx.style.font_color.get(b_placeholder), Some(&Color::red()));
+
+        // Only views which render selectable text are styled by `::selection` rules.
+        assert_eq!(cx.style.selection_color.get(unselectable), None);
+    }
+
+    #[test]
+    fn generated_content_children() {
+        let cx = &mut Context::new();
+        cx.add_stylesheet(r#".a::before { content: "x"; } .a::after { content: "y"; }"#).unwrap();
+
+        let a = Element::new(cx).class("a").entity();
+        let (first, last) =
+            cx.with_current(a, |cx| (Element::new(cx).entity(), Element::new(cx).entity()));
+        let b = Element::new(cx).entity();
+        cx.needs_restyle(Entity::root());
+        style_system(cx);
+
+        // Only views matching the pseudo-element rules have generated content.
+        assert_eq!(ChildIterator::new(&cx.tree, a).count(), 4);
+        assert_eq!(ChildIterator::new(&cx.tree, b).count(), 0);
+
+        // Generated content is skipped when finding the children of a view.
+        let cx = &mut EventContext::new_with_current(cx, a);
+        assert_eq!(cx.nth_child(0), Some(first));
+        assert_eq!(cx.nth_child(1), Some(last));
+        assert_eq!(cx.nth_child(2), None);
+        assert_eq!(cx.last_child(), Some(last));
+    }
+
+    #[test]
+    fn attribute_selectors() {
+        let cx = &mut Context::new();
+        cx.add_stylesheet(
+            r#"
+            element[orientation] { height: 10px; }
+            element[orientation="vertical"] { width: 20px; }
+            element[data-state^=open] { width: 30px; }
+            "#,
+        )
+        .unwrap();
+
+        let vertical = Element::new(cx).attr("orientation", "vertical").entity();
+        let horizontal = Element::new(cx).attr("orientation", "horizontal").entity();
+        let open = Element::new(cx).attr("data-state", "open-left").entity();
+        let closed = Element::new(cx).attr("data-state", "closed").entity();
+        let none = Element::new(cx).entity();
+        cx.needs_restyle(Entity::root());
+        style_system(cx);
+
+        assert_eq!(cx.style.height.get(vertical), Some(&Units::Pixels(10.0)));
+        assert_eq!(cx.style.width.get(vertical), Some(&Units::Pixels(20.0)));
+
+        // Siblings with different attribute values don't share their matched rules.
+        assert_eq!(cx.style.height.get(horizontal), Some(&Units::Pixels(10.0)));
+        assert_eq!(cx.style.width.get(horizontal), None);
+
+        assert_eq!(cx.style.width.get(open), Some(&Units::Pixels(30.0)));
+        assert_eq!(cx.style.width.get(closed), None);
+        assert_eq!(cx.style.height.get(none), None);
+
+        // Changing or removing an attribute restyles the view.
+        let mut ex = EventContext::new_with_current(cx, vertical);
+        ex.set_attr("orientation", "horizontal");
+        ex.with_current(closed, |ex| ex.set_attr("data-state", "open"));
+        ex.with_current(horizontal, |ex| ex.remove_attr("orientation"));
+        style_system(cx);
+
+        assert_eq!(cx.style.width.get(vertical), None);
+        assert_eq!(cx.style.width.get(closed), Some(&Units::Pixels(30.0)));
+        assert_eq!(cx.style.height.get(horizontal), None);
+    }
+
+    #[test]
+    fn resolved_variable_rules() {
+        let cx = &mut Context::new();
+        cx.add_stylesheet(
+            r#"
+            .a { --color: red; }
+            .b { --color: blue; }
+            .c { --color: green; }
+            .v { background-color: var(--color); width: var(--width, 10px); width: 20px; }
+            "#,
+        )
+        .unwrap();
+
+        let v = Element::new(cx).class("v").class("a").entity();
+        cx.needs_restyle(Entity::root());
+        style_system(cx);
+
+        // A later declaration overrides an earlier declaration which references a variable.
+        assert_eq!(cx.style.width.get(v), Some(&Units::Pixels(20.0)));
+        assert_eq!(cx.style.background_color.get(v), Some(&Color::red()));
+
+        // Resolved rules which are no longer used are reused for new values.
+        let classes = [("b", Color::blue()), ("c", Color::green()), ("a", Color::red())];
+        for (class, color) in classes.iter().cycle().take(9) {
+            let mut ecx = EventContext::new_with_current(cx, v);
+            for (other, _) in classes.iter() {
+                ecx.toggle_class(other, other == class);
+            }
+            style_system(cx);
+
+            assert_eq!(cx.style.background_color.get(v), Some(color));
+        }
+
+        assert_eq!(cx.style.resolved_rule_data.len(), 2);
+
+        cx.remove(v);
+        assert!(cx.style.entity_resolved_rules.is_empty());
+        assert_eq!(cx.style.unused_resolved_rules.len(), 2);
+    }
+
+    #[test]
+    fn media_color_scheme() {
+        let cx = &mut Context::new();
+        cx.add_stylesheet("@media (prefers-color-scheme: dark) { .a { width: 20px; } }").unwrap();
+
+        let a = Element::new(cx).class("a").entity();
+        cx.remove_user_themes();
+        cx.needs_restyle(Entity::root());
+        style_system(cx);
+
+        // The color scheme is initialized from the theme of the environment.
+        assert_eq!(cx.style.media.color_scheme, vizia_style::ColorScheme::Light);
+        assert_eq!(cx.style.width.get(a), None);
+
+        EventContext::new_with_current(cx, a).set_theme_mode(ThemeMode::DarkMode);
+        style_system(cx);
+
+        assert_eq!(cx.style.width.get(a), Some(&Units::Pixels(20.0)));
+    }
+
+    #[test]
+    fn restart_css_animations() {
+        let cx = &mut Context::new();
+        cx.add_stylesheet(
+            r#"
+            @keyframes fade { from { opacity: 0; } to { opacity: 1; } }
+            .a { animation: fade 1s; }
+            .a.slow { animation-duration: 2s; }
+            "#,
+        )
+        .unwrap();
+
+        let a = Element::new(cx).class("a").entity();
+        cx.needs_restyle(Entity::root());
+        style_system(cx);
+
+        let durations = |cx: &mut Context| {
+            let pending = cx.style.pending_animations.drain(..);
+            pending.map(|(_, _, options)| options.duration).collect::<Vec<_>>()
+        };
+
+        assert_eq!(durations(cx), vec![Duration::from_secs(1)]);
+
+        // Restyling without changing the playback options doesn't restart the animation.
+        cx.needs_restyle(a);
+        style_system(cx);
+        assert_eq!(durations(cx), vec![]);
+
+        EventContext::new_with_current(cx, a).toggle_class("slow", true);
+        style_system(cx);
+        assert_eq!(durations(cx), vec![Duration::from_secs(2)]);
+    }
+
+    #[test]
+    fn selection_color_transition() {
+        let cx = &mut Context::new();
+        cx.add_stylesheet(
+            r#"
+            .a::selection { color: red; }
+            .a.b::selection { color: blue; transition: color 100ms; }
+            "#,
+        )
+        .unwrap();
+
+        let a = Element::new(cx).class("a").selectable_text().entity();
+        cx.needs_restyle(Entity::root());
+        style_system(cx);
+        assert_eq!(cx.style.selection_font_color.get(a), Some(&Color::red()));
+
+        EventContext::new_with_current(cx, a).toggle_class("b", true);
+        style_system(cx);
+        crate::systems::animation_system(cx);
+        assert_ne!(cx.style.selection_font_color.get(a), Some(&Color::blue()));
+
+        // The transition is ticked until the color reaches its end value.
+        std::thread::sleep(Duration::from_millis(150));
+        crate::systems::animation_system(cx);
+        assert_eq!(cx.style.selection_font_color.get(a), Some(&Color::blue()));
+    }
+}
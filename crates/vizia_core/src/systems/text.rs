@@ -1,14 +1,18 @@
 use skia_safe::{
-    font_arguments::VariationPosition,
+    font_arguments::{variation_position::Coordinate, VariationPosition},
     textlayout::{
-        FontCollection, Paragraph, ParagraphBuilder, ParagraphStyle, RectHeightStyle,
-        RectWidthStyle, TextStyle,
+        Paragraph, ParagraphBuilder, ParagraphStyle, PlaceholderAlignment, PlaceholderStyle,
+        RectHeightStyle, RectWidthStyle, TextBaseline, TextStyle,
     },
-    BlendMode, FontArguments, FontStyle, Paint,
+    BlendMode, FontArguments, FontStyle, FourByteTag, Paint,
 };
 use vizia_storage::{LayoutChildIterator, LayoutTreeIterator};
 
-use crate::{cache::CachedData, prelude::*};
+use crate::{
+    cache::CachedData,
+    prelude::*,
+    text::{FontFaces, TextContext},
+};
 
 pub(crate) fn text_system(cx: &mut Context) {
     let iterator = LayoutTreeIterator::full(&cx.tree);
@@ -21,7 +25,7 @@ pub(crate) fn text_system(cx: &mut Context) {
             && cx.style.display.get(entity).copied().unwrap_or_default() != Display::None
         {
             if let Some(paragraph) =
-                build_paragraph(entity, &mut cx.style, &cx.tree, cx.text_context.font_collection())
+                build_paragraph(entity, &mut cx.style, &cx.tree, &cx.text_context)
             {
                 cx.text_context.text_paragraphs.insert(entity, paragraph);
                 cx.style.needs_relayout();
@@ -146,7 +150,7 @@ pub fn build_paragraph(
     entity: Entity,
     style: &mut Style,
     tree: &Tree<Entity>,
-    font_collection: &FontCollection,
+    text_context: &TextContext,
 ) -> Option<Paragraph> {
     let mut paragraph_style = ParagraphStyle::default();
     // paragraph_style.turn_hinting_off();
@@ -195,9 +199,42 @@ pub fn build_paragraph(
         .into(),
     );
 
-    let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
+    let mut paragraph_builder =
+        ParagraphBuilder::new(&paragraph_style, text_context.font_collection());
+
+    // Views which render selectable text, such as a textbox, use the offsets of the paragraph as byte offsets into
+    // their text, so their text is neither indented nor transformed.
+    let preserve_offsets = style.selectable_text.contains(&entity);
+
+    // Text Indent
+    // The first line is indented by an empty placeholder, which is represented by an object replacement character
+    // in the text of the paragraph.
+    let mut current = 0;
+    if let Some(text_indent) = style.text_indent.get(entity).filter(|_| !preserve_offsets) {
+        let font_size = style.font_size.get(entity).map_or(16.0, |f| f.0);
+        let indent =
+            length_to_px(style, text_indent, font_size).unwrap_or_default() * style.scale_factor();
+        if indent > 0.0 {
+            paragraph_builder.add_placeholder(&PlaceholderStyle::new(
+                indent,
+                0.0,
+                PlaceholderAlignment::Baseline,
+                TextBaseline::Alphabetic,
+                0.0,
+            ));
+            current += '\u{FFFC}'.len_utf8();
+        }
+    }
 
-    add_block(style, tree, entity, &mut paragraph_builder, &mut 0);
+    add_block(
+        style,
+        tree,
+        &text_context.font_faces,
+        entity,
+        preserve_offsets,
+        &mut paragraph_builder,
+        &mut current,
+    );
 
     paragraph_builder.add_text("\u{200B}");
     paragraph_builder.build().into()
@@ -206,7 +243,9 @@ pub fn build_paragraph(
 fn add_block(
     style: &mut Style,
     tree: &Tree<Entity>,
+    font_faces: &FontFaces,
     entity: Entity,
+    preserve_offsets: bool,
     paragraph_builder: &mut ParagraphBuilder,
     current: &mut usize,
 ) {
@@ -218,7 +257,7 @@ fn add_block(
 
             let mut text_style = TextStyle::new();
 
-            let font_color = style.font_color.get(entity).cloned().unwrap_or_default();
+            let font_color = style.current_color(tree, entity);
 
             if let Some(text_decoration_line) = style.text_decoration_line.get(entity).copied() {
                 text_style.set_decoration_type(text_decoration_line.into());
@@ -226,18 +265,39 @@ fn add_block(
             }
 
             // Font Families
+            // Families with font faces added by `@font-face` rules or `add_font_file` are replaced by the font face
+            // which best matches the weight and slant of the text.
+            let font_weight = style.font_weight.get(entity).copied().unwrap_or_default();
+            let font_slant = style.font_slant.get(entity).copied().unwrap_or_default();
+            let font_families = style
+                .font_family
+                .get(entity)
+                .map(Vec::as_slice)
+                .unwrap_or(&[FamilyOwned::Generic(GenericFontFamily::SansSerif)]);
+            let matched_faces = font_families
+                .iter()
+                .map(|family| match family {
+                    FamilyOwned::Named(name) => font_faces.find(name, font_weight, font_slant),
+                    FamilyOwned::Generic(_) => None,
+                })
+                .collect::<Vec<_>>();
+            let font_face = matched_faces.iter().copied().flatten().next();
             text_style.set_font_families(
-                style
-                    .font_family
-                    .get(entity)
-                    .map(Vec::as_slice)
-                    .unwrap_or(&[FamilyOwned::Generic(GenericFontFamily::SansSerif)]),
+                &font_families
+                    .iter()
+                    .zip(matched_faces.iter())
+                    .map(|(family, font_face)| match (family, font_face) {
+                        (_, Some(font_face)) => font_face.alias.as_str(),
+                        (FamilyOwned::Named(name), None) => name.as_str(),
+                        (FamilyOwned::Generic(_), None) => family.as_ref(),
+                    })
+                    .collect::<Vec<_>>(),
             );
 
             let mut paint = Paint::default();
             // Font Color
-            if let Some(font_color) = style.font_color.get(entity) {
-                paint.set_color(*font_color);
+            if style.font_color.get(entity).is_some() {
+                paint.set_color(font_color);
                 paint.set_anti_alias(false);
                 paint.set_blend_mode(BlendMode::SrcOver);
             }
@@ -266,6 +326,62 @@ fn add_block(
             let font_size = style.font_size.get(entity).map_or(16.0, |f| f.0);
             text_style.set_font_size(font_size * style.scale_factor());
 
+            // Line Height
+            if let Some(line_height) = style
+                .line_height
+                .get(entity)
+                .and_then(|line_height| line_height.to_factor(font_size))
+            {
+                text_style.set_height(line_height);
+                text_style.set_height_override(true);
+            }
+
+            // Letter and Word Spacing
+            if let Some(letter_spacing) = style
+                .letter_spacing
+                .get(entity)
+                .and_then(|letter_spacing| length_to_px(style, letter_spacing, font_size))
+            {
+                text_style.set_letter_spacing(letter_spacing * style.scale_factor());
+            }
+
+            if let Some(word_spacing) = style
+                .word_spacing
+                .get(entity)
+                .and_then(|word_spacing| length_to_px(style, word_spacing, font_size))
+            {
+                text_style.set_word_spacing(word_spacing * style.scale_factor());
+            }
+
+            // Text Shadow
+            // The blur radius of a CSS text shadow is twice the standard deviation of the blur.
+            if let Some(text_shadows) = style.text_shadow.get(entity) {
+                for text_shadow in text_shadows {
+                    let (Some(x_offset), Some(y_offset), Some(blur_radius)) = (
+                        length_to_px(style, &text_shadow.x_offset, font_size),
+                        length_to_px(style, &text_shadow.y_offset, font_size),
+                        text_shadow.blur_radius.as_ref().map_or(Some(0.0), |blur_radius| {
+                            length_to_px(style, blur_radius, font_size)
+                        }),
+                    ) else {
+                        continue;
+                    };
+
+                    text_style.add_shadow(skia_safe::textlayout::TextShadow::new(
+                        text_shadow.color.unwrap_or_default().resolve(font_color),
+                        (x_offset * style.scale_factor(), y_offset * style.scale_factor()),
+                        (blur_radius * style.scale_factor() / 2.0) as f64,
+                    ));
+                }
+            }
+
+            // Font Features
+            if let Some(font_features) = style.font_feature_settings.get(entity) {
+                for font_feature in font_features {
+                    text_style.add_font_feature(&font_feature.tag, font_feature.value as i32);
+                }
+            }
+
             // Font Style
             match (
                 style.font_weight.get(entity),
@@ -288,8 +404,27 @@ fn add_block(
                 text_style.set_font_arguments(&FontArguments::new().set_variation_design_position(
                     VariationPosition { coordinates: &coordinates },
                 ));
+            } else if let Some(font_face) =
+                font_face.filter(|font_face| font_face.weight.0 != font_face.weight.1)
+            {
+                // A font face covering a range of weights is a variable font, so the weight is set with its axis.
+                let weight = font_weight.0.clamp(font_face.weight.0 .0, font_face.weight.1 .0);
+                let coordinates = [Coordinate {
+                    axis: FourByteTag::from_chars('w', 'g', 'h', 't'),
+                    value: weight as f32,
+                }];
+                text_style.set_font_arguments(&FontArguments::new().set_variation_design_position(
+                    VariationPosition { coordinates: &coordinates },
+                ));
             }
 
+            // Text Transform
+            let text = if preserve_offsets {
+                text.clone()
+            } else {
+                style.text_transform.get(entity).copied().unwrap_or_default().apply(text)
+            };
+
             paragraph_builder.push_style(&text_style);
             style.text_range.insert(entity, *current..*current + text.len());
             paragraph_builder.add_text(text.as_str());
@@ -300,7 +435,58 @@ fn add_block(
     let iter = LayoutChildIterator::new(tree, entity);
     for child in iter {
         if style.text_span.get(child).copied().unwrap_or_default() {
-            add_block(style, tree, child, paragraph_builder, current);
+            add_block(style, tree, font_faces, child, preserve_offsets, paragraph_builder, current);
+        }
+    }
+}
+
+/// Returns the length in logical pixels, resolving font-relative units against the given font size and the font size
+/// of the root view, or `None` for viewport-relative units and calculations which can't be resolved for text.
+fn length_to_px(style: &Style, length: &Length, font_size: f32) -> Option<f32> {
+    match length {
+        Length::Value(LengthValue::Em(em)) => Some(em * font_size),
+        // The `ex` and `ch` units use the fallback of half an em, as the metrics of the font aren't known here.
+        Length::Value(LengthValue::Ex(ex)) => Some(ex * font_size / 2.0),
+        Length::Value(LengthValue::Ch(ch)) => Some(ch * font_size / 2.0),
+        Length::Value(LengthValue::Rem(rem)) => {
+            Some(rem * style.font_size.get(Entity::root()).map_or(16.0, |f| f.0))
         }
+        _ => length.to_px(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Lens)]
+    struct AppData {
+        text: String,
+    }
+
+    impl Model for AppData {}
+
+    #[test]
+    fn textbox_ignores_indent_and_transform() {
+        let cx = &mut Context::new();
+        cx.add_stylesheet(".indented { text-indent: 2em; text-transform: uppercase; }").unwrap();
+
+        AppData { text: String::from("ﬁx") }.build(cx);
+        let label = Label::new(cx, AppData::text).class("indented").entity();
+        let mut textbox = Entity::null();
+        VStack::new(cx, |cx| {
+            textbox = Textbox::new(cx, AppData::text).entity();
+        })
+        .class("indented");
+
+        cx.needs_restyle(Entity::root());
+        style_system(cx);
+        text_system(cx);
+
+        // The label is indented by a placeholder and its transformed text "FIX" is shorter than its source text.
+        assert_eq!(cx.style.text_range.get(label), Some(&(3..6)));
+
+        // The paragraph offsets of a textbox match the byte offsets into its text.
+        assert_eq!(cx.style.text_range.get(textbox), Some(&(0.."ﬁx".len())));
     }
 }
@@ -0,0 +1,365 @@
+use std::path::{Path, PathBuf};
+
+use hashbrown::HashMap;
+use log::warn;
+use skia_safe::{
+    font_style::Slant, textlayout::TypefaceFontProvider, FontMgr, FontStyle, Typeface,
+};
+use vizia_style::{
+    FontFaceProperty, FontFaceRule, FontFaceSource, FontFamily, FontSlant, FontWeight,
+    FontWeightRange,
+};
+
+use crate::resource::FontError;
+
+/// A location to load a font face from, taken from the `src` descriptor of a `@font-face` rule.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum FontSource {
+    File(PathBuf),
+    Local(String),
+}
+
+/// A font face declared by a `@font-face` rule.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FontFaceDescriptor {
+    pub family: String,
+    pub sources: Vec<FontSource>,
+    pub weight: FontWeightRange,
+    pub slant: FontSlant,
+}
+
+impl FontFaceDescriptor {
+    /// Creates a font face descriptor from a `@font-face` rule, returning `None` if the rule has no family or sources.
+    pub fn from_rule(rule: FontFaceRule) -> Option<Self> {
+        let mut family = None;
+        let mut sources = Vec::new();
+        let mut weight = FontWeightRange::default();
+        let mut slant = FontSlant::default();
+
+        for property in rule.properties {
+            match property {
+                FontFaceProperty::FontFamily(FontFamily::Named(name)) => {
+                    family = Some(name.to_string());
+                }
+
+                // Generic families cannot be redefined by a font face.
+                FontFaceProperty::FontFamily(FontFamily::Generic(_)) => return None,
+
+                FontFaceProperty::Source(source_list) => {
+                    sources = source_list
+                        .into_iter()
+                        .filter_map(|source| match source {
+                            FontFaceSource::Url(url, _) => {
+                                Some(FontSource::File(PathBuf::from(url.url.as_ref())))
+                            }
+                            FontFaceSource::Local(FontFamily::Named(name)) => {
+                                Some(FontSource::Local(name.to_string()))
+                            }
+                            FontFaceSource::Local(FontFamily::Generic(_)) => None,
+                        })
+                        .collect();
+                }
+
+                FontFaceProperty::FontWeight(range) => weight = range,
+
+                FontFaceProperty::FontStyle(font_slant) => slant = font_slant,
+            }
+        }
+
+        if sources.is_empty() {
+            return None;
+        }
+
+        Some(Self { family: family?, sources, weight, slant })
+    }
+
+    /// Resolves relative font file paths against the directory of the stylesheet at the given path.
+    pub fn resolve_paths(&mut self, stylesheet_path: &Path) {
+        let Some(directory) = stylesheet_path.parent() else {
+            return;
+        };
+
+        for source in self.sources.iter_mut() {
+            if let FontSource::File(path) = source {
+                if path.is_relative() {
+                    *path = directory.join(&*path);
+                }
+            }
+        }
+    }
+}
+
+/// A loaded font face which can be selected by family name, weight, and slant.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FontFace {
+    pub family: String,
+    pub weight: FontWeightRange,
+    pub slant: FontSlant,
+    // The name the typeface is registered under with the font collection.
+    pub alias: String,
+    // Whether the font face was declared in a stylesheet, and so should be removed when styles are reloaded.
+    pub from_stylesheet: bool,
+}
+
+/// Stores the font faces declared in stylesheets or added with [`Context::add_font_file`](crate::context::Context::add_font_file).
+#[derive(Default)]
+pub(crate) struct FontFaces {
+    faces: Vec<FontFace>,
+    // Maps the path of each loaded font file to its typeface and the alias it is registered under, so that files
+    // are only loaded once.
+    files: HashMap<PathBuf, (String, Typeface)>,
+}
+
+impl FontFaces {
+    /// Loads the font file at the given path, returning its typeface and the alias it is registered under.
+    pub fn load_file(
+        &mut self,
+        path: &Path,
+        font_manager: &FontMgr,
+        asset_provider: &mut TypefaceFontProvider,
+    ) -> Result<(String, Typeface), FontError> {
+        if let Some(loaded) = self.files.get(path) {
+            return Ok(loaded.clone());
+        }
+
+        let data = std::fs::read(path)?;
+        let typeface = font_manager
+            .new_from_data(&data, None)
+            .ok_or_else(|| FontError::InvalidData(path.to_owned()))?;
+
+        let alias = format!("vizia-font-face-{}", self.files.len());
+        asset_provider.register_typeface(typeface.clone(), Some(alias.as_str()));
+        self.files.insert(path.to_owned(), (alias.clone(), typeface.clone()));
+
+        Ok((alias, typeface))
+    }
+
+    /// Loads the font file at the given path and adds it as a font face of the family, weight, and slant
+    /// described by the font itself.
+    pub fn add_file(
+        &mut self,
+        path: &Path,
+        font_manager: &FontMgr,
+        asset_provider: &mut TypefaceFontProvider,
+    ) -> Result<(), FontError> {
+        let (alias, typeface) = self.load_file(path, font_manager, asset_provider)?;
+        let font_style = typeface.font_style();
+        let weight = FontWeight(*font_style.weight() as u16);
+
+        self.add(FontFace {
+            family: typeface.family_name(),
+            weight: FontWeightRange(weight, weight),
+            slant: match font_style.slant() {
+                Slant::Upright => FontSlant::Normal,
+                Slant::Italic => FontSlant::Italic,
+                Slant::Oblique => FontSlant::Oblique,
+            },
+            alias,
+            from_stylesheet: false,
+        });
+
+        Ok(())
+    }
+
+    /// Adds a font face which is selected for the given family, weight range, and slant.
+    pub fn add(&mut self, face: FontFace) {
+        self.faces.retain(|f| {
+            f.family != face.family || f.weight != face.weight || f.slant != face.slant
+        });
+        self.faces.push(face);
+    }
+
+    /// Replaces the font faces declared in stylesheets, loading the first available source of each.
+    pub fn load_descriptors(
+        &mut self,
+        descriptors: &[FontFaceDescriptor],
+        font_manager: &FontMgr,
+        asset_provider: &mut TypefaceFontProvider,
+    ) {
+        self.faces.retain(|face| !face.from_stylesheet);
+
+        for descriptor in descriptors {
+            let alias = descriptor.sources.iter().find_map(|source| match source {
+                FontSource::File(path) => {
+                    match self.load_file(path, font_manager, asset_provider) {
+                        Ok((alias, _)) => Some(alias),
+                        Err(err) => {
+                            warn!("Failed to load font face '{}': {}", descriptor.family, err);
+                            None
+                        }
+                    }
+                }
+
+                FontSource::Local(name) => font_manager
+                    .match_family_style(name, FontStyle::default())
+                    .map(|_| name.clone()),
+            });
+
+            if let Some(alias) = alias {
+                self.add(FontFace {
+                    family: descriptor.family.clone(),
+                    weight: descriptor.weight,
+                    slant: descriptor.slant,
+                    alias,
+                    from_stylesheet: true,
+                });
+            }
+        }
+    }
+
+    /// Returns the font face which best matches the given family, weight, and slant, following the CSS font matching
+    /// algorithm, or `None` if there are no font faces for the family.
+    pub fn find(&self, family: &str, weight: FontWeight, slant: FontSlant) -> Option<&FontFace> {
+        self.faces
+            .iter()
+            .filter(|face| face.family.eq_ignore_ascii_case(family))
+            .min_by_key(|face| {
+                (slant_distance(slant, face.slant), weight_distance(weight, face.weight))
+            })
+    }
+}
+
+// Italic faces are preferred for italic text, falling back to oblique and then normal faces, and vice versa.
+fn slant_distance(desired: FontSlant, slant: FontSlant) -> u8 {
+    match (desired, slant) {
+        (FontSlant::Normal, FontSlant::Normal)
+        | (FontSlant::Italic, FontSlant::Italic)
+        | (FontSlant::Oblique, FontSlant::Oblique) => 0,
+        (FontSlant::Normal, FontSlant::Oblique)
+        | (FontSlant::Italic, FontSlant::Oblique)
+        | (FontSlant::Oblique, FontSlant::Italic) => 1,
+        _ => 2,
+    }
+}
+
+// Weights between 400 and 500 prefer heavier faces up to 500, then lighter faces, then heavier faces. Lighter weights
+// prefer lighter faces and heavier weights prefer heavier faces.
+fn weight_distance(desired: FontWeight, range: FontWeightRange) -> (u8, u16) {
+    if range.contains(desired) {
+        return (0, 0);
+    }
+
+    let desired = desired.0;
+    let closest = desired.clamp(range.0 .0, range.1 .0);
+
+    if desired < 400 {
+        if closest < desired {
+            (1, desired - closest)
+        } else {
+            (2, closest - desired)
+        }
+    } else if desired > 500 {
+        if closest > desired {
+            (1, closest - desired)
+        } else {
+            (2, desired - closest)
+        }
+    } else if closest > desired && closest <= 500 {
+        (1, closest - desired)
+    } else if closest < desired {
+        (2, desired - closest)
+    } else {
+        (3, closest - desired)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn face(family: &str, weight: (u16, u16), slant: FontSlant, alias: &str) -> FontFace {
+        FontFace {
+            family: family.to_string(),
+            weight: FontWeightRange(FontWeight(weight.0), FontWeight(weight.1)),
+            slant,
+            alias: alias.to_string(),
+            from_stylesheet: true,
+        }
+    }
+
+    fn font_faces() -> FontFaces {
+        let mut font_faces = FontFaces::default();
+        font_faces.add(face("Inter", (300, 300), FontSlant::Normal, "light"));
+        font_faces.add(face("Inter", (400, 400), FontSlant::Normal, "regular"));
+        font_faces.add(face("Inter", (700, 700), FontSlant::Normal, "bold"));
+        font_faces.add(face("Inter", (400, 400), FontSlant::Italic, "italic"));
+        font_faces.add(face("Inter Variable", (100, 900), FontSlant::Normal, "variable"));
+        font_faces
+    }
+
+    #[test]
+    fn match_weight() {
+        let font_faces = font_faces();
+        let find = |weight| {
+            font_faces
+                .find("inter", FontWeight(weight), FontSlant::Normal)
+                .map(|face| face.alias.as_str())
+        };
+
+        assert_eq!(find(400), Some("regular"));
+        assert_eq!(find(700), Some("bold"));
+        assert_eq!(find(500), Some("regular"));
+        assert_eq!(find(600), Some("bold"));
+        assert_eq!(find(900), Some("bold"));
+        assert_eq!(find(200), Some("light"));
+        assert_eq!(find(350), Some("light"));
+        assert_eq!(find(100), Some("light"));
+    }
+
+    #[test]
+    fn match_weight_range() {
+        let font_faces = font_faces();
+
+        assert_eq!(
+            font_faces
+                .find("Inter Variable", FontWeight(650), FontSlant::Normal)
+                .map(|face| face.alias.as_str()),
+            Some("variable")
+        );
+        assert_eq!(
+            font_faces
+                .find("Inter Variable", FontWeight(650), FontSlant::Italic)
+                .map(|face| face.alias.as_str()),
+            Some("variable")
+        );
+    }
+
+    #[test]
+    fn match_slant() {
+        let font_faces = font_faces();
+        let find =
+            |weight, slant| font_faces.find("Inter", weight, slant).map(|face| face.alias.as_str());
+
+        assert_eq!(find(FontWeight(400), FontSlant::Italic), Some("italic"));
+        assert_eq!(find(FontWeight(400), FontSlant::Oblique), Some("italic"));
+        assert_eq!(find(FontWeight(700), FontSlant::Italic), Some("italic"));
+    }
+
+    #[test]
+    fn unknown_family() {
+        assert_eq!(font_faces().find("Roboto", FontWeight(400), FontSlant::Normal), None);
+    }
+
+    #[test]
+    fn resolve_relative_paths() {
+        let mut descriptor = FontFaceDescriptor {
+            family: String::from("Inter"),
+            sources: vec![
+                FontSource::File(PathBuf::from("fonts/Inter.ttf")),
+                FontSource::Local(String::from("Inter")),
+            ],
+            weight: FontWeightRange::default(),
+            slant: FontSlant::Normal,
+        };
+
+        descriptor.resolve_paths(Path::new("assets/theme.css"));
+
+        assert_eq!(
+            descriptor.sources,
+            vec![
+                FontSource::File(Path::new("assets").join("fonts/Inter.ttf")),
+                FontSource::Local(String::from("Inter")),
+            ]
+        );
+    }
+}
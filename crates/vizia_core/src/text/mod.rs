@@ -7,6 +7,9 @@ pub use movement::*;
 pub(crate) mod text_context;
 pub(crate) use text_context::*;
 
+pub(crate) mod font_face;
+pub(crate) use font_face::*;
+
 pub mod editable_text;
 pub use editable_text::*;
 
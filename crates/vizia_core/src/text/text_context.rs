@@ -1,8 +1,12 @@
+use std::path::Path;
+
 use skia_safe::textlayout::{Paragraph, TypefaceFontProvider};
 use skia_safe::{textlayout::FontCollection, FontMgr};
 use vizia_storage::SparseSet;
 
-use crate::{entity::Entity, layout::BoundingBox};
+use crate::{entity::Entity, layout::BoundingBox, resource::FontError};
+
+use super::{FontFaceDescriptor, FontFaces};
 
 pub struct TextContext {
     pub font_collection: FontCollection,
@@ -10,6 +14,7 @@ pub struct TextContext {
     pub asset_provider: TypefaceFontProvider,
     pub text_bounds: SparseSet<BoundingBox>,
     pub text_paragraphs: SparseSet<Paragraph>,
+    pub(crate) font_faces: FontFaces,
 }
 
 impl TextContext {
@@ -18,6 +23,20 @@ impl TextContext {
         &self.font_collection
     }
 
+    /// Loads a font file and adds it as a font face of the family, weight, and slant described by the font.
+    pub(crate) fn add_font_file(&mut self, path: &Path) -> Result<(), FontError> {
+        self.font_faces.add_file(path, &self.default_font_manager, &mut self.asset_provider)
+    }
+
+    /// Loads the font faces declared by `@font-face` rules, replacing any previously declared font faces.
+    pub(crate) fn load_font_faces(&mut self, descriptors: &[FontFaceDescriptor]) {
+        self.font_faces.load_descriptors(
+            descriptors,
+            &self.default_font_manager,
+            &mut self.asset_provider,
+        );
+    }
+
     pub(crate) fn set_text_bounds(&mut self, entity: Entity, bounds: BoundingBox) {
         self.text_bounds.insert(entity, bounds);
     }
@@ -3,6 +3,11 @@ use std::path::{Path, PathBuf};
 // Helper trait for getting CSS from a string or path.
 pub trait IntoCssStr: 'static {
     fn get_style(&self) -> Result<String, std::io::Error>;
+
+    /// Returns the path of the file the stylesheet is loaded from, if any, which relative urls are resolved against.
+    fn get_path(&self) -> Option<&Path> {
+        None
+    }
 }
 
 impl IntoCssStr for CSS {
@@ -13,6 +18,14 @@ impl IntoCssStr for CSS {
             CSS::String(style_string) => Ok(style_string.to_owned()),
         }
     }
+
+    fn get_path(&self) -> Option<&Path> {
+        match self {
+            CSS::Path(path) => Some(path),
+
+            CSS::String(_) => None,
+        }
+    }
 }
 
 impl IntoCssStr for &'static str {
@@ -25,12 +38,20 @@ impl IntoCssStr for PathBuf {
     fn get_style(&self) -> Result<String, std::io::Error> {
         std::fs::read_to_string(self)
     }
+
+    fn get_path(&self) -> Option<&Path> {
+        Some(self)
+    }
 }
 
 impl IntoCssStr for Path {
     fn get_style(&self) -> Result<String, std::io::Error> {
         std::fs::read_to_string(self)
     }
+
+    fn get_path(&self) -> Option<&Path> {
+        Some(self)
+    }
 }
 
 #[doc(hidden)]
@@ -4,6 +4,7 @@ use std::{
     any::{Any, TypeId},
     marker::PhantomData,
 };
+use vizia_style::PseudoElement;
 
 /// A handle to a view which has been built into the tree.
 pub struct Handle<'a, V> {
@@ -69,6 +70,21 @@ impl<V> Handle<'_, V> {
         self.focusable(false)
     }
 
+    /// Marks the view as a part of its layout parent which is styled by the given pseudo-element, such as
+    /// `textbox::placeholder`.
+    pub(crate) fn pseudo_element(self, pseudo_element: PseudoElement) -> Self {
+        self.cx.style.pseudo_element_views.insert(self.entity, pseudo_element);
+        self.cx.needs_restyle(self.entity);
+        self
+    }
+
+    /// Marks the view as rendering selectable text, which is styled by `::selection` rules.
+    pub(crate) fn selectable_text(self) -> Self {
+        self.cx.style.selectable_text.insert(self.entity);
+        self.cx.needs_restyle(self.entity);
+        self
+    }
+
     /// Stop the user from tabbing out of a subtree, which is useful for modal dialogs.
     pub fn lock_focus_to_within(self) -> Self {
         self.cx.tree.set_lock_focus_within(self.entity, true);
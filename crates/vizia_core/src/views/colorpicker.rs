@@ -0,0 +1,853 @@
+use std::cell::RefCell;
+
+use skia_safe::gradient_shader::GradientShaderColors;
+
+use crate::prelude::*;
+use crate::vg;
+
+// The largest OKLCH chroma shown by the color area, which covers the sRGB gamut.
+const MAX_CHROMA: f32 = 0.37;
+// The number of colors kept in the recently used list.
+const MAX_RECENT: usize = 8;
+// The number of pixels along each side of the color area image, which is scaled to the size of the color area.
+const PLANE_SIZE: i32 = 64;
+// The step applied to a color strip by the arrow keys.
+const STRIP_KEYBOARD_STEP: f32 = 0.01;
+
+/// The color model used by the color area and hue strip of a [`ColorPicker`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Data)]
+pub enum ColorModel {
+    /// Saturation and value are picked at a hue.
+    #[default]
+    Hsv,
+    /// Chroma and perceptual lightness are picked at a hue.
+    Oklch,
+}
+
+/// A text format for a color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorFormat {
+    Hex,
+    Rgb,
+    Hsl,
+    Oklch,
+}
+
+impl ColorFormat {
+    const ALL: [ColorFormat; 4] =
+        [ColorFormat::Hex, ColorFormat::Rgb, ColorFormat::Hsl, ColorFormat::Oklch];
+
+    fn label(&self) -> &'static str {
+        match self {
+            ColorFormat::Hex => "HEX",
+            ColorFormat::Rgb => "RGB",
+            ColorFormat::Hsl => "HSL",
+            ColorFormat::Oklch => "OKLCH",
+        }
+    }
+
+    /// Formats the color as a CSS color value.
+    pub fn format(&self, color: Color) -> String {
+        let rgba = color.get_rgba();
+        let alpha = if rgba.a() == 255 {
+            String::new()
+        } else {
+            format!(" / {}", (rgba.a() as f32 / 255.0 * 100.0).round() / 100.0)
+        };
+
+        match self {
+            ColorFormat::Hex if rgba.a() == 255 => {
+                format!("#{:02x}{:02x}{:02x}", rgba.r(), rgba.g(), rgba.b())
+            }
+
+            ColorFormat::Hex => {
+                format!("#{:02x}{:02x}{:02x}{:02x}", rgba.r(), rgba.g(), rgba.b(), rgba.a())
+            }
+
+            ColorFormat::Rgb => format!("rgb({} {} {}{})", rgba.r(), rgba.g(), rgba.b(), alpha),
+
+            ColorFormat::Hsl => {
+                let [hue, saturation, lightness] = ColorSpace::Hsl.from_srgb(srgb(rgba));
+                format!(
+                    "hsl({} {}% {}%{})",
+                    hue.round(),
+                    (saturation * 100.0).round(),
+                    (lightness * 100.0).round(),
+                    alpha
+                )
+            }
+
+            ColorFormat::Oklch => {
+                let [lightness, chroma, hue] = ColorSpace::Oklch.from_srgb(srgb(rgba));
+                format!(
+                    "oklch({:.1}% {:.3} {:.1}{})",
+                    lightness * 100.0,
+                    chroma,
+                    hue.rem_euclid(360.0),
+                    alpha
+                )
+            }
+        }
+    }
+}
+
+/// Parses a color from a CSS color value, or from hex digits without the leading `#`.
+fn parse_color(text: &str) -> Option<Color> {
+    let text = text.trim();
+    let color = if !text.is_empty() && text.chars().all(|c| c.is_ascii_hexdigit()) {
+        format!("#{}", text).parse()
+    } else {
+        text.parse()
+    };
+
+    color.ok().filter(|color| *color != Color::CurrentColor)
+}
+
+fn srgb(rgba: RGBA) -> [f32; 3] {
+    [rgba.r(), rgba.g(), rgba.b()].map(|component| component as f32 / 255.0)
+}
+
+fn hsv_to_srgb(hsv: [f32; 3]) -> [f32; 3] {
+    let [hue, saturation, value] = hsv;
+    ColorSpace::Hwb.to_srgb([hue, (1.0 - saturation) * value, 1.0 - value])
+}
+
+fn srgb_to_hsv(rgb: [f32; 3]) -> [f32; 3] {
+    let [hue, whiteness, blackness] = ColorSpace::Hwb.from_srgb(rgb);
+    let value = 1.0 - blackness;
+    let saturation = if value > 0.0 { 1.0 - whiteness / value } else { 0.0 };
+    [hue, saturation, value]
+}
+
+// Returns the color at a position in the color area, where x and y are in the range 0 to 1.
+fn plane_color(model: ColorModel, hue: f32, x: f32, y: f32) -> Color {
+    let rgb = match model {
+        ColorModel::Hsv => hsv_to_srgb([hue, x, y]),
+        ColorModel::Oklch => ColorSpace::Oklch.to_srgb([y, x * MAX_CHROMA, hue]),
+    };
+
+    RGBA::from_srgb(rgb, 1.0).into()
+}
+
+// Draws the color area for a color model and hue into an image.
+fn plane_image(model: ColorModel, hue: f32) -> Option<vg::Image> {
+    let mut pixels = Vec::with_capacity((PLANE_SIZE * PLANE_SIZE * 4) as usize);
+    for row in 0..PLANE_SIZE {
+        let y = 1.0 - row as f32 / (PLANE_SIZE - 1) as f32;
+        for column in 0..PLANE_SIZE {
+            let x = column as f32 / (PLANE_SIZE - 1) as f32;
+            let rgba = plane_color(model, hue, x, y).get_rgba();
+            pixels.extend([rgba.r(), rgba.g(), rgba.b(), 255]);
+        }
+    }
+
+    let info = vg::ImageInfo::new(
+        (PLANE_SIZE, PLANE_SIZE),
+        vg::ColorType::RGBA8888,
+        vg::AlphaType::Opaque,
+        None,
+    );
+    vg::images::raster_from_data(&info, vg::Data::new_copy(&pixels), PLANE_SIZE as usize * 4)
+}
+
+/// Events for modifying a [`ColorPicker`].
+pub enum ColorPickerEvent {
+    /// Sets the color of the picker and adds it to the recently used colors.
+    SetColor(Color),
+    /// Sets the color model used by the color area and hue strip.
+    SetModel(ColorModel),
+}
+
+enum ColorPickerInternalEvent {
+    SetArea(f32, f32),
+    SetHue(f32),
+    SetAlpha(f32),
+    AddRecent,
+}
+
+/// A view for picking a color, with a color area, hue and alpha strips, text inputs for several color formats,
+/// swatches, and a list of recently used colors.
+///
+/// # Example
+/// ```ignore
+/// ColorPicker::new(cx, AppData::color)
+///     .model(ColorModel::Oklch)
+///     .on_change(|cx, color| cx.emit(AppEvent::SetColor(color)));
+/// ```
+#[derive(Lens)]
+pub struct ColorPicker {
+    color: Color,
+    // The hue in degrees, saturation and value of the color, kept so that the hue is not lost for grays.
+    hsv: [f32; 3],
+    // The lightness, chroma and hue in degrees of the color, kept so that the hue is not lost for grays.
+    oklch: [f32; 3],
+    alpha: f32,
+    model: ColorModel,
+    swatches: Vec<Color>,
+    recent: Vec<Color>,
+
+    #[lens(ignore)]
+    on_change: Option<Box<dyn Fn(&mut EventContext, Color)>>,
+}
+
+impl ColorPicker {
+    /// Creates a new color picker bound to the given color.
+    pub fn new<L: Lens<Target = Color>>(cx: &mut Context, lens: L) -> Handle<Self> {
+        Self {
+            color: Color::black(),
+            hsv: [0.0; 3],
+            oklch: [0.0; 3],
+            alpha: 1.0,
+            model: ColorModel::Hsv,
+            swatches: vec![
+                Color::black(),
+                Color::gray(),
+                Color::white(),
+                Color::red(),
+                Color::orange(),
+                Color::yellow(),
+                Color::lime(),
+                Color::aqua(),
+                Color::blue(),
+                Color::fuchsia(),
+            ],
+            recent: Vec::new(),
+            on_change: None,
+        }
+        .build(cx, |cx| {
+            ZStack::new(cx, |cx| {
+                ColorPlane::new(cx);
+                XYPad::new(cx, ColorPicker::root.map(ColorPicker::area_position))
+                    .on_change(|cx, x, y| cx.emit(ColorPickerInternalEvent::SetArea(x, y)))
+                    .on_mouse_up(|cx, _| cx.emit(ColorPickerInternalEvent::AddRecent))
+                    .size(Stretch(1.0))
+                    .border_width(Pixels(0.0));
+            })
+            .class("color-area");
+
+            HStack::new(cx, |cx| {
+                Element::new(cx).class("preview").background_color(ColorPicker::color);
+
+                VStack::new(cx, |cx| {
+                    ColorStrip::new(
+                        cx,
+                        ColorPicker::root.map(|picker| picker.hue() / 360.0),
+                        ColorPicker::root.map(ColorPicker::hue_stops),
+                        false,
+                        |cx, hue| cx.emit(ColorPickerInternalEvent::SetHue(hue)),
+                    )
+                    .class("hue")
+                    .name("Hue");
+
+                    ColorStrip::new(
+                        cx,
+                        ColorPicker::alpha,
+                        ColorPicker::root.map(ColorPicker::alpha_stops),
+                        true,
+                        |cx, alpha| cx.emit(ColorPickerInternalEvent::SetAlpha(alpha)),
+                    )
+                    .class("alpha")
+                    .name("Alpha");
+                })
+                .class("strips");
+            })
+            .class("color-controls");
+
+            VStack::new(cx, |cx| {
+                for format in ColorFormat::ALL {
+                    HStack::new(cx, move |cx| {
+                        Label::new(cx, format.label()).class("format");
+                        Textbox::new(
+                            cx,
+                            ColorPicker::color.map(move |color| format.format(*color)),
+                        )
+                        .validate(|text| parse_color(text).is_some())
+                        .on_submit(|cx, text, _| {
+                            if let Some(color) = parse_color(&text) {
+                                cx.emit(ColorPickerEvent::SetColor(color));
+                            }
+                        })
+                        .width(Stretch(1.0));
+                    })
+                    .class("color-input");
+                }
+            })
+            .class("color-inputs");
+
+            Binding::new(cx, ColorPicker::swatches, |cx, swatches| {
+                HStack::new(cx, |cx| {
+                    for color in swatches.get(cx) {
+                        swatch(cx, color);
+                    }
+                })
+                .class("swatches");
+            });
+
+            Binding::new(cx, ColorPicker::recent, |cx, recent| {
+                let recent = recent.get(cx);
+                if !recent.is_empty() {
+                    HStack::new(cx, |cx| {
+                        for color in recent {
+                            swatch(cx, color);
+                        }
+                    })
+                    .class("swatches")
+                    .class("recent");
+                }
+            });
+        })
+        .bind(lens, |handle, color| {
+            let color = color.get(&handle);
+            handle.modify(|picker: &mut ColorPicker| picker.set_color(color));
+        })
+    }
+
+    fn set_color(&mut self, color: Color) {
+        if color == self.color {
+            return;
+        }
+
+        let rgba = color.get_rgba();
+        self.color = Color::RGBA(rgba);
+        self.alpha = rgba.a() as f32 / 255.0;
+        self.sync_hsv(srgb(rgba));
+        self.sync_oklch(srgb(rgba));
+    }
+
+    fn sync_hsv(&mut self, rgb: [f32; 3]) {
+        let [hue, saturation, value] = srgb_to_hsv(rgb);
+        // The hue of a gray and the saturation of black are undefined, so the previous values are kept.
+        if saturation > 0.0 && value > 0.0 {
+            self.hsv[0] = hue;
+        }
+        if value > 0.0 {
+            self.hsv[1] = saturation;
+        }
+        self.hsv[2] = value;
+    }
+
+    fn sync_oklch(&mut self, rgb: [f32; 3]) {
+        let [lightness, chroma, hue] = ColorSpace::Oklch.from_srgb(rgb);
+        if chroma > 1e-3 {
+            self.oklch[2] = hue.rem_euclid(360.0);
+        }
+        self.oklch[0] = lightness.clamp(0.0, 1.0);
+        self.oklch[1] = chroma;
+    }
+
+    // Updates the color from the components of the current color model.
+    fn apply(&mut self) {
+        match self.model {
+            ColorModel::Hsv => {
+                self.color = RGBA::from_srgb(hsv_to_srgb(self.hsv), self.alpha).into();
+                self.sync_oklch(srgb(self.color.get_rgba()));
+            }
+
+            ColorModel::Oklch => {
+                let rgb = ColorSpace::Oklch.to_srgb(self.oklch);
+                self.color = RGBA::from_srgb(rgb, self.alpha).into();
+                self.sync_hsv(srgb(self.color.get_rgba()));
+            }
+        }
+    }
+
+    fn hue(&self) -> f32 {
+        match self.model {
+            ColorModel::Hsv => self.hsv[0],
+            ColorModel::Oklch => self.oklch[2],
+        }
+    }
+
+    fn area_position(&self) -> (f32, f32) {
+        match self.model {
+            ColorModel::Hsv => (self.hsv[1], self.hsv[2]),
+            ColorModel::Oklch => ((self.oklch[1] / MAX_CHROMA).min(1.0), self.oklch[0]),
+        }
+    }
+
+    fn hue_stops(&self) -> Vec<Color> {
+        let (x, y) = match self.model {
+            ColorModel::Hsv => (1.0, 1.0),
+            ColorModel::Oklch => self.area_position(),
+        };
+
+        (0..=12).map(|step| plane_color(self.model, step as f32 * 30.0, x, y)).collect()
+    }
+
+    fn alpha_stops(&self) -> Vec<Color> {
+        let rgba = self.color.get_rgba();
+        vec![Color::rgba(rgba.r(), rgba.g(), rgba.b(), 0), Color::rgb(rgba.r(), rgba.g(), rgba.b())]
+    }
+
+    fn add_recent(&mut self) {
+        self.recent.retain(|color| *color != self.color);
+        self.recent.insert(0, self.color);
+        self.recent.truncate(MAX_RECENT);
+    }
+
+    fn changed(&self, cx: &mut EventContext) {
+        if let Some(on_change) = &self.on_change {
+            on_change(cx, self.color);
+        }
+    }
+}
+
+impl View for ColorPicker {
+    fn element(&self) -> Option<&'static str> {
+        Some("colorpicker")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|color_picker_event, meta| {
+            match color_picker_event {
+                ColorPickerEvent::SetColor(color) => {
+                    self.set_color(*color);
+                    self.add_recent();
+                    self.changed(cx);
+                }
+
+                ColorPickerEvent::SetModel(model) => {
+                    self.model = *model;
+                }
+            }
+
+            meta.consume();
+        });
+
+        event.map(|color_picker_event, meta| {
+            match color_picker_event {
+                ColorPickerInternalEvent::SetArea(x, y) => {
+                    match self.model {
+                        ColorModel::Hsv => {
+                            self.hsv[1] = *x;
+                            self.hsv[2] = *y;
+                        }
+
+                        ColorModel::Oklch => {
+                            self.oklch[1] = *x * MAX_CHROMA;
+                            self.oklch[0] = *y;
+                        }
+                    }
+
+                    self.apply();
+                    self.changed(cx);
+                }
+
+                ColorPickerInternalEvent::SetHue(hue) => {
+                    match self.model {
+                        ColorModel::Hsv => self.hsv[0] = *hue * 360.0,
+                        ColorModel::Oklch => self.oklch[2] = *hue * 360.0,
+                    }
+
+                    self.apply();
+                    self.changed(cx);
+                }
+
+                ColorPickerInternalEvent::SetAlpha(alpha) => {
+                    self.alpha = *alpha;
+                    self.color = RGBA::from_srgb(srgb(self.color.get_rgba()), self.alpha).into();
+                    self.changed(cx);
+                }
+
+                ColorPickerInternalEvent::AddRecent => self.add_recent(),
+            }
+
+            meta.consume();
+        });
+    }
+}
+
+impl Handle<'_, ColorPicker> {
+    /// Sets the color model used by the color area and hue strip.
+    pub fn model(self, model: impl Res<ColorModel>) -> Self {
+        self.bind(model, |handle, model| {
+            let model = model.get(&handle);
+            handle.modify(|picker: &mut ColorPicker| picker.model = model);
+        })
+    }
+
+    /// Sets the swatches shown below the text inputs.
+    pub fn swatches(self, swatches: impl IntoIterator<Item = Color>) -> Self {
+        let swatches = swatches.into_iter().collect();
+        self.modify(|picker: &mut ColorPicker| picker.swatches = swatches)
+    }
+
+    /// Sets the callback triggered when the color is changed by the user.
+    pub fn on_change<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, Color),
+    {
+        self.modify(|picker: &mut ColorPicker| picker.on_change = Some(Box::new(callback)))
+    }
+}
+
+fn swatch(cx: &mut Context, color: Color) {
+    Element::new(cx)
+        .class("swatch")
+        .background_color(color)
+        .navigable(true)
+        .role(Role::Button)
+        .name(ColorFormat::Hex.format(color))
+        .on_press(move |cx| cx.emit(ColorPickerEvent::SetColor(color)));
+}
+
+// The color area of a color picker, drawn as an image of the current color model at the current hue.
+struct ColorPlane {
+    model: ColorModel,
+    hue: f32,
+    // The image is kept with the model and hue it was drawn for, and is only redrawn when either of them changes.
+    image: RefCell<Option<(ColorModel, f32, vg::Image)>>,
+}
+
+impl ColorPlane {
+    fn new(cx: &mut Context) -> Handle<Self> {
+        Self { model: ColorModel::Hsv, hue: 0.0, image: RefCell::new(None) }
+            .build(cx, |_| {})
+            .bind(ColorPicker::root.map(|picker| (picker.model, picker.hue())), |handle, plane| {
+                let (model, hue) = plane.get(&handle);
+                handle
+                    .modify(|plane: &mut ColorPlane| {
+                        plane.model = model;
+                        plane.hue = hue;
+                    })
+                    .needs_redraw();
+            })
+            .hoverable(false)
+    }
+}
+
+impl View for ColorPlane {
+    fn element(&self) -> Option<&'static str> {
+        Some("color-plane")
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &Canvas) {
+        let bounds = cx.bounds();
+        if bounds.w <= 0.0 || bounds.h <= 0.0 {
+            return;
+        }
+
+        let mut image = self.image.borrow_mut();
+        if !matches!(&*image, Some((model, hue, _)) if *model == self.model && *hue == self.hue) {
+            *image = plane_image(self.model, self.hue).map(|image| (self.model, self.hue, image));
+        }
+
+        let Some((_, _, image)) = &*image else {
+            return;
+        };
+
+        let matrix = vg::Matrix::rect_to_rect(
+            vg::Rect::new(0.0, 0.0, PLANE_SIZE as f32, PLANE_SIZE as f32),
+            vg::Rect::new(bounds.left(), bounds.top(), bounds.right(), bounds.bottom()),
+            None,
+        );
+
+        let mut paint = vg::Paint::default();
+        paint.set_shader(image.to_shader(
+            (vg::TileMode::Clamp, vg::TileMode::Clamp),
+            vg::SamplingOptions::new(vg::FilterMode::Linear, vg::MipmapMode::None),
+            &matrix,
+        ));
+
+        canvas.draw_rect(vg::Rect::from_xywh(bounds.x, bounds.y, bounds.w, bounds.h), &paint);
+    }
+}
+
+// A strip for picking a value in the range 0 to 1, such as the hue or alpha of a color picker.
+struct ColorStrip {
+    value: f32,
+    stops: Vec<Color>,
+    checkered: bool,
+    is_dragging: bool,
+    on_change: Box<dyn Fn(&mut EventContext, f32)>,
+}
+
+impl ColorStrip {
+    fn new(
+        cx: &mut Context,
+        value: impl Lens<Target = f32>,
+        stops: impl Lens<Target = Vec<Color>>,
+        checkered: bool,
+        on_change: impl 'static + Fn(&mut EventContext, f32),
+    ) -> Handle<Self> {
+        Self {
+            value: 0.0,
+            stops: Vec::new(),
+            checkered,
+            is_dragging: false,
+            on_change: Box::new(on_change),
+        }
+        .build(cx, |cx| {
+            Element::new(cx)
+                .class("thumb")
+                .position_type(PositionType::Absolute)
+                .left(value.map(|value| Percentage(*value * 100.0)))
+                .translate(Translate::new(
+                    Length::Value(LengthValue::Px(-3.0)),
+                    Length::Value(LengthValue::Px(0.0)),
+                ))
+                .hoverable(false);
+        })
+        .bind(value, |handle, value| {
+            let value = value.get(&handle);
+            handle.modify(|strip: &mut ColorStrip| strip.value = value);
+        })
+        .bind(stops, |handle, stops| {
+            let stops = stops.get(&handle);
+            handle.modify(|strip: &mut ColorStrip| strip.stops = stops).needs_redraw();
+        })
+        .navigable(true)
+        .role(Role::Slider)
+        .numeric_value(value.map(|value| *value as f64))
+    }
+
+    fn set_value_from_cursor(&self, cx: &mut EventContext, x: f32) {
+        let bounds = cx.bounds();
+        if bounds.w > 0.0 {
+            (self.on_change)(cx, ((x - bounds.x) / bounds.w).clamp(0.0, 1.0));
+        }
+    }
+}
+
+impl View for ColorStrip {
+    fn element(&self) -> Option<&'static str> {
+        Some("color-strip")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(MouseButton::Left) => {
+                if cx.is_disabled() {
+                    return;
+                }
+
+                self.is_dragging = true;
+                cx.capture();
+                cx.set_active(true);
+                self.set_value_from_cursor(cx, cx.mouse.cursor_x);
+                meta.consume();
+            }
+
+            WindowEvent::MouseMove(x, _) => {
+                if self.is_dragging {
+                    self.set_value_from_cursor(cx, *x);
+                }
+            }
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                if self.is_dragging {
+                    self.is_dragging = false;
+                    cx.release();
+                    cx.set_active(false);
+                    cx.emit(ColorPickerInternalEvent::AddRecent);
+                    meta.consume();
+                }
+            }
+
+            WindowEvent::KeyDown(Code::ArrowLeft, _) | WindowEvent::KeyDown(Code::ArrowDown, _) => {
+                (self.on_change)(cx, (self.value - STRIP_KEYBOARD_STEP).max(0.0));
+            }
+
+            WindowEvent::KeyDown(Code::ArrowRight, _) | WindowEvent::KeyDown(Code::ArrowUp, _) => {
+                (self.on_change)(cx, (self.value + STRIP_KEYBOARD_STEP).min(1.0));
+            }
+
+            _ => {}
+        });
+    }
+
+    fn draw(&self, cx: &mut DrawContext, canvas: &Canvas) {
+        let bounds = cx.bounds();
+        if bounds.w <= 0.0 || bounds.h <= 0.0 {
+            return;
+        }
+
+        let rect = vg::Rect::from_xywh(bounds.x, bounds.y, bounds.w, bounds.h);
+        let mut paint = vg::Paint::default();
+
+        if self.checkered {
+            canvas.save();
+            canvas.clip_rect(rect, vg::ClipOp::Intersect, true);
+
+            let size = bounds.h / 2.0;
+            let columns = (bounds.w / size).ceil() as usize;
+            for column in 0..columns {
+                for row in 0..2 {
+                    paint.set_color(if (column + row) % 2 == 0 {
+                        vg::Color::from_rgb(204, 204, 204)
+                    } else {
+                        vg::Color::WHITE
+                    });
+                    canvas.draw_rect(
+                        vg::Rect::from_xywh(
+                            bounds.x + column as f32 * size,
+                            bounds.y + row as f32 * size,
+                            size,
+                            size,
+                        ),
+                        &paint,
+                    );
+                }
+            }
+
+            canvas.restore();
+        }
+
+        if self.stops.len() >= 2 {
+            let colors = self.stops.iter().map(|color| vg::Color::from(*color)).collect::<Vec<_>>();
+            paint.set_shader(vg::Shader::linear_gradient(
+                (vg::Point::new(bounds.x, bounds.y), vg::Point::new(bounds.right(), bounds.y)),
+                GradientShaderColors::Colors(&colors),
+                None,
+                vg::TileMode::Clamp,
+                None,
+                None,
+            ));
+            canvas.draw_rect(rect, &paint);
+        }
+    }
+}
+
+/// A compact button showing a color, which opens a [`ColorPicker`] for the color in a popup when pressed.
+///
+/// # Example
+/// ```ignore
+/// ColorButton::new(cx, AppData::color)
+///     .on_change(|cx, color| cx.emit(AppEvent::SetColor(color)));
+/// ```
+#[derive(Lens)]
+pub struct ColorButton {
+    is_open: bool,
+
+    #[lens(ignore)]
+    on_change: Option<Box<dyn Fn(&mut EventContext, Color)>>,
+}
+
+enum ColorButtonEvent {
+    Change(Color),
+}
+
+impl ColorButton {
+    /// Creates a compact button showing the given color, which opens a [`ColorPicker`] in a popup when pressed.
+    pub fn new<L: Lens<Target = Color>>(cx: &mut Context, lens: L) -> Handle<Self> {
+        Self { is_open: false, on_change: None }
+            .build(cx, |cx| {
+                Button::new(cx, |cx| {
+                    Element::new(cx).class("swatch").background_color(lens).hoverable(false)
+                })
+                .on_press(|cx| cx.emit(PopupEvent::Switch))
+                .name(lens.map(|color| ColorFormat::Hex.format(*color)));
+
+                Binding::new(cx, ColorButton::is_open, move |cx, is_open| {
+                    if is_open.get(cx) {
+                        Popup::new(cx, |cx| {
+                            ColorPicker::new(cx, lens).on_change(|cx, color| {
+                                cx.emit(ColorButtonEvent::Change(color));
+                            });
+                        })
+                        .arrow_size(Pixels(4.0))
+                        .on_blur(|cx| cx.emit(PopupEvent::Close));
+                    }
+                });
+            })
+            .navigable(false)
+    }
+}
+
+impl View for ColorButton {
+    fn element(&self) -> Option<&'static str> {
+        Some("colorbutton")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|color_button_event, meta| match color_button_event {
+            ColorButtonEvent::Change(color) => {
+                if let Some(on_change) = &self.on_change {
+                    on_change(cx, *color);
+                }
+                meta.consume();
+            }
+        });
+
+        event.map(|popup_event, meta| match popup_event {
+            PopupEvent::Open => {
+                self.is_open = true;
+                meta.consume();
+            }
+
+            PopupEvent::Close => {
+                self.is_open = false;
+                meta.consume();
+            }
+
+            PopupEvent::Switch => {
+                self.is_open ^= true;
+                meta.consume();
+            }
+        });
+    }
+}
+
+impl Handle<'_, ColorButton> {
+    /// Sets the callback triggered when the color is changed by the user with the popup color picker.
+    pub fn on_change<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, Color),
+    {
+        self.modify(|color_button: &mut ColorButton| {
+            color_button.on_change = Some(Box::new(callback))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        let color = Color::rgb(255, 0, 0);
+        assert_eq!(ColorFormat::Hex.format(color), "#ff0000");
+        assert_eq!(ColorFormat::Rgb.format(color), "rgb(255 0 0)");
+        assert_eq!(ColorFormat::Hsl.format(color), "hsl(0 100% 50%)");
+        assert_eq!(ColorFormat::Oklch.format(color), "oklch(62.8% 0.258 29.2)");
+
+        let color = Color::rgba(0, 0, 255, 128);
+        assert_eq!(ColorFormat::Hex.format(color), "#0000ff80");
+        assert_eq!(ColorFormat::Rgb.format(color), "rgb(0 0 255 / 0.5)");
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        for color in [Color::rgb(255, 0, 0), Color::rgb(18, 52, 86), Color::rgba(0, 128, 255, 51)] {
+            for format in [ColorFormat::Hex, ColorFormat::Rgb] {
+                assert_eq!(parse_color(&format.format(color)), Some(color));
+            }
+        }
+
+        assert_eq!(parse_color("ff8000"), Some(Color::rgb(255, 128, 0)));
+        assert_eq!(parse_color("currentcolor"), None);
+        assert_eq!(parse_color("#ff00"), Some(Color::rgba(255, 255, 0, 0)));
+        assert_eq!(parse_color("not a color"), None);
+    }
+
+    #[test]
+    fn test_hsv_round_trip() {
+        for rgb in [[1.0, 0.0, 0.0], [0.2, 0.6, 0.4], [0.5, 0.5, 0.5], [0.0, 0.0, 0.0]] {
+            let result = hsv_to_srgb(srgb_to_hsv(rgb));
+            for (value, expected) in result.iter().zip(rgb.iter()) {
+                assert!((value - expected).abs() < 1e-4, "{:?} != {:?}", result, rgb);
+            }
+        }
+
+        assert_eq!(srgb_to_hsv([1.0, 0.0, 0.0]), [0.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_plane_image() {
+        for model in [ColorModel::Hsv, ColorModel::Oklch] {
+            let image = plane_image(model, 120.0).unwrap();
+            assert_eq!((image.width(), image.height()), (PLANE_SIZE, PLANE_SIZE));
+        }
+    }
+}
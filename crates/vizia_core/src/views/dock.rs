@@ -0,0 +1,896 @@
+use std::rc::Rc;
+
+use crate::prelude::*;
+
+// The size in logical pixels of a floating panel which was not undocked from a group of tabs.
+const DEFAULT_FLOATING_SIZE: (f32, f32) = (300.0, 200.0);
+// The minimum size in logical pixels a floating panel can be resized to.
+const MIN_FLOATING_SIZE: (f32, f32) = (120.0, 80.0);
+
+/// A position relative to a group of tabs in a [`DockArea`] at which a panel can be docked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Data)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DockPosition {
+    Left,
+    Right,
+    Top,
+    Bottom,
+    /// As a tab alongside the other panels of the group.
+    Center,
+}
+
+impl DockPosition {
+    const ALL: [DockPosition; 5] = [
+        DockPosition::Left,
+        DockPosition::Right,
+        DockPosition::Top,
+        DockPosition::Bottom,
+        DockPosition::Center,
+    ];
+
+    fn class(&self) -> &'static str {
+        match self {
+            DockPosition::Left => "left",
+            DockPosition::Right => "right",
+            DockPosition::Top => "top",
+            DockPosition::Bottom => "bottom",
+            DockPosition::Center => "center",
+        }
+    }
+
+    // The left, top, width and height of the drop zone as percentages of the size of the group.
+    fn zone(&self) -> (f32, f32, f32, f32) {
+        match self {
+            DockPosition::Left => (0.0, 0.0, 25.0, 100.0),
+            DockPosition::Right => (75.0, 0.0, 25.0, 100.0),
+            DockPosition::Top => (25.0, 0.0, 50.0, 25.0),
+            DockPosition::Bottom => (25.0, 75.0, 50.0, 25.0),
+            DockPosition::Center => (25.0, 25.0, 50.0, 50.0),
+        }
+    }
+}
+
+/// A node in the layout of a [`DockArea`].
+///
+/// Nodes are addressed by a path of child indices from the root, where `0` is the first and `1` the second child of
+/// a split.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DockLayout {
+    /// A group of panels, identified by name, shown as the tabs of a [`TabView`].
+    Tabs { panels: Vec<String>, selected: usize },
+    /// Two layouts divided by a [`SplitView`], where `split` is the size of the first as a fraction of the total.
+    Split { orientation: Orientation, split: f32, first: Box<DockLayout>, second: Box<DockLayout> },
+}
+
+impl Default for DockLayout {
+    fn default() -> Self {
+        DockLayout::Tabs { panels: Vec::new(), selected: 0 }
+    }
+}
+
+impl Data for DockLayout {
+    fn same(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+// The part of a layout node which requires the node to be rebuilt when it changes.
+#[derive(Clone, PartialEq, Data)]
+enum DockShape {
+    Split(Orientation),
+    Tabs(Vec<String>),
+}
+
+impl DockLayout {
+    /// Creates a group of tabs for the given panels with the first panel selected.
+    pub fn tabs<S: Into<String>>(panels: impl IntoIterator<Item = S>) -> Self {
+        DockLayout::Tabs { panels: panels.into_iter().map(Into::into).collect(), selected: 0 }
+    }
+
+    /// Creates a split between two layouts.
+    pub fn split(orientation: Orientation, split: f32, first: Self, second: Self) -> Self {
+        DockLayout::Split { orientation, split, first: Box::new(first), second: Box::new(second) }
+    }
+
+    /// Returns the node at the given path.
+    pub fn at(&self, path: &[usize]) -> Option<&DockLayout> {
+        match (path.split_first(), self) {
+            (None, _) => Some(self),
+            (Some((0, rest)), DockLayout::Split { first, .. }) => first.at(rest),
+            (Some((1, rest)), DockLayout::Split { second, .. }) => second.at(rest),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the node at the given path.
+    pub fn at_mut(&mut self, path: &[usize]) -> Option<&mut DockLayout> {
+        match (path.split_first(), self) {
+            (None, layout) => Some(layout),
+            (Some((0, rest)), DockLayout::Split { first, .. }) => first.at_mut(rest),
+            (Some((1, rest)), DockLayout::Split { second, .. }) => second.at_mut(rest),
+            _ => None,
+        }
+    }
+
+    /// Returns the names of the docked panels in layout order.
+    pub fn panels(&self) -> Vec<&str> {
+        match self {
+            DockLayout::Tabs { panels, .. } => panels.iter().map(String::as_str).collect(),
+            DockLayout::Split { first, second, .. } => {
+                let mut panels = first.panels();
+                panels.extend(second.panels());
+                panels
+            }
+        }
+    }
+
+    fn shape(&self) -> DockShape {
+        match self {
+            DockLayout::Tabs { panels, .. } => DockShape::Tabs(panels.clone()),
+            DockLayout::Split { orientation, .. } => DockShape::Split(*orientation),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        matches!(self, DockLayout::Tabs { panels, .. } if panels.is_empty())
+    }
+
+    // Removes a panel without simplifying the layout, so that the paths of other nodes remain valid.
+    fn remove_panel(&mut self, panel: &str) -> bool {
+        match self {
+            DockLayout::Tabs { panels, selected } => {
+                let Some(index) = panels.iter().position(|name| name == panel) else {
+                    return false;
+                };
+
+                panels.remove(index);
+                if *selected > index || *selected >= panels.len() {
+                    *selected = selected.saturating_sub(1);
+                }
+
+                true
+            }
+
+            DockLayout::Split { first, second, .. } => {
+                first.remove_panel(panel) || second.remove_panel(panel)
+            }
+        }
+    }
+
+    fn insert_panel(&mut self, panel: String, position: DockPosition) {
+        let (orientation, new_first) = match position {
+            DockPosition::Left => (Orientation::Horizontal, true),
+            DockPosition::Right => (Orientation::Horizontal, false),
+            DockPosition::Top => (Orientation::Vertical, true),
+            DockPosition::Bottom => (Orientation::Vertical, false),
+            DockPosition::Center => {
+                match self {
+                    DockLayout::Tabs { panels, selected } => {
+                        panels.push(panel);
+                        *selected = panels.len() - 1;
+                    }
+
+                    DockLayout::Split { .. } => self.insert_panel(panel, DockPosition::Right),
+                }
+
+                return;
+            }
+        };
+
+        let existing = std::mem::take(self);
+        let new = DockLayout::tabs([panel]);
+        *self = if new_first {
+            DockLayout::split(orientation, 0.5, new, existing)
+        } else {
+            DockLayout::split(orientation, 0.5, existing, new)
+        };
+    }
+
+    // Replaces splits containing an empty group of tabs with their other child.
+    fn simplify(&mut self) {
+        if let DockLayout::Split { first, second, .. } = self {
+            first.simplify();
+            second.simplify();
+
+            let replacement = if first.is_empty() {
+                Some(std::mem::take(second.as_mut()))
+            } else if second.is_empty() {
+                Some(std::mem::take(first.as_mut()))
+            } else {
+                None
+            };
+
+            if let Some(replacement) = replacement {
+                *self = replacement;
+            }
+        }
+    }
+}
+
+/// A panel which has been undocked from the layout of a [`DockArea`].
+#[derive(Debug, Clone, PartialEq, Data)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FloatingPanel {
+    pub panel: String,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+/// The state of a [`DockArea`], made up of the docked layout and any floating panels.
+///
+/// With the `serde` feature enabled the state can be serialized to save a layout and deserialized to restore it.
+#[derive(Debug, Default, Clone, PartialEq, Data)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DockState {
+    pub root: DockLayout,
+    pub floating: Vec<FloatingPanel>,
+}
+
+impl DockState {
+    /// Creates a new dock state with the given layout and no floating panels.
+    pub fn new(root: DockLayout) -> Self {
+        Self { root, floating: Vec::new() }
+    }
+
+    /// Returns true if the panel with the given name is floating.
+    pub fn is_floating(&self, panel: &str) -> bool {
+        self.floating.iter().any(|floating| floating.panel == panel)
+    }
+
+    /// Docks a panel at the given position relative to the group of tabs at the given path.
+    ///
+    /// If the path does not refer to a group of tabs the panel is docked to the right of the layout.
+    pub fn move_panel(&mut self, panel: &str, target: &[usize], position: DockPosition) {
+        self.detach(panel);
+
+        match self.root.at_mut(target) {
+            Some(layout) if matches!(layout, DockLayout::Tabs { .. }) => {
+                layout.insert_panel(panel.to_owned(), position)
+            }
+            _ => self.root.insert_panel(panel.to_owned(), DockPosition::Right),
+        }
+
+        self.root.simplify();
+    }
+
+    /// Undocks a panel so that it floats above the layout with the given position and size.
+    pub fn float_panel(&mut self, panel: &str, x: f32, y: f32, width: f32, height: f32) {
+        self.detach(panel);
+        self.root.simplify();
+        self.floating.push(FloatingPanel { panel: panel.to_owned(), x, y, width, height });
+    }
+
+    /// Sets the position and size of a floating panel.
+    pub fn set_floating(&mut self, floating: FloatingPanel) {
+        if let Some(current) = self.floating.iter_mut().find(|f| f.panel == floating.panel) {
+            *current = floating;
+        }
+    }
+
+    fn detach(&mut self, panel: &str) {
+        if let Some(index) = self.floating.iter().position(|floating| floating.panel == panel) {
+            self.floating.remove(index);
+        } else {
+            self.root.remove_panel(panel);
+        }
+    }
+}
+
+/// Events for modifying the layout of a [`DockArea`].
+pub enum DockEvent {
+    /// Docks a panel at a position relative to the group of tabs at the given path.
+    MovePanel(String, Vec<usize>, DockPosition),
+    /// Undocks a panel so that it floats, or docks it to the right of the layout if it is already floating.
+    ToggleFloating(String),
+    /// Sets the position and size of a floating panel.
+    SetFloating(FloatingPanel),
+    /// Selects a tab of the group at the given path.
+    SelectTab(Vec<usize>, usize),
+    /// Sets the split of the split at the given path.
+    SetSplit(Vec<usize>, f32),
+}
+
+enum DockAreaEvent {
+    StartDrag(String),
+    Drop(Vec<usize>, DockPosition),
+    EndDrag,
+}
+
+type PanelContent = Rc<dyn Fn(&mut Context, &str) -> TabPair>;
+
+/// A view which arranges panels in groups of tabs divided by resizable splits, where panels can be rearranged by
+/// dragging their tabs onto the edges or center of another group.
+///
+/// The layout is described by a [`DockState`], and the view is rebuilt whenever the bound state changes so that a
+/// saved layout can be restored. Double-clicking a tab undocks the panel so that it floats above the layout in a
+/// popup, which can be moved by dragging its title bar and resized by dragging its bottom right corner.
+///
+/// # Example
+/// ```ignore
+/// DockArea::new(cx, AppData::dock, |cx, panel| {
+///     let name = panel.to_owned();
+///     TabPair::new(
+///         move |cx| {
+///             Label::new(cx, &name);
+///         },
+///         |cx| {
+///             Element::new(cx).size(Stretch(1.0));
+///         },
+///     )
+/// })
+/// .on_change(|cx, state| cx.emit(AppEvent::SetDock(state.clone())));
+/// ```
+#[derive(Lens)]
+pub struct DockArea {
+    state: DockState,
+    dragging: Option<String>,
+
+    #[lens(ignore)]
+    on_change: Option<Box<dyn Fn(&mut EventContext, &DockState)>>,
+}
+
+impl DockArea {
+    /// Creates a new dock area with the given state and a closure which builds the tab header and content of a
+    /// panel given its name.
+    pub fn new<L, F>(cx: &mut Context, state: L, panel: F) -> Handle<Self>
+    where
+        L: Lens<Target = DockState>,
+        F: 'static + Fn(&mut Context, &str) -> TabPair,
+    {
+        let panel: PanelContent = Rc::new(panel);
+
+        Self { state: state.get(cx), dragging: None, on_change: None }
+            .build(cx, move |cx| {
+                build_layout(cx, Vec::new(), panel.clone());
+
+                Binding::new(
+                    cx,
+                    DockArea::state.map(|state| state.floating.clone()),
+                    move |cx, floating| {
+                        for floating in floating.get(cx) {
+                            FloatingDockPanel::new(cx, floating, &*panel);
+                        }
+                    },
+                );
+            })
+            .bind(state, |handle, state| {
+                let state = state.get(&handle);
+                handle.modify(|dock_area: &mut DockArea| dock_area.state = state);
+            })
+    }
+
+    fn changed(&self, cx: &mut EventContext) {
+        if let Some(on_change) = &self.on_change {
+            on_change(cx, &self.state);
+        }
+    }
+}
+
+impl View for DockArea {
+    fn element(&self) -> Option<&'static str> {
+        Some("dockarea")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|dock_area_event, meta| match dock_area_event {
+            DockAreaEvent::StartDrag(panel) => {
+                self.dragging = Some(panel.clone());
+                meta.consume();
+            }
+
+            DockAreaEvent::Drop(target, position) => {
+                if let Some(panel) = self.dragging.take() {
+                    self.state.move_panel(&panel, target, *position);
+                    self.changed(cx);
+                }
+                meta.consume();
+            }
+
+            DockAreaEvent::EndDrag => {
+                self.dragging = None;
+                meta.consume();
+            }
+        });
+
+        event.take(|dock_event, meta| match dock_event {
+            DockEvent::MovePanel(panel, target, position) => {
+                self.state.move_panel(&panel, &target, position);
+                self.changed(cx);
+            }
+
+            DockEvent::ToggleFloating(panel) => {
+                if self.state.is_floating(&panel) {
+                    self.state.move_panel(&panel, &[], DockPosition::Right);
+                } else {
+                    let bounds = cx.bounds();
+                    let scale_factor = cx.scale_factor();
+                    let x = (cx.mouse.cursor_x - bounds.x) / scale_factor - 20.0;
+                    let y = (cx.mouse.cursor_y - bounds.y) / scale_factor - 10.0;
+
+                    // The floating panel takes the size of the group of tabs it was undocked from.
+                    let (width, height) = meta
+                        .origin
+                        .parent_iter(cx.tree)
+                        .find(|entity| {
+                            cx.views
+                                .get(&entity)
+                                .and_then(|view| view.downcast_ref::<DockGroup>())
+                                .is_some()
+                        })
+                        .map(|group| {
+                            let bounds = cx.cache.get_bounds(group);
+                            (bounds.w / scale_factor, bounds.h / scale_factor)
+                        })
+                        .unwrap_or(DEFAULT_FLOATING_SIZE);
+
+                    self.state.float_panel(&panel, x.max(0.0), y.max(0.0), width, height);
+                }
+                self.changed(cx);
+            }
+
+            DockEvent::SetFloating(floating) => {
+                if self.state.floating.iter().any(|current| *current == floating) {
+                    return;
+                }
+
+                self.state.set_floating(floating);
+                self.changed(cx);
+            }
+
+            DockEvent::SelectTab(path, index) => {
+                if let Some(DockLayout::Tabs { selected, .. }) = self.state.root.at_mut(&path) {
+                    if *selected != index {
+                        *selected = index;
+                        self.changed(cx);
+                    }
+                }
+            }
+
+            DockEvent::SetSplit(path, value) => {
+                if let Some(DockLayout::Split { split, .. }) = self.state.root.at_mut(&path) {
+                    *split = value;
+                    self.changed(cx);
+                }
+            }
+        });
+
+        event.map(|window_event, _| match window_event {
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                if self.dragging.is_some() {
+                    // Discard the drop data if the panel was not dropped onto a drop zone.
+                    cx.drop_data.take();
+                    // Emitted after any drop event from a drop zone so that the drop is handled first.
+                    cx.emit(DockAreaEvent::EndDrag);
+                }
+            }
+
+            _ => {}
+        });
+    }
+}
+
+impl Handle<'_, DockArea> {
+    /// Sets the callback triggered when the layout is changed by the user, such as by moving a panel or resizing a
+    /// split.
+    pub fn on_change<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, &DockState),
+    {
+        self.modify(|dock_area: &mut DockArea| dock_area.on_change = Some(Box::new(callback)))
+    }
+}
+
+// Builds the layout node at the given path, rebuilding it only when its shape changes.
+fn build_layout(cx: &mut Context, path: Vec<usize>, panel: PanelContent) {
+    let shape = DockArea::state.map({
+        let path = path.clone();
+        move |state| state.root.at(&path).map(DockLayout::shape)
+    });
+
+    Binding::new(cx, shape, move |cx, shape| match shape.get(cx) {
+        Some(DockShape::Split(orientation)) => {
+            let split = DockArea::state.map({
+                let path = path.clone();
+                move |state| match state.root.at(&path) {
+                    Some(DockLayout::Split { split, .. }) => *split,
+                    _ => 0.5,
+                }
+            });
+
+            let first = [path.as_slice(), &[0]].concat();
+            let second = [path.as_slice(), &[1]].concat();
+            let path = path.clone();
+
+            SplitView::new(
+                cx,
+                orientation,
+                |cx| build_layout(cx, first, panel.clone()),
+                |cx| build_layout(cx, second, panel.clone()),
+            )
+            .split(split)
+            .min_sizes(80.0, 80.0)
+            .on_change(move |cx, split| cx.emit(DockEvent::SetSplit(path.clone(), split)));
+        }
+
+        Some(DockShape::Tabs(_)) => {
+            DockGroup::new(cx, path.clone(), panel.clone());
+        }
+
+        None => {}
+    });
+}
+
+struct DockGroup;
+
+impl DockGroup {
+    fn new(cx: &mut Context, path: Vec<usize>, panel: PanelContent) -> Handle<Self> {
+        Self {}.build(cx, move |cx| {
+            let panels = DockArea::state.map({
+                let path = path.clone();
+                move |state| match state.root.at(&path) {
+                    Some(DockLayout::Tabs { panels, .. }) => panels.clone(),
+                    _ => Vec::new(),
+                }
+            });
+
+            let selected = DockArea::state.map({
+                let path = path.clone();
+                move |state| match state.root.at(&path) {
+                    Some(DockLayout::Tabs { selected, .. }) => *selected,
+                    _ => 0,
+                }
+            });
+
+            TabView::new(cx, panels, move |cx, item| {
+                let name = item.get(cx);
+                let TabPair { header, content } = (panel)(cx, &name);
+                TabPair::new(
+                    move |cx| {
+                        DockTab::new(cx, name.clone(), &*header);
+                    },
+                    content,
+                )
+            })
+            .with_selected(selected)
+            .on_select({
+                let path = path.clone();
+                move |cx, index| cx.emit(DockEvent::SelectTab(path.clone(), index))
+            });
+
+            // Drop zones are shown over every group while a panel is dragged.
+            Binding::new(cx, DockArea::dragging.map(Option::is_some), move |cx, dragging| {
+                if dragging.get(cx) {
+                    let path = path.clone();
+                    ZStack::new(cx, move |cx| {
+                        for position in DockPosition::ALL {
+                            let path = path.clone();
+                            let (left, top, width, height) = position.zone();
+                            Element::new(cx)
+                                .class("dock-zone")
+                                .class(position.class())
+                                .position_type(PositionType::Absolute)
+                                .left(Percentage(left))
+                                .top(Percentage(top))
+                                .width(Percentage(width))
+                                .height(Percentage(height))
+                                .on_drop(move |cx, _| {
+                                    cx.emit(DockAreaEvent::Drop(path.clone(), position))
+                                });
+                        }
+                    })
+                    .class("dock-zones")
+                    .position_type(PositionType::Absolute)
+                    .size(Stretch(1.0));
+                }
+            });
+        })
+    }
+}
+
+impl View for DockGroup {
+    fn element(&self) -> Option<&'static str> {
+        Some("dockgroup")
+    }
+}
+
+struct DockTab;
+
+impl DockTab {
+    fn new(cx: &mut Context, panel: String, content: &dyn Fn(&mut Context)) -> Handle<Self> {
+        Self {}
+            .build(cx, |cx| (content)(cx))
+            .on_drag({
+                let panel = panel.clone();
+                move |cx| {
+                    cx.emit(DockAreaEvent::StartDrag(panel.clone()));
+                    cx.set_drop_data(cx.current());
+                }
+            })
+            .on_double_click(move |cx, _| cx.emit(DockEvent::ToggleFloating(panel.clone())))
+    }
+}
+
+impl View for DockTab {
+    fn element(&self) -> Option<&'static str> {
+        Some("docktab")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FloatingDragKind {
+    Move,
+    Resize,
+}
+
+enum FloatingPanelEvent {
+    StartDrag(FloatingDragKind),
+}
+
+// A part of a floating panel which moves or resizes the panel when dragged.
+struct FloatingPanelHandle {
+    kind: FloatingDragKind,
+}
+
+impl FloatingPanelHandle {
+    fn new(
+        cx: &mut Context,
+        kind: FloatingDragKind,
+        content: impl FnOnce(&mut Context),
+    ) -> Handle<Self> {
+        Self { kind }.build(cx, content)
+    }
+}
+
+impl View for FloatingPanelHandle {
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            // Pressing on a child of the handle, such as the tab in the title bar, doesn't start a drag.
+            WindowEvent::MouseDown(MouseButton::Left) if meta.target == cx.current() => {
+                cx.emit(FloatingPanelEvent::StartDrag(self.kind));
+            }
+
+            _ => {}
+        });
+    }
+}
+
+struct FloatingDrag {
+    kind: FloatingDragKind,
+    start_x: f32,
+    start_y: f32,
+    // The position and size of the panel when the drag started.
+    start: FloatingPanel,
+}
+
+// A popup containing a panel which has been undocked from the layout.
+struct FloatingDockPanel {
+    floating: FloatingPanel,
+    drag: Option<FloatingDrag>,
+}
+
+impl FloatingDockPanel {
+    fn new(
+        cx: &mut Context,
+        floating: FloatingPanel,
+        panel: &dyn Fn(&mut Context, &str) -> TabPair,
+    ) -> Handle<Self> {
+        let TabPair { header, content } = (panel)(cx, &floating.panel);
+        let name = floating.panel.clone();
+
+        Self { floating: floating.clone(), drag: None }
+            .build(cx, move |cx| {
+                Popup::new(cx, move |cx| {
+                    FloatingPanelHandle::new(cx, FloatingDragKind::Move, move |cx| {
+                        DockTab::new(cx, name, &*header);
+                    })
+                    .class("title");
+
+                    VStack::new(cx, |cx| (content)(cx)).class("content");
+
+                    FloatingPanelHandle::new(cx, FloatingDragKind::Resize, |_| {})
+                        .class("resize-handle")
+                        .position_type(PositionType::Absolute);
+                })
+                .placement(Placement::Over)
+                .arrow(false)
+                .should_reposition(false);
+            })
+            .position_type(PositionType::Absolute)
+            .left(Pixels(floating.x))
+            .top(Pixels(floating.y))
+            .width(Pixels(floating.width))
+            .height(Pixels(floating.height))
+    }
+}
+
+impl View for FloatingDockPanel {
+    fn element(&self) -> Option<&'static str> {
+        Some("floating-panel")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|floating_event, meta| match floating_event {
+            FloatingPanelEvent::StartDrag(kind) => {
+                self.drag = Some(FloatingDrag {
+                    kind: *kind,
+                    start_x: cx.mouse.cursor_x,
+                    start_y: cx.mouse.cursor_y,
+                    start: self.floating.clone(),
+                });
+
+                cx.capture();
+                meta.consume();
+            }
+        });
+
+        event.map(|window_event, _| match window_event {
+            WindowEvent::MouseMove(x, y) => {
+                if let Some(drag) = &self.drag {
+                    let scale_factor = cx.scale_factor();
+                    let dx = (*x - drag.start_x) / scale_factor;
+                    let dy = (*y - drag.start_y) / scale_factor;
+
+                    match drag.kind {
+                        FloatingDragKind::Move => {
+                            self.floating.x = (drag.start.x + dx).max(0.0);
+                            self.floating.y = (drag.start.y + dy).max(0.0);
+                            cx.set_left(Pixels(self.floating.x));
+                            cx.set_top(Pixels(self.floating.y));
+                        }
+
+                        FloatingDragKind::Resize => {
+                            self.floating.width = (drag.start.width + dx).max(MIN_FLOATING_SIZE.0);
+                            self.floating.height =
+                                (drag.start.height + dy).max(MIN_FLOATING_SIZE.1);
+                            cx.set_width(Pixels(self.floating.width));
+                            cx.set_height(Pixels(self.floating.height));
+                        }
+                    }
+                }
+            }
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                if self.drag.take().is_some() {
+                    cx.release();
+                    cx.emit(DockEvent::SetFloating(self.floating.clone()));
+                }
+            }
+
+            _ => {}
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state() -> DockState {
+        DockState::new(DockLayout::split(
+            Orientation::Horizontal,
+            0.25,
+            DockLayout::tabs(["files", "search"]),
+            DockLayout::tabs(["editor"]),
+        ))
+    }
+
+    #[test]
+    fn test_at() {
+        let state = state();
+        assert_eq!(state.root.at(&[1]), Some(&DockLayout::tabs(["editor"])));
+        assert_eq!(state.root.at(&[1, 0]), None);
+        assert_eq!(state.root.at(&[2]), None);
+    }
+
+    #[test]
+    fn test_move_panel_to_edge() {
+        let mut state = state();
+        state.move_panel("search", &[1], DockPosition::Bottom);
+
+        assert_eq!(
+            state.root,
+            DockLayout::split(
+                Orientation::Horizontal,
+                0.25,
+                DockLayout::tabs(["files"]),
+                DockLayout::split(
+                    Orientation::Vertical,
+                    0.5,
+                    DockLayout::tabs(["editor"]),
+                    DockLayout::tabs(["search"]),
+                ),
+            )
+        );
+    }
+
+    #[test]
+    fn test_move_last_panel_removes_group() {
+        let mut state = state();
+        state.move_panel("editor", &[0], DockPosition::Center);
+
+        assert_eq!(
+            state.root,
+            DockLayout::Tabs {
+                panels: vec!["files".to_owned(), "search".to_owned(), "editor".to_owned()],
+                selected: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn test_move_only_panel_beside_itself() {
+        let mut state = state();
+        let before = state.clone();
+        state.move_panel("editor", &[1], DockPosition::Left);
+
+        assert_eq!(state, before);
+    }
+
+    #[test]
+    fn test_remove_selected_panel() {
+        let mut layout = DockLayout::Tabs {
+            panels: vec!["a".to_owned(), "b".to_owned(), "c".to_owned()],
+            selected: 2,
+        };
+
+        layout.remove_panel("c");
+        assert_eq!(
+            layout,
+            DockLayout::Tabs { panels: vec!["a".to_owned(), "b".to_owned()], selected: 1 }
+        );
+
+        layout.remove_panel("a");
+        assert_eq!(layout, DockLayout::Tabs { panels: vec!["b".to_owned()], selected: 0 });
+    }
+
+    #[test]
+    fn test_float_and_dock() {
+        let mut state = state();
+        state.float_panel("editor", 10.0, 20.0, 300.0, 200.0);
+
+        assert!(state.is_floating("editor"));
+        assert_eq!(state.root, DockLayout::tabs(["files", "search"]));
+
+        state.move_panel("editor", &[], DockPosition::Right);
+
+        assert!(!state.is_floating("editor"));
+        assert_eq!(state.root.panels(), vec!["files", "search", "editor"]);
+        assert!(matches!(
+            state.root,
+            DockLayout::Split { orientation: Orientation::Horizontal, .. }
+        ));
+    }
+
+    #[test]
+    fn test_set_floating() {
+        let mut state = state();
+        state.float_panel("editor", 10.0, 20.0, 300.0, 200.0);
+
+        let moved = FloatingPanel {
+            panel: "editor".to_owned(),
+            x: 40.0,
+            y: 50.0,
+            width: 400.0,
+            height: 250.0,
+        };
+        state.set_floating(moved.clone());
+        assert_eq!(state.floating, vec![moved.clone()]);
+
+        // Panels which aren't floating are unaffected.
+        state.set_floating(FloatingPanel { panel: "files".to_owned(), ..moved });
+        assert!(!state.is_floating("files"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let mut state = state();
+        state.float_panel("search", 10.0, 20.0, 300.0, 200.0);
+
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(serde_json::from_str::<DockState>(&json).unwrap(), state);
+    }
+}
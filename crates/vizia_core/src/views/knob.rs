@@ -317,6 +317,17 @@ impl View for ArcTrack {
         paint.set_stroke_cap(vg::PaintCap::Round);
         paint.set_style(vg::PaintStyle::Stroke);
         paint.set_anti_alias(true);
+
+        // A background gradient, such as a conic gradient, fills the active arc in place of the font color.
+        if let Some(shader) = cx.background_images().and_then(|images| {
+            images.iter().find_map(|image| match image {
+                ImageOrGradient::Gradient(gradient) => cx.gradient_shader(gradient, bounds),
+                ImageOrGradient::Image(_) => None,
+            })
+        }) {
+            paint.set_shader(shader);
+        }
+
         canvas.draw_path(&path, &paint);
     }
 }
@@ -12,6 +12,38 @@ pub enum Selectable {
 
 impl_res_simple!(Selectable);
 
+/// Toggles the selection of the item with the given index according to the given selection mode.
+///
+/// Returns `Some(true)` if the item was selected, `Some(false)` if it was deselected, and `None` if
+/// the items are not selectable.
+pub(crate) fn toggle_selection<T: Ord>(
+    selectable: Selectable,
+    selected: &mut BTreeSet<T>,
+    index: T,
+) -> Option<bool> {
+    match selectable {
+        Selectable::Single => {
+            let was_selected = selected.contains(&index);
+            selected.clear();
+            if !was_selected {
+                selected.insert(index);
+            }
+            Some(!was_selected)
+        }
+
+        Selectable::Multi => {
+            if selected.remove(&index) {
+                Some(false)
+            } else {
+                selected.insert(index);
+                Some(true)
+            }
+        }
+
+        Selectable::None => None,
+    }
+}
+
 pub enum ListEvent {
     Select(usize),
     SelectFocused,
@@ -185,37 +217,20 @@ impl View for List {
         event.take(|list_event, _| match list_event {
             ListEvent::Select(index) => {
                 cx.focus();
-                match self.selectable {
-                    Selectable::Single => {
-                        if self.selected.contains(&index) {
-                            self.selected.clear();
-                            self.focused = None;
-                        } else {
-                            self.selected.clear();
-                            self.selected.insert(index);
-                            self.focused = Some(index);
-                            self.focus_visible = false;
-                            if let Some(on_select) = &self.on_select {
-                                on_select(cx, index);
-                            }
+                match toggle_selection(self.selectable, &mut self.selected, index) {
+                    Some(true) => {
+                        self.focused = Some(index);
+                        self.focus_visible = false;
+                        if let Some(on_select) = &self.on_select {
+                            on_select(cx, index);
                         }
                     }
 
-                    Selectable::Multi => {
-                        if self.selected.contains(&index) {
-                            self.selected.remove(&index);
-                            self.focused = None;
-                        } else {
-                            self.selected.insert(index);
-                            self.focused = Some(index);
-                            self.focus_visible = false;
-                            if let Some(on_select) = &self.on_select {
-                                on_select(cx, index);
-                            }
-                        }
+                    Some(false) => {
+                        self.focused = None;
                     }
 
-                    Selectable::None => {}
+                    None => {}
                 }
             }
 
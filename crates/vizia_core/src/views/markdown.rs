@@ -6,6 +6,7 @@ use comrak::nodes::{Ast, NodeValue};
 use comrak::{parse_document, Arena, Options};
 
 use crate::prelude::*;
+use vizia_style::PseudoElement;
 
 // A view which parses and displays markdown as rich text.
 pub struct Markdown {}
@@ -113,7 +114,7 @@ fn parse_node<'a>(
 
         NodeValue::Item(_list) => {
             HStack::new(cx, |cx| {
-                Label::new(cx, "\u{2022} ").width(Auto);
+                Label::new(cx, "\u{2022} ").width(Auto).pseudo_element(PseudoElement::Marker);
                 VStack::new(cx, |cx| {
                     for child in node.children() {
                         parse_node(cx, child, list_level + 1);
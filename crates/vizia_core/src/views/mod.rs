@@ -5,10 +5,12 @@ mod badge;
 mod button;
 mod checkbox;
 mod chip;
+mod colorpicker;
 mod combobox;
 mod datepicker;
 mod dialog;
 mod divider;
+mod dock;
 mod dropdown;
 mod element;
 mod image;
@@ -27,12 +29,16 @@ mod scrollbar;
 mod scrollview;
 mod slider;
 mod spinbox;
+mod splitview;
 mod stack;
 mod switch;
+mod table;
 mod tabview;
 mod textbox;
+mod toast;
 mod toggle_button;
 mod tooltip;
+mod treeview;
 mod virtual_list;
 mod xypad;
 
@@ -42,10 +48,12 @@ pub use badge::*;
 pub use button::{Button, ButtonGroup, ButtonModifiers, ButtonVariant};
 pub use checkbox::Checkbox;
 pub use chip::*;
+pub use colorpicker::*;
 pub use combobox::*;
 pub use datepicker::Datepicker;
 pub use dialog::*;
 pub use divider::*;
+pub use dock::*;
 pub use dropdown::Dropdown;
 pub use element::*;
 pub use image::*;
@@ -64,12 +72,16 @@ pub use scrollbar::Scrollbar;
 pub use scrollview::{ScrollEvent, ScrollView};
 pub use slider::{NamedSlider, Slider};
 pub use spinbox::{Spinbox, SpinboxEvent, SpinboxIcons};
+pub use splitview::*;
 pub use stack::{HStack, VStack, ZStack};
 pub use switch::Switch;
+pub use table::*;
 pub use tabview::*;
 pub use textbox::{TextEvent, Textbox};
+pub use toast::*;
 pub use toggle_button::ToggleButton;
 pub use tooltip::Tooltip;
+pub use treeview::*;
 pub use virtual_list::*;
 pub use xypad::XYPad;
 
@@ -77,6 +89,7 @@ use crate::prelude::Data;
 
 /// The orientation of a widget, such as a slider or scrollbar
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Data)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Orientation {
     #[default]
     Horizontal,
@@ -0,0 +1,410 @@
+use crate::prelude::*;
+
+// The distance in logical pixels the divider is moved by the arrow keys.
+const KEYBOARD_STEP: f32 = 10.0;
+
+/// A pane of a [`SplitView`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Data)]
+pub enum SplitPane {
+    First,
+    Second,
+}
+
+/// Events for modifying a [`SplitView`].
+pub enum SplitViewEvent {
+    /// Sets the size of the first pane as a fraction of the size of the split view.
+    SetSplit(f32),
+    /// Moves the divider by the given distance in logical pixels.
+    MoveDivider(f32),
+    /// Collapses the given pane to the edge of the split view, or restores it if it is already collapsed.
+    ToggleCollapsed(SplitPane),
+}
+
+enum SplitViewInternalEvent {
+    // Sets the position of the divider in physical pixels while it is dragged.
+    DragDivider(f32),
+}
+
+/// A view which divides its space between two panes with a divider which can be dragged to resize them.
+///
+/// A horizontal split view places the panes side by side, while a vertical split view places the first pane above
+/// the second. Split views can be nested within the panes of another split view.
+///
+/// # Example
+/// ```ignore
+/// SplitView::new(
+///     cx,
+///     Orientation::Horizontal,
+///     |cx| {
+///         Label::new(cx, "Sidebar");
+///     },
+///     |cx| {
+///         Label::new(cx, "Editor");
+///     },
+/// )
+/// .split(0.25)
+/// .min_sizes(100.0, 200.0)
+/// .collapsible(true);
+/// ```
+#[derive(Lens)]
+pub struct SplitView {
+    orientation: Orientation,
+    split: f32,
+    min_sizes: (f32, f32),
+    max_sizes: (f32, f32),
+    collapsible: bool,
+    collapsed: Option<SplitPane>,
+    size: f32,
+
+    #[lens(ignore)]
+    on_change: Option<Box<dyn Fn(&mut EventContext, f32)>>,
+}
+
+impl SplitView {
+    /// Creates a new split view with the given orientation and content for its two panes.
+    pub fn new(
+        cx: &mut Context,
+        orientation: Orientation,
+        first: impl FnOnce(&mut Context),
+        second: impl FnOnce(&mut Context),
+    ) -> Handle<Self> {
+        Self {
+            orientation,
+            split: 0.5,
+            min_sizes: (0.0, 0.0),
+            max_sizes: (f32::MAX, f32::MAX),
+            collapsible: false,
+            collapsed: None,
+            size: 0.0,
+            on_change: None,
+        }
+        .build(cx, |cx| {
+            VStack::new(cx, first).class("pane").bind(
+                SplitView::root.map(SplitView::first_pane_size),
+                move |handle, size| {
+                    let size = size.get(&handle);
+                    match orientation {
+                        Orientation::Horizontal => handle.width(size),
+                        Orientation::Vertical => handle.height(size),
+                    };
+                },
+            );
+
+            SplitDivider::new(cx, orientation);
+
+            VStack::new(cx, second).class("pane").bind(
+                SplitView::collapsed.map(|collapsed| match collapsed {
+                    Some(SplitPane::Second) => Pixels(0.0),
+                    _ => Stretch(1.0),
+                }),
+                move |handle, size| {
+                    let size = size.get(&handle);
+                    match orientation {
+                        Orientation::Horizontal => handle.width(size),
+                        Orientation::Vertical => handle.height(size),
+                    };
+                },
+            );
+        })
+        .layout_type(match orientation {
+            Orientation::Horizontal => LayoutType::Row,
+            Orientation::Vertical => LayoutType::Column,
+        })
+        .class(match orientation {
+            Orientation::Horizontal => "horizontal",
+            Orientation::Vertical => "vertical",
+        })
+    }
+
+    fn first_pane_size(&self) -> Units {
+        match self.collapsed {
+            Some(SplitPane::First) => Pixels(0.0),
+            Some(SplitPane::Second) => Stretch(1.0),
+            // Until the size of the split view is known the split is applied as a percentage.
+            None if self.size <= 0.0 => Percentage(self.split * 100.0),
+            None => Pixels(self.clamp_first(self.split * self.size)),
+        }
+    }
+
+    // Clamps the size of the first pane so that neither pane is smaller than its minimum or larger than its maximum.
+    fn clamp_first(&self, first: f32) -> f32 {
+        let lower = self.min_sizes.0.max(self.size - self.max_sizes.1);
+        let upper = self.max_sizes.0.min(self.size - self.min_sizes.1);
+        first.min(upper).max(lower).max(0.0)
+    }
+
+    // Resizes the first pane to the given size, collapsing a pane if the divider is dragged past half of its minimum.
+    fn resize_first(&mut self, first: f32, allow_collapse: bool) {
+        if self.size <= 0.0 {
+            return;
+        }
+
+        if allow_collapse && self.collapsible && first < self.min_sizes.0 / 2.0 {
+            self.collapsed = Some(SplitPane::First);
+        } else if allow_collapse && self.collapsible && self.size - first < self.min_sizes.1 / 2.0 {
+            self.collapsed = Some(SplitPane::Second);
+        } else {
+            self.collapsed = None;
+            self.split = self.clamp_first(first) / self.size;
+        }
+    }
+
+    fn current_first(&self) -> f32 {
+        match self.collapsed {
+            Some(SplitPane::First) => 0.0,
+            Some(SplitPane::Second) => self.size,
+            None => self.clamp_first(self.split * self.size),
+        }
+    }
+
+    fn changed(&self, cx: &mut EventContext) {
+        if let Some(on_change) = &self.on_change {
+            on_change(cx, self.split);
+        }
+    }
+}
+
+impl View for SplitView {
+    fn element(&self) -> Option<&'static str> {
+        Some("splitview")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|split_view_event, meta| {
+            match split_view_event {
+                SplitViewEvent::SetSplit(split) => {
+                    self.split = split.clamp(0.0, 1.0);
+                    self.collapsed = None;
+                }
+
+                SplitViewEvent::MoveDivider(delta) => {
+                    self.resize_first(self.current_first() + *delta, false);
+                }
+
+                SplitViewEvent::ToggleCollapsed(pane) => {
+                    if self.collapsed == Some(*pane) {
+                        self.collapsed = None;
+                    } else {
+                        self.collapsed = Some(*pane);
+                    }
+                }
+            }
+
+            self.changed(cx);
+            meta.consume();
+        });
+
+        event.map(|split_view_event, meta| match split_view_event {
+            SplitViewInternalEvent::DragDivider(position) => {
+                let bounds = cx.bounds();
+                let start = match self.orientation {
+                    Orientation::Horizontal => bounds.x,
+                    Orientation::Vertical => bounds.y,
+                };
+
+                self.resize_first((*position - start) / cx.scale_factor(), true);
+                self.changed(cx);
+                meta.consume();
+            }
+        });
+
+        event.map(|window_event, _| match window_event {
+            WindowEvent::GeometryChanged(_) => {
+                // The divider is the second child of the split view.
+                let divider = cx.nth_child(1);
+                let divider_bounds = divider.map(|divider| cx.cache.get_bounds(divider));
+                let bounds = cx.bounds();
+                let size = match self.orientation {
+                    Orientation::Horizontal => {
+                        bounds.w - divider_bounds.map(|bounds| bounds.w).unwrap_or_default()
+                    }
+                    Orientation::Vertical => {
+                        bounds.h - divider_bounds.map(|bounds| bounds.h).unwrap_or_default()
+                    }
+                };
+
+                self.size = size.max(0.0) / cx.scale_factor();
+            }
+
+            _ => {}
+        });
+    }
+}
+
+impl Handle<'_, SplitView> {
+    /// Sets the size of the first pane as a fraction of the size of the split view.
+    pub fn split(self, split: impl Res<f32>) -> Self {
+        self.bind(split, |handle, split| {
+            let split = split.get(&handle).clamp(0.0, 1.0);
+            handle.modify(|split_view: &mut SplitView| split_view.split = split);
+        })
+    }
+
+    /// Sets the minimum sizes in logical pixels of the first and second panes.
+    pub fn min_sizes(self, first: f32, second: f32) -> Self {
+        self.modify(|split_view: &mut SplitView| split_view.min_sizes = (first, second))
+    }
+
+    /// Sets the maximum sizes in logical pixels of the first and second panes.
+    pub fn max_sizes(self, first: f32, second: f32) -> Self {
+        self.modify(|split_view: &mut SplitView| split_view.max_sizes = (first, second))
+    }
+
+    /// Sets whether a pane collapses to the edge of the split view when the divider is dragged past half of its
+    /// minimum size.
+    pub fn collapsible(self, collapsible: impl Res<bool>) -> Self {
+        self.bind(collapsible, |handle, collapsible| {
+            let collapsible = collapsible.get(&handle);
+            handle.modify(|split_view: &mut SplitView| split_view.collapsible = collapsible);
+        })
+    }
+
+    /// Sets the callback triggered when the split changes, which is passed the size of the first pane as a fraction
+    /// of the size of the split view.
+    pub fn on_change<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, f32),
+    {
+        self.modify(|split_view: &mut SplitView| split_view.on_change = Some(Box::new(callback)))
+    }
+}
+
+struct SplitDivider {
+    orientation: Orientation,
+    // The offset from the start of the divider to the cursor while the divider is dragged.
+    drag_offset: Option<f32>,
+}
+
+impl SplitDivider {
+    fn new(cx: &mut Context, orientation: Orientation) -> Handle<Self> {
+        Self { orientation, drag_offset: None }
+            .build(cx, |_| {})
+            .class(match orientation {
+                Orientation::Horizontal => "horizontal",
+                Orientation::Vertical => "vertical",
+            })
+            .navigable(true)
+            .role(Role::Splitter)
+            .numeric_value(SplitView::split.map(|split| *split as f64 * 100.0))
+    }
+
+    fn cursor_position(&self, cx: &EventContext) -> f32 {
+        match self.orientation {
+            Orientation::Horizontal => cx.mouse.cursor_x,
+            Orientation::Vertical => cx.mouse.cursor_y,
+        }
+    }
+}
+
+impl View for SplitDivider {
+    fn element(&self) -> Option<&'static str> {
+        Some("splitter")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, meta| match window_event {
+            WindowEvent::MouseDown(MouseButton::Left) => {
+                let bounds = cx.bounds();
+                let start = match self.orientation {
+                    Orientation::Horizontal => bounds.x,
+                    Orientation::Vertical => bounds.y,
+                };
+
+                self.drag_offset = Some(self.cursor_position(cx) - start);
+                cx.capture();
+                cx.set_active(true);
+                meta.consume();
+            }
+
+            WindowEvent::MouseMove(_, _) => {
+                if let Some(drag_offset) = self.drag_offset {
+                    let position = self.cursor_position(cx) - drag_offset;
+                    cx.emit(SplitViewInternalEvent::DragDivider(position));
+                }
+            }
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                if self.drag_offset.take().is_some() {
+                    cx.release();
+                    cx.set_active(false);
+                    meta.consume();
+                }
+            }
+
+            WindowEvent::MouseDoubleClick(MouseButton::Left) => {
+                cx.emit(SplitViewEvent::ToggleCollapsed(SplitPane::First));
+                meta.consume();
+            }
+
+            WindowEvent::KeyDown(code, _) => match (self.orientation, code) {
+                (Orientation::Horizontal, Code::ArrowLeft)
+                | (Orientation::Vertical, Code::ArrowUp) => {
+                    cx.emit(SplitViewEvent::MoveDivider(-KEYBOARD_STEP));
+                }
+
+                (Orientation::Horizontal, Code::ArrowRight)
+                | (Orientation::Vertical, Code::ArrowDown) => {
+                    cx.emit(SplitViewEvent::MoveDivider(KEYBOARD_STEP));
+                }
+
+                (_, Code::Enter) => {
+                    cx.emit(SplitViewEvent::ToggleCollapsed(SplitPane::First));
+                }
+
+                _ => {}
+            },
+
+            _ => {}
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split_view(size: f32) -> SplitView {
+        SplitView {
+            orientation: Orientation::Horizontal,
+            split: 0.5,
+            min_sizes: (100.0, 200.0),
+            max_sizes: (400.0, f32::MAX),
+            collapsible: true,
+            collapsed: None,
+            size,
+            on_change: None,
+        }
+    }
+
+    #[test]
+    fn test_resize_clamped() {
+        let mut split_view = split_view(1000.0);
+
+        split_view.resize_first(300.0, true);
+        assert_eq!(split_view.split, 0.3);
+
+        split_view.resize_first(900.0, false);
+        assert_eq!(split_view.current_first(), 400.0);
+
+        split_view.resize_first(60.0, false);
+        assert_eq!(split_view.current_first(), 100.0);
+        assert_eq!(split_view.collapsed, None);
+    }
+
+    #[test]
+    fn test_resize_collapse() {
+        let mut split_view = split_view(1000.0);
+
+        split_view.resize_first(40.0, true);
+        assert_eq!(split_view.collapsed, Some(SplitPane::First));
+        assert_eq!(split_view.current_first(), 0.0);
+
+        split_view.max_sizes = (f32::MAX, f32::MAX);
+        split_view.resize_first(950.0, true);
+        assert_eq!(split_view.collapsed, Some(SplitPane::Second));
+
+        split_view.resize_first(500.0, true);
+        assert_eq!(split_view.collapsed, None);
+        assert_eq!(split_view.split, 0.5);
+    }
+}
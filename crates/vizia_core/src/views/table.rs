@@ -0,0 +1,857 @@
+use std::{
+    cmp::Ordering,
+    collections::BTreeSet,
+    ops::{Deref, Range},
+    rc::Rc,
+};
+
+use crate::icons::{ICON_CHEVRON_DOWN, ICON_CHEVRON_UP};
+use crate::prelude::*;
+use crate::views::list::toggle_selection;
+
+// The width in logical pixels of the area at the right edge of a column header which resizes the column.
+const RESIZE_HANDLE_WIDTH: f32 = 6.0;
+// The distance in logical pixels the cursor must move while pressed on a column header to move the column.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// The direction in which the rows of a [`Table`] are sorted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Data)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+/// Events for modifying a [`Table`].
+pub enum TableEvent {
+    /// Sorts the rows by the column with the given index, cycling between ascending, descending and unsorted.
+    Sort(usize),
+    /// Sets the width of the column with the given index, clamped to its minimum and maximum width.
+    ResizeColumn(usize, f32),
+    /// Moves the column with the given index to the given position in the header.
+    MoveColumn(usize, usize),
+    /// Toggles the selection of the row with the given index into the bound list.
+    SelectRow(usize),
+    SelectFocused,
+    FocusNext,
+    FocusPrev,
+    ClearSelection,
+}
+
+enum TableViewEvent {
+    UpdateRows,
+    Scroll(f32, f32),
+    DropColumn(usize, f32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Data)]
+struct ColumnLayout {
+    width: f32,
+    min_width: f32,
+    max_width: f32,
+    sortable: bool,
+}
+
+// The rows and columns of a table which are currently visible, as (position, row index) and (column index, x, width).
+#[derive(Debug, Clone, PartialEq, Data)]
+struct TableViewport {
+    rows: Vec<(usize, usize)>,
+    columns: Vec<(usize, f32, f32)>,
+}
+
+// Returns the indices of the rows of the list in the order given by the sort column and direction.
+type SortRows = Box<dyn Fn(&EventContext, Option<(usize, SortDirection)>) -> Vec<usize>>;
+
+/// A column of a [`Table`], created with [`TableColumns::column`].
+pub struct TableColumn<L: Lens, T> {
+    header: Rc<dyn Fn(&mut Context)>,
+    cell: Rc<dyn Fn(&mut Context, usize, Index<L, T>)>,
+    layout: ColumnLayout,
+    compare: Option<Rc<dyn Fn(&T, &T) -> Ordering>>,
+}
+
+impl<L: Lens, T: 'static> TableColumn<L, T> {
+    /// Sets the initial width of the column in logical pixels.
+    pub fn width(&mut self, width: f32) -> &mut Self {
+        self.layout.width = width;
+        self
+    }
+
+    /// Sets the minimum width the column can be resized to.
+    pub fn min_width(&mut self, min_width: f32) -> &mut Self {
+        self.layout.min_width = min_width;
+        self
+    }
+
+    /// Sets the maximum width the column can be resized to.
+    pub fn max_width(&mut self, max_width: f32) -> &mut Self {
+        self.layout.max_width = max_width;
+        self
+    }
+
+    /// Makes the column sortable by clicking its header, ordering rows with the given comparator.
+    pub fn sort_by(&mut self, compare: impl 'static + Fn(&T, &T) -> Ordering) -> &mut Self {
+        self.compare = Some(Rc::new(compare));
+        self.layout.sortable = true;
+        self
+    }
+
+    /// Makes the column sortable by clicking its header, ordering rows by the given key.
+    pub fn sort_by_key<K: Ord>(&mut self, key: impl 'static + Fn(&T) -> K) -> &mut Self {
+        self.sort_by(move |a, b| key(a).cmp(&key(b)))
+    }
+}
+
+/// The columns of a [`Table`], which are defined in the closure passed to [`Table::new`].
+pub struct TableColumns<L: Lens, T> {
+    columns: Vec<TableColumn<L, T>>,
+}
+
+impl<L: Lens, T: 'static> TableColumns<L, T> {
+    /// Adds a column with the given header content and a template for constructing its cell for each row.
+    pub fn column(
+        &mut self,
+        header: impl 'static + Fn(&mut Context),
+        cell: impl 'static + Fn(&mut Context, usize, Index<L, T>),
+    ) -> &mut TableColumn<L, T> {
+        self.columns.push(TableColumn {
+            header: Rc::new(header),
+            cell: Rc::new(cell),
+            layout: ColumnLayout {
+                width: 100.0,
+                min_width: 20.0,
+                max_width: f32::MAX,
+                sortable: false,
+            },
+            compare: None,
+        });
+
+        self.columns.last_mut().unwrap()
+    }
+}
+
+/// A view for displaying a list of rows in columns, which can be sorted, resized and reordered by interacting with
+/// their headers.
+///
+/// Only the rows and columns within the visible area of the table are built, so a table can display very large lists.
+///
+/// # Example
+/// ```ignore
+/// Table::new(cx, AppData::people, 32.0, |table| {
+///     table
+///         .column(
+///             |cx| {
+///                 Label::new(cx, "Name");
+///             },
+///             |cx, _, person| {
+///                 Label::new(cx, person.map(|person| person.name.clone()));
+///             },
+///         )
+///         .width(200.0)
+///         .sort_by_key(|person| person.name.clone());
+/// })
+/// .selectable(Selectable::Multi);
+/// ```
+#[derive(Lens)]
+pub struct Table {
+    columns: Vec<ColumnLayout>,
+    column_order: Vec<usize>,
+    sort: Option<(usize, SortDirection)>,
+    rows: Vec<usize>,
+    row_height: f32,
+    content_size: (f32, f32),
+    scroll: (f32, f32),
+    scroll_left: f32,
+    visible_rows: Range<usize>,
+    visible_columns: Range<usize>,
+    selected: BTreeSet<usize>,
+    selectable: Selectable,
+    focused: Option<usize>,
+    focus_visible: bool,
+    selection_follows_focus: bool,
+    sort_rows: SortRows,
+    on_select: Option<Box<dyn Fn(&mut EventContext, usize)>>,
+}
+
+impl Table {
+    /// Creates a new table with a binding to the given list of rows, the height of each row, and a closure which
+    /// defines the columns of the table.
+    pub fn new<L: Lens, T: 'static>(
+        cx: &mut Context,
+        list: L,
+        row_height: f32,
+        columns: impl FnOnce(&mut TableColumns<L, T>),
+    ) -> Handle<Self>
+    where
+        L::Target: Deref<Target = [T]> + Data,
+    {
+        let mut definitions = TableColumns { columns: Vec::new() };
+        (columns)(&mut definitions);
+
+        let layouts: Vec<ColumnLayout> =
+            definitions.columns.iter().map(|column| column.layout).collect();
+        let headers: Vec<_> =
+            definitions.columns.iter().map(|column| column.header.clone()).collect();
+        let cells: Vec<_> = definitions.columns.iter().map(|column| column.cell.clone()).collect();
+        let comparators: Vec<_> =
+            definitions.columns.into_iter().map(|column| column.compare).collect();
+
+        let sort_rows: SortRows = Box::new(move |cx, sort| {
+            let Some(items) = list.get_ref(cx) else {
+                return Vec::new();
+            };
+            let items: &[T] = &items;
+
+            let mut rows: Vec<usize> = (0..items.len()).collect();
+            if let Some((column, direction)) = sort {
+                if let Some(compare) = comparators.get(column).and_then(Option::as_ref) {
+                    // A stable sort keeps rows which compare equal in the order of the list.
+                    rows.sort_by(|a, b| {
+                        let ordering = compare(&items[*a], &items[*b]);
+                        match direction {
+                            SortDirection::Ascending => ordering,
+                            SortDirection::Descending => ordering.reverse(),
+                        }
+                    });
+                }
+            }
+
+            rows
+        });
+
+        Self {
+            column_order: (0..layouts.len()).collect(),
+            columns: layouts,
+            sort: None,
+            rows: Vec::new(),
+            row_height,
+            content_size: (0.0, 0.0),
+            scroll: (0.0, 0.0),
+            scroll_left: 0.0,
+            visible_rows: 0..0,
+            visible_columns: 0..0,
+            selected: BTreeSet::default(),
+            selectable: Selectable::None,
+            focused: None,
+            focus_visible: false,
+            selection_follows_focus: false,
+            sort_rows,
+            on_select: None,
+        }
+        .build(cx, move |cx| {
+            Keymap::from(vec![
+                (
+                    KeyChord::new(Modifiers::empty(), Code::ArrowDown),
+                    KeymapEntry::new("Focus Next", |cx| cx.emit(TableEvent::FocusNext)),
+                ),
+                (
+                    KeyChord::new(Modifiers::empty(), Code::ArrowUp),
+                    KeymapEntry::new("Focus Previous", |cx| cx.emit(TableEvent::FocusPrev)),
+                ),
+                (
+                    KeyChord::new(Modifiers::empty(), Code::Space),
+                    KeymapEntry::new("Select Focused", |cx| cx.emit(TableEvent::SelectFocused)),
+                ),
+                (
+                    KeyChord::new(Modifiers::empty(), Code::Enter),
+                    KeymapEntry::new("Select Focused", |cx| cx.emit(TableEvent::SelectFocused)),
+                ),
+            ])
+            .build(cx);
+
+            // Sort the rows again whenever the list changes.
+            Binding::new(cx, list, |cx, _| cx.emit(TableViewEvent::UpdateRows));
+
+            HStack::new(cx, move |cx| {
+                Binding::new(cx, Table::column_order, move |cx, column_order| {
+                    for column in column_order.get(cx) {
+                        TableHeader::new(cx, column, headers[column].clone());
+                    }
+                });
+            })
+            .class("header-row")
+            .height(Pixels(row_height))
+            .bind(Table::scroll_left, |handle, scroll_left| {
+                let scroll_left = scroll_left.get(&handle);
+                handle.left(Pixels(-scroll_left));
+            })
+            .bind(Table::content_size, |handle, content_size| {
+                let (width, _) = content_size.get(&handle);
+                handle.width(Pixels(width));
+            });
+
+            ScrollView::new(cx, move |cx| {
+                // The ScrollView contains a VStack which is sized to fit all of the rows and columns, within which
+                // only the visible rows and cells are built.
+                VStack::new(cx, move |cx| {
+                    // The rows are rebuilt whenever the length of the list changes, which removes the cells of rows
+                    // which no longer exist before their bindings are updated.
+                    Binding::new(cx, list.map(|list| list.len()), move |cx, num_rows| {
+                        let num_rows = num_rows.get(cx);
+                        let cells = cells.clone();
+                        Binding::new(cx, Table::root.map(Table::viewport), move |cx, viewport| {
+                            let viewport = viewport.get(cx);
+                            // The rows may not have been sorted again yet if the list has just shrunk.
+                            for &(position, row) in
+                                viewport.rows.iter().filter(|(_, row)| *row < num_rows)
+                            {
+                                TableRow::new(cx, row, |cx| {
+                                    for &(column, x, width) in viewport.columns.iter() {
+                                        let cell = &cells[column];
+                                        TableCell::new(cx, |cx| cell(cx, row, list.idx(row)))
+                                            .left(Pixels(x))
+                                            .width(Pixels(width));
+                                    }
+                                })
+                                .top(Pixels(position as f32 * row_height))
+                                .height(Pixels(row_height));
+                            }
+                        });
+                    });
+                })
+                .bind(Table::content_size, |handle, content_size| {
+                    let (width, height) = content_size.get(&handle);
+                    handle.width(Pixels(width)).height(Pixels(height));
+                });
+            })
+            .on_scroll(|cx, x, y| {
+                if x.is_finite() && y.is_finite() {
+                    cx.emit(TableViewEvent::Scroll(x, y));
+                }
+            });
+        })
+        .toggle_class("selectable", Table::selectable.map(|s| *s != Selectable::None))
+        .navigable(true)
+        .role(Role::Table)
+    }
+
+    fn ordered_widths(&self) -> Vec<f32> {
+        self.column_order.iter().map(|column| self.columns[*column].width).collect()
+    }
+
+    fn viewport(&self) -> TableViewport {
+        let mut x = 0.0;
+        let mut columns = Vec::new();
+        for (position, column) in self.column_order.iter().enumerate() {
+            let width = self.columns[*column].width;
+            if self.visible_columns.contains(&position) {
+                columns.push((*column, x, width));
+            }
+            x += width;
+        }
+
+        let rows = self
+            .visible_rows
+            .clone()
+            .filter_map(|position| self.rows.get(position).map(|row| (position, *row)))
+            .collect();
+
+        TableViewport { rows, columns }
+    }
+
+    fn recalc(&mut self, cx: &EventContext) {
+        // The body of the table is shorter than the table by the height of the header, so using the size of the
+        // table over-estimates the visible area rather than leaving gaps at its edges.
+        let bounds = cx.bounds();
+        let viewport_width = bounds.w / cx.scale_factor();
+        let viewport_height = bounds.h / cx.scale_factor();
+
+        let widths = self.ordered_widths();
+        let content_width: f32 = widths.iter().sum();
+        let content_height = self.rows.len() as f32 * self.row_height;
+        self.content_size = (content_width, content_height);
+
+        self.scroll_left = self.scroll.0 * (content_width - viewport_width).max(0.0);
+        let scroll_top = self.scroll.1 * (content_height - viewport_height).max(0.0);
+
+        self.visible_columns = visible_columns(&widths, self.scroll_left, viewport_width);
+        self.visible_rows =
+            visible_rows(self.rows.len(), self.row_height, scroll_top, viewport_height);
+    }
+
+    fn focus_position(&mut self, cx: &mut EventContext, position: usize) {
+        if let Some(row) = self.rows.get(position) {
+            self.focused = Some(*row);
+            self.focus_visible = true;
+
+            if self.selection_follows_focus {
+                cx.emit(TableEvent::SelectFocused);
+            }
+        }
+    }
+
+    fn focused_position(&self) -> Option<usize> {
+        self.focused.and_then(|focused| self.rows.iter().position(|row| *row == focused))
+    }
+}
+
+impl View for Table {
+    fn element(&self) -> Option<&'static str> {
+        Some("table")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.take(|table_event, _| match table_event {
+            TableEvent::Sort(column) => {
+                if matches!(self.columns.get(column), Some(layout) if layout.sortable) {
+                    self.sort = match self.sort {
+                        Some((sorted, SortDirection::Ascending)) if sorted == column => {
+                            Some((column, SortDirection::Descending))
+                        }
+                        Some((sorted, SortDirection::Descending)) if sorted == column => None,
+                        _ => Some((column, SortDirection::Ascending)),
+                    };
+
+                    self.rows = (self.sort_rows)(cx, self.sort);
+                    self.recalc(cx);
+                }
+            }
+
+            TableEvent::ResizeColumn(column, width) => {
+                if let Some(layout) = self.columns.get_mut(column) {
+                    layout.width = width.min(layout.max_width).max(layout.min_width);
+                    self.recalc(cx);
+                }
+            }
+
+            TableEvent::MoveColumn(column, position) => {
+                if let Some(index) = self.column_order.iter().position(|c| *c == column) {
+                    self.column_order.remove(index);
+                    self.column_order.insert(position.min(self.column_order.len()), column);
+                    self.recalc(cx);
+                }
+            }
+
+            TableEvent::SelectRow(row) => {
+                cx.focus();
+                match toggle_selection(self.selectable, &mut self.selected, row) {
+                    Some(true) => {
+                        self.focused = Some(row);
+                        self.focus_visible = false;
+                        if let Some(on_select) = &self.on_select {
+                            on_select(cx, row);
+                        }
+                    }
+
+                    Some(false) => {
+                        self.focused = None;
+                    }
+
+                    None => {}
+                }
+            }
+
+            TableEvent::SelectFocused => {
+                if let Some(focused) = self.focused {
+                    cx.emit(TableEvent::SelectRow(focused));
+                }
+            }
+
+            TableEvent::FocusNext => {
+                let next = match self.focused_position() {
+                    Some(position) if position + 1 < self.rows.len() => position + 1,
+                    _ => 0,
+                };
+
+                self.focus_position(cx, next);
+            }
+
+            TableEvent::FocusPrev => {
+                let prev = match self.focused_position() {
+                    Some(position) if position > 0 => position - 1,
+                    _ => self.rows.len().saturating_sub(1),
+                };
+
+                self.focus_position(cx, prev);
+            }
+
+            TableEvent::ClearSelection => {
+                self.selected.clear();
+            }
+        });
+
+        event.map(|table_view_event, _| match table_view_event {
+            TableViewEvent::UpdateRows => {
+                self.rows = (self.sort_rows)(cx, self.sort);
+                self.recalc(cx);
+            }
+
+            TableViewEvent::Scroll(x, y) => {
+                self.scroll = (*x, *y);
+                self.recalc(cx);
+            }
+
+            TableViewEvent::DropColumn(column, cursor_x) => {
+                let x = (*cursor_x - cx.bounds().x) / cx.scale_factor() + self.scroll_left;
+                if let Some(index) = self.column_order.iter().position(|c| c == column) {
+                    let mut position = drop_position(&self.ordered_widths(), x);
+                    // The position is counted before the column is removed from its current position.
+                    if index < position {
+                        position -= 1;
+                    }
+
+                    cx.emit(TableEvent::MoveColumn(*column, position));
+                }
+            }
+        });
+
+        event.map(|window_event, _| match window_event {
+            WindowEvent::GeometryChanged(geo) => {
+                if geo.intersects(GeoChanged::WIDTH_CHANGED | GeoChanged::HEIGHT_CHANGED) {
+                    self.recalc(cx);
+                }
+            }
+
+            _ => {}
+        });
+    }
+}
+
+impl Handle<'_, Table> {
+    pub fn selected<S: Lens>(self, selected: S) -> Self
+    where
+        S::Target: Deref<Target = [usize]> + Data,
+    {
+        self.bind(selected, |handle, s| {
+            let ss = s.get(&handle).deref().to_vec();
+            handle.modify(|table| {
+                for idx in ss {
+                    table.selected.insert(idx);
+                    table.focused = Some(idx);
+                }
+            });
+        })
+    }
+
+    pub fn on_select<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, usize),
+    {
+        self.modify(|table: &mut Table| table.on_select = Some(Box::new(callback)))
+    }
+
+    pub fn selectable<U: Into<Selectable>>(self, selectable: impl Res<U>) -> Self {
+        self.bind(selectable, |handle, selectable| {
+            let s = selectable.get(&handle).into();
+            handle.modify(|table: &mut Table| table.selectable = s);
+        })
+    }
+
+    pub fn selection_follows_focus<U: Into<bool>>(self, flag: impl Res<U>) -> Self {
+        self.bind(flag, |handle, selection_follows_focus| {
+            let s = selection_follows_focus.get(&handle).into();
+            handle.modify(|table: &mut Table| table.selection_follows_focus = s);
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+enum HeaderDrag {
+    // Resizing the column from the width it had when the drag started.
+    Resize { start_x: f32, start_width: f32 },
+    // Pressed on the header, which sorts the column when released or moves the column when dragged.
+    Press { start_x: f32, moved: bool },
+}
+
+struct TableHeader {
+    column: usize,
+    drag: Option<HeaderDrag>,
+}
+
+impl TableHeader {
+    fn new(cx: &mut Context, column: usize, header: Rc<dyn Fn(&mut Context)>) -> Handle<Self> {
+        Self { column, drag: None }
+            .build(cx, move |cx| {
+                (header)(cx);
+
+                Binding::new(cx, Table::sort, move |cx, sort| match sort.get(cx) {
+                    Some((sorted, SortDirection::Ascending)) if sorted == column => {
+                        Svg::new(cx, ICON_CHEVRON_UP).class("sort-indicator");
+                    }
+
+                    Some((sorted, SortDirection::Descending)) if sorted == column => {
+                        Svg::new(cx, ICON_CHEVRON_DOWN).class("sort-indicator");
+                    }
+
+                    _ => {}
+                });
+
+                Element::new(cx)
+                    .class("resize-handle")
+                    .width(Pixels(RESIZE_HANDLE_WIDTH))
+                    .left(Stretch(1.0))
+                    .position_type(PositionType::Absolute);
+            })
+            .toggle_class("sortable", Table::columns.map(move |columns| columns[column].sortable))
+            .bind(Table::columns.map(move |columns| columns[column].width), |handle, width| {
+                let width = width.get(&handle);
+                handle.width(Pixels(width));
+            })
+            .role(Role::ColumnHeader)
+    }
+}
+
+impl View for TableHeader {
+    fn element(&self) -> Option<&'static str> {
+        Some("table-header")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|window_event, _| match window_event {
+            WindowEvent::MouseDown(MouseButton::Left) => {
+                let bounds = cx.bounds();
+                let scale_factor = cx.scale_factor();
+                let start_x = cx.mouse.cursor_x;
+
+                self.drag =
+                    Some(if bounds.right() - start_x <= RESIZE_HANDLE_WIDTH * scale_factor {
+                        HeaderDrag::Resize { start_x, start_width: bounds.w / scale_factor }
+                    } else {
+                        HeaderDrag::Press { start_x, moved: false }
+                    });
+
+                cx.capture();
+                cx.set_active(true);
+            }
+
+            WindowEvent::MouseMove(x, _) => match &mut self.drag {
+                Some(HeaderDrag::Resize { start_x, start_width }) => {
+                    let width = *start_width + (*x - *start_x) / cx.scale_factor();
+                    cx.emit(TableEvent::ResizeColumn(self.column, width));
+                }
+
+                Some(HeaderDrag::Press { start_x, moved }) => {
+                    if (*x - *start_x).abs() > DRAG_THRESHOLD * cx.scale_factor() {
+                        *moved = true;
+                    }
+                }
+
+                None => {}
+            },
+
+            WindowEvent::MouseUp(MouseButton::Left) => {
+                if let Some(drag) = self.drag.take() {
+                    cx.release();
+                    cx.set_active(false);
+
+                    if let HeaderDrag::Press { moved, .. } = drag {
+                        if moved {
+                            cx.emit(TableViewEvent::DropColumn(self.column, cx.mouse.cursor_x));
+                        } else {
+                            cx.emit(TableEvent::Sort(self.column));
+                        }
+                    }
+                }
+            }
+
+            _ => {}
+        });
+    }
+}
+
+struct TableRow {}
+
+impl TableRow {
+    fn new(cx: &mut Context, row: usize, content: impl FnOnce(&mut Context)) -> Handle<Self> {
+        Self {}
+            .build(cx, content)
+            .position_type(PositionType::Absolute)
+            .role(Role::Row)
+            .checked(Table::selected.map(move |selected| selected.contains(&row)))
+            .focused_with_visibility(
+                Table::focused.map(move |focused| *focused == Some(row)),
+                Table::focus_visible,
+            )
+            .on_press(move |cx| cx.emit(TableEvent::SelectRow(row)))
+    }
+}
+
+impl View for TableRow {
+    fn element(&self) -> Option<&'static str> {
+        Some("table-row")
+    }
+}
+
+struct TableCell {}
+
+impl TableCell {
+    fn new(cx: &mut Context, content: impl FnOnce(&mut Context)) -> Handle<Self> {
+        Self {}.build(cx, content).position_type(PositionType::Absolute).role(Role::Cell)
+    }
+}
+
+impl View for TableCell {
+    fn element(&self) -> Option<&'static str> {
+        Some("table-cell")
+    }
+}
+
+// Returns the range of columns, in display order, which overlap the viewport at the given horizontal offset.
+fn visible_columns(widths: &[f32], offset: f32, viewport: f32) -> Range<usize> {
+    let mut start = widths.len();
+    let mut end = widths.len();
+    let mut x = 0.0;
+    for (index, width) in widths.iter().enumerate() {
+        if x >= offset + viewport {
+            end = index;
+            break;
+        }
+
+        if start == widths.len() && x + width > offset {
+            start = index;
+        }
+
+        x += width;
+    }
+
+    start.min(end)..end
+}
+
+// Returns the range of rows, in display order, which overlap the viewport at the given vertical offset.
+fn visible_rows(num_rows: usize, row_height: f32, offset: f32, viewport: f32) -> Range<usize> {
+    if row_height <= 0.0 {
+        return 0..0;
+    }
+
+    let start = ((offset / row_height).floor().max(0.0) as usize).min(num_rows);
+    let end = (((offset + viewport) / row_height).ceil().max(0.0) as usize).min(num_rows);
+
+    start..end.max(start)
+}
+
+// Returns the position in the header, before any column is removed, at which a column dropped at the given x is
+// inserted.
+fn drop_position(widths: &[f32], x: f32) -> usize {
+    let mut left = 0.0;
+    widths
+        .iter()
+        .take_while(|width| {
+            let center = left + *width / 2.0;
+            left += *width;
+            center < x
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{events::EventManager, systems::binding_system};
+
+    #[derive(Lens)]
+    struct AppData {
+        items: Vec<u32>,
+    }
+
+    enum AppEvent {
+        Truncate(usize),
+    }
+
+    impl Model for AppData {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|app_event, _| match app_event {
+                AppEvent::Truncate(len) => self.items.truncate(*len),
+            });
+        }
+    }
+
+    // Builds a table with a sortable column of the given items, large enough to show all of them.
+    fn table(cx: &mut Context, items: Vec<u32>) -> Entity {
+        cx.style.dpi_factor = 1.0;
+        AppData { items }.build(cx);
+        let table = Table::new(cx, AppData::items, 20.0, |table| {
+            table
+                .column(
+                    |cx| {
+                        Label::new(cx, "Value");
+                    },
+                    |cx, _, item| {
+                        Label::new(cx, item.map(|item| item.to_string()));
+                    },
+                )
+                .sort_by_key(|item| *item);
+        })
+        .entity();
+        cx.cache.set_bounds(table, BoundingBox::from_min_max(0.0, 0.0, 200.0, 200.0));
+
+        update(cx);
+        table
+    }
+
+    // Dispatches any pending events and updates the bindings which depend on them.
+    fn update(cx: &mut Context) {
+        let mut event_manager = EventManager::new();
+        while !cx.event_queue.is_empty() {
+            event_manager.flush_events(cx, |_| {});
+            binding_system(cx);
+        }
+    }
+
+    fn rows(cx: &Context, table: Entity) -> Vec<usize> {
+        cx.views.get(&table).and_then(|view| view.downcast_ref::<Table>()).unwrap().rows.clone()
+    }
+
+    fn num_built_rows(cx: &Context) -> usize {
+        cx.views.values().filter(|view| view.element() == Some("table-row")).count()
+    }
+
+    #[test]
+    fn test_shrink_list() {
+        let cx = &mut Context::new();
+        let table = table(cx, vec![3, 1, 4, 1, 5]);
+        assert_eq!(num_built_rows(cx), 5);
+
+        cx.emit_to(Entity::root(), AppEvent::Truncate(2));
+        update(cx);
+        assert_eq!(rows(cx, table), vec![0, 1]);
+        assert_eq!(num_built_rows(cx), 2);
+    }
+
+    #[test]
+    fn test_sort() {
+        let cx = &mut Context::new();
+        let table = table(cx, vec![3, 1, 4, 1, 5]);
+        assert_eq!(rows(cx, table), vec![0, 1, 2, 3, 4]);
+
+        cx.emit_to(table, TableEvent::Sort(0));
+        update(cx);
+        // Rows which compare equal keep their order in the list.
+        assert_eq!(rows(cx, table), vec![1, 3, 0, 2, 4]);
+
+        cx.emit_to(table, TableEvent::Sort(0));
+        update(cx);
+        assert_eq!(rows(cx, table), vec![4, 2, 0, 1, 3]);
+
+        cx.emit_to(table, TableEvent::Sort(0));
+        update(cx);
+        assert_eq!(rows(cx, table), vec![0, 1, 2, 3, 4]);
+        assert_eq!(num_built_rows(cx), 5);
+    }
+
+    #[test]
+    fn test_visible_columns() {
+        let widths = [100.0, 100.0, 100.0, 100.0];
+        assert_eq!(visible_columns(&widths, 0.0, 150.0), 0..2);
+        assert_eq!(visible_columns(&widths, 150.0, 100.0), 1..3);
+        assert_eq!(visible_columns(&widths, 200.0, 200.0), 2..4);
+        assert_eq!(visible_columns(&widths, 0.0, 1000.0), 0..4);
+        assert_eq!(visible_columns(&[], 0.0, 100.0), 0..0);
+    }
+
+    #[test]
+    fn test_visible_rows() {
+        assert_eq!(visible_rows(100_000, 20.0, 0.0, 100.0), 0..5);
+        assert_eq!(visible_rows(100_000, 20.0, 30.0, 100.0), 1..7);
+        assert_eq!(visible_rows(100_000, 20.0, 1_999_900.0, 100.0), 99_995..100_000);
+        assert_eq!(visible_rows(3, 20.0, 0.0, 100.0), 0..3);
+        assert_eq!(visible_rows(0, 20.0, 0.0, 100.0), 0..0);
+    }
+
+    #[test]
+    fn test_drop_position() {
+        let widths = [100.0, 100.0, 100.0];
+        assert_eq!(drop_position(&widths, 10.0), 0);
+        assert_eq!(drop_position(&widths, 60.0), 1);
+        assert_eq!(drop_position(&widths, 160.0), 2);
+        assert_eq!(drop_position(&widths, 400.0), 3);
+    }
+}
@@ -7,9 +7,11 @@ use crate::text::{
 };
 // use crate::views::scrollview::SCROLL_SENSITIVITY;
 use accesskit::{ActionData, ActionRequest};
+use skia_safe::canvas::SaveLayerRec;
 use skia_safe::textlayout::{RectHeightStyle, RectWidthStyle};
-use skia_safe::{Paint, PaintStyle, Rect};
+use skia_safe::{color_filters, BlendMode, ClipOp, Paint, PaintStyle, Rect};
 use unicode_segmentation::UnicodeSegmentation;
+use vizia_style::PseudoElement;
 
 /// Events for modifying a textbox.
 pub enum TextEvent {
@@ -185,6 +187,7 @@ where
                         .position_type(PositionType::Absolute)
                         .hidden(true)
                         .class("placeholder")
+                        .pseudo_element(PseudoElement::Placeholder)
                         .bind(lens, |handle, lens| {
                             let flag = lens.get(&handle).to_string_local(handle.cx).is_empty();
                             handle.display(flag);
@@ -195,6 +198,7 @@ where
         .toggle_class("multiline", kind == TextboxKind::MultiLineWrapped)
         .text_wrap(kind == TextboxKind::MultiLineWrapped)
         .navigable(true)
+        .selectable_text()
         .role(Role::TextInput)
         .text_value(lens)
         .default_action_verb(DefaultActionVerb::Focus)
@@ -409,7 +413,9 @@ where
         }
     }
 
-    pub fn draw_selection(&self, cx: &mut DrawContext, canvas: &Canvas) {
+    // Returns the rectangles covering the selected text of the textbox.
+    fn selection_rects(&self, cx: &DrawContext) -> Vec<Rect> {
+        let mut rects = Vec::new();
         if !self.selection.is_caret() {
             if let Some(paragraph) = cx.text_context.text_paragraphs.get(cx.current) {
                 if let Some(text) = cx.style.text.get(cx.current) {
@@ -462,16 +468,42 @@ where
                         let x2 = x + (cursor_rect.rect.right - cursor_rect.rect.left);
                         let y2 = y + (cursor_rect.rect.bottom - cursor_rect.rect.top);
 
-                        let mut paint = Paint::default();
-                        paint.set_anti_alias(true);
-                        paint.set_style(PaintStyle::Fill);
-                        paint.set_color(cx.selection_color());
-
-                        canvas.draw_rect(Rect::new(x, y, x2, y2), &paint);
+                        rects.push(Rect::new(x, y, x2, y2));
                     }
                 }
             }
         }
+
+        rects
+    }
+
+    /// Draw the selection of the textbox, using the `background-color` and `color` of its `::selection` rules.
+    pub fn draw_selection(&self, cx: &mut DrawContext, canvas: &Canvas) {
+        let rects = self.selection_rects(cx);
+
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_style(PaintStyle::Fill);
+        paint.set_color(cx.selection_color());
+
+        for rect in rects.iter() {
+            canvas.draw_rect(rect, &paint);
+        }
+
+        // The selected text is redrawn with its color replaced by the selection text color.
+        if let Some(color) = cx.selection_font_color() {
+            let mut paint = Paint::default();
+            paint.set_color_filter(color_filters::blend(color, BlendMode::SrcIn));
+
+            for rect in rects {
+                canvas.save();
+                canvas.clip_rect(rect, ClipOp::Intersect, true);
+                canvas.save_layer(&SaveLayerRec::default().paint(&paint));
+                cx.draw_text(canvas);
+                canvas.restore();
+                canvas.restore();
+            }
+        }
     }
 
     /// Draw text caret for the current view.
@@ -0,0 +1,588 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use hashbrown::HashMap;
+
+use crate::{icons::ICON_X, prelude::*};
+
+// The time between updates of the toast timeouts.
+const TICK_INTERVAL: Duration = Duration::from_millis(100);
+// The time a dismissed toast is kept for its exit transition, which should match the transition duration in the theme.
+const EXIT_DURATION: Duration = Duration::from_millis(200);
+
+/// The severity of a [`Toast`], which is added to the toast as a class for styling.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Data)]
+pub enum ToastSeverity {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastSeverity {
+    fn class(&self) -> &'static str {
+        match self {
+            ToastSeverity::Info => "info",
+            ToastSeverity::Success => "success",
+            ToastSeverity::Warning => "warning",
+            ToastSeverity::Error => "error",
+        }
+    }
+}
+
+// A button shown on a toast, which dismisses the toast when pressed.
+#[derive(Clone)]
+struct ToastAction {
+    label: String,
+    callback: Arc<dyn Fn(&mut EventContext) + Send + Sync>,
+}
+
+/// A notification shown by a [`Toaster`].
+///
+/// Toasts are shown by emitting a [`ToastEvent::Show`] from any view, or from a [`ContextProxy`] on another thread.
+///
+/// # Example
+/// ```ignore
+/// cx.emit(ToastEvent::Show(
+///     Toast::new("Export failed")
+///         .severity(ToastSeverity::Error)
+///         .body("The file could not be written.")
+///         .action("Retry", |cx| cx.emit(AppEvent::Export)),
+/// ));
+/// ```
+#[derive(Clone)]
+pub struct Toast {
+    // Assigned by the toaster when the toast is shown.
+    id: u64,
+    severity: ToastSeverity,
+    title: String,
+    body: Option<String>,
+    actions: Vec<ToastAction>,
+    timeout: Option<Duration>,
+}
+
+impl Toast {
+    /// Creates a new informational toast with the given title, which is dismissed after five seconds.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            id: 0,
+            severity: ToastSeverity::Info,
+            title: title.into(),
+            body: None,
+            actions: Vec::new(),
+            timeout: Some(Duration::from_secs(5)),
+        }
+    }
+
+    /// Sets the severity of the toast.
+    pub fn severity(mut self, severity: ToastSeverity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Sets the text shown below the title of the toast.
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Adds an action button to the toast. The toast is dismissed after the callback is called.
+    pub fn action(
+        mut self,
+        label: impl Into<String>,
+        callback: impl 'static + Fn(&mut EventContext) + Send + Sync,
+    ) -> Self {
+        self.actions.push(ToastAction { label: label.into(), callback: Arc::new(callback) });
+        self
+    }
+
+    /// Sets the time after which the toast is dismissed. Pass `None` for a toast which stays until it is closed.
+    ///
+    /// The timeout is paused while the mouse is over the toasts.
+    pub fn timeout(mut self, timeout: impl Into<Option<Duration>>) -> Self {
+        self.timeout = timeout.into();
+        self
+    }
+}
+
+impl Data for Toast {
+    fn same(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+/// Events for showing and dismissing toasts, which are handled by the [`Toaster`] regardless of where they are emitted.
+pub enum ToastEvent {
+    /// Shows a toast, or queues it if the maximum number of toasts are already shown.
+    Show(Toast),
+    /// Dismisses all of the shown and queued toasts.
+    DismissAll,
+}
+
+enum ToasterEvent {
+    Dismiss(u64),
+    SetPaused(bool),
+    Tick,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ToastPhase {
+    // The toast has been built but not yet transitioned in.
+    Entering,
+    Shown,
+    // The toast is transitioning out, with the time since it was dismissed.
+    Leaving(Duration),
+}
+
+struct ToastEntry {
+    toast: Toast,
+    remaining: Option<Duration>,
+    phase: ToastPhase,
+}
+
+/// An overlay which shows a stack of toast notifications in a corner of the window.
+///
+/// The toaster should be built once, as the last child of the window content, so that it is drawn above other views.
+/// Any [`ToastEvent`] emitted in the application is handled by the toaster. Shown toasts are announced to assistive
+/// technologies through a polite live region.
+///
+/// # Example
+/// ```ignore
+/// Application::new(|cx| {
+///     VStack::new(cx, |cx| {
+///         Button::new(cx, |cx| Label::new(cx, "Save"))
+///             .on_press(|cx| cx.emit(ToastEvent::Show(Toast::new("Saved"))));
+///     });
+///
+///     Toaster::new(cx).placement(Placement::BottomEnd);
+/// })
+/// .run()
+/// ```
+#[derive(Lens)]
+pub struct Toaster {
+    toasts: Vec<ToastEntry>,
+    #[lens(ignore)]
+    queue: VecDeque<Toast>,
+    placement: Placement,
+    max_visible: usize,
+    is_paused: bool,
+    #[lens(ignore)]
+    next_id: u64,
+    #[lens(ignore)]
+    timer: Timer,
+    #[lens(ignore)]
+    last_tick: Instant,
+}
+
+impl Toaster {
+    /// Creates a new toaster, which shows toasts in the bottom right corner of the window by default.
+    pub fn new(cx: &mut Context) -> Handle<Self> {
+        let timer = cx.add_timer(TICK_INTERVAL, None, |cx, action| {
+            if matches!(action, TimerAction::Tick(_)) {
+                cx.emit(ToasterEvent::Tick);
+            }
+        });
+
+        Self {
+            toasts: Vec::new(),
+            queue: VecDeque::new(),
+            placement: Placement::BottomEnd,
+            max_visible: 3,
+            is_paused: false,
+            next_id: 1,
+            timer,
+            last_tick: Instant::now(),
+        }
+        .build(cx, |cx| {
+            VStack::new(cx, |cx| {
+                // The toast items are built in the stack rather than in the binding, so that they aren't rebuilt
+                // when the binding updates.
+                Binding::new(cx, Toaster::root.map(Toaster::shown_toasts), |cx, toasts| {
+                    if let Some(stack) = cx.tree.get_parent(cx.current()) {
+                        update_toast_items(cx, stack, toasts.get(cx));
+                    }
+                });
+            })
+            .class("toast-stack")
+            .pointer_events(PointerEvents::Auto)
+            .on_over(|cx| cx.emit(ToasterEvent::SetPaused(true)))
+            .on_over_out(|cx| cx.emit(ToasterEvent::SetPaused(false)));
+        })
+        .position_type(PositionType::Absolute)
+        .size(Stretch(1.0))
+        .pointer_events(PointerEvents::None)
+        .z_index(120)
+        .alignment(Alignment::BottomRight)
+        .on_build(|ex| {
+            ex.add_listener(|toaster: &mut Toaster, ex, event| {
+                event.take(|toast_event: ToastEvent, meta| {
+                    match toast_event {
+                        ToastEvent::Show(toast) => toaster.show(toast),
+                        ToastEvent::DismissAll => toaster.dismiss_all(),
+                    }
+
+                    if !ex.timer_is_running(toaster.timer) {
+                        toaster.last_tick = Instant::now();
+                        ex.start_timer(toaster.timer);
+                    }
+
+                    meta.consume();
+                });
+            });
+        })
+    }
+
+    // Returns the toasts to show, ordered so that the newest toast is closest to the corner of the window.
+    fn shown_toasts(&self) -> Vec<Toast> {
+        let toasts = self.toasts.iter().map(|entry| entry.toast.clone());
+        if matches!(self.placement, Placement::TopStart | Placement::Top | Placement::TopEnd) {
+            toasts.rev().collect()
+        } else {
+            toasts.collect()
+        }
+    }
+
+    fn phase(&self, id: u64) -> Option<ToastPhase> {
+        self.toasts.iter().find(|entry| entry.toast.id == id).map(|entry| entry.phase)
+    }
+
+    fn active_count(&self) -> usize {
+        self.toasts.iter().filter(|entry| !matches!(entry.phase, ToastPhase::Leaving(_))).count()
+    }
+
+    fn show(&mut self, mut toast: Toast) {
+        toast.id = self.next_id;
+        self.next_id += 1;
+        self.queue.push_back(toast);
+        self.promote();
+    }
+
+    // Moves queued toasts into the shown toasts while there is room for them.
+    fn promote(&mut self) {
+        while self.active_count() < self.max_visible {
+            let Some(toast) = self.queue.pop_front() else {
+                break;
+            };
+
+            self.toasts.push(ToastEntry {
+                remaining: toast.timeout,
+                toast,
+                phase: ToastPhase::Entering,
+            });
+        }
+    }
+
+    fn dismiss(&mut self, id: u64) {
+        if let Some(entry) = self.toasts.iter_mut().find(|entry| entry.toast.id == id) {
+            if !matches!(entry.phase, ToastPhase::Leaving(_)) {
+                entry.phase = ToastPhase::Leaving(Duration::ZERO);
+            }
+        }
+    }
+
+    fn dismiss_all(&mut self) {
+        self.queue.clear();
+        for entry in self.toasts.iter_mut() {
+            if !matches!(entry.phase, ToastPhase::Leaving(_)) {
+                entry.phase = ToastPhase::Leaving(Duration::ZERO);
+            }
+        }
+    }
+
+    // Advances the toasts by the elapsed time, returning true if there are any toasts left to show.
+    fn advance(&mut self, elapsed: Duration) -> bool {
+        for entry in self.toasts.iter_mut() {
+            match entry.phase {
+                ToastPhase::Entering => entry.phase = ToastPhase::Shown,
+
+                ToastPhase::Shown => {
+                    if self.is_paused {
+                        continue;
+                    }
+
+                    if let Some(remaining) = &mut entry.remaining {
+                        *remaining = remaining.saturating_sub(elapsed);
+                        if remaining.is_zero() {
+                            entry.phase = ToastPhase::Leaving(Duration::ZERO);
+                        }
+                    }
+                }
+
+                ToastPhase::Leaving(time) => entry.phase = ToastPhase::Leaving(time + elapsed),
+            }
+        }
+
+        self.toasts.retain(
+            |entry| !matches!(entry.phase, ToastPhase::Leaving(time) if time >= EXIT_DURATION),
+        );
+        self.promote();
+
+        !self.toasts.is_empty()
+    }
+}
+
+impl View for Toaster {
+    fn element(&self) -> Option<&'static str> {
+        Some("toaster")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.map(|toaster_event, meta| {
+            match toaster_event {
+                ToasterEvent::Dismiss(id) => self.dismiss(*id),
+
+                ToasterEvent::SetPaused(is_paused) => self.is_paused = *is_paused,
+
+                ToasterEvent::Tick => {
+                    let now = Instant::now();
+                    let elapsed = now.duration_since(self.last_tick);
+                    self.last_tick = now;
+
+                    if !self.advance(elapsed) {
+                        cx.stop_timer(self.timer);
+                    }
+                }
+            }
+
+            meta.consume();
+        });
+    }
+}
+
+impl Handle<'_, Toaster> {
+    /// Sets the corner of the window in which toasts are shown. Only the top and bottom placements are supported.
+    pub fn placement(self, placement: impl Res<Placement>) -> Self {
+        self.bind(placement, |handle, placement| {
+            let placement = placement.get(&handle);
+            let alignment = match placement {
+                Placement::TopStart => Alignment::TopLeft,
+                Placement::Top => Alignment::TopCenter,
+                Placement::TopEnd => Alignment::TopRight,
+                Placement::BottomStart => Alignment::BottomLeft,
+                Placement::Bottom => Alignment::BottomCenter,
+                _ => Alignment::BottomRight,
+            };
+
+            handle
+                .modify(|toaster: &mut Toaster| toaster.placement = placement)
+                .alignment(alignment);
+        })
+    }
+
+    /// Sets the maximum number of toasts shown at once. Any further toasts are queued until a shown toast is dismissed.
+    pub fn max_visible(self, max_visible: usize) -> Self {
+        self.modify(|toaster: &mut Toaster| toaster.max_visible = max_visible.max(1))
+    }
+}
+
+// Builds an item for each new toast and removes the items of toasts which are no longer shown, so that a toast keeps
+// its view while it is shown and isn't announced again by assistive technologies when other toasts change.
+fn update_toast_items(cx: &mut Context, stack: Entity, toasts: Vec<Toast>) {
+    let mut items = stack
+        .child_iter(&cx.tree)
+        .filter_map(|entity| {
+            cx.views
+                .get(&entity)
+                .and_then(|view| view.downcast_ref::<ToastItem>())
+                .map(|item| (item.id, entity))
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut previous = None;
+    for toast in toasts {
+        let entity = match items.remove(&toast.id) {
+            Some(entity) => entity,
+            None => cx.with_current(stack, |cx| ToastItem::new(cx, toast).entity()),
+        };
+
+        // Moves the item after the previous item if the order of the toasts has changed.
+        if let Some(previous) = previous {
+            if cx.tree.get_next_sibling(previous) != Some(entity) {
+                cx.tree.set_next_sibling(previous, entity).unwrap();
+                cx.needs_relayout();
+            }
+        }
+
+        previous = Some(entity);
+    }
+
+    for entity in items.into_values() {
+        cx.remove(entity);
+    }
+}
+
+// A single toast within a toaster.
+struct ToastItem {
+    id: u64,
+}
+
+impl ToastItem {
+    fn new(cx: &mut Context, toast: Toast) -> Handle<Self> {
+        let id = toast.id;
+        let mut handle = Self { id }.build(cx, |cx| {
+            VStack::new(cx, |cx| {
+                Label::new(cx, toast.title).class("title");
+                if let Some(body) = toast.body {
+                    Label::new(cx, body).class("body");
+                }
+
+                if !toast.actions.is_empty() {
+                    HStack::new(cx, |cx| {
+                        for action in toast.actions {
+                            Button::new(cx, |cx| Label::new(cx, action.label.clone())).on_press(
+                                move |cx| {
+                                    (action.callback)(cx);
+                                    cx.emit(ToasterEvent::Dismiss(id));
+                                },
+                            );
+                        }
+                    })
+                    .class("actions");
+                }
+            })
+            .class("content");
+
+            Button::new(cx, |cx| Svg::new(cx, ICON_X))
+                .class("close-icon")
+                .name("Close")
+                .on_press(move |cx| cx.emit(ToasterEvent::Dismiss(id)));
+        });
+
+        // The lenses are created with the item as the current entity so that they're removed along with it.
+        let entity = handle.entity();
+        let (shown, leaving) = handle.context().with_current(entity, |_| {
+            (
+                Toaster::root.map(move |toaster| toaster.phase(id) == Some(ToastPhase::Shown)),
+                Toaster::root
+                    .map(move |toaster| matches!(toaster.phase(id), Some(ToastPhase::Leaving(_)))),
+            )
+        });
+
+        handle
+            .class(toast.severity.class())
+            .toggle_class("shown", shown)
+            .toggle_class("leaving", leaving)
+            .role(Role::Status)
+            .live(Live::Polite)
+            .layout_type(LayoutType::Row)
+    }
+}
+
+impl View for ToastItem {
+    fn element(&self) -> Option<&'static str> {
+        Some("toast")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{events::EventManager, systems::binding_system};
+
+    fn toaster() -> Toaster {
+        Toaster {
+            toasts: Vec::new(),
+            queue: VecDeque::new(),
+            placement: Placement::BottomEnd,
+            max_visible: 2,
+            is_paused: false,
+            next_id: 1,
+            timer: Timer(0),
+            last_tick: Instant::now(),
+        }
+    }
+
+    fn ids(toaster: &Toaster) -> Vec<u64> {
+        toaster.toasts.iter().map(|entry| entry.toast.id).collect()
+    }
+
+    #[test]
+    fn test_queue() {
+        let mut toaster = toaster();
+        toaster.show(Toast::new("a"));
+        toaster.show(Toast::new("b"));
+        toaster.show(Toast::new("c"));
+        assert_eq!(ids(&toaster), vec![1, 2]);
+        assert_eq!(toaster.queue.len(), 1);
+
+        toaster.dismiss(1);
+        assert!(toaster.advance(EXIT_DURATION));
+        assert_eq!(ids(&toaster), vec![2, 3]);
+        assert!(toaster.queue.is_empty());
+    }
+
+    #[test]
+    fn test_timeout() {
+        let mut toaster = toaster();
+        toaster.show(Toast::new("a").timeout(Duration::from_secs(1)));
+        toaster.show(Toast::new("b").timeout(None));
+
+        toaster.advance(Duration::ZERO);
+        assert_eq!(toaster.phase(1), Some(ToastPhase::Shown));
+
+        toaster.is_paused = true;
+        toaster.advance(Duration::from_secs(2));
+        assert_eq!(toaster.phase(1), Some(ToastPhase::Shown));
+
+        toaster.is_paused = false;
+        toaster.advance(Duration::from_secs(1));
+        assert_eq!(toaster.phase(1), Some(ToastPhase::Leaving(Duration::ZERO)));
+
+        toaster.advance(EXIT_DURATION);
+        assert_eq!(ids(&toaster), vec![2]);
+        assert_eq!(toaster.phase(2), Some(ToastPhase::Shown));
+    }
+
+    #[test]
+    fn test_dismiss_all() {
+        let mut toaster = toaster();
+        for title in ["a", "b", "c"] {
+            toaster.show(Toast::new(title));
+        }
+
+        toaster.dismiss_all();
+        assert!(toaster.queue.is_empty());
+        assert!(!toaster.advance(EXIT_DURATION));
+    }
+
+    fn update(cx: &mut Context) {
+        let mut event_manager = EventManager::new();
+        while !cx.event_queue.is_empty() {
+            event_manager.flush_events(cx, |_| {});
+            binding_system(cx);
+        }
+    }
+
+    // Returns the toast items in the order they are shown, along with the ids of their toasts.
+    fn items(cx: &Context) -> Vec<(u64, Entity)> {
+        cx.tree
+            .into_iter()
+            .filter_map(|entity| {
+                cx.views
+                    .get(&entity)
+                    .and_then(|view| view.downcast_ref::<ToastItem>())
+                    .map(|item| (item.id, entity))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_items_are_kept() {
+        let cx = &mut Context::new();
+        let toaster = Toaster::new(cx).entity();
+
+        cx.emit_to(toaster, ToastEvent::Show(Toast::new("a")));
+        update(cx);
+        let first = items(cx);
+        assert_eq!(first.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![1]);
+
+        // Showing another toast keeps the item of the first toast.
+        cx.emit_to(toaster, ToastEvent::Show(Toast::new("b")));
+        update(cx);
+        let second = items(cx);
+        assert_eq!(second.len(), 2);
+        assert_eq!(second[0], first[0]);
+        assert_eq!(second[1].0, 2);
+    }
+}
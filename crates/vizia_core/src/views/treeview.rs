@@ -0,0 +1,618 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    ops::Deref,
+    rc::Rc,
+};
+
+use crate::icons::{ICON_CHEVRON_DOWN, ICON_CHEVRON_RIGHT};
+use crate::prelude::*;
+use crate::views::list::toggle_selection;
+
+/// Trait for data which can be displayed as a node of a [`TreeView`].
+pub trait TreeNode: 'static + Sized {
+    /// Returns the child nodes of the node.
+    fn children(&self) -> &[Self];
+}
+
+/// Events for modifying a [`TreeView`].
+///
+/// Nodes are identified by their path, which is the index of each node from a root node down to the node.
+pub enum TreeViewEvent {
+    /// Expands the node with the given path if it is collapsed, or collapses it if it is expanded.
+    ToggleExpanded(Vec<usize>),
+    /// Expands the node with the given path if it has children.
+    Expand(Vec<usize>),
+    /// Collapses the node with the given path, moving the focus to the node if it was within its children.
+    Collapse(Vec<usize>),
+    /// Toggles the selection of the node with the given path.
+    Select(Vec<usize>),
+    /// Toggles the selection of the focused node.
+    SelectFocused,
+    /// Focuses the next visible node, wrapping around to the first node.
+    FocusNext,
+    /// Focuses the previous visible node, wrapping around to the last node.
+    FocusPrev,
+    /// Expands the focused node, or focuses its first child if it is already expanded.
+    ExpandFocused,
+    /// Collapses the focused node, or focuses its parent if it is already collapsed.
+    CollapseFocused,
+    /// Deselects all of the nodes.
+    ClearSelection,
+}
+
+enum TreeViewUpdateEvent {
+    // Removes the paths of nodes which no longer exist from the expanded, selected and focused nodes.
+    PruneNodes,
+}
+
+// Returns the number of children of the node with the given path, or the number of root nodes for an empty path.
+type NumChildren = Box<dyn Fn(&EventContext, &[usize]) -> usize>;
+
+type ItemContent<L, T> = Rc<dyn Fn(&mut Context, &[usize], TryMapRef<L, T>)>;
+
+/// A view for displaying hierarchical data, where the children of a node are only built when the node is expanded.
+///
+/// # Example
+/// ```ignore
+/// TreeView::new(cx, AppData::files, |cx, _, file| {
+///     Label::new(cx, file.map(|file| file.name.clone()));
+/// })
+/// .selectable(Selectable::Single);
+/// ```
+#[derive(Lens)]
+pub struct TreeView {
+    expanded: HashSet<Vec<usize>>,
+    selected: BTreeSet<Vec<usize>>,
+    selectable: Selectable,
+    focused: Option<Vec<usize>>,
+    focus_visible: bool,
+    selection_follows_focus: bool,
+    indent: f32,
+    num_children: NumChildren,
+    on_select: Option<Box<dyn Fn(&mut EventContext, &[usize])>>,
+}
+
+impl TreeView {
+    /// Creates a new tree view with a binding to the given list of root nodes and a template for constructing the
+    /// content of each node, which is given the path of the node.
+    pub fn new<L: Lens, T: TreeNode>(
+        cx: &mut Context,
+        roots: L,
+        item_content: impl 'static + Fn(&mut Context, &[usize], TryMapRef<L, T>),
+    ) -> Handle<Self>
+    where
+        L::Target: Deref<Target = [T]> + Data,
+    {
+        let content: ItemContent<L, T> = Rc::new(item_content);
+        let num_children: NumChildren = Box::new(move |cx, path| {
+            roots.get_ref(cx).map_or(0, |roots| child_count(&roots, path))
+        });
+
+        Self {
+            expanded: HashSet::new(),
+            selected: BTreeSet::new(),
+            selectable: Selectable::None,
+            focused: None,
+            focus_visible: false,
+            selection_follows_focus: false,
+            indent: 16.0,
+            num_children,
+            on_select: None,
+        }
+        .build(cx, move |cx| {
+            Keymap::from(vec![
+                (
+                    KeyChord::new(Modifiers::empty(), Code::ArrowDown),
+                    KeymapEntry::new("Focus Next", |cx| cx.emit(TreeViewEvent::FocusNext)),
+                ),
+                (
+                    KeyChord::new(Modifiers::empty(), Code::ArrowUp),
+                    KeymapEntry::new("Focus Previous", |cx| cx.emit(TreeViewEvent::FocusPrev)),
+                ),
+                (
+                    KeyChord::new(Modifiers::empty(), Code::ArrowRight),
+                    KeymapEntry::new("Expand Focused", |cx| cx.emit(TreeViewEvent::ExpandFocused)),
+                ),
+                (
+                    KeyChord::new(Modifiers::empty(), Code::ArrowLeft),
+                    KeymapEntry::new("Collapse Focused", |cx| {
+                        cx.emit(TreeViewEvent::CollapseFocused)
+                    }),
+                ),
+                (
+                    KeyChord::new(Modifiers::empty(), Code::Space),
+                    KeymapEntry::new("Select Focused", |cx| cx.emit(TreeViewEvent::SelectFocused)),
+                ),
+                (
+                    KeyChord::new(Modifiers::empty(), Code::Enter),
+                    KeymapEntry::new("Select Focused", |cx| cx.emit(TreeViewEvent::SelectFocused)),
+                ),
+            ])
+            .build(cx);
+
+            // Nodes are identified by their paths, so the state of nodes which no longer exist is removed whenever
+            // the tree changes.
+            Binding::new(cx, roots, |cx, _| cx.emit(TreeViewUpdateEvent::PruneNodes));
+
+            ScrollView::new(cx, move |cx| {
+                build_children(cx, roots, Vec::new(), content);
+            });
+        })
+        .toggle_class("selectable", TreeView::selectable.map(|s| *s != Selectable::None))
+        .navigable(true)
+        .role(Role::Tree)
+    }
+
+    fn visible_nodes(&self, cx: &EventContext) -> Vec<Vec<usize>> {
+        visible_paths(&self.expanded, |path| (self.num_children)(cx, path))
+    }
+
+    fn focused_position(&self, nodes: &[Vec<usize>]) -> Option<usize> {
+        self.focused.as_ref().and_then(|focused| nodes.iter().position(|node| node == focused))
+    }
+
+    fn focus_node(&mut self, cx: &mut EventContext, path: Vec<usize>) {
+        self.focused = Some(path);
+        self.focus_visible = true;
+
+        if self.selection_follows_focus {
+            cx.emit(TreeViewEvent::SelectFocused);
+        }
+    }
+}
+
+impl View for TreeView {
+    fn element(&self) -> Option<&'static str> {
+        Some("treeview")
+    }
+
+    fn event(&mut self, cx: &mut EventContext, event: &mut Event) {
+        event.take(|tree_view_event, _| match tree_view_event {
+            TreeViewEvent::ToggleExpanded(path) => {
+                if self.expanded.contains(&path) {
+                    cx.emit(TreeViewEvent::Collapse(path));
+                } else {
+                    cx.emit(TreeViewEvent::Expand(path));
+                }
+            }
+
+            TreeViewEvent::Expand(path) => {
+                if (self.num_children)(cx, &path) > 0 {
+                    self.expanded.insert(path);
+                }
+            }
+
+            TreeViewEvent::Collapse(path) => {
+                // Move the focus out of the collapsed children.
+                let focus_within = self.focused.as_ref().is_some_and(|focused| {
+                    focused.len() > path.len() && focused.starts_with(&path)
+                });
+
+                if focus_within {
+                    self.focused = Some(path.clone());
+                }
+
+                self.expanded.remove(&path);
+            }
+
+            TreeViewEvent::Select(path) => {
+                cx.focus();
+                match toggle_selection(self.selectable, &mut self.selected, path.clone()) {
+                    Some(true) => {
+                        self.focus_visible = false;
+                        if let Some(on_select) = &self.on_select {
+                            on_select(cx, &path);
+                        }
+                        self.focused = Some(path);
+                    }
+
+                    Some(false) => {
+                        self.focused = None;
+                    }
+
+                    None => {}
+                }
+            }
+
+            TreeViewEvent::SelectFocused => {
+                if let Some(focused) = &self.focused {
+                    cx.emit(TreeViewEvent::Select(focused.clone()));
+                }
+            }
+
+            TreeViewEvent::FocusNext => {
+                let nodes = self.visible_nodes(cx);
+                let next = match self.focused_position(&nodes) {
+                    Some(position) if position + 1 < nodes.len() => position + 1,
+                    _ => 0,
+                };
+
+                if let Some(node) = nodes.into_iter().nth(next) {
+                    self.focus_node(cx, node);
+                }
+            }
+
+            TreeViewEvent::FocusPrev => {
+                let nodes = self.visible_nodes(cx);
+                let prev = match self.focused_position(&nodes) {
+                    Some(position) if position > 0 => position - 1,
+                    _ => nodes.len().saturating_sub(1),
+                };
+
+                if let Some(node) = nodes.into_iter().nth(prev) {
+                    self.focus_node(cx, node);
+                }
+            }
+
+            TreeViewEvent::ExpandFocused => {
+                if let Some(mut focused) = self.focused.clone() {
+                    if (self.num_children)(cx, &focused) > 0 {
+                        if self.expanded.contains(&focused) {
+                            focused.push(0);
+                            self.focus_node(cx, focused);
+                        } else {
+                            self.expanded.insert(focused);
+                            self.focus_visible = true;
+                        }
+                    }
+                }
+            }
+
+            TreeViewEvent::CollapseFocused => {
+                if let Some(mut focused) = self.focused.clone() {
+                    if self.expanded.remove(&focused) {
+                        self.focus_visible = true;
+                    } else if focused.len() > 1 {
+                        focused.pop();
+                        self.focus_node(cx, focused);
+                    }
+                }
+            }
+
+            TreeViewEvent::ClearSelection => {
+                self.selected.clear();
+            }
+        });
+
+        event.take(|update_event, _| match update_event {
+            TreeViewUpdateEvent::PruneNodes => {
+                let num_children = &self.num_children;
+                let exists = |path: &[usize]| {
+                    path.split_last()
+                        .is_some_and(|(index, parent)| *index < num_children(cx, parent))
+                };
+
+                self.expanded.retain(|path| exists(path) && num_children(cx, path) > 0);
+                self.selected.retain(|path| exists(path));
+                if !self.focused.as_deref().is_some_and(exists) {
+                    self.focused = None;
+                }
+            }
+        });
+    }
+}
+
+impl Handle<'_, TreeView> {
+    /// Sets a callback which is called with the path of a node when it is selected.
+    pub fn on_select<F>(self, callback: F) -> Self
+    where
+        F: 'static + Fn(&mut EventContext, &[usize]),
+    {
+        self.modify(|tree_view: &mut TreeView| tree_view.on_select = Some(Box::new(callback)))
+    }
+
+    /// Sets whether nodes can be selected, and whether one or multiple nodes can be selected at a time.
+    pub fn selectable<U: Into<Selectable>>(self, selectable: impl Res<U>) -> Self {
+        self.bind(selectable, |handle, selectable| {
+            let s = selectable.get(&handle).into();
+            handle.modify(|tree_view: &mut TreeView| tree_view.selectable = s);
+        })
+    }
+
+    /// Sets whether moving the focus with the keyboard also selects the focused node.
+    pub fn selection_follows_focus<U: Into<bool>>(self, flag: impl Res<U>) -> Self {
+        self.bind(flag, |handle, selection_follows_focus| {
+            let s = selection_follows_focus.get(&handle).into();
+            handle.modify(|tree_view: &mut TreeView| tree_view.selection_follows_focus = s);
+        })
+    }
+
+    /// Sets the indentation in logical pixels of each level of the tree.
+    pub fn indent(self, indent: impl Res<f32>) -> Self {
+        self.bind(indent, |handle, indent| {
+            let indent = indent.get(&handle);
+            handle.modify(|tree_view: &mut TreeView| tree_view.indent = indent);
+        })
+    }
+}
+
+// Builds an item for each child of the node with the given path, followed by its own children while it is expanded.
+fn build_children<L: Lens, T: TreeNode>(
+    cx: &mut Context,
+    roots: L,
+    path: Vec<usize>,
+    content: ItemContent<L, T>,
+) where
+    L::Target: Deref<Target = [T]>,
+{
+    let num_children = roots.map({
+        let path = path.clone();
+        move |roots| child_count(roots, &path)
+    });
+
+    Binding::new(cx, num_children, move |cx, num_children| {
+        for index in 0..num_children.get(cx) {
+            let mut child = path.clone();
+            child.push(index);
+
+            TreeItem::new(cx, roots, child.clone(), content.clone());
+
+            let expanded = TreeView::expanded.map({
+                let child = child.clone();
+                move |expanded| expanded.contains(&child)
+            });
+
+            let content = content.clone();
+            Binding::new(cx, expanded, move |cx, expanded| {
+                if expanded.get(cx) {
+                    build_children(cx, roots, child.clone(), content.clone());
+                }
+            });
+        }
+    });
+}
+
+struct TreeItem {
+    expandable: bool,
+    expanded: bool,
+}
+
+impl TreeItem {
+    fn new<L: Lens, T: TreeNode>(
+        cx: &mut Context,
+        roots: L,
+        path: Vec<usize>,
+        content: ItemContent<L, T>,
+    ) -> Handle<Self>
+    where
+        L::Target: Deref<Target = [T]>,
+    {
+        let depth = path.len() - 1;
+
+        let expandable = roots.map({
+            let path = path.clone();
+            move |roots| child_count(roots, &path) > 0
+        });
+
+        let expanded = TreeView::expanded.map({
+            let path = path.clone();
+            move |expanded| expanded.contains(&path)
+        });
+
+        let item = roots.try_map_ref({
+            let path = path.clone();
+            move |roots| node_at(roots, &path)
+        });
+
+        Self { expandable: false, expanded: false }
+            .build(cx, |cx| {
+                Element::new(cx).class("indent").bind(TreeView::indent, move |handle, indent| {
+                    let indent = indent.get(&handle);
+                    handle.width(Pixels(depth as f32 * indent));
+                });
+
+                Svg::new(
+                    cx,
+                    expanded.map(
+                        |expanded| {
+                            if *expanded {
+                                ICON_CHEVRON_DOWN
+                            } else {
+                                ICON_CHEVRON_RIGHT
+                            }
+                        },
+                    ),
+                )
+                .class("expander")
+                .visibility(expandable)
+                .on_press({
+                    let path = path.clone();
+                    move |cx| cx.emit(TreeViewEvent::ToggleExpanded(path.clone()))
+                });
+
+                content(cx, &path, item);
+            })
+            .role(Role::TreeItem)
+            .toggle_class("expanded", expanded)
+            .bind(expandable, |handle, expandable| {
+                let expandable = expandable.get(&handle);
+                let entity = handle.entity();
+                handle
+                    .modify(|tree_item: &mut TreeItem| tree_item.expandable = expandable)
+                    .context()
+                    .style
+                    .needs_access_update(entity);
+            })
+            .bind(expanded, |handle, expanded| {
+                let expanded = expanded.get(&handle);
+                let entity = handle.entity();
+                handle
+                    .modify(|tree_item: &mut TreeItem| tree_item.expanded = expanded)
+                    .context()
+                    .style
+                    .needs_access_update(entity);
+            })
+            .checked(TreeView::selected.map({
+                let path = path.clone();
+                move |selected| selected.contains(&path)
+            }))
+            .focused_with_visibility(
+                TreeView::focused.map({
+                    let path = path.clone();
+                    move |focused| focused.as_ref() == Some(&path)
+                }),
+                TreeView::focus_visible,
+            )
+            .on_press(move |cx| cx.emit(TreeViewEvent::Select(path.clone())))
+    }
+}
+
+impl View for TreeItem {
+    fn element(&self) -> Option<&'static str> {
+        Some("tree-item")
+    }
+
+    fn accessibility(&self, _cx: &mut AccessContext, node: &mut AccessNode) {
+        if self.expandable {
+            node.set_expanded(self.expanded);
+        }
+    }
+}
+
+// Returns the node with the given path.
+fn node_at<'a, T: TreeNode>(roots: &'a [T], path: &[usize]) -> Option<&'a T> {
+    let (first, rest) = path.split_first()?;
+    rest.iter().try_fold(roots.get(*first)?, |node, index| node.children().get(*index))
+}
+
+// Returns the number of children of the node with the given path, or the number of roots for an empty path.
+fn child_count<T: TreeNode>(roots: &[T], path: &[usize]) -> usize {
+    if path.is_empty() {
+        roots.len()
+    } else {
+        node_at(roots, path).map_or(0, |node| node.children().len())
+    }
+}
+
+// Returns the paths of the nodes which are not within a collapsed node, in the order they are displayed.
+fn visible_paths(
+    expanded: &HashSet<Vec<usize>>,
+    num_children: impl Fn(&[usize]) -> usize,
+) -> Vec<Vec<usize>> {
+    let mut paths = Vec::new();
+    let mut stack: Vec<Vec<usize>> =
+        (0..num_children(&[])).rev().map(|index| vec![index]).collect();
+    while let Some(path) = stack.pop() {
+        if expanded.contains(&path) {
+            for index in (0..num_children(&path)).rev() {
+                let mut child = path.clone();
+                child.push(index);
+                stack.push(child);
+            }
+        }
+
+        paths.push(path);
+    }
+
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{events::EventManager, systems::binding_system};
+
+    #[derive(Clone, PartialEq)]
+    struct Node(Vec<Node>);
+
+    impl Data for Node {
+        fn same(&self, other: &Self) -> bool {
+            self == other
+        }
+    }
+
+    impl TreeNode for Node {
+        fn children(&self) -> &[Self] {
+            &self.0
+        }
+    }
+
+    fn tree() -> Vec<Node> {
+        vec![Node(vec![Node(vec![]), Node(vec![Node(vec![])])]), Node(vec![])]
+    }
+
+    #[test]
+    fn test_child_count() {
+        let roots = tree();
+        assert_eq!(child_count(&roots, &[]), 2);
+        assert_eq!(child_count(&roots, &[0]), 2);
+        assert_eq!(child_count(&roots, &[0, 1]), 1);
+        assert_eq!(child_count(&roots, &[1]), 0);
+        assert_eq!(child_count(&roots, &[2]), 0);
+        assert!(node_at(&roots, &[0, 1, 0]).is_some());
+        assert!(node_at(&roots, &[0, 2]).is_none());
+    }
+
+    #[test]
+    fn test_visible_paths() {
+        let roots = tree();
+        let mut expanded = HashSet::new();
+        assert_eq!(visible_paths(&expanded, |path| child_count(&roots, path)), [vec![0], vec![1]]);
+
+        expanded.insert(vec![0]);
+        expanded.insert(vec![0, 1]);
+        assert_eq!(
+            visible_paths(&expanded, |path| child_count(&roots, path)),
+            [vec![0], vec![0, 0], vec![0, 1], vec![0, 1, 0], vec![1]]
+        );
+
+        // The children of an expanded node within a collapsed node are not visible.
+        expanded.remove(&vec![0]);
+        assert_eq!(visible_paths(&expanded, |path| child_count(&roots, path)), [vec![0], vec![1]]);
+    }
+
+    #[derive(Lens)]
+    struct AppData {
+        roots: Vec<Node>,
+    }
+
+    enum AppEvent {
+        RemoveRoot(usize),
+    }
+
+    impl Model for AppData {
+        fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+            event.map(|app_event, _| match app_event {
+                AppEvent::RemoveRoot(index) => {
+                    self.roots.remove(*index);
+                }
+            });
+        }
+    }
+
+    // Dispatches any pending events and updates the bindings which depend on them.
+    fn update(cx: &mut Context) {
+        let mut event_manager = EventManager::new();
+        while !cx.event_queue.is_empty() {
+            event_manager.flush_events(cx, |_| {});
+            binding_system(cx);
+        }
+    }
+
+    #[test]
+    fn test_prune_nodes() {
+        let cx = &mut Context::new();
+        AppData { roots: tree() }.build(cx);
+        let tree_view = TreeView::new(cx, AppData::roots, |cx, _, node| {
+            Label::new(cx, node.map(|node| node.0.len().to_string()));
+        })
+        .selectable(Selectable::Multi)
+        .entity();
+        update(cx);
+
+        cx.emit_to(tree_view, TreeViewEvent::Expand(vec![0]));
+        cx.emit_to(tree_view, TreeViewEvent::Expand(vec![0, 1]));
+        cx.emit_to(tree_view, TreeViewEvent::Select(vec![0, 1, 0]));
+        cx.emit_to(tree_view, TreeViewEvent::Select(vec![1]));
+        update(cx);
+
+        cx.emit_to(Entity::root(), AppEvent::RemoveRoot(1));
+        update(cx);
+
+        let tree_view =
+            cx.views.get(&tree_view).and_then(|view| view.downcast_ref::<TreeView>()).unwrap();
+        assert_eq!(tree_view.expanded, HashSet::from([vec![0], vec![0, 1]]));
+        assert_eq!(tree_view.selected, BTreeSet::from([vec![0, 1, 0]]));
+        assert_eq!(tree_view.focused, None);
+    }
+}
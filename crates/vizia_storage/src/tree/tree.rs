@@ -355,6 +355,7 @@ where
             self.prev_sibling[pfc.index()] = Some(entity);
         }
 
+        self.prev_sibling[index] = None;
         self.next_sibling[index] = previous_first_child;
 
         self.first_child[parent.index()] = Some(entity);
@@ -600,3 +601,66 @@ where
         TreeIterator::full(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vizia_id::{
+        impl_generational_id, GENERATIONAL_ID_GENERATION_MASK, GENERATIONAL_ID_INDEX_BITS,
+        GENERATIONAL_ID_INDEX_MASK,
+    };
+
+    #[derive(Copy, Clone, PartialEq)]
+    struct Entity(u64);
+
+    impl_generational_id!(Entity);
+
+    /// Creates a tree where the root has three children.
+    fn tree() -> (Tree<Entity>, [Entity; 3]) {
+        let mut tree = Tree::new();
+        let children = [Entity::new(1, 0), Entity::new(2, 0), Entity::new(3, 0)];
+        for child in children {
+            tree.add(child, Entity::root()).unwrap();
+        }
+
+        (tree, children)
+    }
+
+    fn children(tree: &Tree<Entity>) -> Vec<Entity> {
+        Entity::root().child_iter(tree).collect()
+    }
+
+    /// Test for moving the last child to the start of its siblings
+    #[test]
+    fn set_first_child_from_last() {
+        let (mut tree, [a, b, c]) = tree();
+
+        tree.set_first_child(c).unwrap();
+        assert_eq!(children(&tree), [c, a, b]);
+        assert_eq!(tree.get_prev_sibling(c), None);
+        assert_eq!(tree.get_prev_sibling(a), Some(c));
+        assert_eq!(tree.get_next_sibling(b), None);
+        assert_eq!(tree.get_last_child(Entity::root()), Some(&b));
+    }
+
+    /// Test for moving a middle child to the start of its siblings
+    #[test]
+    fn set_first_child_from_middle() {
+        let (mut tree, [a, b, c]) = tree();
+
+        tree.set_first_child(b).unwrap();
+        assert_eq!(children(&tree), [b, a, c]);
+        assert_eq!(tree.get_prev_sibling(b), None);
+        assert_eq!(tree.get_next_sibling(a), Some(c));
+        assert_eq!(tree.get_prev_sibling(c), Some(a));
+    }
+
+    /// Test for moving a child which is already the first child
+    #[test]
+    fn set_first_child_already_first() {
+        let (mut tree, [a, b, c]) = tree();
+
+        assert!(matches!(tree.set_first_child(a), Err(TreeError::AlreadyFirstChild)));
+        assert_eq!(children(&tree), [a, b, c]);
+    }
+}
@@ -59,10 +59,34 @@ pub(crate) fn parse_declaration<'i>(
     input: &mut cssparser::Parser<'i, '_>,
     declarations: &mut DeclarationList<'i>,
     important_declarations: &mut DeclarationList<'i>,
-    _options: &ParserOptions,
+    options: &ParserOptions<'i>,
 ) -> Result<(), ParseError<'i, CustomParseError<'i>>> {
-    let property =
-        input.parse_until_before(Delimiter::Bang, |input| Property::parse_value(name, input))?;
+    let location = input.current_source_location();
+    let property = input
+        .parse_until_before(Delimiter::Bang, |input| Property::parse_value(name.clone(), input))
+        .map_err(|_| {
+            location.new_custom_error(if Property::is_supported(&name) {
+                CustomParseError::InvalidPropertyValue(name)
+            } else {
+                CustomParseError::UnknownProperty(name)
+            })
+        })?;
+
+    // Declarations which fail to parse are kept unparsed, and are only valid if they contain variables which can be
+    // substituted later.
+    if let Property::Unparsed(unparsed) = &property {
+        if !Property::is_supported(&unparsed.name) {
+            options.warn(
+                location.new_custom_error(CustomParseError::UnknownProperty(unparsed.name.clone())),
+            );
+        } else if !unparsed.value.has_variables() {
+            options.warn(
+                location.new_custom_error(CustomParseError::InvalidPropertyValue(
+                    unparsed.name.clone(),
+                )),
+            );
+        }
+    }
 
     let important = input
         .try_parse(|input| {
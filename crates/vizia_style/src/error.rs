@@ -64,6 +64,31 @@ pub enum CustomParseError<'i> {
     AtRuleInvalid(CowRcStr<'i>),
     AtRuleBodyInvalid,
     QualifiedRuleInvalid,
+    UnknownProperty(CowRcStr<'i>),
+    InvalidPropertyValue(CowRcStr<'i>),
+    UnexpectedImportRule,
+}
+
+impl fmt::Display for CustomParseError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use CustomParseError::*;
+        match self {
+            InvalidValue => f.write_str("Invalid value"),
+            InvalidDeclaration => f.write_str("Invalid declaration"),
+            InvalidNesting => f.write_str("Invalid nesting"),
+            SelectorError(err) => f.write_str(&err.reason()),
+            EndOfInput => f.write_str("Unexpected end of input"),
+            UnexpectedToken(token) => write!(f, "Unexpected token: {:?}", token),
+            AtRuleInvalid(name) => write!(f, "Unknown at rule: @{}", name),
+            AtRuleBodyInvalid => f.write_str("Invalid at rule body"),
+            QualifiedRuleInvalid => f.write_str("Invalid rule"),
+            UnknownProperty(name) => write!(f, "Unknown property: {}", name),
+            InvalidPropertyValue(name) => write!(f, "Invalid value for property: {}", name),
+            UnexpectedImportRule => {
+                f.write_str("@import rules must come before all other rules except @layer statements")
+            }
+        }
+    }
 }
 
 impl<'i> From<SelectorParseErrorKind<'i>> for CustomParseError<'i> {
@@ -157,7 +182,7 @@ impl<'i> From<SelectorParseErrorKind<'i>> for SelectorError<'i> {
 }
 
 impl SelectorError<'_> {
-    fn _reason(&self) -> String {
+    fn reason(&self) -> String {
         use SelectorError::*;
         match self {
         NoQualifiedNameInAttributeSelector(token) => format!("No qualified name in attribute selector: {:?}.", token),
@@ -37,4 +37,5 @@ pub use stylesheet::*;
 mod selector;
 pub use selector::*;
 
+pub use cssparser::ToCss;
 pub use selectors;
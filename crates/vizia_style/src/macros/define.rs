@@ -76,16 +76,40 @@ macro_rules! define_property {
                             }
                         }
                     )+
-                    _ => {
+                    _ if name_ref.starts_with("--") => {
                         if let Ok(custom) = CustomProperty::parse(name.clone(), input) {
                             return Ok(Property::Custom(custom));
                         }
                     }
+                    _ => {}
                 }
 
                 input.reset(&state);
                 return Ok(Property::Unparsed(UnparsedProperty::parse(name, input)?));
             }
+
+            /// Returns the name of the property.
+            pub fn name(&self) -> &str {
+                match self {
+                    $(
+                        $name::$variant(_) => $str,
+                    )+
+                    $name::Unparsed(unparsed) => &unparsed.name,
+                    $name::Custom(custom) => &custom.name,
+                }
+            }
+
+            /// Returns true if the property with the given name is supported, or is a custom property.
+            pub fn is_supported(name: &str) -> bool {
+                matches!(name, $($str)|+) || name.starts_with("--")
+            }
+
+            /// Parses the value of the property with the given name from a string, failing if the whole string is not consumed.
+            pub fn parse_string(name: &'i str, value: &'i str) -> Result<Self, cssparser::ParseError<'i, CustomParseError<'i>>> {
+                let mut input = cssparser::ParserInput::new(value);
+                let mut parser = Parser::new(&mut input);
+                parser.parse_entirely(|input| Self::parse_value(name.into(), input))
+            }
         }
     };
 }
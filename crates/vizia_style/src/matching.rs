@@ -59,6 +59,7 @@ mod test {
     pub struct Store {
         element: HashMap<Entity, String>,
         classes: HashMap<Entity, HashSet<String>>,
+        attributes: HashMap<Entity, HashMap<String, String>>,
         pseudo_class: HashMap<Entity, PseudoClass>,
     }
 
@@ -179,6 +180,15 @@ mod test {
                 &<Self::Impl as selectors::SelectorImpl>::AttrValue,
             >,
         ) -> bool {
+            if let Some(value) = self
+                .store
+                .attributes
+                .get(&self.entity)
+                .and_then(|attributes| attributes.get(&local_name.0))
+            {
+                return operation.eval_str(value);
+            }
+
             false
         }
 
@@ -254,6 +264,7 @@ mod test {
         let mut store = Store {
             element: HashMap::new(),
             classes: HashMap::new(),
+            attributes: HashMap::new(),
             pseudo_class: HashMap::new(),
         };
 
@@ -287,6 +298,7 @@ mod test {
         let mut store = Store {
             element: HashMap::new(),
             classes: HashMap::new(),
+            attributes: HashMap::new(),
             pseudo_class: HashMap::new(),
         };
 
@@ -326,6 +338,7 @@ mod test {
         let mut store = Store {
             element: HashMap::new(),
             classes: HashMap::new(),
+            attributes: HashMap::new(),
             pseudo_class: HashMap::new(),
         };
 
@@ -427,6 +440,7 @@ mod test {
         let mut store = Store {
             element: HashMap::new(),
             classes: HashMap::new(),
+            attributes: HashMap::new(),
             pseudo_class: HashMap::new(),
         };
 
@@ -464,4 +478,59 @@ mod test {
             assert!(!result);
         }
     }
+
+    #[test]
+    fn attribute_match() {
+        let mut store = Store {
+            element: HashMap::new(),
+            classes: HashMap::new(),
+            attributes: HashMap::new(),
+            pseudo_class: HashMap::new(),
+        };
+
+        let root = Entity(0);
+        let child = Entity(1);
+
+        let mut attributes = HashMap::new();
+        attributes.insert(String::from("orientation"), String::from("vertical"));
+        attributes.insert(String::from("lang"), String::from("en-GB"));
+        attributes.insert(String::from("data-state"), String::from("open closed"));
+        store.attributes.insert(root, attributes);
+
+        let root_node = Node { entity: root, store: &store };
+
+        let child_node = Node { entity: child, store: &store };
+
+        let selectors = [
+            ("[orientation]", true),
+            ("[orientation=vertical]", true),
+            ("[orientation=\"horizontal\"]", false),
+            ("[orientation=VERTICAL i]", true),
+            ("[data-state~=closed]", true),
+            ("[lang|=en]", true),
+            ("[lang^=en]", true),
+            ("[lang$=GB]", true),
+            ("[lang*=n-G]", true),
+            ("[lang*=fr]", false),
+        ];
+
+        for (selector, matches) in selectors {
+            let selector_list = parse(selector).unwrap();
+            let mut cache = SelectorCaches::default();
+            let mut context = MatchingContext::new(
+                MatchingMode::Normal,
+                None,
+                &mut cache,
+                QuirksMode::NoQuirks,
+                NeedsSelectorFlags::No,
+                MatchingForInvalidation::No,
+            );
+
+            let result = matches_selector_list(&selector_list, &root_node, &mut context);
+            assert_eq!(result, matches, "{}", selector);
+
+            let result = matches_selector_list(&selector_list, &child_node, &mut context);
+            assert!(!result);
+        }
+    }
 }
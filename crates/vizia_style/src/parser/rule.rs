@@ -1,7 +1,9 @@
 use crate::{
-    parse_declaration, CssRule, CssRuleList, CustomParseError, DeclarationBlock, DeclarationList,
-    KeyframeListParser, KeyframesName, KeyframesRule, Location, Parse, ParserOptions,
-    SelectorParser, Selectors, StyleRule,
+    parse_declaration, ContainerQuery, ContainerRule, CssRule, CssRuleList, CustomParseError,
+    DeclarationBlock, DeclarationList, FontFaceDeclarationParser, FontFaceRule, ImportRule,
+    KeyframeListParser, KeyframesName, KeyframesRule, LayerBlockRule, LayerName,
+    LayerStatementRule, Location, MediaQueryList, MediaRule, Parse, ParserOptions, SelectorParser,
+    Selectors, StyleRule,
 };
 use cssparser::*;
 use selectors::{parser::ParseRelative, SelectorList};
@@ -9,8 +11,8 @@ use selectors::{parser::ParseRelative, SelectorList};
 #[derive(PartialEq, PartialOrd)]
 enum State {
     Start = 1,
-    // Layers = 2,
-    // Imports = 3,
+    Layers = 2,
+    Imports = 3,
     // Namespaces = 4,
     Body = 5,
 }
@@ -42,6 +44,11 @@ impl<'a, 'i> TopLevelRuleParser<'a, 'i> {
 pub enum AtRulePrelude<'i> {
     // Property(DashedIdent<'i>),
     Keyframes(KeyframesName<'i>),
+    Media(MediaQueryList),
+    Container(ContainerQuery),
+    FontFace,
+    Import(CowRcStr<'i>, Option<Option<LayerName<'i>>>, MediaQueryList),
+    Layer(Vec<LayerName<'i>>),
 }
 
 impl<'i> AtRuleParser<'i> for TopLevelRuleParser<'_, 'i> {
@@ -54,9 +61,66 @@ impl<'i> AtRuleParser<'i> for TopLevelRuleParser<'_, 'i> {
         name: cssparser::CowRcStr<'i>,
         input: &mut Parser<'i, 't>,
     ) -> Result<Self::Prelude, ParseError<'i, Self::Error>> {
+        // Imports are only valid at the top level of a stylesheet, before any other rules except `@layer` statements.
+        if name.eq_ignore_ascii_case("import") {
+            if self.state > State::Imports {
+                return Err(input.new_custom_error(CustomParseError::UnexpectedImportRule));
+            }
+
+            let url = input.expect_url_or_string()?.clone();
+
+            let layer = if input.try_parse(|input| input.expect_ident_matching("layer")).is_ok() {
+                Some(None)
+            } else if input.try_parse(|input| input.expect_function_matching("layer")).is_ok() {
+                Some(Some(input.parse_nested_block(LayerName::parse)?))
+            } else {
+                None
+            };
+
+            let media = MediaQueryList::parse(input)?;
+
+            return Ok(AtRulePrelude::Import(url, layer, media));
+        }
+
         AtRuleParser::parse_prelude(&mut self.nested(), name, input)
     }
 
+    fn rule_without_block(
+        &mut self,
+        prelude: Self::Prelude,
+        start: &ParserState,
+    ) -> Result<Self::AtRule, ()> {
+        match prelude {
+            AtRulePrelude::Import(url, layer, media) => {
+                self.state = State::Imports;
+                let loc = start.source_location();
+                self.rules.0.push(CssRule::Import(ImportRule {
+                    url,
+                    layer,
+                    media,
+                    loc: Location { line: loc.line, column: loc.column },
+                }));
+                Ok(())
+            }
+
+            // Layer statements may come before imports, but any imports after a layer statement which follows an
+            // import are invalid.
+            AtRulePrelude::Layer(_) => {
+                if self.state <= State::Layers {
+                    self.state = State::Layers;
+                } else {
+                    self.state = State::Body;
+                }
+                AtRuleParser::rule_without_block(&mut self.nested(), prelude, start)
+            }
+
+            _ => {
+                self.state = State::Body;
+                AtRuleParser::rule_without_block(&mut self.nested(), prelude, start)
+            }
+        }
+    }
+
     fn parse_block<'t>(
         &mut self,
         prelude: Self::Prelude,
@@ -123,9 +187,8 @@ impl<'i> NestedRuleParser<'_, 'i> {
             match result {
                 Ok(()) => {}
                 Err((e, _)) => {
+                    // An invalid declaration is ignored without discarding the other declarations in the block.
                     if parse_declarations {
-                        iter.parser.declarations.clear();
-                        iter.parser.important_declarations.clear();
                         errors.push(e);
                     } else {
                         if iter.parser.options.error_recovery {
@@ -178,6 +241,26 @@ impl<'i> AtRuleParser<'i> for NestedRuleParser<'_, 'i> {
                 let name = input.try_parse(KeyframesName::parse)?;
                 Ok(AtRulePrelude::Keyframes(name))
             },
+            "media" => {
+                let query = MediaQueryList::parse(input)?;
+                Ok(AtRulePrelude::Media(query))
+            },
+            "container" => {
+                let query = ContainerQuery::parse(input)?;
+                Ok(AtRulePrelude::Container(query))
+            },
+            "font-face" => {
+                Ok(AtRulePrelude::FontFace)
+            },
+            "layer" => {
+                // A layer block may have no name, in which case it is an anonymous layer.
+                let names = if input.is_exhausted() {
+                    Vec::new()
+                } else {
+                    Vec::<LayerName>::parse(input)?
+                };
+                Ok(AtRulePrelude::Layer(names))
+            },
             _ => Err(input.new_error(BasicParseErrorKind::AtRuleInvalid(name)))
         }
     }
@@ -200,6 +283,64 @@ impl<'i> AtRuleParser<'i> for NestedRuleParser<'_, 'i> {
                 }));
                 Ok(())
             }
+
+            AtRulePrelude::Media(query) => {
+                let (_, rules) = self.parse_nested(input, false)?;
+                self.rules.0.push(CssRule::Media(MediaRule { query, rules, loc }));
+                Ok(())
+            }
+
+            AtRulePrelude::Container(query) => {
+                let (_, rules) = self.parse_nested(input, false)?;
+                self.rules.0.push(CssRule::Container(ContainerRule { query, rules, loc }));
+                Ok(())
+            }
+
+            AtRulePrelude::FontFace => {
+                let mut parser = FontFaceDeclarationParser;
+                let iter = RuleBodyParser::new(input, &mut parser);
+                self.rules.0.push(CssRule::FontFace(FontFaceRule {
+                    properties: iter.filter_map(Result::ok).collect(),
+                    loc,
+                }));
+                Ok(())
+            }
+
+            AtRulePrelude::Layer(names) => {
+                // A layer block can only name a single layer.
+                if names.len() > 1 {
+                    return Err(input.new_error(BasicParseErrorKind::AtRuleBodyInvalid));
+                }
+
+                let (_, rules) = self.parse_nested(input, false)?;
+                self.rules.0.push(CssRule::LayerBlock(LayerBlockRule {
+                    name: names.into_iter().next(),
+                    rules,
+                    loc,
+                }));
+                Ok(())
+            }
+
+            AtRulePrelude::Import(..) => {
+                Err(input.new_error(BasicParseErrorKind::AtRuleBodyInvalid))
+            }
+        }
+    }
+
+    fn rule_without_block(
+        &mut self,
+        prelude: Self::Prelude,
+        start: &ParserState,
+    ) -> Result<Self::AtRule, ()> {
+        match prelude {
+            // A layer statement must name at least one layer.
+            AtRulePrelude::Layer(names) if !names.is_empty() => {
+                let loc = self.loc(start);
+                self.rules.0.push(CssRule::LayerStatement(LayerStatementRule { names, loc }));
+                Ok(())
+            }
+
+            _ => Err(()),
         }
     }
 }
@@ -1,11 +1,15 @@
 use crate::{
-    define_property, Alignment, Angle, BackgroundImage, BackgroundSize, BlendMode, Border,
-    BorderStyle, BorderWidth, ClipPath, Color, CornerRadius, CornerShape, CursorIcon,
-    CustomParseError, CustomProperty, Display, Filter, FontFamily, FontSize, FontSlant,
-    FontVariation, FontWeight, FontWidth, LayoutType, Length, LengthOrPercentage, LineClamp,
-    Opacity, Outline, Overflow, Parse, PointerEvents, Position, PositionType, Rect, Scale, Shadow,
-    TextAlign, TextDecoration, TextDecorationLine, TextDecorationStyle, TextOverflow, TextStroke,
-    TextStrokeStyle, Transform, Transition, Translate, Units, UnparsedProperty, Visibility,
+    define_property, Alignment, Angle, AnimationDirection, AnimationFillMode,
+    AnimationIterationCount, AnimationName, BackgroundImage, BackgroundSize, BlendMode, Border,
+    BorderColor, BorderStyle, BorderStyleKeyword, BorderWidth, BorderWidthValue, ClipPath, Color,
+    Container, ContainerName, ContainerType, Content, CornerRadius, CornerShape, CounterChange,
+    CssAnimation, CursorIcon, CustomParseError, CustomProperty, Display, Duration, EasingFunction,
+    Filter, FontFamily, FontFeature, FontSize, FontSlant, FontVariation, FontWeight, FontWidth,
+    LayoutType, Length, LengthOrPercentage, LineClamp, LineHeight, Opacity, Outline, Overflow,
+    Parse, PointerEvents, Position, PositionType, Rect, Scale, Shadow, Spacing, TextAlign,
+    TextDecoration, TextDecorationLine, TextDecorationStyle, TextOverflow, TextShadow, TextStroke,
+    TextStrokeStyle, TextTransform, Transform, Transition, Translate, Units, UnparsedProperty,
+    Visibility,
 };
 use cssparser::Parser;
 
@@ -28,6 +32,19 @@ define_property! {
 
         "alignment": Alignment(Alignment),
 
+        // Grid
+        "grid-columns": GridColumns(Vec<Units>),
+        "grid-rows": GridRows(Vec<Units>),
+        "column-start": ColumnStart(usize),
+        "column-span": ColumnSpan(usize),
+        "row-start": RowStart(usize),
+        "row-span": RowSpan(usize),
+
+        // Containers
+        "container": Container(Container),
+        "container-name": ContainerName(ContainerName),
+        "container-type": ContainerType(ContainerType),
+
         // Position and Size
         "space": Space(Units),
         "left": Left(Units),
@@ -68,14 +85,17 @@ define_property! {
 
         // Border Shorthand
         "border": Border(Border),
+        "border-top": BorderTop(Border),
+        "border-right": BorderRight(Border),
+        "border-bottom": BorderBottom(Border),
+        "border-left": BorderLeft(Border),
 
         // Border Color
-        "border-color": BorderColor(Color),
-        // TODO: Support coloring individual borders.
-        // "border-top-color": BorderTopColor(Color),
-        // "border-right-color": BorderRightColor(Color),
-        // "border-bottom-color": BorderBottomColor(Color),
-        // "border-left-color": BorderLeftColor(Color),
+        "border-color": BorderColor(BorderColor),
+        "border-top-color": BorderTopColor(Color),
+        "border-right-color": BorderRightColor(Color),
+        "border-bottom-color": BorderBottomColor(Color),
+        "border-left-color": BorderLeftColor(Color),
 
         // Corner Shape
         "corner-shape": CornerShape(Rect<CornerShape>),
@@ -92,19 +112,18 @@ define_property! {
         "corner-bottom-right-radius": CornerBottomRightRadius(LengthOrPercentage),
 
         // Border Style
-        // TODO: Support styling borders.
         "border-style": BorderStyle(BorderStyle),
-        // "border-top-style": BorderTopStyle(BorderStyleKeyword),
-        // "border-right-style": BorderRightStyle(BorderStyleKeyword),
-        // "border-bottom-style": BorderBottomStyle(BorderStyleKeyword),
-        // "border-left-style": BorderLeftStyle(BorderStyleKeyword),
+        "border-top-style": BorderTopStyle(BorderStyleKeyword),
+        "border-right-style": BorderRightStyle(BorderStyleKeyword),
+        "border-bottom-style": BorderBottomStyle(BorderStyleKeyword),
+        "border-left-style": BorderLeftStyle(BorderStyleKeyword),
 
         // Border Width
         "border-width": BorderWidth(BorderWidth),
-        // "border-top-width": BorderTopWidth(BorderWidthValue),
-        // "border-right-width": BorderRightWidth(BorderWidthValue),
-        // "border-bottom-width": BorderBottomWidth(BorderWidthValue),
-        // "border-left-width": BorderLeftWidth(BorderWidthValue),
+        "border-top-width": BorderTopWidth(BorderWidthValue),
+        "border-right-width": BorderRightWidth(BorderWidthValue),
+        "border-bottom-width": BorderBottomWidth(BorderWidthValue),
+        "border-left-width": BorderLeftWidth(BorderWidthValue),
 
 
         // ----- Outline -----
@@ -150,8 +169,10 @@ define_property! {
         "font-variation-settings": FontVariationSettings(Vec<FontVariation>),
         "font-weight": FontWeight(FontWeight),
         "font-slant": FontSlant(FontSlant),
+        "font-style": FontStyle(FontSlant),
         "font-width": FontWidth(FontWidth),
-        "selection-color": SelectionColor(Color), // TODO: Remove this once we have the pseudoselector version.
+        "font-feature-settings": FontFeatureSettings(Vec<FontFeature>),
+        "selection-color": SelectionColor(Color), // Deprecated: use `background-color` in a `::selection` rule.
         "caret-color": CaretColor(Color),
         "text-wrap": TextWrap(bool),
         "text-align": TextAlign(TextAlign),
@@ -162,6 +183,12 @@ define_property! {
         "text-stroke": TextStroke(TextStroke),
         "text-stroke-width": TextStrokeWidth(Length),
         "text-stroke-style": TextStrokeStyle(TextStrokeStyle),
+        "text-shadow": TextShadow(Vec<TextShadow>),
+        "text-transform": TextTransform(TextTransform),
+        "text-indent": TextIndent(Length),
+        "line-height": LineHeight(LineHeight),
+        "letter-spacing": LetterSpacing(Spacing),
+        "word-spacing": WordSpacing(Spacing),
         "underline-style": UnderlineStyle(TextDecorationStyle),
         "underline-thickness": UnderlineThickness(LengthOrPercentage),
         "underline-color": UnderlineColor(Color),
@@ -175,11 +202,20 @@ define_property! {
         // Shadow
         "shadow": Shadow(Vec<Shadow>),
 
-        // Backdrop Filter
-        "backdrop-filter": BackdropFilter(Filter),
+        // Filters
+        "filter": Filter(Vec<Filter>),
+        "backdrop-filter": BackdropFilter(Vec<Filter>),
 
         // Animations
         "transition": Transition(Vec<Transition>),
+        "animation": Animation(Vec<CssAnimation<'i>>),
+        "animation-name": AnimationName(Vec<AnimationName<'i>>),
+        "animation-duration": AnimationDuration(Vec<Duration>),
+        "animation-delay": AnimationDelay(Vec<Duration>),
+        "animation-timing-function": AnimationTimingFunction(Vec<EasingFunction>),
+        "animation-iteration-count": AnimationIterationCount(Vec<AnimationIterationCount>),
+        "animation-direction": AnimationDirection(Vec<AnimationDirection>),
+        "animation-fill-mode": AnimationFillMode(Vec<AnimationFillMode>),
 
         // Transform
         "transform": Transform(Vec<Transform>),
@@ -191,6 +227,11 @@ define_property! {
         // Cursor
         "cursor": Cursor(CursorIcon),
         "pointer-events": PointerEvents(PointerEvents),
+
+        // Generated Content
+        "content": Content(Content),
+        "counter-reset": CounterReset(Vec<CounterChange>),
+        "counter-increment": CounterIncrement(Vec<CounterChange>),
     }
 }
 
@@ -6,20 +6,27 @@ use crate::Selectors;
 pub enum PseudoElement {
     After,
     Before,
+    Marker,
+    Placeholder,
     Selection,
     Custom(String),
 }
 
 impl ToCss for PseudoElement {
-    fn to_css<W>(&self, _dest: &mut W) -> std::fmt::Result
+    fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
     where
         W: std::fmt::Write,
     {
-        match *self {
-            PseudoElement::After => todo!(),
-            PseudoElement::Before => todo!(),
-            PseudoElement::Selection => todo!(),
-            PseudoElement::Custom(_) => todo!(),
+        match self {
+            PseudoElement::After => dest.write_str("::after"),
+            PseudoElement::Before => dest.write_str("::before"),
+            PseudoElement::Marker => dest.write_str("::marker"),
+            PseudoElement::Placeholder => dest.write_str("::placeholder"),
+            PseudoElement::Selection => dest.write_str("::selection"),
+            PseudoElement::Custom(name) => {
+                dest.write_str("::")?;
+                dest.write_str(name)
+            }
         }
     }
 }
@@ -0,0 +1,74 @@
+use cssparser::*;
+
+use crate::{
+    parse_container_name, CssRuleList, CustomParseError, Location, MediaCondition, MediaState,
+    Parse,
+};
+
+/// A rule whose nested rules are only applied to views within a container whose size matches its query,
+/// e.g. `@container sidebar (min-width: 400px) {}`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ContainerRule<'i> {
+    pub query: ContainerQuery,
+    pub rules: CssRuleList<'i>,
+    pub loc: Location,
+}
+
+/// The query of a `@container` rule, which is evaluated against the size of the nearest ancestor container with
+/// the given name, or the nearest ancestor container if no name is given.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerQuery {
+    pub name: Option<String>,
+    pub condition: MediaCondition,
+}
+
+impl ContainerQuery {
+    /// Returns true if the query matches a container with the given logical width and height, where the height of
+    /// an `inline-size` container is unknown.
+    pub fn matches(&self, width: f32, height: Option<f32>) -> bool {
+        // Comparisons against an unknown height are always false.
+        let media = MediaState { width, height: height.unwrap_or(f32::NAN), ..Default::default() };
+        self.condition.matches(&media)
+    }
+}
+
+impl<'i> Parse<'i> for ContainerQuery {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let name = input.try_parse(parse_container_name).ok();
+        let condition = MediaCondition::parse(input)?;
+        Ok(ContainerQuery { name, condition })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(query: &str, width: f32, height: Option<f32>) -> bool {
+        let mut parser_input = ParserInput::new(query);
+        let mut parser = Parser::new(&mut parser_input);
+        parser.parse_entirely(ContainerQuery::parse).unwrap().matches(width, height)
+    }
+
+    #[test]
+    fn container_query_name() {
+        let mut parser_input = ParserInput::new("sidebar (min-width: 400px)");
+        let mut parser = Parser::new(&mut parser_input);
+        let query = parser.parse_entirely(ContainerQuery::parse).unwrap();
+        assert_eq!(query.name.as_deref(), Some("sidebar"));
+
+        let mut parser_input = ParserInput::new("not (min-width: 400px)");
+        let mut parser = Parser::new(&mut parser_input);
+        let query = parser.parse_entirely(ContainerQuery::parse).unwrap();
+        assert_eq!(query.name, None);
+    }
+
+    #[test]
+    fn container_query_matching() {
+        assert!(matches("(min-width: 400px)", 500.0, Some(300.0)));
+        assert!(!matches("(min-width: 400px)", 300.0, Some(300.0)));
+        assert!(matches("panel (width > 200px) and (height < 400px)", 300.0, Some(300.0)));
+        assert!(matches("(orientation: landscape)", 500.0, Some(300.0)));
+        assert!(!matches("(height < 400px)", 300.0, None));
+    }
+}
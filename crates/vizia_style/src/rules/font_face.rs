@@ -0,0 +1,216 @@
+use cssparser::*;
+
+use crate::{CustomParseError, FontFamily, FontSlant, FontWeight, Location, Parse, Url};
+
+/// A `@font-face` rule, which defines a font face that can be referred to by the `font-family` property.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FontFaceRule<'i> {
+    pub properties: Vec<FontFaceProperty<'i>>,
+    pub loc: Location,
+}
+
+/// A descriptor within a `@font-face` rule.
+#[derive(Debug, PartialEq, Clone)]
+pub enum FontFaceProperty<'i> {
+    /// The `src` descriptor, a list of locations to load the font face from in order of preference.
+    Source(Vec<FontFaceSource<'i>>),
+    /// The `font-family` descriptor, the family name used to refer to the font face.
+    FontFamily(FontFamily<'i>),
+    /// The `font-weight` descriptor, the range of weights covered by the font face.
+    FontWeight(FontWeightRange),
+    /// The `font-style` descriptor, the slant of the font face.
+    FontStyle(FontSlant),
+}
+
+/// A location to load a font face from.
+#[derive(Debug, PartialEq, Clone)]
+pub enum FontFaceSource<'i> {
+    /// A font file, along with an optional format hint.
+    Url(Url<'i>, Option<CowRcStr<'i>>),
+    /// A font installed on the system, given by its full name.
+    Local(FontFamily<'i>),
+}
+
+impl<'i> Parse<'i> for FontFaceSource<'i> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if input.try_parse(|input| input.expect_function_matching("local")).is_ok() {
+            let family = input.parse_nested_block(FontFamily::parse)?;
+            return Ok(FontFaceSource::Local(family));
+        }
+
+        let url = Url::parse(input)?;
+
+        let format = input
+            .try_parse(|input| {
+                input.expect_function_matching("format")?;
+                input.parse_nested_block(|input| {
+                    input.expect_ident_or_string().cloned().map_err(ParseError::from)
+                })
+            })
+            .ok();
+
+        Ok(FontFaceSource::Url(url, format))
+    }
+}
+
+impl<'i> Parse<'i> for Vec<FontFaceSource<'i>> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        input.parse_comma_separated(FontFaceSource::parse)
+    }
+}
+
+/// The range of weights covered by a font face, such as `400` or `100 900` for a variable font.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FontWeightRange(pub FontWeight, pub FontWeight);
+
+impl Default for FontWeightRange {
+    fn default() -> Self {
+        FontWeightRange(FontWeight::default(), FontWeight::default())
+    }
+}
+
+impl FontWeightRange {
+    /// Returns true if the given weight falls within the range.
+    pub fn contains(&self, weight: FontWeight) -> bool {
+        self.0 .0 <= weight.0 && weight.0 <= self.1 .0
+    }
+}
+
+impl<'i> Parse<'i> for FontWeightRange {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let start = FontWeight::parse(input)?;
+        let end = input.try_parse(FontWeight::parse).unwrap_or(start);
+
+        // A reversed range is treated as if the bounds were swapped.
+        if end.0 < start.0 {
+            Ok(FontWeightRange(end, start))
+        } else {
+            Ok(FontWeightRange(start, end))
+        }
+    }
+}
+
+/// Parses the descriptors within the block of a `@font-face` rule.
+pub struct FontFaceDeclarationParser;
+
+impl<'i> DeclarationParser<'i> for FontFaceDeclarationParser {
+    type Declaration = FontFaceProperty<'i>;
+    type Error = CustomParseError<'i>;
+
+    fn parse_value<'t>(
+        &mut self,
+        name: CowRcStr<'i>,
+        input: &mut Parser<'i, 't>,
+    ) -> Result<Self::Declaration, ParseError<'i, Self::Error>> {
+        let location = input.current_source_location();
+        let property = match_ignore_ascii_case! { &name,
+            "src" => FontFaceProperty::Source(Vec::<FontFaceSource>::parse(input)?),
+            "font-family" => FontFaceProperty::FontFamily(FontFamily::parse(input)?),
+            "font-weight" => FontFaceProperty::FontWeight(FontWeightRange::parse(input)?),
+            "font-style" => FontFaceProperty::FontStyle(FontSlant::parse(input)?),
+            _ => return Err(location.new_custom_error(CustomParseError::InvalidDeclaration)),
+        };
+
+        input.expect_exhausted()?;
+
+        Ok(property)
+    }
+}
+
+impl<'i> AtRuleParser<'i> for FontFaceDeclarationParser {
+    type Prelude = ();
+    type AtRule = FontFaceProperty<'i>;
+    type Error = CustomParseError<'i>;
+}
+
+impl<'i> QualifiedRuleParser<'i> for FontFaceDeclarationParser {
+    type Prelude = ();
+    type QualifiedRule = FontFaceProperty<'i>;
+    type Error = CustomParseError<'i>;
+}
+
+impl<'i> RuleBodyItemParser<'i, FontFaceProperty<'i>, CustomParseError<'i>>
+    for FontFaceDeclarationParser
+{
+    fn parse_qualified(&self) -> bool {
+        false
+    }
+
+    fn parse_declarations(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        FontFaceSource, assert_source,
+
+        custom {
+            success {
+                "url(fonts/Inter-Bold.ttf)" => FontFaceSource::Url(Url { url: "fonts/Inter-Bold.ttf".into() }, None),
+                "\"Inter.woff2\" format(\"woff2\")" => FontFaceSource::Url(Url { url: "Inter.woff2".into() }, Some("woff2".into())),
+                "local(Inter)" => FontFaceSource::Local(FontFamily::Named("Inter".into())),
+                "local(\"Fira Sans\")" => FontFaceSource::Local(FontFamily::Named("Fira Sans".into())),
+            }
+
+            failure {
+                "test",
+                "local()",
+            }
+        }
+    }
+
+    assert_parse! {
+        FontWeightRange, assert_font_weight_range,
+
+        custom {
+            success {
+                "bold" => FontWeightRange(FontWeight(700), FontWeight(700)),
+                "100 900" => FontWeightRange(FontWeight(100), FontWeight(900)),
+                "700 300" => FontWeightRange(FontWeight(300), FontWeight(700)),
+                "normal bold" => FontWeightRange(FontWeight(400), FontWeight(700)),
+            }
+
+            failure {
+                "test",
+                "italic",
+            }
+        }
+    }
+
+    #[test]
+    fn parse_font_face_rule() {
+        let css = r#"
+            @font-face {
+                font-family: "Inter";
+                src: url(Inter-Variable.ttf), local(Inter);
+                font-weight: 100 900;
+                font-style: italic;
+                unicode-range: U+0000-00FF;
+            }
+        "#;
+
+        let stylesheet = crate::StyleSheet::parse(css, crate::ParserOptions::new()).unwrap();
+        let rule = match &stylesheet.rules.0[..] {
+            [crate::CssRule::FontFace(rule)] => rule,
+            rules => panic!("expected a single @font-face rule, found {:?}", rules),
+        };
+
+        assert_eq!(
+            rule.properties,
+            vec![
+                FontFaceProperty::FontFamily(FontFamily::Named("Inter".into())),
+                FontFaceProperty::Source(vec![
+                    FontFaceSource::Url(Url { url: "Inter-Variable.ttf".into() }, None),
+                    FontFaceSource::Local(FontFamily::Named("Inter".into())),
+                ]),
+                FontFaceProperty::FontWeight(FontWeightRange(FontWeight(100), FontWeight(900))),
+                FontFaceProperty::FontStyle(FontSlant::Italic),
+            ]
+        );
+    }
+}
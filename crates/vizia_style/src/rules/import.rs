@@ -0,0 +1,67 @@
+use cssparser::*;
+
+use crate::{LayerName, Location, MediaQueryList};
+
+/// An `@import` rule, which includes the rules of another stylesheet.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ImportRule<'i> {
+    /// The url of the imported stylesheet, which is relative to the importing stylesheet.
+    pub url: CowRcStr<'i>,
+    /// The cascade layer the imported rules are added to. `Some(None)` is an anonymous layer, given by the `layer`
+    /// keyword without a name.
+    pub layer: Option<Option<LayerName<'i>>>,
+    /// The media queries which must match for the imported rules to apply.
+    pub media: MediaQueryList,
+    pub loc: Location,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CssRule, CustomParseError, ParserOptions, StyleSheet};
+
+    #[test]
+    fn parse_import_rules() {
+        let css = r#"
+            @layer theme;
+            @import url("base.css") layer(theme) (prefers-color-scheme: dark);
+            @import "reset.css" layer;
+
+            .foo {
+                width: 10px;
+            }
+
+            @import "late.css";
+        "#;
+
+        let warnings = std::sync::Arc::new(std::sync::RwLock::new(Vec::new()));
+        let mut options = ParserOptions::new();
+        options.warnings = Some(warnings.clone());
+
+        let stylesheet = StyleSheet::parse(css, options).unwrap();
+        let imports = stylesheet
+            .rules
+            .0
+            .iter()
+            .filter_map(|rule| match rule {
+                CssRule::Import(import) => Some(import),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(imports.len(), 2);
+
+        assert_eq!(imports[0].url.as_ref(), "base.css");
+        assert_eq!(imports[0].layer, Some(Some(LayerName(vec!["theme".into()]))));
+        assert_eq!(imports[0].media.0.len(), 1);
+
+        assert_eq!(imports[1].url.as_ref(), "reset.css");
+        assert_eq!(imports[1].layer, Some(None));
+        assert!(imports[1].media.0.is_empty());
+
+        // Imports after other rules are ignored.
+        let warnings = warnings.read().unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].kind, CustomParseError::UnexpectedImportRule);
+    }
+}
@@ -0,0 +1,136 @@
+use cssparser::*;
+
+use crate::{CssRuleList, CustomParseError, Location, Parse};
+
+/// The name of a cascade layer, such as `theme` or `framework.components` for a layer nested within another.
+#[derive(Debug, PartialEq, Clone)]
+pub struct LayerName<'i>(pub Vec<CowRcStr<'i>>);
+
+impl LayerName<'_> {
+    /// Returns the full name of the layer, with the names of nested layers separated by dots.
+    pub fn as_string(&self) -> String {
+        self.0.iter().map(|name| name.as_ref()).collect::<Vec<_>>().join(".")
+    }
+}
+
+impl<'i> Parse<'i> for LayerName<'i> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let mut names = vec![input.expect_ident()?.clone()];
+
+        // The names of nested layers are separated by dots without any whitespace.
+        while let Ok(name) =
+            input.try_parse(|input| -> Result<_, ParseError<'i, CustomParseError<'i>>> {
+                let location = input.current_source_location();
+                match input.next_including_whitespace()? {
+                    Token::Delim('.') => {}
+                    token => return Err(location.new_unexpected_token_error(token.clone())),
+                }
+
+                let location = input.current_source_location();
+                match input.next_including_whitespace()? {
+                    Token::Ident(name) => Ok(name.clone()),
+                    token => Err(location.new_unexpected_token_error(token.clone())),
+                }
+            })
+        {
+            names.push(name);
+        }
+
+        Ok(LayerName(names))
+    }
+}
+
+impl<'i> Parse<'i> for Vec<LayerName<'i>> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        input.parse_comma_separated(LayerName::parse)
+    }
+}
+
+/// A `@layer` statement, such as `@layer theme, components;`, which declares the order of cascade layers.
+#[derive(Debug, PartialEq, Clone)]
+pub struct LayerStatementRule<'i> {
+    pub names: Vec<LayerName<'i>>,
+    pub loc: Location,
+}
+
+/// A `@layer` block, which adds its rules to a cascade layer, or to a new anonymous layer if it has no name.
+#[derive(Debug, PartialEq, Clone)]
+pub struct LayerBlockRule<'i> {
+    pub name: Option<LayerName<'i>>,
+    pub rules: CssRuleList<'i>,
+    pub loc: Location,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        LayerName, assert_layer_name,
+
+        custom {
+            success {
+                "theme" => LayerName(vec!["theme".into()]),
+                "framework.components" => LayerName(vec!["framework".into(), "components".into()]),
+                "a.b.c" => LayerName(vec!["a".into(), "b".into(), "c".into()]),
+            }
+
+            failure {
+                "10px",
+                ".theme",
+            }
+        }
+    }
+
+    #[test]
+    fn parse_layer_rules() {
+        let css = r#"
+            @layer theme, framework.components;
+
+            @layer theme {
+                .foo {
+                    width: 10px;
+                }
+            }
+
+            @layer {
+                .bar {
+                    width: 20px;
+                }
+            }
+
+            @layer a, b {
+                .baz {
+                    width: 30px;
+                }
+            }
+        "#;
+
+        let stylesheet = crate::StyleSheet::parse(css, crate::ParserOptions::new()).unwrap();
+
+        match &stylesheet.rules.0[..] {
+            [crate::CssRule::LayerStatement(statement), crate::CssRule::LayerBlock(named), crate::CssRule::LayerBlock(anonymous)] =>
+            {
+                assert_eq!(
+                    statement.names,
+                    vec![
+                        LayerName(vec!["theme".into()]),
+                        LayerName(vec!["framework".into(), "components".into()]),
+                    ]
+                );
+                assert_eq!(named.name, Some(LayerName(vec!["theme".into()])));
+                assert_eq!(named.rules.0.len(), 1);
+                assert_eq!(anonymous.name, None);
+                assert_eq!(anonymous.rules.0.len(), 1);
+            }
+            rules => panic!("expected a layer statement and two layer blocks, found {:?}", rules),
+        }
+    }
+
+    #[test]
+    fn layer_name_as_string() {
+        let name = LayerName(vec!["framework".into(), "components".into()]);
+        assert_eq!(name.as_string(), "framework.components");
+    }
+}
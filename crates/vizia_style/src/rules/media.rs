@@ -0,0 +1,613 @@
+use cssparser::*;
+
+use crate::{CssRuleList, CustomParseError, Length, Location, Parse};
+
+/// A rule whose nested rules are only applied while its media query list matches, e.g. `@media (max-width: 600px) {}`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct MediaRule<'i> {
+    pub query: MediaQueryList,
+    pub rules: CssRuleList<'i>,
+    pub loc: Location,
+}
+
+/// The state of the window and environment which media queries are evaluated against.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MediaState {
+    /// The logical width of the window.
+    pub width: f32,
+    /// The logical height of the window.
+    pub height: f32,
+    /// The scale factor of the window, in dots per pixel.
+    pub scale_factor: f32,
+    /// The preferred color scheme of the application.
+    pub color_scheme: ColorScheme,
+    /// Whether non-essential motion should be reduced.
+    pub reduced_motion: bool,
+}
+
+impl Default for MediaState {
+    fn default() -> Self {
+        Self {
+            width: 0.0,
+            height: 0.0,
+            scale_factor: 1.0,
+            color_scheme: ColorScheme::default(),
+            reduced_motion: false,
+        }
+    }
+}
+
+/// A comma separated list of media queries, which matches if any of the queries match.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MediaQueryList(pub Vec<MediaQuery>);
+
+impl MediaQueryList {
+    /// Returns true if the media query list matches the given media state.
+    pub fn matches(&self, media: &MediaState) -> bool {
+        self.0.is_empty() || self.0.iter().any(|query| query.matches(media))
+    }
+}
+
+impl<'i> Parse<'i> for MediaQueryList {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if input.is_exhausted() {
+            return Ok(MediaQueryList::default());
+        }
+
+        // An invalid query doesn't invalidate the list, but is treated as `not all`.
+        input
+            .parse_comma_separated(|input| {
+                if let Ok(query) = input.try_parse(MediaQuery::parse) {
+                    return Ok(query);
+                }
+
+                while input.next().is_ok() {}
+
+                Ok(MediaQuery {
+                    qualifier: Some(MediaQualifier::Not),
+                    media_type: MediaType::All,
+                    condition: None,
+                })
+            })
+            .map(MediaQueryList)
+    }
+}
+
+/// A single media query, e.g. `screen and (min-width: 600px)` or `(prefers-color-scheme: dark)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaQuery {
+    pub qualifier: Option<MediaQualifier>,
+    pub media_type: MediaType,
+    pub condition: Option<MediaCondition>,
+}
+
+impl MediaQuery {
+    /// Returns true if the media query matches the given media state.
+    pub fn matches(&self, media: &MediaState) -> bool {
+        let matches = matches!(self.media_type, MediaType::All | MediaType::Screen)
+            && self.condition.as_ref().map_or(true, |condition| condition.matches(media));
+
+        if self.qualifier == Some(MediaQualifier::Not) {
+            !matches
+        } else {
+            matches
+        }
+    }
+}
+
+impl<'i> Parse<'i> for MediaQuery {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if let Ok(query) = input.try_parse(|input| {
+            let qualifier = input.try_parse(MediaQualifier::parse).ok();
+            let media_type = MediaType::parse(input)?;
+            let condition = if input.try_parse(|input| input.expect_ident_matching("and")).is_ok() {
+                Some(MediaCondition::parse_with_operators(input, false)?)
+            } else {
+                None
+            };
+
+            Ok::<_, ParseError<'i, CustomParseError<'i>>>(MediaQuery {
+                qualifier,
+                media_type,
+                condition,
+            })
+        }) {
+            return Ok(query);
+        }
+
+        let condition = MediaCondition::parse_with_operators(input, true)?;
+
+        Ok(MediaQuery { qualifier: None, media_type: MediaType::All, condition: Some(condition) })
+    }
+}
+
+/// The qualifier at the start of a media query with a media type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaQualifier {
+    Only,
+    Not,
+}
+
+impl<'i> Parse<'i> for MediaQualifier {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let location = input.current_source_location();
+        let ident = input.expect_ident()?;
+        Ok(match_ignore_ascii_case! { ident,
+            "only" => MediaQualifier::Only,
+            "not" => MediaQualifier::Not,
+            _ => return Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
+        })
+    }
+}
+
+/// The type of media of a media query. Only `all` and `screen` match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MediaType {
+    All,
+    Screen,
+    Print,
+    Custom(String),
+}
+
+impl<'i> Parse<'i> for MediaType {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let location = input.current_source_location();
+        let ident = input.expect_ident()?;
+        Ok(match_ignore_ascii_case! { ident,
+            "all" => MediaType::All,
+            "screen" => MediaType::Screen,
+            "print" => MediaType::Print,
+            "not" | "only" | "and" | "or" => {
+                return Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
+            },
+            _ => MediaType::Custom(ident.to_string()),
+        })
+    }
+}
+
+/// A condition of a media query, made up of media features combined with `not`, `and`, and `or`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MediaCondition {
+    Feature(MediaFeature),
+    Not(Box<MediaCondition>),
+    And(Vec<MediaCondition>),
+    Or(Vec<MediaCondition>),
+}
+
+impl MediaCondition {
+    /// Returns true if the condition matches the given media state.
+    pub fn matches(&self, media: &MediaState) -> bool {
+        match self {
+            MediaCondition::Feature(feature) => feature.matches(media),
+            MediaCondition::Not(condition) => !condition.matches(media),
+            MediaCondition::And(conditions) => {
+                conditions.iter().all(|condition| condition.matches(media))
+            }
+            MediaCondition::Or(conditions) => {
+                conditions.iter().any(|condition| condition.matches(media))
+            }
+        }
+    }
+
+    // Conditions following a media type can't contain a top-level `or`.
+    fn parse_with_operators<'i, 't>(
+        input: &mut Parser<'i, 't>,
+        allow_or: bool,
+    ) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if input.try_parse(|input| input.expect_ident_matching("not")).is_ok() {
+            return Ok(MediaCondition::Not(Box::new(Self::parse_in_parens(input)?)));
+        }
+
+        let first = Self::parse_in_parens(input)?;
+
+        let location = input.current_source_location();
+        let Ok(operator) = input.try_parse(|input| input.expect_ident_cloned()) else {
+            return Ok(first);
+        };
+
+        let is_and = match_ignore_ascii_case! { &operator,
+            "and" => true,
+            "or" => false,
+            _ => return Err(location.new_unexpected_token_error(Token::Ident(operator.clone()))),
+        };
+
+        if !is_and && !allow_or {
+            return Err(location.new_unexpected_token_error(Token::Ident(operator.clone())));
+        }
+
+        // Operators can't be mixed without parentheses.
+        let mut conditions = vec![first, Self::parse_in_parens(input)?];
+        while input.try_parse(|input| input.expect_ident_matching(&operator)).is_ok() {
+            conditions.push(Self::parse_in_parens(input)?);
+        }
+
+        Ok(if is_and { MediaCondition::And(conditions) } else { MediaCondition::Or(conditions) })
+    }
+
+    fn parse_in_parens<'i, 't>(
+        input: &mut Parser<'i, 't>,
+    ) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        input.expect_parenthesis_block()?;
+        input.parse_nested_block(|input| {
+            if let Ok(condition) = input.try_parse(|input| {
+                let condition = Self::parse_with_operators(input, true)?;
+                input.expect_exhausted()?;
+                Ok::<_, ParseError<'i, CustomParseError<'i>>>(condition)
+            }) {
+                return Ok(condition);
+            }
+
+            MediaFeature::parse(input).map(MediaCondition::Feature)
+        })
+    }
+}
+
+impl<'i> Parse<'i> for MediaCondition {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        Self::parse_with_operators(input, true)
+    }
+}
+
+/// A media feature which can be compared against a range of values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeFeature {
+    /// The logical width of the window in pixels.
+    Width,
+    /// The logical height of the window in pixels.
+    Height,
+    /// The scale factor of the window in dots per pixel.
+    Resolution,
+}
+
+impl RangeFeature {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match_ignore_ascii_case! { name,
+            "width" => RangeFeature::Width,
+            "height" => RangeFeature::Height,
+            "resolution" => RangeFeature::Resolution,
+            _ => return None,
+        })
+    }
+
+    fn parse_value<'i, 't>(
+        &self,
+        input: &mut Parser<'i, 't>,
+    ) -> Result<f32, ParseError<'i, CustomParseError<'i>>> {
+        let location = input.current_source_location();
+        match self {
+            RangeFeature::Width | RangeFeature::Height => Length::parse(input)?
+                .to_px()
+                .ok_or(location.new_custom_error(CustomParseError::InvalidValue)),
+
+            RangeFeature::Resolution => match input.next()? {
+                Token::Dimension { value, unit, .. } => Ok(match_ignore_ascii_case! { unit,
+                    "dppx" | "x" => *value,
+                    "dpi" => *value / 96.0,
+                    "dpcm" => *value * 2.54 / 96.0,
+                    _ => return Err(location.new_custom_error(CustomParseError::InvalidValue)),
+                }),
+
+                token => Err(location.new_unexpected_token_error(token.clone())),
+            },
+        }
+    }
+
+    fn value(&self, media: &MediaState) -> f32 {
+        match self {
+            RangeFeature::Width => media.width,
+            RangeFeature::Height => media.height,
+            RangeFeature::Resolution => media.scale_factor,
+        }
+    }
+}
+
+/// The comparison used by a range media feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaComparison {
+    Equal,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl MediaComparison {
+    fn compare(&self, value: f32, bound: f32) -> bool {
+        match self {
+            MediaComparison::Equal => value == bound,
+            MediaComparison::Less => value < bound,
+            MediaComparison::LessOrEqual => value <= bound,
+            MediaComparison::Greater => value > bound,
+            MediaComparison::GreaterOrEqual => value >= bound,
+        }
+    }
+
+    // Returns the comparison with the operands swapped, so `400px < width` becomes `width > 400px`.
+    fn flip(&self) -> Self {
+        match self {
+            MediaComparison::Equal => MediaComparison::Equal,
+            MediaComparison::Less => MediaComparison::Greater,
+            MediaComparison::LessOrEqual => MediaComparison::GreaterOrEqual,
+            MediaComparison::Greater => MediaComparison::Less,
+            MediaComparison::GreaterOrEqual => MediaComparison::LessOrEqual,
+        }
+    }
+}
+
+impl<'i> Parse<'i> for MediaComparison {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let location = input.current_source_location();
+        let comparison = match input.next()? {
+            Token::Delim('=') => return Ok(MediaComparison::Equal),
+            Token::Delim('<') => MediaComparison::Less,
+            Token::Delim('>') => MediaComparison::Greater,
+            token => return Err(location.new_unexpected_token_error(token.clone())),
+        };
+
+        // The `=` of `<=` and `>=` can't be separated by whitespace.
+        let or_equal = input
+            .try_parse(|input| match input.next_including_whitespace() {
+                Ok(Token::Delim('=')) => Ok(()),
+                _ => Err(()),
+            })
+            .is_ok();
+
+        Ok(match (comparison, or_equal) {
+            (MediaComparison::Less, true) => MediaComparison::LessOrEqual,
+            (MediaComparison::Greater, true) => MediaComparison::GreaterOrEqual,
+            (comparison, _) => comparison,
+        })
+    }
+}
+
+/// The preferred color scheme queried by the `prefers-color-scheme` media feature.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ColorScheme {
+    #[default]
+    Light,
+    Dark,
+}
+
+/// The orientation of the window queried by the `orientation` media feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Portrait,
+    Landscape,
+}
+
+/// A media feature of a media query, e.g. `(min-width: 600px)` or `(prefers-reduced-motion)`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MediaFeature {
+    /// A range feature which matches if all of the comparisons of its value against the given bounds are true.
+    Range(RangeFeature, Vec<(MediaComparison, f32)>),
+    /// Matches the given color scheme, or any color scheme if none is specified.
+    PrefersColorScheme(Option<ColorScheme>),
+    /// Matches if reduced motion is preferred, or if it isn't when `false`.
+    PrefersReducedMotion(bool),
+    /// Matches the given orientation, or any orientation if none is specified.
+    Orientation(Option<Orientation>),
+}
+
+impl MediaFeature {
+    /// Returns true if the media feature matches the given media state.
+    pub fn matches(&self, media: &MediaState) -> bool {
+        match self {
+            MediaFeature::Range(feature, comparisons) => {
+                let value = feature.value(media);
+                comparisons.iter().all(|(comparison, bound)| comparison.compare(value, *bound))
+            }
+
+            MediaFeature::PrefersColorScheme(color_scheme) => {
+                color_scheme.map_or(true, |color_scheme| color_scheme == media.color_scheme)
+            }
+
+            MediaFeature::PrefersReducedMotion(reduced_motion) => {
+                *reduced_motion == media.reduced_motion
+            }
+
+            MediaFeature::Orientation(orientation) => {
+                let current = if media.height >= media.width {
+                    Orientation::Portrait
+                } else {
+                    Orientation::Landscape
+                };
+
+                orientation.map_or(true, |orientation| orientation == current)
+            }
+        }
+    }
+
+    // Parses a feature of the form `name: value`, where range features can be prefixed with `min-` or `max-`.
+    fn parse_plain<'i, 't>(
+        name: &CowRcStr<'i>,
+        input: &mut Parser<'i, 't>,
+    ) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let location = input.current_source_location();
+
+        let (comparison, range_name) = if let Some(range_name) = strip_prefix(name, "min-") {
+            (MediaComparison::GreaterOrEqual, range_name)
+        } else if let Some(range_name) = strip_prefix(name, "max-") {
+            (MediaComparison::LessOrEqual, range_name)
+        } else {
+            (MediaComparison::Equal, name.as_ref())
+        };
+
+        if let Some(feature) = RangeFeature::from_name(range_name) {
+            let value = feature.parse_value(input)?;
+            return Ok(MediaFeature::Range(feature, vec![(comparison, value)]));
+        }
+
+        let value = input.expect_ident_cloned()?;
+        let feature = match_ignore_ascii_case! { name,
+            "prefers-color-scheme" => MediaFeature::PrefersColorScheme(Some(match_ignore_ascii_case! { &value,
+                "light" => ColorScheme::Light,
+                "dark" => ColorScheme::Dark,
+                _ => return Err(location.new_unexpected_token_error(Token::Ident(value.clone()))),
+            })),
+            "prefers-reduced-motion" => MediaFeature::PrefersReducedMotion(match_ignore_ascii_case! { &value,
+                "reduce" => true,
+                "no-preference" => false,
+                _ => return Err(location.new_unexpected_token_error(Token::Ident(value.clone()))),
+            }),
+            "orientation" => MediaFeature::Orientation(Some(match_ignore_ascii_case! { &value,
+                "portrait" => Orientation::Portrait,
+                "landscape" => Orientation::Landscape,
+                _ => return Err(location.new_unexpected_token_error(Token::Ident(value.clone()))),
+            })),
+            _ => return Err(location.new_unexpected_token_error(Token::Ident(name.clone()))),
+        };
+
+        Ok(feature)
+    }
+
+    // Parses a feature without a value, which matches if the feature has a value other than zero or `none`.
+    fn parse_boolean<'i, 't>(
+        name: &CowRcStr<'i>,
+        input: &mut Parser<'i, 't>,
+    ) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if let Some(feature) = RangeFeature::from_name(name) {
+            return Ok(MediaFeature::Range(feature, vec![(MediaComparison::Greater, 0.0)]));
+        }
+
+        Ok(match_ignore_ascii_case! { name,
+            "prefers-color-scheme" => MediaFeature::PrefersColorScheme(None),
+            "prefers-reduced-motion" => MediaFeature::PrefersReducedMotion(true),
+            "orientation" => MediaFeature::Orientation(None),
+            _ => return Err(input.new_unexpected_token_error(Token::Ident(name.clone()))),
+        })
+    }
+
+    // Parses a feature using range syntax, e.g. `width > 600px` or `400px <= width <= 700px`.
+    fn parse_range<'i, 't>(
+        input: &mut Parser<'i, 't>,
+    ) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let location = input.current_source_location();
+
+        if let Ok((feature, comparison)) = input.try_parse(|input| {
+            let name = input.expect_ident_cloned()?;
+            let feature = RangeFeature::from_name(&name)
+                .ok_or(location.new_unexpected_token_error(Token::Ident(name.clone())))?;
+            Ok::<_, ParseError<'i, CustomParseError<'i>>>((feature, MediaComparison::parse(input)?))
+        }) {
+            let value = feature.parse_value(input)?;
+            return Ok(MediaFeature::Range(feature, vec![(comparison, value)]));
+        }
+
+        // The name of the feature follows the first value, which is parsed once the feature is known.
+        let start = input.state();
+        input.next()?;
+        MediaComparison::parse(input)?;
+        let name = input.expect_ident_cloned()?;
+        let feature = RangeFeature::from_name(&name)
+            .ok_or(location.new_unexpected_token_error(Token::Ident(name.clone())))?;
+        input.reset(&start);
+
+        let value = feature.parse_value(input)?;
+        let comparison = MediaComparison::parse(input)?;
+        input.expect_ident()?;
+        let mut comparisons = vec![(comparison.flip(), value)];
+
+        if let Ok(comparison) = input.try_parse(MediaComparison::parse) {
+            comparisons.push((comparison, feature.parse_value(input)?));
+        }
+
+        Ok(MediaFeature::Range(feature, comparisons))
+    }
+}
+
+impl<'i> Parse<'i> for MediaFeature {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if let Ok(feature) = input.try_parse(|input| {
+            let name = input.expect_ident_cloned()?;
+            input.expect_colon()?;
+            Self::parse_plain(&name, input)
+        }) {
+            return Ok(feature);
+        }
+
+        if let Ok(feature) = input.try_parse(|input| {
+            let name = input.expect_ident_cloned()?;
+            input.expect_exhausted()?;
+            Self::parse_boolean(&name, input)
+        }) {
+            return Ok(feature);
+        }
+
+        Self::parse_range(input)
+    }
+}
+
+fn strip_prefix<'a>(name: &'a str, prefix: &str) -> Option<&'a str> {
+    if name.len() > prefix.len() && name[..prefix.len()].eq_ignore_ascii_case(prefix) {
+        Some(&name[prefix.len()..])
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    fn media(width: f32, height: f32) -> MediaState {
+        MediaState { width, height, ..Default::default() }
+    }
+
+    fn matches(query: &str, media: &MediaState) -> bool {
+        let mut parser_input = ParserInput::new(query);
+        let mut parser = Parser::new(&mut parser_input);
+        parser.parse_entirely(MediaQueryList::parse).unwrap().matches(media)
+    }
+
+    assert_parse! {
+        MediaFeature, assert_media_feature,
+
+        custom {
+            success {
+                "min-width: 600px" => MediaFeature::Range(RangeFeature::Width, vec![(MediaComparison::GreaterOrEqual, 600.0)]),
+                "max-height: 1in" => MediaFeature::Range(RangeFeature::Height, vec![(MediaComparison::LessOrEqual, 96.0)]),
+                "resolution: 2dppx" => MediaFeature::Range(RangeFeature::Resolution, vec![(MediaComparison::Equal, 2.0)]),
+                "min-resolution: 192dpi" => MediaFeature::Range(RangeFeature::Resolution, vec![(MediaComparison::GreaterOrEqual, 2.0)]),
+                "width >= 600px" => MediaFeature::Range(RangeFeature::Width, vec![(MediaComparison::GreaterOrEqual, 600.0)]),
+                "400px < width <= 700px" => MediaFeature::Range(RangeFeature::Width, vec![
+                    (MediaComparison::Greater, 400.0),
+                    (MediaComparison::LessOrEqual, 700.0),
+                ]),
+                "prefers-color-scheme: dark" => MediaFeature::PrefersColorScheme(Some(ColorScheme::Dark)),
+                "prefers-reduced-motion: no-preference" => MediaFeature::PrefersReducedMotion(false),
+                "prefers-reduced-motion" => MediaFeature::PrefersReducedMotion(true),
+                "orientation: portrait" => MediaFeature::Orientation(Some(Orientation::Portrait)),
+            }
+
+            failure {
+                "min-width: 10%",
+                "width > > 600px",
+                "prefers-color-scheme: blue",
+                "color",
+            }
+        }
+    }
+
+    #[test]
+    fn media_query_matching() {
+        let compact = media(400.0, 800.0);
+        let wide = media(1200.0, 800.0);
+
+        assert!(matches("(max-width: 600px)", &compact));
+        assert!(!matches("(max-width: 600px)", &wide));
+        assert!(matches("screen and (orientation: landscape)", &wide));
+        assert!(!matches("print", &wide));
+        assert!(matches("not print", &wide));
+        assert!(matches("(width < 500px) or (height < 500px)", &compact));
+        assert!(!matches("not ((width < 500px) or (height < 500px))", &compact));
+        assert!(matches("print, (min-width: 1000px)", &wide));
+        assert!(!matches("(unknown-feature), print", &wide));
+
+        let dark = MediaState { color_scheme: ColorScheme::Dark, ..compact };
+        assert!(matches("(prefers-color-scheme: dark) and (max-width: 600px)", &dark));
+        assert!(!matches("(prefers-color-scheme: dark)", &compact));
+        assert!(matches("(prefers-reduced-motion: no-preference)", &compact));
+        assert!(matches("(min-resolution: 2x)", &MediaState { scale_factor: 2.0, ..compact }));
+    }
+}
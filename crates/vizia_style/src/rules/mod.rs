@@ -7,6 +7,21 @@ pub use keyframes::*;
 pub mod property;
 pub use property::*;
 
+pub mod media;
+pub use media::*;
+
+pub mod container;
+pub use container::*;
+
+pub mod font_face;
+pub use font_face::*;
+
+pub mod import;
+pub use import::*;
+
+pub mod layer;
+pub use layer::*;
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct CssRuleList<'i>(pub Vec<CssRule<'i>>);
 
@@ -16,4 +31,10 @@ pub enum CssRule<'i> {
     Property(PropertyRule<'i>),
     Ignored,
     Keyframes(KeyframesRule<'i>),
+    Media(MediaRule<'i>),
+    Container(ContainerRule<'i>),
+    FontFace(FontFaceRule<'i>),
+    Import(ImportRule<'i>),
+    LayerStatement(LayerStatementRule<'i>),
+    LayerBlock(LayerBlockRule<'i>),
 }
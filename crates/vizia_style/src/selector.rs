@@ -22,6 +22,12 @@ impl std::convert::From<&str> for SelectorString {
     }
 }
 
+impl AsRef<str> for SelectorString {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
 impl ToCss for SelectorString {
     fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
     where
@@ -156,6 +162,8 @@ impl<'i> selectors::parser::Parser<'i> for SelectorParser<'_, 'i> {
         let pseudo_element = match_ignore_ascii_case! { &name,
             "before" => Before,
             "after" => After,
+            "marker" => Marker,
+            "placeholder" => Placeholder,
             "selection" => Selection,
             _=> Custom(name.to_string())
         };
@@ -129,6 +129,68 @@ test {
         let style_sheet = StyleSheet::parse(CSS_EXAMPLE, ParserOptions::default());
         println!("{:#?}", style_sheet);
     }
+
+    #[test]
+    fn parse_stylesheet_warnings() {
+        let css = r#"
+.foo {
+    background-color: red;
+    backgrond-color: blue;
+    width: 10px 20px;
+    height: 30px;
+}
+
+.bar[ {
+    color: red;
+}
+"#;
+
+        let warnings = std::sync::Arc::new(std::sync::RwLock::new(Vec::new()));
+        let options = ParserOptions {
+            filename: String::from("test.css"),
+            warnings: Some(warnings.clone()),
+            ..ParserOptions::new()
+        };
+
+        let style_sheet = StyleSheet::parse(css, options).unwrap();
+
+        // Invalid declarations don't discard the valid declarations of the same rule.
+        match &style_sheet.rules.0[0] {
+            crate::CssRule::Style(rule) => assert_eq!(rule.declarations.declarations.len(), 3),
+            rule => panic!("expected a style rule, found {:?}", rule),
+        }
+
+        let warnings = warnings.read().unwrap();
+        assert_eq!(warnings.len(), 3);
+
+        assert_eq!(warnings[0].kind, CustomParseError::UnknownProperty("backgrond-color".into()));
+        assert_eq!(warnings[0].location.as_ref().map(|location| location.line), Some(3));
+        assert_eq!(
+            warnings[0].location.as_ref().map(|location| location.filename.as_str()),
+            Some("test.css")
+        );
+
+        assert_eq!(warnings[1].kind, CustomParseError::InvalidPropertyValue("width".into()));
+        assert_eq!(warnings[1].location.as_ref().map(|location| location.line), Some(4));
+
+        assert_eq!(warnings[2].location.as_ref().map(|location| location.line), Some(8));
+    }
+
+    #[test]
+    fn parse_invalid_declaration_warnings() {
+        let css = ".foo { widht: var(10px); width: var(10px); }";
+
+        let warnings = std::sync::Arc::new(std::sync::RwLock::new(Vec::new()));
+        let options = ParserOptions { warnings: Some(warnings.clone()), ..ParserOptions::new() };
+
+        StyleSheet::parse(css, options).unwrap();
+
+        // Declarations which fail to parse are reported as unknown properties unless the property is supported.
+        let warnings = warnings.read().unwrap();
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].kind, CustomParseError::UnknownProperty("widht".into()));
+        assert_eq!(warnings[1].kind, CustomParseError::InvalidPropertyValue("width".into()));
+    }
 }
 
 // use cssparser::*;
@@ -0,0 +1,83 @@
+use crate::{macros::impl_parse, Angle, Parse, Percentage};
+use cssparser::*;
+
+/// An angle or a percentage value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AngleOrPercentage {
+    Angle(Angle),
+    Percentage(f32),
+}
+
+impl Default for AngleOrPercentage {
+    fn default() -> Self {
+        AngleOrPercentage::Angle(Angle::default())
+    }
+}
+
+impl AngleOrPercentage {
+    /// Returns the value as a fraction of a full turn.
+    pub fn to_turns(&self) -> f32 {
+        match self {
+            AngleOrPercentage::Angle(angle) => angle.to_degrees() / 360.0,
+            AngleOrPercentage::Percentage(val) => val / 100.0,
+        }
+    }
+}
+
+impl_parse! {
+    AngleOrPercentage,
+
+    try_parse {
+        Angle,
+        Percentage,
+    }
+}
+
+impl From<Angle> for AngleOrPercentage {
+    fn from(angle: Angle) -> Self {
+        AngleOrPercentage::Angle(angle)
+    }
+}
+
+impl From<Percentage> for AngleOrPercentage {
+    fn from(percentage: Percentage) -> Self {
+        AngleOrPercentage::Percentage(percentage.0)
+    }
+}
+
+impl From<&str> for AngleOrPercentage {
+    fn from(s: &str) -> Self {
+        let mut input = ParserInput::new(s);
+        let mut parser = Parser::new(&mut input);
+        AngleOrPercentage::parse(&mut parser).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        AngleOrPercentage, parse_angle_percentage,
+
+        custom {
+            success {
+                "90deg" => AngleOrPercentage::Angle(Angle::Deg(90.0)),
+                "0.5turn" => AngleOrPercentage::Angle(Angle::Turn(0.5)),
+                "25%" => AngleOrPercentage::Percentage(25.0),
+            }
+
+            failure {
+                "test",
+                "10px",
+            }
+        }
+    }
+
+    #[test]
+    fn to_turns() {
+        assert_eq!(AngleOrPercentage::Angle(Angle::Deg(90.0)).to_turns(), 0.25);
+        assert_eq!(AngleOrPercentage::Percentage(50.0).to_turns(), 0.5);
+    }
+}
@@ -0,0 +1,248 @@
+use crate::{
+    AnimationDirection, AnimationFillMode, AnimationIterationCount, CustomParseError, Duration,
+    EasingFunction, KeyframesName, Parse,
+};
+use cssparser::{ParseError, ParseErrorKind, Parser};
+
+/// The name of the `@keyframes` rule played by an animation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnimationName<'i> {
+    /// No animation is played.
+    None,
+    /// The name of a `@keyframes` rule.
+    Name(KeyframesName<'i>),
+}
+
+impl AnimationName<'_> {
+    /// Returns the name of the keyframes rule, or `None` if no animation should be played.
+    pub fn as_string(&self) -> Option<String> {
+        match self {
+            AnimationName::None => None,
+            AnimationName::Name(name) => Some(name.as_string()),
+        }
+    }
+}
+
+impl<'i> Parse<'i> for AnimationName<'i> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+            return Ok(AnimationName::None);
+        }
+
+        Ok(AnimationName::Name(KeyframesName::parse(input)?))
+    }
+}
+
+impl<'i> Parse<'i> for Vec<AnimationName<'i>> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        input.parse_comma_separated(AnimationName::parse)
+    }
+}
+
+/// The animation shorthand, which plays a `@keyframes` animation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CssAnimation<'i> {
+    /// The name of the keyframes animation.
+    pub name: AnimationName<'i>,
+    /// The duration of one iteration of the animation.
+    pub duration: Duration,
+    /// The easing function applied between each keyframe.
+    pub timing_function: EasingFunction,
+    /// The number of times the animation plays.
+    pub iteration_count: AnimationIterationCount,
+    /// The direction in which the animation plays.
+    pub direction: AnimationDirection,
+    /// How the animation values are applied before and after the animation.
+    pub fill_mode: AnimationFillMode,
+    /// The delay before the animation starts.
+    pub delay: Duration,
+}
+
+impl Default for CssAnimation<'_> {
+    fn default() -> Self {
+        Self {
+            name: AnimationName::None,
+            duration: Duration::ZERO,
+            timing_function: EasingFunction::Ease,
+            iteration_count: AnimationIterationCount::default(),
+            direction: AnimationDirection::default(),
+            fill_mode: AnimationFillMode::default(),
+            delay: Duration::ZERO,
+        }
+    }
+}
+
+impl<'i> Parse<'i> for CssAnimation<'i> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let location = input.current_source_location();
+
+        let mut name = None;
+        let mut duration = None;
+        let mut timing_function = None;
+        let mut iteration_count = None;
+        let mut direction = None;
+        let mut fill_mode = None;
+        let mut delay = None;
+
+        // The first time value is the duration and the second is the delay.
+        loop {
+            // `none` is the name of the animation rather than its fill mode, unless a name has already been given.
+            if name.is_none()
+                && input.try_parse(|input| input.expect_ident_matching("none")).is_ok()
+            {
+                name = Some(AnimationName::None);
+                continue;
+            }
+
+            if let Ok(value) = input.try_parse(Duration::parse) {
+                if duration.is_none() {
+                    duration = Some(value);
+                    continue;
+                } else if delay.is_none() {
+                    delay = Some(value);
+                    continue;
+                }
+
+                break;
+            }
+
+            if timing_function.is_none() {
+                if let Ok(value) = input.try_parse(EasingFunction::parse) {
+                    timing_function = Some(value);
+                    continue;
+                }
+            }
+
+            if iteration_count.is_none() {
+                if let Ok(value) = input.try_parse(AnimationIterationCount::parse) {
+                    iteration_count = Some(value);
+                    continue;
+                }
+            }
+
+            if direction.is_none() {
+                if let Ok(value) = input.try_parse(AnimationDirection::parse) {
+                    direction = Some(value);
+                    continue;
+                }
+            }
+
+            if fill_mode.is_none() {
+                if let Ok(value) = input.try_parse(AnimationFillMode::parse) {
+                    fill_mode = Some(value);
+                    continue;
+                }
+            }
+
+            if name.is_none() {
+                if let Ok(value) = input.try_parse(AnimationName::parse) {
+                    name = Some(value);
+                    continue;
+                }
+            }
+
+            break;
+        }
+
+        if name.is_none()
+            && duration.is_none()
+            && timing_function.is_none()
+            && iteration_count.is_none()
+            && direction.is_none()
+            && fill_mode.is_none()
+        {
+            return Err(ParseError {
+                kind: ParseErrorKind::Custom(CustomParseError::InvalidDeclaration),
+                location,
+            });
+        }
+
+        let default = CssAnimation::default();
+
+        Ok(CssAnimation {
+            name: name.unwrap_or(default.name),
+            duration: duration.unwrap_or(default.duration),
+            timing_function: timing_function.unwrap_or(default.timing_function),
+            iteration_count: iteration_count.unwrap_or(default.iteration_count),
+            direction: direction.unwrap_or(default.direction),
+            fill_mode: fill_mode.unwrap_or(default.fill_mode),
+            delay: delay.unwrap_or(default.delay),
+        })
+    }
+}
+
+impl<'i> Parse<'i> for Vec<CssAnimation<'i>> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        input.parse_comma_separated(CssAnimation::parse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::assert_parse, Ident};
+
+    fn name(name: &str) -> AnimationName<'_> {
+        AnimationName::Name(KeyframesName::Ident(Ident(name.into())))
+    }
+
+    assert_parse! {
+        Vec<AnimationName>, assert_animation_name,
+
+        custom {
+            success {
+                "none" => vec![AnimationName::None],
+                "spin" => vec![name("spin")],
+                "spin, pulse" => vec![name("spin"), name("pulse")],
+            }
+
+            failure {
+                "1s",
+            }
+        }
+    }
+
+    assert_parse! {
+        CssAnimation, assert_animation,
+
+        custom {
+            success {
+                "spin 1s" => CssAnimation {
+                    name: name("spin"),
+                    duration: Duration::from_secs(1),
+                    ..Default::default()
+                },
+                "pulse 2s linear 500ms infinite alternate both" => CssAnimation {
+                    name: name("pulse"),
+                    duration: Duration::from_secs(2),
+                    timing_function: EasingFunction::Linear,
+                    iteration_count: AnimationIterationCount::Infinite,
+                    direction: AnimationDirection::Alternate,
+                    fill_mode: AnimationFillMode::Both,
+                    delay: Duration::from_millis(500),
+                },
+                "none" => CssAnimation::default(),
+                "none forwards" => CssAnimation {
+                    fill_mode: AnimationFillMode::Forwards,
+                    ..Default::default()
+                },
+                "spin 1s none" => CssAnimation {
+                    name: name("spin"),
+                    duration: Duration::from_secs(1),
+                    ..Default::default()
+                },
+                "3 reverse 1s fade" => CssAnimation {
+                    name: name("fade"),
+                    duration: Duration::from_secs(1),
+                    iteration_count: AnimationIterationCount::Count(3.0),
+                    direction: AnimationDirection::Reverse,
+                    ..Default::default()
+                },
+            }
+
+            failure {
+                ", spin",
+            }
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{macros::define_enum, CustomParseError, Parse};
+use cssparser::{ParseError, Parser};
+
+define_enum! {
+    /// Determines whether an animation plays forwards, backwards, or alternates between the two on each iteration.
+    #[derive(Default)]
+    pub enum AnimationDirection {
+        /// The animation plays forwards on each iteration.
+        #[default]
+        "normal": Normal,
+        /// The animation plays backwards on each iteration.
+        "reverse": Reverse,
+        /// The animation plays forwards on the first iteration and then alternates direction.
+        "alternate": Alternate,
+        /// The animation plays backwards on the first iteration and then alternates direction.
+        "alternate-reverse": AlternateReverse,
+    }
+}
+
+impl<'i> Parse<'i> for Vec<AnimationDirection> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        input.parse_comma_separated(AnimationDirection::parse)
+    }
+}
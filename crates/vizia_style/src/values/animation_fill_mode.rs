@@ -0,0 +1,24 @@
+use crate::{macros::define_enum, CustomParseError, Parse};
+use cssparser::{ParseError, Parser};
+
+define_enum! {
+    /// Determines whether the values of an animation are applied before it starts and after it ends.
+    #[derive(Default)]
+    pub enum AnimationFillMode {
+        /// The animation values are only applied while the animation is playing.
+        #[default]
+        "none": None,
+        /// The values of the last keyframe are retained after the animation ends.
+        "forwards": Forwards,
+        /// The values of the first keyframe are applied during the animation delay.
+        "backwards": Backwards,
+        /// The animation values are applied both before the animation starts and after it ends.
+        "both": Both,
+    }
+}
+
+impl<'i> Parse<'i> for Vec<AnimationFillMode> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        input.parse_comma_separated(AnimationFillMode::parse)
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{CustomParseError, Parse};
+use cssparser::{ParseError, Parser, Token};
+
+/// The number of times an animation plays.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnimationIterationCount {
+    /// The animation plays the given number of times, which may be fractional.
+    Count(f32),
+    /// The animation repeats forever.
+    Infinite,
+}
+
+impl Default for AnimationIterationCount {
+    fn default() -> Self {
+        AnimationIterationCount::Count(1.0)
+    }
+}
+
+impl<'i> Parse<'i> for AnimationIterationCount {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let location = input.current_source_location();
+        match input.next()? {
+            Token::Number { value, .. } if *value >= 0.0 => {
+                Ok(AnimationIterationCount::Count(*value))
+            }
+            Token::Ident(ident) if ident.eq_ignore_ascii_case("infinite") => {
+                Ok(AnimationIterationCount::Infinite)
+            }
+            t => Err(location.new_unexpected_token_error(t.clone())),
+        }
+    }
+}
+
+impl<'i> Parse<'i> for Vec<AnimationIterationCount> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        input.parse_comma_separated(AnimationIterationCount::parse)
+    }
+}
+
+impl From<f32> for AnimationIterationCount {
+    fn from(count: f32) -> Self {
+        AnimationIterationCount::Count(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        AnimationIterationCount, assert_animation_iteration_count,
+
+        custom {
+            success {
+                "1" => AnimationIterationCount::Count(1.0),
+                "2.5" => AnimationIterationCount::Count(2.5),
+                "infinite" => AnimationIterationCount::Infinite,
+            }
+
+            failure {
+                "-1",
+                "1s",
+                "forever",
+            }
+        }
+    }
+}
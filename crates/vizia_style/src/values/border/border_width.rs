@@ -39,6 +39,13 @@ impl From<Rect<BorderWidthValue>> for BorderWidth {
     }
 }
 
+impl<T: Into<BorderWidthValue>> From<T> for BorderWidth {
+    fn from(value: T) -> Self {
+        let value: BorderWidthValue = value.into();
+        BorderWidth::new(value.clone(), value.clone(), value.clone(), value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
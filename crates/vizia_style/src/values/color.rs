@@ -1,5 +1,10 @@
-use crate::{macros::impl_parse, Parse};
-use cssparser::{Parser, ParserInput};
+use crate::{
+    Angle, ColorInterpolationMethod, ColorSpace, CustomParseError, Error, Parse, Percentage,
+};
+use cssparser::{
+    color::PredefinedColorSpace, match_ignore_ascii_case, ParseError, ParseErrorKind, Parser,
+    ParserInput, Token,
+};
 
 /// A color value.
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -31,6 +36,14 @@ impl Color {
         }
     }
 
+    /// Returns the color, replacing `currentcolor` with the given current color.
+    pub fn resolve(self, current_color: Color) -> Color {
+        match self {
+            Color::CurrentColor => current_color,
+            Color::RGBA(_) => self,
+        }
+    }
+
     pub fn r(&self) -> u8 {
         match self {
             Color::CurrentColor => 0,
@@ -60,11 +73,317 @@ impl Color {
     }
 }
 
-impl_parse! {
-    Color,
+impl<'i> Parse<'i> for Color {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let location = input.current_source_location();
+
+        if let Ok(color) = input.try_parse(parse_color_function) {
+            return Ok(color);
+        }
+
+        if let Ok(color) = input.try_parse(cssparser_color::Color::parse) {
+            return Ok(color.into());
+        }
+
+        Err(ParseError {
+            kind: ParseErrorKind::Custom(CustomParseError::InvalidDeclaration),
+            location,
+        })
+    }
+}
+
+/// Parses the color functions which are not supported by `cssparser_color`, which are `color-mix()`
+/// and the relative color syntax, e.g. `rgb(from red r g b / 50%)`.
+fn parse_color_function<'i>(
+    input: &mut Parser<'i, '_>,
+) -> Result<Color, ParseError<'i, CustomParseError<'i>>> {
+    let location = input.current_source_location();
+    let function = input.expect_function()?.clone();
+
+    let relative = match_ignore_ascii_case! { &function,
+        "color-mix" => return input.parse_nested_block(parse_color_mix),
+        "rgb" | "rgba" => RelativeColorFunction::Rgb,
+        "hsl" | "hsla" => RelativeColorFunction::Hsl,
+        "hwb" => RelativeColorFunction::Hwb,
+        "lab" => RelativeColorFunction::Lab,
+        "lch" => RelativeColorFunction::Lch,
+        "oklab" => RelativeColorFunction::Oklab,
+        "oklch" => RelativeColorFunction::Oklch,
+        _ => return Err(location.new_unexpected_token_error(Token::Function(function))),
+    };
+
+    input.parse_nested_block(|input| relative.parse(input))
+}
+
+/// Parses the arguments of `color-mix()`, e.g. `color-mix(in oklab, red 25%, blue)`.
+fn parse_color_mix<'i>(
+    input: &mut Parser<'i, '_>,
+) -> Result<Color, ParseError<'i, CustomParseError<'i>>> {
+    let method = ColorInterpolationMethod::parse(input)?;
+    input.expect_comma()?;
+    let (first, first_percentage) = parse_color_mix_item(input)?;
+    input.expect_comma()?;
+    let location = input.current_source_location();
+    let (second, second_percentage) = parse_color_mix_item(input)?;
+
+    let (first_percentage, second_percentage) = match (first_percentage, second_percentage) {
+        (None, None) => (0.5, 0.5),
+        (Some(first), None) => (first, 1.0 - first),
+        (None, Some(second)) => (1.0 - second, second),
+        (Some(first), Some(second)) => (first, second),
+    };
+
+    let sum = first_percentage + second_percentage;
+    if sum <= 0.0 {
+        return Err(ParseError {
+            kind: ParseErrorKind::Custom(CustomParseError::InvalidValue),
+            location,
+        });
+    }
+
+    let mut mixed = method.mix(first, second, second_percentage / sum);
+
+    // Percentages which sum to less than 100% make the result partially transparent.
+    if sum < 1.0 {
+        mixed.alpha = (mixed.alpha as f32 * sum).round() as u8;
+    }
+
+    Ok(Color::RGBA(mixed))
+}
+
+/// Parses a color and an optional percentage, in either order, returning the percentage as a factor.
+fn parse_color_mix_item<'i>(
+    input: &mut Parser<'i, '_>,
+) -> Result<(RGBA, Option<f32>), ParseError<'i, CustomParseError<'i>>> {
+    let location = input.current_source_location();
+    let percentage = input.try_parse(Percentage::parse).ok();
+    let color = Color::parse(input)?;
+    let percentage = percentage.or_else(|| input.try_parse(Percentage::parse).ok());
 
-    try_parse {
-        cssparser_color::Color,
+    match (color, percentage) {
+        // The current color is not known until the style is applied, so it cannot be mixed while parsing.
+        (Color::CurrentColor, _) => Err(ParseError {
+            kind: ParseErrorKind::Custom(CustomParseError::InvalidValue),
+            location,
+        }),
+
+        (_, Some(Percentage(percentage))) if !(0.0..=100.0).contains(&percentage) => {
+            Err(ParseError {
+                kind: ParseErrorKind::Custom(CustomParseError::InvalidValue),
+                location,
+            })
+        }
+
+        (Color::RGBA(rgba), percentage) => Ok((rgba, percentage.map(|p| p.0 / 100.0))),
+    }
+}
+
+/// A color function which supports the relative color syntax.
+#[derive(Clone, Copy)]
+enum RelativeColorFunction {
+    Rgb,
+    Hsl,
+    Hwb,
+    Lab,
+    Lch,
+    Oklab,
+    Oklch,
+}
+
+impl RelativeColorFunction {
+    fn space(&self) -> ColorSpace {
+        match self {
+            RelativeColorFunction::Rgb => ColorSpace::Srgb,
+            RelativeColorFunction::Hsl => ColorSpace::Hsl,
+            RelativeColorFunction::Hwb => ColorSpace::Hwb,
+            RelativeColorFunction::Lab => ColorSpace::Lab,
+            RelativeColorFunction::Lch => ColorSpace::Lch,
+            RelativeColorFunction::Oklab => ColorSpace::Oklab,
+            RelativeColorFunction::Oklch => ColorSpace::Oklch,
+        }
+    }
+
+    /// The keywords which refer to the channels of the origin color.
+    fn channels(&self) -> [&'static str; 3] {
+        match self {
+            RelativeColorFunction::Rgb => ["r", "g", "b"],
+            RelativeColorFunction::Hsl => ["h", "s", "l"],
+            RelativeColorFunction::Hwb => ["h", "w", "b"],
+            RelativeColorFunction::Lab | RelativeColorFunction::Oklab => ["l", "a", "b"],
+            RelativeColorFunction::Lch | RelativeColorFunction::Oklch => ["l", "c", "h"],
+        }
+    }
+
+    /// The value of each channel which corresponds to 100%, or `None` if the channel is a hue.
+    fn percentage_basis(&self) -> [Option<f32>; 3] {
+        match self {
+            RelativeColorFunction::Rgb => [Some(255.0); 3],
+            RelativeColorFunction::Hsl | RelativeColorFunction::Hwb => {
+                [None, Some(100.0), Some(100.0)]
+            }
+            RelativeColorFunction::Lab => [Some(100.0), Some(125.0), Some(125.0)],
+            RelativeColorFunction::Lch => [Some(100.0), Some(150.0), None],
+            RelativeColorFunction::Oklab => [Some(1.0), Some(0.4), Some(0.4)],
+            RelativeColorFunction::Oklch => [Some(1.0), Some(0.4), None],
+        }
+    }
+
+    /// The factor which converts each channel into the corresponding component of the color space.
+    fn scale(&self) -> [f32; 3] {
+        match self {
+            RelativeColorFunction::Rgb => [1.0 / 255.0; 3],
+            RelativeColorFunction::Hsl | RelativeColorFunction::Hwb => [1.0, 0.01, 0.01],
+            _ => [1.0; 3],
+        }
+    }
+
+    /// Parses the arguments of a relative color, e.g. `from red r g b / 50%`.
+    fn parse<'i>(
+        &self,
+        input: &mut Parser<'i, '_>,
+    ) -> Result<Color, ParseError<'i, CustomParseError<'i>>> {
+        input.expect_ident_matching("from")?;
+
+        let location = input.current_source_location();
+        let origin = match Color::parse(input)? {
+            Color::RGBA(rgba) => rgba,
+            // The current color is not known until the style is applied, so it cannot be used as an origin.
+            Color::CurrentColor => {
+                return Err(ParseError {
+                    kind: ParseErrorKind::Custom(CustomParseError::InvalidValue),
+                    location,
+                })
+            }
+        };
+
+        let space = self.space();
+        let scale = self.scale();
+        let names = self.channels();
+        let basis = self.percentage_basis();
+
+        let origin_components = space.from_srgb([
+            origin.red as f32 / 255.0,
+            origin.green as f32 / 255.0,
+            origin.blue as f32 / 255.0,
+        ]);
+
+        let keywords = [
+            (names[0], origin_components[0] / scale[0]),
+            (names[1], origin_components[1] / scale[1]),
+            (names[2], origin_components[2] / scale[2]),
+            ("alpha", origin.alpha as f32 / 255.0),
+        ];
+
+        let mut components = [0.0; 3];
+        for (index, component) in components.iter_mut().enumerate() {
+            let channel = ChannelParser { keywords: &keywords, basis: basis[index] };
+            *component = channel.parse_component(input)? * scale[index];
+        }
+
+        let alpha = if input.try_parse(|input| input.expect_delim('/')).is_ok() {
+            ChannelParser { keywords: &keywords, basis: Some(1.0) }.parse_component(input)?
+        } else {
+            keywords[3].1
+        };
+
+        Ok(Color::RGBA(RGBA::from_srgb(space.to_srgb(components), alpha)))
+    }
+}
+
+/// Parses a channel of a relative color, which may refer to the channels of the origin color.
+struct ChannelParser<'a> {
+    keywords: &'a [(&'static str, f32); 4],
+    basis: Option<f32>,
+}
+
+impl ChannelParser<'_> {
+    fn parse_component<'i>(
+        &self,
+        input: &mut Parser<'i, '_>,
+    ) -> Result<f32, ParseError<'i, CustomParseError<'i>>> {
+        if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+            return Ok(0.0);
+        }
+
+        self.parse_term(input)
+    }
+
+    fn parse_sum<'i>(
+        &self,
+        input: &mut Parser<'i, '_>,
+    ) -> Result<f32, ParseError<'i, CustomParseError<'i>>> {
+        let mut value = self.parse_product(input)?;
+
+        loop {
+            let state = input.state();
+            match input.next() {
+                Ok(&Token::Delim('+')) => value += self.parse_product(input)?,
+                Ok(&Token::Delim('-')) => value -= self.parse_product(input)?,
+                _ => {
+                    input.reset(&state);
+                    return Ok(value);
+                }
+            }
+        }
+    }
+
+    fn parse_product<'i>(
+        &self,
+        input: &mut Parser<'i, '_>,
+    ) -> Result<f32, ParseError<'i, CustomParseError<'i>>> {
+        let mut value = self.parse_term(input)?;
+
+        loop {
+            let state = input.state();
+            match input.next() {
+                Ok(&Token::Delim('*')) => value *= self.parse_term(input)?,
+                Ok(&Token::Delim('/')) => value /= self.parse_term(input)?,
+                _ => {
+                    input.reset(&state);
+                    return Ok(value);
+                }
+            }
+        }
+    }
+
+    fn parse_term<'i>(
+        &self,
+        input: &mut Parser<'i, '_>,
+    ) -> Result<f32, ParseError<'i, CustomParseError<'i>>> {
+        match self.basis {
+            // Angles are only valid for hue channels, which are the only channels without a percentage basis.
+            None => {
+                if let Ok(angle) = input.try_parse(Angle::parse) {
+                    return Ok(angle.to_degrees());
+                }
+            }
+
+            Some(basis) => {
+                if let Ok(percentage) = input.try_parse(|input| input.expect_percentage()) {
+                    return Ok(percentage * basis);
+                }
+            }
+        }
+
+        let location = input.current_source_location();
+        match input.next()?.clone() {
+            Token::Number { value, .. } => Ok(value),
+
+            Token::Ident(ident) => self
+                .keywords
+                .iter()
+                .find(|(name, _)| ident.eq_ignore_ascii_case(name))
+                .map(|(_, value)| *value)
+                .ok_or_else(|| location.new_unexpected_token_error(Token::Ident(ident.clone()))),
+
+            Token::Function(function) if function.eq_ignore_ascii_case("calc") => {
+                input.parse_nested_block(|input| self.parse_sum(input))
+            }
+
+            Token::ParenthesisBlock => input.parse_nested_block(|input| self.parse_sum(input)),
+
+            token => Err(location.new_unexpected_token_error(token)),
+        }
     }
 }
 
@@ -76,11 +395,39 @@ impl From<RGBA> for Color {
 
 impl From<cssparser_color::Color> for Color {
     fn from(color: cssparser_color::Color) -> Self {
-        match color {
-            cssparser_color::Color::CurrentColor => Color::CurrentColor,
-            cssparser_color::Color::Rgba(rgba) => Color::RGBA(rgba.into()),
-            _ => Color::CurrentColor,
-        }
+        use cssparser_color::Color as CssColor;
+
+        let (space, components, alpha) = match color {
+            CssColor::CurrentColor => return Color::CurrentColor,
+            CssColor::Rgba(rgba) => return Color::RGBA(rgba.into()),
+            CssColor::Hsl(hsl) => {
+                (ColorSpace::Hsl, [hsl.hue, hsl.saturation, hsl.lightness], hsl.alpha)
+            }
+            CssColor::Hwb(hwb) => {
+                (ColorSpace::Hwb, [hwb.hue, hwb.whiteness, hwb.blackness], hwb.alpha)
+            }
+            CssColor::Lab(lab) => (ColorSpace::Lab, [lab.lightness, lab.a, lab.b], lab.alpha),
+            CssColor::Lch(lch) => {
+                (ColorSpace::Lch, [lch.lightness, lch.chroma, lch.hue], lch.alpha)
+            }
+            CssColor::Oklab(lab) => (ColorSpace::Oklab, [lab.lightness, lab.a, lab.b], lab.alpha),
+            CssColor::Oklch(lch) => {
+                (ColorSpace::Oklch, [lch.lightness, lch.chroma, lch.hue], lch.alpha)
+            }
+            CssColor::ColorFunction(function) => {
+                let space = match function.color_space {
+                    PredefinedColorSpace::SrgbLinear => ColorSpace::SrgbLinear,
+                    // Wide gamut color spaces are approximated by sRGB.
+                    _ => ColorSpace::Srgb,
+                };
+
+                (space, [function.c1, function.c2, function.c3], function.alpha)
+            }
+        };
+
+        // Missing components, specified with the `none` keyword, are treated as zero.
+        let components = space.to_srgb(components.map(|component| component.unwrap_or(0.0)));
+        Color::RGBA(RGBA::from_srgb(components, alpha.unwrap_or(1.0)))
     }
 }
 
@@ -730,6 +1077,15 @@ impl RGBA {
         RGBA { red, green, blue, alpha }
     }
 
+    /// Creates a new RGBA from sRGB components and an alpha, each in the range 0 to 1.
+    ///
+    /// Components outside of this range, such as those of a color outside of the sRGB gamut, are clamped.
+    #[must_use]
+    pub fn from_srgb(components: [f32; 3], alpha: f32) -> Self {
+        let [r, g, b] = components.map(to_byte);
+        Self::rgba(r, g, b, to_byte(alpha))
+    }
+
     /// Creates a new RGBA from HSL values.
     #[must_use]
     pub fn hsl(h: f32, s: f32, l: f32) -> Self {
@@ -776,6 +1132,10 @@ impl RGBA {
     }
 }
 
+fn to_byte(component: f32) -> u8 {
+    (component.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
 fn hue(mut h: f32, m1: f32, m2: f32) -> f32 {
     if h < 0.0 {
         h += 1.0;
@@ -828,6 +1188,22 @@ impl From<&str> for Color {
     }
 }
 
+impl std::str::FromStr for Color {
+    type Err = Error<String>;
+
+    /// Parses a color from a CSS color value, failing if the whole string is not a valid color.
+    ///
+    /// The parse error borrows from the string, so the error is returned with its message instead.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut input = ParserInput::new(s);
+        let mut parser = Parser::new(&mut input);
+        parser.parse_entirely(Color::parse).map_err(|err| Error {
+            kind: Error::from(err, String::new()).kind.to_string(),
+            location: None,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -842,12 +1218,43 @@ mod tests {
             "#123456" => Color::rgb(18, 52, 86),
             "rgba(12, 34, 56, 0.3)" => Color::rgba(12, 34, 56, 77),
             "red" => Color::rgb(255, 0, 0),
+            "hsl(120, 100%, 50%)" => Color::rgb(0, 255, 0),
+            "hsl(0 100% 50% / 50%)" => Color::rgba(255, 0, 0, 128),
+            "hwb(0 0% 0%)" => Color::rgb(255, 0, 0),
+            "lab(100 0 0)" => Color::rgb(255, 255, 255),
+            "oklab(1 0 0)" => Color::rgb(255, 255, 255),
+            "oklch(0 0 0)" => Color::rgb(0, 0, 0),
+            "rgb(from red r g b / 50%)" => Color::rgba(255, 0, 0, 128),
+            "rgb(from #336699 b g r)" => Color::rgb(153, 102, 51),
+            "rgb(from red calc(r - 55) 0 b)" => Color::rgb(200, 0, 0),
+            "rgb(from red r g b / calc(alpha / 2))" => Color::rgba(255, 0, 0, 128),
+            "hsl(from red calc(h + 120) s l)" => Color::rgb(0, 255, 0),
+            "color-mix(in srgb, red, blue)" => Color::rgb(128, 0, 128),
+            "color-mix(in srgb, red 25%, blue)" => Color::rgb(64, 0, 191),
+            "color-mix(in srgb, 40% red, blue 60%)" => Color::rgb(102, 0, 153),
+            "color-mix(in srgb, white 30%, black 20%)" => Color::rgba(153, 153, 153, 128),
         }
 
         failure {
             "0",
             "#000000000",
             "#FFFFFFFFF",
+            "rgb(from red x g b)",
+            "rgb(from currentcolor r g b)",
+            "hsl(from red h 50deg l)",
+            "color-mix(in srgb, red)",
+            "color-mix(in srgb, red 0%, blue 0%)",
+            "color-mix(in srgb, currentcolor, blue)",
         }
     }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("#ff0000".parse(), Ok(Color::rgb(255, 0, 0)));
+        assert_eq!("oklch(0 0 0)".parse(), Ok(Color::rgb(0, 0, 0)));
+        assert_eq!(
+            "#ff0000 blue".parse::<Color>(),
+            Err(Error { kind: "Unexpected token: Ident(\"blue\")".to_owned(), location: None })
+        );
+    }
 }
@@ -0,0 +1,156 @@
+use crate::{ColorSpace, CustomParseError, HueInterpolationMethod, Parse, RGBA};
+use cssparser::*;
+
+/// Determines how colors are interpolated, such as `in oklch longer hue`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ColorInterpolationMethod {
+    /// The color space in which the colors are interpolated.
+    pub space: ColorSpace,
+    /// The way hues are interpolated, which only applies to polar color spaces.
+    pub hue: HueInterpolationMethod,
+}
+
+impl ColorInterpolationMethod {
+    /// Creates a new color interpolation method.
+    pub fn new(space: ColorSpace, hue: HueInterpolationMethod) -> Self {
+        Self { space, hue }
+    }
+
+    /// Returns the color at `t` between `start` and `end`, interpolated with premultiplied alpha.
+    pub fn mix(&self, start: RGBA, end: RGBA, t: f32) -> RGBA {
+        let to_components = |color: RGBA| {
+            self.space.from_srgb([
+                color.red as f32 / 255.0,
+                color.green as f32 / 255.0,
+                color.blue as f32 / 255.0,
+            ])
+        };
+
+        let mut from = to_components(start);
+        let mut to = to_components(end);
+        let from_alpha = start.alpha as f32 / 255.0;
+        let to_alpha = end.alpha as f32 / 255.0;
+
+        let hue_index = self.space.hue_index();
+
+        if let Some(index) = hue_index {
+            // A powerless hue, such as the hue of a gray, takes the hue of the other color.
+            match (self.is_powerless(&from), self.is_powerless(&to)) {
+                (true, false) => from[index] = to[index],
+                (false, true) => to[index] = from[index],
+                _ => {}
+            }
+
+            (from[index], to[index]) = self.hue.fixup(from[index], to[index]);
+        }
+
+        let alpha = from_alpha + (to_alpha - from_alpha) * t;
+
+        let components: [f32; 3] = std::array::from_fn(|i| {
+            if hue_index == Some(i) {
+                return from[i] + (to[i] - from[i]) * t;
+            }
+
+            let premultiplied =
+                from[i] * from_alpha + (to[i] * to_alpha - from[i] * from_alpha) * t;
+            if alpha > 0.0 {
+                premultiplied / alpha
+            } else {
+                premultiplied
+            }
+        });
+
+        RGBA::from_srgb(self.space.to_srgb(components), alpha)
+    }
+
+    fn is_powerless(&self, components: &[f32; 3]) -> bool {
+        match self.space {
+            ColorSpace::Hsl => components[1] <= 1e-4,
+            ColorSpace::Hwb => components[1] + components[2] >= 1.0 - 1e-4,
+            ColorSpace::Lch => components[1] <= 2e-2,
+            ColorSpace::Oklch => components[1] <= 2e-4,
+            _ => false,
+        }
+    }
+}
+
+impl<'i> Parse<'i> for ColorInterpolationMethod {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        input.expect_ident_matching("in")?;
+        let space = ColorSpace::parse(input)?;
+
+        // A hue interpolation method is only valid for polar color spaces.
+        let hue = if space.hue_index().is_some() {
+            input
+                .try_parse(|input| {
+                    let hue = HueInterpolationMethod::parse(input)?;
+                    input.expect_ident_matching("hue")?;
+                    Ok::<_, ParseError<'i, CustomParseError<'i>>>(hue)
+                })
+                .unwrap_or_default()
+        } else {
+            HueInterpolationMethod::default()
+        };
+
+        Ok(ColorInterpolationMethod { space, hue })
+    }
+}
+
+impl From<ColorSpace> for ColorInterpolationMethod {
+    fn from(space: ColorSpace) -> Self {
+        ColorInterpolationMethod { space, hue: HueInterpolationMethod::default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        ColorInterpolationMethod, assert_color_interpolation_method,
+
+        custom {
+            success {
+                "in srgb" => ColorInterpolationMethod::new(ColorSpace::Srgb, HueInterpolationMethod::Shorter),
+                "in oklab" => ColorInterpolationMethod::new(ColorSpace::Oklab, HueInterpolationMethod::Shorter),
+                "in oklch" => ColorInterpolationMethod::new(ColorSpace::Oklch, HueInterpolationMethod::Shorter),
+                "in hsl longer hue" => ColorInterpolationMethod::new(ColorSpace::Hsl, HueInterpolationMethod::Longer),
+            }
+
+            failure {
+                "oklab",
+                "in",
+                "in rgb",
+            }
+        }
+    }
+
+    #[test]
+    fn mix_srgb() {
+        let method = ColorInterpolationMethod::default();
+        assert_eq!(method.mix(RGBA::BLACK, RGBA::WHITE, 0.5), RGBA::rgb(128, 128, 128));
+        assert_eq!(method.mix(RGBA::RED, RGBA::BLUE, 0.0), RGBA::RED);
+        assert_eq!(method.mix(RGBA::RED, RGBA::BLUE, 1.0), RGBA::BLUE);
+    }
+
+    #[test]
+    fn mix_premultiplied() {
+        // Interpolating towards a transparent color does not darken the result.
+        let method = ColorInterpolationMethod::default();
+        assert_eq!(method.mix(RGBA::WHITE, RGBA::TRANSPARENT, 0.5), RGBA::rgba(255, 255, 255, 128));
+    }
+
+    #[test]
+    fn mix_oklch_hue() {
+        // Red to blue along the shorter hue arc passes through purple rather than green.
+        let method = ColorInterpolationMethod::from(ColorSpace::Oklch);
+        let mid = method.mix(RGBA::RED, RGBA::BLUE, 0.5);
+        assert!(mid.red > mid.green && mid.blue > mid.green, "{:?}", mid);
+
+        // A gray end point keeps the hue of the other color.
+        let method = ColorInterpolationMethod::from(ColorSpace::Hsl);
+        let mid = method.mix(RGBA::RED, RGBA::GRAY, 0.5);
+        assert!(mid.red > mid.green && mid.green == mid.blue, "{:?}", mid);
+    }
+}
@@ -0,0 +1,293 @@
+use crate::{define_enum, Parse};
+
+define_enum! {
+    /// The color space in which colors are interpolated, such as between the stops of a gradient.
+    #[derive(Default)]
+    pub enum ColorSpace {
+        /// The gamma-encoded sRGB color space.
+        #[default]
+        "srgb": Srgb,
+        /// The linear-light sRGB color space.
+        "srgb-linear": SrgbLinear,
+        /// The CIE Lab color space.
+        "lab": Lab,
+        /// The Oklab color space.
+        "oklab": Oklab,
+        /// The polar form of the CIE Lab color space.
+        "lch": Lch,
+        /// The polar form of the Oklab color space.
+        "oklch": Oklch,
+        /// The hue, saturation and lightness color space.
+        "hsl": Hsl,
+        /// The hue, whiteness and blackness color space.
+        "hwb": Hwb,
+    }
+}
+
+impl ColorSpace {
+    /// Returns the index of the hue component for polar color spaces, or `None` for rectangular color spaces.
+    pub fn hue_index(&self) -> Option<usize> {
+        match self {
+            ColorSpace::Hsl | ColorSpace::Hwb => Some(0),
+            ColorSpace::Lch | ColorSpace::Oklch => Some(2),
+            _ => None,
+        }
+    }
+
+    /// Converts gamma-encoded sRGB components, in the range 0 to 1, into the components of this color space.
+    pub fn from_srgb(&self, rgb: [f32; 3]) -> [f32; 3] {
+        match self {
+            ColorSpace::Srgb => rgb,
+            ColorSpace::SrgbLinear => rgb.map(srgb_to_linear),
+            ColorSpace::Lab => linear_srgb_to_lab(rgb.map(srgb_to_linear)),
+            ColorSpace::Oklab => linear_srgb_to_oklab(rgb.map(srgb_to_linear)),
+            ColorSpace::Lch => lab_to_lch(linear_srgb_to_lab(rgb.map(srgb_to_linear))),
+            ColorSpace::Oklch => lab_to_lch(linear_srgb_to_oklab(rgb.map(srgb_to_linear))),
+            ColorSpace::Hsl => srgb_to_hsl(rgb),
+            ColorSpace::Hwb => srgb_to_hwb(rgb),
+        }
+    }
+
+    /// Converts the components of this color space into gamma-encoded sRGB components, which may be out of gamut.
+    pub fn to_srgb(&self, components: [f32; 3]) -> [f32; 3] {
+        match self {
+            ColorSpace::Srgb => components,
+            ColorSpace::SrgbLinear => components.map(linear_to_srgb),
+            ColorSpace::Lab => lab_to_linear_srgb(components).map(linear_to_srgb),
+            ColorSpace::Oklab => oklab_to_linear_srgb(components).map(linear_to_srgb),
+            ColorSpace::Lch => lab_to_linear_srgb(lch_to_lab(components)).map(linear_to_srgb),
+            ColorSpace::Oklch => oklab_to_linear_srgb(lch_to_lab(components)).map(linear_to_srgb),
+            ColorSpace::Hsl => hsl_to_srgb(components),
+            ColorSpace::Hwb => hwb_to_srgb(components),
+        }
+    }
+}
+
+fn srgb_to_linear(c: f32) -> f32 {
+    let abs = c.abs();
+    if abs <= 0.04045 {
+        c / 12.92
+    } else {
+        c.signum() * ((abs + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f32) -> f32 {
+    let abs = c.abs();
+    if abs <= 0.0031308 {
+        c * 12.92
+    } else {
+        c.signum() * (1.055 * abs.powf(1.0 / 2.4) - 0.055)
+    }
+}
+
+fn multiply(matrix: &[[f32; 3]; 3], v: [f32; 3]) -> [f32; 3] {
+    [
+        matrix[0][0] * v[0] + matrix[0][1] * v[1] + matrix[0][2] * v[2],
+        matrix[1][0] * v[0] + matrix[1][1] * v[1] + matrix[1][2] * v[2],
+        matrix[2][0] * v[0] + matrix[2][1] * v[1] + matrix[2][2] * v[2],
+    ]
+}
+
+// Linear sRGB to CIE XYZ, chromatically adapted to the D50 white point used by Lab.
+const LINEAR_SRGB_TO_XYZ_D50: [[f32; 3]; 3] = [
+    [0.436_074_7, 0.385_064_9, 0.143_080_4],
+    [0.222_504_5, 0.716_878_6, 0.060_616_9],
+    [0.013_932_2, 0.097_104_5, 0.714_173_3],
+];
+
+const XYZ_D50_TO_LINEAR_SRGB: [[f32; 3]; 3] = [
+    [3.133_856_1, -1.616_866_7, -0.490_614_6],
+    [-0.978_768_4, 1.916_141_5, 0.033_454],
+    [0.071_945_3, -0.228_991_4, 1.405_242_7],
+];
+
+const D50_WHITE: [f32; 3] = [0.964_22, 1.0, 0.825_21];
+
+const LAB_EPSILON: f32 = 216.0 / 24389.0;
+const LAB_KAPPA: f32 = 24389.0 / 27.0;
+
+fn linear_srgb_to_lab(rgb: [f32; 3]) -> [f32; 3] {
+    let xyz = multiply(&LINEAR_SRGB_TO_XYZ_D50, rgb);
+
+    let f = |i: usize| {
+        let t = xyz[i] / D50_WHITE[i];
+        if t > LAB_EPSILON {
+            t.cbrt()
+        } else {
+            (LAB_KAPPA * t + 16.0) / 116.0
+        }
+    };
+
+    let (fx, fy, fz) = (f(0), f(1), f(2));
+
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+fn lab_to_linear_srgb(lab: [f32; 3]) -> [f32; 3] {
+    let [l, a, b] = lab;
+    let fy = (l + 16.0) / 116.0;
+    let fx = a / 500.0 + fy;
+    let fz = fy - b / 200.0;
+
+    let inverse = |f: f32| {
+        let cubed = f * f * f;
+        if cubed > LAB_EPSILON {
+            cubed
+        } else {
+            (116.0 * f - 16.0) / LAB_KAPPA
+        }
+    };
+
+    let y = if l > LAB_KAPPA * LAB_EPSILON { fy * fy * fy } else { l / LAB_KAPPA };
+
+    let xyz = [inverse(fx) * D50_WHITE[0], y * D50_WHITE[1], inverse(fz) * D50_WHITE[2]];
+
+    multiply(&XYZ_D50_TO_LINEAR_SRGB, xyz)
+}
+
+fn linear_srgb_to_oklab(rgb: [f32; 3]) -> [f32; 3] {
+    let lms = multiply(
+        &[
+            [0.412_221_47, 0.536_332_55, 0.051_445_995],
+            [0.211_903_5, 0.680_699_5, 0.107_396_96],
+            [0.088_302_46, 0.281_718_85, 0.629_978_7],
+        ],
+        rgb,
+    );
+
+    multiply(
+        &[
+            [0.210_454_26, 0.793_617_8, -0.004_072_047],
+            [1.977_998_5, -2.428_592_2, 0.450_593_7],
+            [0.025_904_037, 0.782_771_77, -0.808_675_77],
+        ],
+        lms.map(f32::cbrt),
+    )
+}
+
+fn oklab_to_linear_srgb(lab: [f32; 3]) -> [f32; 3] {
+    let lms = multiply(
+        &[
+            [1.0, 0.396_337_78, 0.215_803_76],
+            [1.0, -0.105_561_346, -0.063_854_17],
+            [1.0, -0.089_484_18, -1.291_485_5],
+        ],
+        lab,
+    );
+
+    multiply(
+        &[
+            [4.076_741_7, -3.307_711_6, 0.230_969_94],
+            [-1.268_438, 2.609_757_4, -0.341_319_38],
+            [-0.004_196_086_3, -0.703_418_6, 1.707_614_7],
+        ],
+        lms.map(|c| c * c * c),
+    )
+}
+
+fn lab_to_lch(lab: [f32; 3]) -> [f32; 3] {
+    let [l, a, b] = lab;
+    let hue = b.atan2(a).to_degrees();
+    [l, (a * a + b * b).sqrt(), if hue < 0.0 { hue + 360.0 } else { hue }]
+}
+
+fn lch_to_lab(lch: [f32; 3]) -> [f32; 3] {
+    let [l, c, h] = lch;
+    let (sin, cos) = h.to_radians().sin_cos();
+    [l, c * cos, c * sin]
+}
+
+// Returns the hue, in degrees, along with the minimum and maximum components.
+fn srgb_hue(rgb: [f32; 3]) -> (f32, f32, f32) {
+    let [r, g, b] = rgb;
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+
+    (hue, min, max)
+}
+
+fn srgb_to_hsl(rgb: [f32; 3]) -> [f32; 3] {
+    let (hue, min, max) = srgb_hue(rgb);
+    let lightness = (max + min) / 2.0;
+    let saturation =
+        if max == min { 0.0 } else { (max - min) / (1.0 - (2.0 * lightness - 1.0).abs()) };
+
+    [hue, saturation, lightness]
+}
+
+fn hsl_to_srgb(hsl: [f32; 3]) -> [f32; 3] {
+    let [h, s, l] = hsl;
+    let h = h.rem_euclid(360.0);
+    let a = s * l.min(1.0 - l);
+    let f = |n: f32| {
+        let k = (n + h / 30.0) % 12.0;
+        l - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+
+    [f(0.0), f(8.0), f(4.0)]
+}
+
+fn srgb_to_hwb(rgb: [f32; 3]) -> [f32; 3] {
+    let (hue, min, max) = srgb_hue(rgb);
+    [hue, min, 1.0 - max]
+}
+
+fn hwb_to_srgb(hwb: [f32; 3]) -> [f32; 3] {
+    let [h, w, b] = hwb;
+    if w + b >= 1.0 {
+        let gray = w / (w + b);
+        return [gray; 3];
+    }
+
+    hsl_to_srgb([h, 1.0, 0.5]).map(|c| c * (1.0 - w - b) + w)
+}
+
+#[cfg(test)]
+mod tests_conversion {
+    use super::*;
+
+    fn assert_round_trip(space: ColorSpace, rgb: [f32; 3]) {
+        let result = space.to_srgb(space.from_srgb(rgb));
+        for (value, expected) in result.iter().zip(rgb.iter()) {
+            assert!((value - expected).abs() < 1e-3, "{:?}: {:?} != {:?}", space, result, rgb);
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for space in [
+            ColorSpace::Srgb,
+            ColorSpace::SrgbLinear,
+            ColorSpace::Lab,
+            ColorSpace::Oklab,
+            ColorSpace::Lch,
+            ColorSpace::Oklch,
+            ColorSpace::Hsl,
+            ColorSpace::Hwb,
+        ] {
+            for rgb in [[1.0, 0.0, 0.0], [0.2, 0.6, 0.4], [0.5, 0.5, 0.5], [0.0, 0.0, 1.0]] {
+                assert_round_trip(space, rgb);
+            }
+        }
+    }
+
+    #[test]
+    fn oklab_white() {
+        let [l, a, b] = ColorSpace::Oklab.from_srgb([1.0, 1.0, 1.0]);
+        assert!((l - 1.0).abs() < 1e-3);
+        assert!(a.abs() < 1e-3);
+        assert!(b.abs() < 1e-3);
+    }
+}
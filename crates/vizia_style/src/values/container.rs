@@ -0,0 +1,118 @@
+use cssparser::*;
+
+use crate::{define_enum, CustomParseError, Parse};
+
+define_enum! {
+    /// Determines whether a view is a container which can be queried by `@container` rules.
+    #[derive(Default)]
+    pub enum ContainerType {
+        /// The view is not a size container.
+        #[default]
+        "normal": Normal,
+        /// The width and height of the view can be queried.
+        "size": Size,
+        /// Only the width of the view can be queried.
+        "inline-size": InlineSize,
+    }
+}
+
+/// The names of a container, which `@container` rules can use to query a specific ancestor container.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ContainerName(pub Vec<String>);
+
+impl ContainerName {
+    /// Returns true if the container has the given name.
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.iter().any(|container_name| container_name == name)
+    }
+}
+
+impl From<&str> for ContainerName {
+    fn from(names: &str) -> Self {
+        ContainerName(names.split_whitespace().map(String::from).collect())
+    }
+}
+
+impl<'i> Parse<'i> for ContainerName {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+            return Ok(ContainerName::default());
+        }
+
+        let mut names = vec![parse_container_name(input)?];
+        while let Ok(name) = input.try_parse(parse_container_name) {
+            names.push(name);
+        }
+
+        Ok(ContainerName(names))
+    }
+}
+
+// Parses the name of a container, which can't be a keyword used by container queries.
+pub(crate) fn parse_container_name<'i>(
+    input: &mut Parser<'i, '_>,
+) -> Result<String, ParseError<'i, CustomParseError<'i>>> {
+    let location = input.current_source_location();
+    let name = input.expect_ident_cloned()?;
+    match_ignore_ascii_case! { &name,
+        "none" | "and" | "not" | "or" | "normal" | "size" | "inline-size" => {
+            Err(location.new_unexpected_token_error(Token::Ident(name.clone())))
+        },
+        _ => Ok(name.to_string()),
+    }
+}
+
+/// The shorthand for the name and type of a container, e.g. `container: sidebar / inline-size`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Container {
+    pub name: ContainerName,
+    pub container_type: ContainerType,
+}
+
+impl<'i> Parse<'i> for Container {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let name = ContainerName::parse(input)?;
+
+        let container_type = if input.try_parse(|input| input.expect_delim('/')).is_ok() {
+            ContainerType::parse(input)?
+        } else {
+            ContainerType::default()
+        };
+
+        Ok(Container { name, container_type })
+    }
+}
+
+#[cfg(test)]
+mod tests_shorthand {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        Container, assert_container,
+
+        custom {
+            success {
+                "none" => Container::default(),
+                "sidebar" => Container {
+                    name: ContainerName(vec![String::from("sidebar")]),
+                    container_type: ContainerType::Normal,
+                },
+                "sidebar panel / inline-size" => Container {
+                    name: ContainerName(vec![String::from("sidebar"), String::from("panel")]),
+                    container_type: ContainerType::InlineSize,
+                },
+                "none / size" => Container {
+                    name: ContainerName::default(),
+                    container_type: ContainerType::Size,
+                },
+            }
+
+            failure {
+                "size",
+                "sidebar /",
+                "sidebar / large",
+            }
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use cssparser::*;
+
+use crate::{CustomParseError, Parse};
+
+/// An item of the content generated by a `::before` or `::after` pseudo-element.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContentItem {
+    /// A string, which can contain escaped icon codepoints such as `"\e900"`.
+    String(String),
+    /// The value of an attribute of the originating view, e.g. `attr(data-unit)`.
+    Attr(String),
+    /// The value of a counter, e.g. `counter(item)`.
+    Counter(String),
+}
+
+impl<'i> Parse<'i> for ContentItem {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let location = input.current_source_location();
+        match input.next()?.clone() {
+            Token::QuotedString(string) => Ok(ContentItem::String(string.to_string())),
+            Token::Function(function) => input.parse_nested_block(|input| {
+                match_ignore_ascii_case! { &function,
+                    "attr" => Ok(ContentItem::Attr(input.expect_ident()?.to_string())),
+                    "counter" => Ok(ContentItem::Counter(input.expect_ident()?.to_string())),
+                    _ => Err(location.new_unexpected_token_error(Token::Function(function.clone()))),
+                }
+            }),
+            token => Err(location.new_unexpected_token_error(token)),
+        }
+    }
+}
+
+/// The content generated by a `::before` or `::after` pseudo-element.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum Content {
+    /// No content is generated.
+    #[default]
+    None,
+    /// The generated content is the concatenation of the items.
+    Items(Vec<ContentItem>),
+}
+
+impl<'i> Parse<'i> for Content {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if input.try_parse(|input| input.expect_ident_matching("none")).is_ok()
+            || input.try_parse(|input| input.expect_ident_matching("normal")).is_ok()
+        {
+            return Ok(Content::None);
+        }
+
+        let mut items = vec![ContentItem::parse(input)?];
+        while let Ok(item) = input.try_parse(ContentItem::parse) {
+            items.push(item);
+        }
+
+        Ok(Content::Items(items))
+    }
+}
+
+/// A change to the value of a named counter, used by the `counter-reset` and `counter-increment` properties.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CounterChange {
+    /// The name of the counter.
+    pub name: String,
+    /// The value to reset the counter to, or increment it by, if specified.
+    pub value: Option<i32>,
+}
+
+impl CounterChange {
+    pub fn new(name: &str, value: Option<i32>) -> Self {
+        Self { name: name.to_owned(), value }
+    }
+}
+
+impl<'i> Parse<'i> for Vec<CounterChange> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+            return Ok(Vec::new());
+        }
+
+        let mut changes = Vec::new();
+        loop {
+            let location = input.current_source_location();
+            let Ok(name) = input.try_parse(|input| input.expect_ident_cloned()) else {
+                break;
+            };
+
+            if name.eq_ignore_ascii_case("none") {
+                return Err(location.new_unexpected_token_error(Token::Ident(name)));
+            }
+
+            let value = input.try_parse(|input| input.expect_integer()).ok();
+            changes.push(CounterChange { name: name.to_string(), value });
+        }
+
+        if changes.is_empty() {
+            let location = input.current_source_location();
+            return Err(location.new_custom_error(CustomParseError::InvalidDeclaration));
+        }
+
+        Ok(changes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        Content, assert_content,
+
+        custom {
+            success {
+                "none" => Content::None,
+                "normal" => Content::None,
+                "\"*\"" => Content::Items(vec![ContentItem::String(String::from("*"))]),
+                "\"\\e900\"" => Content::Items(vec![ContentItem::String(String::from("\u{e900}"))]),
+                "attr(data-unit)" => Content::Items(vec![ContentItem::Attr(String::from("data-unit"))]),
+                "counter(item) \". \"" => Content::Items(vec![
+                    ContentItem::Counter(String::from("item")),
+                    ContentItem::String(String::from(". ")),
+                ]),
+            }
+
+            failure {
+                "10px",
+                "url(foo)",
+            }
+        }
+    }
+
+    assert_parse! {
+        Vec<CounterChange>, assert_counter_changes,
+
+        custom {
+            success {
+                "none" => vec![],
+                "item" => vec![CounterChange::new("item", None)],
+                "item 2 section -1" => vec![CounterChange::new("item", Some(2)), CounterChange::new("section", Some(-1))],
+            }
+
+            failure {
+                "2",
+                "item none",
+            }
+        }
+    }
+}
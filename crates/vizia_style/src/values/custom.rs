@@ -70,7 +70,9 @@ impl TokenOrValue<'_> {
 }
 
 impl<'i> TokenList<'i> {
-    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+    pub fn parse<'t>(
+        input: &mut Parser<'i, 't>,
+    ) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
         input.parse_until_before(Delimiter::Bang | Delimiter::Semicolon, |input| {
             let mut tokens = vec![];
             TokenList::parse_into(input, &mut tokens)?;
@@ -146,9 +148,9 @@ impl<'i> TokenList<'i> {
                 //     last_is_delim = false;
                 //     last_is_whitespace = false;
                 // }
-                Ok(&cssparser::Token::UnquotedUrl(_)) => {
-                    input.reset(&state);
+                Ok(token @ &cssparser::Token::UnquotedUrl(_)) => {
                     //tokens.push(TokenOrValue::Url(Url::parse(input)?));
+                    tokens.push(token.clone().into());
                     last_is_delim = false;
                     last_is_whitespace = false;
                 }
@@ -190,6 +192,86 @@ impl<'i> TokenList<'i> {
 
         Ok(())
     }
+
+    /// Returns whether the token list contains a `var()` reference.
+    pub fn has_variables(&self) -> bool {
+        self.0.iter().any(|token| matches!(token, TokenOrValue::Var(_)))
+    }
+
+    /// Serializes the token list, replacing any `var()` references with the value returned by `lookup`,
+    /// or with their fallback if `lookup` returns `None`.
+    ///
+    /// Returns `None` if a referenced variable is undefined and has no fallback.
+    pub fn substitute_variables(
+        &self,
+        lookup: &mut dyn FnMut(&str) -> Option<String>,
+    ) -> Option<String> {
+        let mut dest = String::new();
+        self.write(&mut dest, &mut Some(lookup))?;
+        Some(dest.trim().to_owned())
+    }
+
+    fn write(
+        &self,
+        dest: &mut String,
+        lookup: &mut Option<&mut dyn FnMut(&str) -> Option<String>>,
+    ) -> Option<()> {
+        for token in self.0.iter() {
+            match token {
+                TokenOrValue::Token(Token::WhiteSpace(_)) => write_space(dest),
+                // Whitespace following a delimeter is dropped when parsing, but is
+                // required to round-trip values such as `calc(1px - 2px)`.
+                TokenOrValue::Token(token @ Token::Delim(_)) => {
+                    write_space(dest);
+                    token.to_css(dest).ok()?;
+                    dest.push(' ');
+                }
+                TokenOrValue::Token(token @ Token::Comma) => {
+                    token.to_css(dest).ok()?;
+                    dest.push(' ');
+                }
+                TokenOrValue::Token(token) => token.to_css(dest).ok()?,
+                TokenOrValue::Color(color) => color.to_css(dest).ok()?,
+                TokenOrValue::Var(variable) => {
+                    write_space(dest);
+                    variable.write(dest, lookup)?;
+                    dest.push(' ');
+                }
+            }
+        }
+
+        Some(())
+    }
+}
+
+impl ToCss for TokenList<'_> {
+    fn to_css<W>(&self, dest: &mut W) -> std::fmt::Result
+    where
+        W: std::fmt::Write,
+    {
+        let mut css = String::new();
+        self.write(&mut css, &mut None).ok_or(std::fmt::Error)?;
+        dest.write_str(css.trim())
+    }
+}
+
+/// Substitutes the `var()` references within a css value string using the given `lookup`.
+///
+/// Returns `None` if the value could not be parsed or if a referenced variable is undefined and has no fallback.
+pub fn substitute_variables(
+    value: &str,
+    lookup: &mut dyn FnMut(&str) -> Option<String>,
+) -> Option<String> {
+    let mut input = ParserInput::new(value);
+    let mut parser = Parser::new(&mut input);
+    TokenList::parse(&mut parser).ok()?.substitute_variables(lookup)
+}
+
+#[inline]
+fn write_space(dest: &mut String) {
+    if !dest.is_empty() && !dest.ends_with([' ', '(']) {
+        dest.push(' ');
+    }
 }
 
 #[inline]
@@ -234,13 +316,37 @@ impl<'i> Variable<'i> {
 
         Ok(Variable { name, fallback })
     }
+
+    fn write(
+        &self,
+        dest: &mut String,
+        lookup: &mut Option<&mut dyn FnMut(&str) -> Option<String>>,
+    ) -> Option<()> {
+        if let Some(lookup) = lookup.as_mut() {
+            if let Some(value) = lookup(&self.name.0) {
+                dest.push_str(&value);
+                return Some(());
+            }
+        } else {
+            dest.push_str("var(");
+            dest.push_str(&self.name.0);
+            if let Some(fallback) = &self.fallback {
+                dest.push_str(", ");
+                fallback.write(dest, lookup)?;
+            }
+            dest.push(')');
+            return Some(());
+        }
+
+        self.fallback.as_ref()?.write(dest, lookup)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use cssparser::CowRcStr;
+    use cssparser::{CowRcStr, ToCss};
 
-    use crate::CustomProperty;
+    use crate::{substitute_variables, CustomProperty};
 
     #[test]
     fn parse_custom_ident() {
@@ -277,4 +383,39 @@ mod tests {
         let _result = CustomProperty::parse(CowRcStr::from("custom"), &mut parser);
         // assert_eq!(result, Ok($value));
     }
+
+    #[test]
+    fn serialize_variable() {
+        let mut parser_input = cssparser::ParserInput::new("1px solid var(--color, red)");
+        let mut parser = cssparser::Parser::new(&mut parser_input);
+        let result = CustomProperty::parse(CowRcStr::from("--border"), &mut parser).unwrap();
+        assert!(result.value.has_variables());
+        assert_eq!(result.value.to_css_string(), "1px solid var(--color, red)");
+    }
+
+    #[test]
+    fn substitute_variable() {
+        let mut lookup = |name: &str| (name == "--size").then(|| String::from("10px"));
+
+        assert_eq!(substitute_variables("var(--size)", &mut lookup), Some(String::from("10px")));
+        assert_eq!(
+            substitute_variables("calc(var(--size) * 2)", &mut lookup),
+            Some(String::from("calc(10px * 2)"))
+        );
+    }
+
+    #[test]
+    fn substitute_variable_fallback() {
+        let mut lookup = |_: &str| None;
+
+        assert_eq!(
+            substitute_variables("1px solid var(--color, red)", &mut lookup),
+            Some(String::from("1px solid red"))
+        );
+        assert_eq!(
+            substitute_variables("var(--color, var(--other, blue))", &mut lookup),
+            Some(String::from("blue"))
+        );
+        assert_eq!(substitute_variables("var(--color)", &mut lookup), None);
+    }
 }
@@ -1,4 +1,5 @@
-use crate::{macros::impl_parse, Parse};
+use crate::{macros::impl_parse, CustomParseError, Parse};
+use cssparser::{ParseError, Parser};
 pub use std::time::Duration;
 
 impl_parse! {
@@ -16,6 +17,12 @@ impl_parse! {
     }
 }
 
+impl<'i> Parse<'i> for Vec<Duration> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        input.parse_comma_separated(Duration::parse)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
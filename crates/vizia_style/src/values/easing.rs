@@ -1,7 +1,7 @@
 use crate::{CustomParseError, Parse};
 use cssparser::*;
 
-#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub enum EasingFunction {
     #[default]
     Linear,
@@ -10,7 +10,126 @@ pub enum EasingFunction {
     EaseOut,
     EaseInOut,
     CubicBezier(f32, f32, f32, f32),
-    // TODO: Steps
+    /// Divides the output into a number of equal steps, e.g. `steps(4, jump-start)`.
+    Steps {
+        count: u32,
+        position: StepPosition,
+    },
+    /// Interpolates linearly between a list of stops, e.g. `linear(0, 0.25 75%, 1)`.
+    LinearStops(Vec<LinearStop>),
+    /// A physically based spring which starts at rest, e.g. `spring(100, 10, 1)`.
+    ///
+    /// The duration of the animation is mapped onto the time taken for the spring to settle.
+    Spring {
+        stiffness: f32,
+        damping: f32,
+        mass: f32,
+    },
+}
+
+/// Determines where the jumps of a `steps()` easing function occur.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum StepPosition {
+    /// The first jump happens at the start of the animation.
+    JumpStart,
+    /// The last jump happens at the end of the animation.
+    #[default]
+    JumpEnd,
+    /// There is no jump at the start or end of the animation.
+    JumpNone,
+    /// There is a jump at both the start and end of the animation.
+    JumpBoth,
+}
+
+impl<'i> Parse<'i> for StepPosition {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let location = input.current_source_location();
+        let ident = input.expect_ident()?;
+        Ok(match_ignore_ascii_case! { ident,
+            "jump-start" | "start" => StepPosition::JumpStart,
+            "jump-end" | "end" => StepPosition::JumpEnd,
+            "jump-none" => StepPosition::JumpNone,
+            "jump-both" => StepPosition::JumpBoth,
+            _ => return Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
+        })
+    }
+}
+
+/// A stop of a `linear()` easing function, mapping an input progress to an output progress.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearStop {
+    pub input: f32,
+    pub output: f32,
+}
+
+impl LinearStop {
+    pub fn new(input: f32, output: f32) -> Self {
+        Self { input, output }
+    }
+}
+
+// Parses the stops of a `linear()` easing function, filling in any missing input progress values.
+fn parse_linear_stops<'i, 't>(
+    input: &mut Parser<'i, 't>,
+) -> Result<Vec<LinearStop>, ParseError<'i, CustomParseError<'i>>> {
+    let location = input.current_source_location();
+
+    // Each stop is a number followed by up to two percentages, where two percentages are the same as two stops.
+    let parsed = input.parse_comma_separated(
+        |input| -> Result<_, ParseError<'i, CustomParseError<'i>>> {
+            let output = input.expect_number()?;
+            let first = input.try_parse(|input| input.expect_percentage()).ok();
+            let second = input.try_parse(|input| input.expect_percentage()).ok();
+            Ok((output, first, second))
+        },
+    )?;
+
+    let mut stops: Vec<(f32, Option<f32>)> = Vec::new();
+    for (output, first, second) in parsed {
+        stops.push((output, first));
+        if second.is_some() {
+            stops.push((output, second));
+        }
+    }
+
+    if stops.len() < 2 {
+        return Err(location.new_custom_error(CustomParseError::InvalidDeclaration));
+    }
+
+    // The first and last stops default to the start and end of the animation,
+    // and a stop can never come before a previous stop.
+    let last = stops.len() - 1;
+    stops[0].1.get_or_insert(0.0);
+    stops[last].1.get_or_insert(1.0);
+    let mut max = f32::MIN;
+    for stop in stops.iter_mut() {
+        if let Some(input) = stop.1.as_mut() {
+            *input = input.max(max);
+            max = *input;
+        }
+    }
+
+    // Stops without an input are spread evenly between the stops either side of them.
+    let mut resolved = Vec::with_capacity(stops.len());
+    let mut previous = 0;
+    for (index, (output, input)) in stops.iter().enumerate() {
+        let input = match input {
+            Some(input) => {
+                previous = index;
+                *input
+            }
+            None => {
+                let next = (index..stops.len()).find(|&i| stops[i].1.is_some()).unwrap_or(last);
+                let start = stops[previous].1.unwrap_or_default();
+                let end = stops[next].1.unwrap_or(1.0);
+                start + (end - start) * (index - previous) as f32 / (next - previous) as f32
+            }
+        };
+
+        resolved.push(LinearStop::new(input, *output));
+    }
+
+    Ok(resolved)
 }
 
 impl<'i> Parse<'i> for EasingFunction {
@@ -23,8 +142,8 @@ impl<'i> Parse<'i> for EasingFunction {
               "ease-in" => EasingFunction::EaseIn,
               "ease-out" => EasingFunction::EaseOut,
               "ease-in-out" => EasingFunction::EaseInOut,
-            //   "step-start" => EasingFunction::Steps { count: 1, position: StepPosition::Start },
-            //   "step-end" => EasingFunction::Steps { count: 1, position: StepPosition::End },
+              "step-start" => EasingFunction::Steps { count: 1, position: StepPosition::JumpStart },
+              "step-end" => EasingFunction::Steps { count: 1, position: StepPosition::JumpEnd },
               _ => return Err(location.new_unexpected_token_error(Token::Ident(ident.clone())))
             };
             return Ok(keyword);
@@ -44,16 +163,90 @@ impl<'i> Parse<'i> for EasingFunction {
                 let y2 = input.try_parse(|input| input.expect_number())?;
                 Ok(EasingFunction::CubicBezier(x1, y1, x2, y2))
               },
-            //   "steps" => {
-            //     let count = CSSInteger::parse(input)?;
-            //     let position = input.try_parse(|input| {
-            //       input.expect_comma()?;
-            //       StepPosition::parse(input)
-            //     }).unwrap_or_default();
-            //     Ok(EasingFunction::Steps { count, position })
-            //   },
+              "steps" => {
+                let count = input.expect_integer()?;
+                let position = input.try_parse(|input| {
+                  input.expect_comma()?;
+                  StepPosition::parse(input)
+                }).unwrap_or_default();
+
+                // There must be at least one step, or two if neither end jumps.
+                let min = if position == StepPosition::JumpNone { 2 } else { 1 };
+                if count < min {
+                    return Err(location.new_custom_error(CustomParseError::InvalidDeclaration));
+                }
+
+                Ok(EasingFunction::Steps { count: count as u32, position })
+              },
+              "linear" => {
+                Ok(EasingFunction::LinearStops(parse_linear_stops(input)?))
+              },
+              "spring" => {
+                let stiffness = input.expect_number()?;
+                input.expect_comma()?;
+                let damping = input.expect_number()?;
+                let mass = input.try_parse(|input| {
+                  input.expect_comma()?;
+                  input.expect_number()
+                }).unwrap_or(1.0);
+
+                if stiffness <= 0.0 || damping < 0.0 || mass <= 0.0 {
+                    return Err(location.new_custom_error(CustomParseError::InvalidDeclaration));
+                }
+
+                Ok(EasingFunction::Spring { stiffness, damping, mass })
+              },
               _ => Err(location.new_unexpected_token_error(Token::Ident(function.clone())))
             }
         })
     }
 }
+
+impl<'i> Parse<'i> for Vec<EasingFunction> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        input.parse_comma_separated(EasingFunction::parse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        EasingFunction, assert_easing_function,
+
+        custom {
+            success {
+                "ease-in" => EasingFunction::EaseIn,
+                "cubic-bezier(0.1, 0.7, 1.0, 0.1)" => EasingFunction::CubicBezier(0.1, 0.7, 1.0, 0.1),
+                "step-start" => EasingFunction::Steps { count: 1, position: StepPosition::JumpStart },
+                "steps(4)" => EasingFunction::Steps { count: 4, position: StepPosition::JumpEnd },
+                "steps(4, jump-both)" => EasingFunction::Steps { count: 4, position: StepPosition::JumpBoth },
+                "steps(2, start)" => EasingFunction::Steps { count: 2, position: StepPosition::JumpStart },
+                "linear(0, 1)" => EasingFunction::LinearStops(vec![LinearStop::new(0.0, 0.0), LinearStop::new(1.0, 1.0)]),
+                "linear(0, 0.25, 1)" => EasingFunction::LinearStops(vec![
+                    LinearStop::new(0.0, 0.0),
+                    LinearStop::new(0.5, 0.25),
+                    LinearStop::new(1.0, 1.0),
+                ]),
+                "linear(0, 0.5 25% 75%, 1)" => EasingFunction::LinearStops(vec![
+                    LinearStop::new(0.0, 0.0),
+                    LinearStop::new(0.25, 0.5),
+                    LinearStop::new(0.75, 0.5),
+                    LinearStop::new(1.0, 1.0),
+                ]),
+                "spring(100, 10)" => EasingFunction::Spring { stiffness: 100.0, damping: 10.0, mass: 1.0 },
+                "spring(200, 20, 2)" => EasingFunction::Spring { stiffness: 200.0, damping: 20.0, mass: 2.0 },
+            }
+
+            failure {
+                "steps(0)",
+                "steps(1, jump-none)",
+                "steps(2.5)",
+                "linear(0)",
+                "spring(0, 10)",
+            }
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use crate::{Angle, CustomParseError, Length, Parse, PercentageOrNumber, TextShadow};
+use cssparser::*;
+
+/// A graphical effect applied to a view, or to the area behind a view when used as a backdrop filter.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Filter {
+    /// Blurs the content with the given radius.
+    Blur(Length),
+    /// Multiplies the brightness of the content, where `1.0` leaves it unchanged.
+    Brightness(f32),
+    /// Adjusts the contrast of the content, where `1.0` leaves it unchanged.
+    Contrast(f32),
+    /// Converts the content to grayscale, where `1.0` is completely grayscale.
+    Grayscale(f32),
+    /// Rotates the hue of the content by the given angle.
+    HueRotate(Angle),
+    /// Inverts the colors of the content, where `1.0` is completely inverted.
+    Invert(f32),
+    /// Multiplies the opacity of the content.
+    Opacity(f32),
+    /// Adjusts the saturation of the content, where `1.0` leaves it unchanged.
+    Saturate(f32),
+    /// Converts the content to sepia, where `1.0` is completely sepia.
+    Sepia(f32),
+    /// Draws a blurred, offset copy of the alpha mask of the content behind it.
+    DropShadow(TextShadow),
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Filter::Blur(Length::default())
+    }
+}
+
+impl Filter {
+    /// Returns the row-major 4x5 color matrix of the filter, or `None` for filters which are not color filters.
+    ///
+    /// The matrices are those given in the [Filter Effects specification](https://drafts.fxtf.org/filter-effects/).
+    #[rustfmt::skip]
+    pub fn color_matrix(&self) -> Option<[f32; 20]> {
+        let matrix = match self {
+            Filter::Brightness(amount) => {
+                let a = *amount;
+                [
+                    a,   0.0, 0.0, 0.0, 0.0,
+                    0.0, a,   0.0, 0.0, 0.0,
+                    0.0, 0.0, a,   0.0, 0.0,
+                    0.0, 0.0, 0.0, 1.0, 0.0,
+                ]
+            }
+
+            Filter::Contrast(amount) => {
+                let a = *amount;
+                let o = (1.0 - a) / 2.0;
+                [
+                    a,   0.0, 0.0, 0.0, o,
+                    0.0, a,   0.0, 0.0, o,
+                    0.0, 0.0, a,   0.0, o,
+                    0.0, 0.0, 0.0, 1.0, 0.0,
+                ]
+            }
+
+            Filter::Grayscale(amount) => {
+                let a = 1.0 - amount.clamp(0.0, 1.0);
+                [
+                    0.2126 + 0.7874 * a, 0.7152 - 0.7152 * a, 0.0722 - 0.0722 * a, 0.0, 0.0,
+                    0.2126 - 0.2126 * a, 0.7152 + 0.2848 * a, 0.0722 - 0.0722 * a, 0.0, 0.0,
+                    0.2126 - 0.2126 * a, 0.7152 - 0.7152 * a, 0.0722 + 0.9278 * a, 0.0, 0.0,
+                    0.0,                 0.0,                 0.0,                 1.0, 0.0,
+                ]
+            }
+
+            Filter::HueRotate(angle) => {
+                let (s, c) = angle.to_radians().sin_cos();
+                [
+                    0.213 + 0.787 * c - 0.213 * s, 0.715 - 0.715 * c - 0.715 * s, 0.072 - 0.072 * c + 0.928 * s, 0.0, 0.0,
+                    0.213 - 0.213 * c + 0.143 * s, 0.715 + 0.285 * c + 0.140 * s, 0.072 - 0.072 * c - 0.283 * s, 0.0, 0.0,
+                    0.213 - 0.213 * c - 0.787 * s, 0.715 - 0.715 * c + 0.715 * s, 0.072 + 0.928 * c + 0.072 * s, 0.0, 0.0,
+                    0.0,                           0.0,                           0.0,                           1.0, 0.0,
+                ]
+            }
+
+            Filter::Invert(amount) => {
+                let a = amount.clamp(0.0, 1.0);
+                let s = 1.0 - 2.0 * a;
+                [
+                    s,   0.0, 0.0, 0.0, a,
+                    0.0, s,   0.0, 0.0, a,
+                    0.0, 0.0, s,   0.0, a,
+                    0.0, 0.0, 0.0, 1.0, 0.0,
+                ]
+            }
+
+            Filter::Opacity(amount) => {
+                let a = amount.clamp(0.0, 1.0);
+                [
+                    1.0, 0.0, 0.0, 0.0, 0.0,
+                    0.0, 1.0, 0.0, 0.0, 0.0,
+                    0.0, 0.0, 1.0, 0.0, 0.0,
+                    0.0, 0.0, 0.0, a,   0.0,
+                ]
+            }
+
+            Filter::Saturate(amount) => {
+                let s = *amount;
+                [
+                    0.213 + 0.787 * s, 0.715 - 0.715 * s, 0.072 - 0.072 * s, 0.0, 0.0,
+                    0.213 - 0.213 * s, 0.715 + 0.285 * s, 0.072 - 0.072 * s, 0.0, 0.0,
+                    0.213 - 0.213 * s, 0.715 - 0.715 * s, 0.072 + 0.928 * s, 0.0, 0.0,
+                    0.0,               0.0,               0.0,               1.0, 0.0,
+                ]
+            }
+
+            Filter::Sepia(amount) => {
+                let a = 1.0 - amount.clamp(0.0, 1.0);
+                [
+                    0.393 + 0.607 * a, 0.769 - 0.769 * a, 0.189 - 0.189 * a, 0.0, 0.0,
+                    0.349 - 0.349 * a, 0.686 + 0.314 * a, 0.168 - 0.168 * a, 0.0, 0.0,
+                    0.272 - 0.272 * a, 0.534 - 0.534 * a, 0.131 + 0.869 * a, 0.0, 0.0,
+                    0.0,               0.0,               0.0,               1.0, 0.0,
+                ]
+            }
+
+            Filter::Blur(_) | Filter::DropShadow(_) => return None,
+        };
+
+        Some(matrix)
+    }
+}
+
+impl<'i> Parse<'i> for Filter {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let function = input.expect_function()?.clone();
+
+        input.parse_nested_block(|input| {
+            let location = input.current_source_location();
+
+            match_ignore_ascii_case! { &function,
+                "blur" => {
+                    Ok(Filter::Blur(input.try_parse(Length::parse).unwrap_or(Length::px(0.0))))
+                },
+
+                "brightness" => Ok(Filter::Brightness(parse_amount(input)?)),
+                "contrast" => Ok(Filter::Contrast(parse_amount(input)?)),
+                "grayscale" => Ok(Filter::Grayscale(parse_amount(input)?)),
+                "invert" => Ok(Filter::Invert(parse_amount(input)?)),
+                "opacity" => Ok(Filter::Opacity(parse_amount(input)?)),
+                "saturate" => Ok(Filter::Saturate(parse_amount(input)?)),
+                "sepia" => Ok(Filter::Sepia(parse_amount(input)?)),
+
+                "hue-rotate" => {
+                    Ok(Filter::HueRotate(input.try_parse(Angle::parse).unwrap_or(Angle::Deg(0.0))))
+                },
+
+                "drop-shadow" => Ok(Filter::DropShadow(TextShadow::parse(input)?)),
+
+                _ => {
+                    Err(location.new_unexpected_token_error(Token::Ident(function)))
+                }
+            }
+        })
+    }
+}
+
+/// Parses the amount of a color filter, which is a non-negative number or percentage that defaults to 1.
+fn parse_amount<'i>(
+    input: &mut Parser<'i, '_>,
+) -> Result<f32, ParseError<'i, CustomParseError<'i>>> {
+    let location = input.current_source_location();
+    match input.try_parse(PercentageOrNumber::parse) {
+        Ok(amount) if amount.to_factor() < 0.0 => Err(ParseError {
+            kind: ParseErrorKind::Custom(CustomParseError::InvalidValue),
+            location,
+        }),
+        Ok(amount) => Ok(amount.to_factor()),
+        Err(_) => Ok(1.0),
+    }
+}
+
+impl<'i> Parse<'i> for Vec<Filter> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if input.try_parse(|input| input.expect_ident_matching("none")).is_ok() {
+            return Ok(Vec::new());
+        }
+
+        // Filter functions are separated by whitespace and applied in order.
+        let mut filters = vec![Filter::parse(input)?];
+        while let Ok(filter) = input.try_parse(Filter::parse) {
+            filters.push(filter);
+        }
+
+        Ok(filters)
+    }
+}
+
+impl From<Filter> for Vec<Filter> {
+    fn from(filter: Filter) -> Self {
+        vec![filter]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::assert_parse, Color};
+
+    assert_parse! {
+        Filter, assert_filter,
+
+        custom {
+            success {
+                "blur(4px)" => Filter::Blur(Length::px(4.0)),
+                "blur()" => Filter::Blur(Length::px(0.0)),
+                "brightness(1.5)" => Filter::Brightness(1.5),
+                "contrast(50%)" => Filter::Contrast(0.5),
+                "grayscale()" => Filter::Grayscale(1.0),
+                "hue-rotate(90deg)" => Filter::HueRotate(Angle::Deg(90.0)),
+                "invert(100%)" => Filter::Invert(1.0),
+                "opacity(0.5)" => Filter::Opacity(0.5),
+                "saturate(2)" => Filter::Saturate(2.0),
+                "sepia(0)" => Filter::Sepia(0.0),
+                "drop-shadow(2px 4px 6px red)" => Filter::DropShadow(TextShadow::new(
+                    Length::px(2.0),
+                    Length::px(4.0),
+                    Some(Length::px(6.0)),
+                    Some(Color::rgb(255, 0, 0)),
+                )),
+            }
+
+            failure {
+                "blur",
+                "brightness(-1)",
+                "drop-shadow(2px)",
+                "shine(1)",
+            }
+        }
+    }
+
+    assert_parse! {
+        Vec<Filter>, assert_filters,
+
+        custom {
+            success {
+                "none" => vec![],
+                "grayscale(1) brightness(150%)" => vec![Filter::Grayscale(1.0), Filter::Brightness(1.5)],
+            }
+
+            failure {
+                "test",
+                "10px",
+            }
+        }
+    }
+
+    #[test]
+    fn identity_color_matrix() {
+        let identity = [
+            1.0, 0.0, 0.0, 0.0, 0.0, //
+            0.0, 1.0, 0.0, 0.0, 0.0, //
+            0.0, 0.0, 1.0, 0.0, 0.0, //
+            0.0, 0.0, 0.0, 1.0, 0.0,
+        ];
+
+        for filter in [
+            Filter::Brightness(1.0),
+            Filter::Contrast(1.0),
+            Filter::Grayscale(0.0),
+            Filter::HueRotate(Angle::Deg(0.0)),
+            Filter::Invert(0.0),
+            Filter::Opacity(1.0),
+            Filter::Saturate(1.0),
+            Filter::Sepia(0.0),
+        ] {
+            let matrix = filter.color_matrix().unwrap();
+            for (value, expected) in matrix.iter().zip(identity.iter()) {
+                assert!((value - expected).abs() < 1e-3, "{:?}: {:?}", filter, matrix);
+            }
+        }
+
+        assert_eq!(Filter::Blur(Length::px(1.0)).color_matrix(), None);
+    }
+}
@@ -0,0 +1,104 @@
+use cssparser::*;
+
+use crate::{CustomParseError, Parse};
+
+/// An OpenType font feature, such as `"liga" 0` to disable ligatures.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FontFeature {
+    /// The four character tag of the feature.
+    pub tag: String,
+    /// The value of the feature, where `0` disables the feature and `1` enables it.
+    pub value: u32,
+}
+
+impl FontFeature {
+    /// Creates a new font feature.
+    pub fn new(tag: impl Into<String>, value: u32) -> Self {
+        Self { tag: tag.into(), value }
+    }
+}
+
+impl<'i> Parse<'i> for FontFeature {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let location = input.current_source_location();
+
+        let tag = input.expect_string()?.clone();
+
+        if tag.len() != 4 || !tag.chars().all(|c| c.is_ascii_graphic() || c == ' ') {
+            return Err(ParseError {
+                kind: ParseErrorKind::Custom(CustomParseError::InvalidValue),
+                location,
+            });
+        }
+
+        let value = if let Ok(value) = input.try_parse(u32::parse) {
+            value
+        } else if input.try_parse(|input| input.expect_ident_matching("off")).is_ok() {
+            0
+        } else {
+            let _ = input.try_parse(|input| input.expect_ident_matching("on"));
+            1
+        };
+
+        Ok(FontFeature::new(tag.as_ref(), value))
+    }
+}
+
+impl<'i> Parse<'i> for Vec<FontFeature> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+            return Ok(Vec::new());
+        }
+
+        input.parse_comma_separated(FontFeature::parse)
+    }
+}
+
+impl From<&str> for FontFeature {
+    fn from(s: &str) -> Self {
+        let mut input = ParserInput::new(s);
+        let mut parser = Parser::new(&mut input);
+        FontFeature::parse(&mut parser).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        FontFeature, assert_font_feature,
+
+        custom {
+            success {
+                "\"liga\"" => FontFeature::new("liga", 1),
+                "\"liga\" 0" => FontFeature::new("liga", 0),
+                "\"swsh\" 2" => FontFeature::new("swsh", 2),
+                "\"smcp\" on" => FontFeature::new("smcp", 1),
+                "\"kern\" off" => FontFeature::new("kern", 0),
+            }
+
+            failure {
+                "liga",
+                "\"lig\"",
+                "\"ligat\" 1",
+            }
+        }
+    }
+
+    assert_parse! {
+        Vec<FontFeature>, assert_font_features,
+
+        custom {
+            success {
+                "normal" => vec![],
+                "\"liga\" 0, \"tnum\"" => vec![FontFeature::new("liga", 0), FontFeature::new("tnum", 1)],
+            }
+
+            failure {
+                "\"liga\" 0 \"tnum\"",
+            }
+        }
+    }
+}
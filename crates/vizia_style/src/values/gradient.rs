@@ -1,6 +1,6 @@
 use crate::{
-    Angle, Color, CustomParseError, HorizontalPositionKeyword, LengthOrPercentage, Parse, Position,
-    VerticalPositionKeyword,
+    Angle, AngleOrPercentage, Color, ColorInterpolationMethod, ColorSpace, CustomParseError,
+    HorizontalPositionKeyword, LengthOrPercentage, Parse, Position, VerticalPositionKeyword, RGBA,
 };
 use cssparser::*;
 
@@ -9,7 +9,23 @@ pub enum Gradient {
     #[default]
     None,
     Linear(LinearGradient),
+    RepeatingLinear(LinearGradient),
     Radial(RadialGradient),
+    RepeatingRadial(RadialGradient),
+    Conic(ConicGradient),
+    RepeatingConic(ConicGradient),
+}
+
+impl Gradient {
+    /// Returns true if the color stops of the gradient repeat beyond the first and last stop.
+    pub fn is_repeating(&self) -> bool {
+        matches!(
+            self,
+            Gradient::RepeatingLinear(_)
+                | Gradient::RepeatingRadial(_)
+                | Gradient::RepeatingConic(_)
+        )
+    }
 }
 
 impl From<LinearGradient> for Gradient {
@@ -18,6 +34,18 @@ impl From<LinearGradient> for Gradient {
     }
 }
 
+impl From<RadialGradient> for Gradient {
+    fn from(radial_gradient: RadialGradient) -> Self {
+        Gradient::Radial(radial_gradient)
+    }
+}
+
+impl From<ConicGradient> for Gradient {
+    fn from(conic_gradient: ConicGradient) -> Self {
+        Gradient::Conic(conic_gradient)
+    }
+}
+
 impl<'i> Parse<'i> for Gradient {
     fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
         let location = input.current_source_location();
@@ -25,7 +53,11 @@ impl<'i> Parse<'i> for Gradient {
         input.parse_nested_block(|input| {
             match_ignore_ascii_case! { &func,
               "linear-gradient" => Ok(Gradient::Linear(LinearGradient::parse(input)?)),
+              "repeating-linear-gradient" => Ok(Gradient::RepeatingLinear(LinearGradient::parse(input)?)),
               "radial-gradient" => Ok(Gradient::Radial(RadialGradient::parse(input)?)),
+              "repeating-radial-gradient" => Ok(Gradient::RepeatingRadial(RadialGradient::parse(input)?)),
+              "conic-gradient" => Ok(Gradient::Conic(ConicGradient::parse(input)?)),
+              "repeating-conic-gradient" => Ok(Gradient::RepeatingConic(ConicGradient::parse(input)?)),
               _ => Err(location.new_unexpected_token_error(cssparser::Token::Ident(func.clone())))
             }
         })
@@ -112,48 +144,74 @@ impl From<(HorizontalPositionKeyword, VerticalPositionKeyword)> for LineDirectio
 #[derive(Debug, Clone, PartialEq)]
 pub struct LinearGradient {
     pub direction: LineDirection,
-    pub stops: Vec<ColorStop<LengthOrPercentage>>,
+    pub interpolation: ColorInterpolationMethod,
+    pub items: Vec<GradientItem<LengthOrPercentage>>,
 }
 
 impl<'i> Parse<'i> for LinearGradient {
     fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
-        let direction = if let Ok(direction) = input.try_parse(|input| LineDirection::parse(input))
-        {
+        let mut direction = None;
+        let mut interpolation = None;
+
+        // The direction and the interpolation method can be given in either order.
+        loop {
+            if direction.is_none() {
+                if let Ok(value) = input.try_parse(LineDirection::parse) {
+                    direction = Some(value);
+                    continue;
+                }
+            }
+
+            if interpolation.is_none() {
+                if let Ok(value) = input.try_parse(ColorInterpolationMethod::parse) {
+                    interpolation = Some(value);
+                    continue;
+                }
+            }
+
+            break;
+        }
+
+        if direction.is_some() || interpolation.is_some() {
             input.expect_comma()?;
-            direction
-        } else {
-            LineDirection::Vertical(VerticalPositionKeyword::Bottom)
-        };
-        let stops = parse_items(input)?;
-        Ok(LinearGradient { direction, stops })
+        }
+
+        let items = parse_items(input)?;
+        Ok(LinearGradient {
+            direction: direction
+                .unwrap_or(LineDirection::Vertical(VerticalPositionKeyword::Bottom)),
+            interpolation: interpolation.unwrap_or_default(),
+            items,
+        })
     }
 }
 
 fn parse_items<'i, 't, D: Parse<'i>>(
     input: &mut Parser<'i, 't>,
-) -> Result<Vec<ColorStop<D>>, ParseError<'i, CustomParseError<'i>>> {
+) -> Result<Vec<GradientItem<D>>, ParseError<'i, CustomParseError<'i>>> {
     let mut items = Vec::new();
     let mut seen_stop = false;
 
     loop {
         input.parse_until_before(Delimiter::Comma, |input| {
-            // if seen_stop {
-            //     if let Ok(hint) = input.try_parse(D::parse) {
-            //         seen_stop = false;
-            //         items.push(GradientItem::Hint(hint));
-            //         return Ok(());
-            //     }
-            // }
+            // A color hint can only appear between two color stops.
+            if seen_stop {
+                if let Ok(hint) = input.try_parse(D::parse) {
+                    seen_stop = false;
+                    items.push(GradientItem::Hint(hint));
+                    return Ok(());
+                }
+            }
 
             let stop = ColorStop::parse(input)?;
 
             if let Ok(position) = input.try_parse(D::parse) {
                 let color = stop.color;
-                items.push(stop);
+                items.push(GradientItem::ColorStop(stop));
 
-                items.push(ColorStop { color, position: Some(position) })
+                items.push(GradientItem::ColorStop(ColorStop { color, position: Some(position) }))
             } else {
-                items.push(stop);
+                items.push(GradientItem::ColorStop(stop));
             }
 
             seen_stop = true;
@@ -167,13 +225,18 @@ fn parse_items<'i, 't, D: Parse<'i>>(
         }
     }
 
+    if !seen_stop {
+        return Err(input.new_custom_error(CustomParseError::InvalidValue));
+    }
+
     Ok(items)
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct RadialGradient {
     pub position: Position,
-    pub stops: Vec<ColorStop<LengthOrPercentage>>,
+    pub interpolation: ColorInterpolationMethod,
+    pub items: Vec<GradientItem<LengthOrPercentage>>,
 }
 
 impl<'i> RadialGradient {
@@ -181,6 +244,62 @@ impl<'i> RadialGradient {
         input: &mut Parser<'i, 't>,
     ) -> Result<RadialGradient, ParseError<'i, CustomParseError<'i>>> {
         // let shape = input.try_parse(EndingShape::parse).ok();
+        let mut position = None;
+        let mut interpolation = None;
+
+        loop {
+            if position.is_none() {
+                if let Ok(value) = input.try_parse(|input| {
+                    input.expect_ident_matching("at")?;
+                    Position::parse(input)
+                }) {
+                    position = Some(value);
+                    continue;
+                }
+            }
+
+            if interpolation.is_none() {
+                if let Ok(value) = input.try_parse(ColorInterpolationMethod::parse) {
+                    interpolation = Some(value);
+                    continue;
+                }
+            }
+
+            break;
+        }
+
+        if position.is_some() || interpolation.is_some() {
+            input.expect_comma()?;
+        }
+
+        let items = parse_items(input)?;
+        Ok(RadialGradient {
+            // shape: shape.unwrap_or_default(),
+            position: position.unwrap_or(Position::center()),
+            interpolation: interpolation.unwrap_or_default(),
+            items,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConicGradient {
+    /// The angle of the start of the gradient, clockwise from the top.
+    pub angle: Angle,
+    pub position: Position,
+    pub interpolation: ColorInterpolationMethod,
+    pub items: Vec<GradientItem<AngleOrPercentage>>,
+}
+
+impl<'i> Parse<'i> for ConicGradient {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let angle = input
+            .try_parse(|input| {
+                input.expect_ident_matching("from")?;
+                Angle::parse(input)
+            })
+            .ok();
+
         let position = input
             .try_parse(|input| {
                 input.expect_ident_matching("at")?;
@@ -188,19 +307,42 @@ impl<'i> RadialGradient {
             })
             .ok();
 
-        // if shape.is_some() || position.is_some() {
-        //     input.expect_comma()?;
-        // }
+        let interpolation = input.try_parse(ColorInterpolationMethod::parse).ok();
 
-        let stops = parse_items(input)?;
-        Ok(RadialGradient {
-            // shape: shape.unwrap_or_default(),
+        if angle.is_some() || position.is_some() || interpolation.is_some() {
+            input.expect_comma()?;
+        }
+
+        let items = parse_items(input)?;
+        Ok(ConicGradient {
+            angle: angle.unwrap_or(Angle::Deg(0.0)),
             position: position.unwrap_or(Position::center()),
-            stops,
+            interpolation: interpolation.unwrap_or_default(),
+            items,
         })
     }
 }
 
+/// A color stop or a color hint within a gradient.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GradientItem<D> {
+    ColorStop(ColorStop<D>),
+    /// The position between two color stops at which the color is halfway between them.
+    Hint(D),
+}
+
+impl<D> From<ColorStop<D>> for GradientItem<D> {
+    fn from(stop: ColorStop<D>) -> Self {
+        GradientItem::ColorStop(stop)
+    }
+}
+
+impl<D> From<Color> for GradientItem<D> {
+    fn from(color: Color) -> Self {
+        GradientItem::ColorStop(color.into())
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ColorStop<D> {
     pub color: Color,
@@ -226,3 +368,260 @@ impl<D> From<(Color, D)> for ColorStop<D> {
         ColorStop { color: value.0, position: Some(value.1) }
     }
 }
+
+// The number of segments used to approximate the transition between two color stops which is not linear in sRGB.
+const TRANSITION_SEGMENTS: usize = 16;
+
+/// Resolves the items of a gradient into colors with positions along the gradient line, where 0 is the start of the
+/// line and 1 is the end, ready to be interpolated linearly in sRGB.
+///
+/// The positions of color stops are converted with `to_fraction`, and `currentcolor` is replaced by `current_color`.
+/// Stops without a position are spread evenly between their neighbours, and extra stops are added to approximate
+/// color hints and interpolation in other color spaces. Positions can lie outside of the range 0 to 1 but never
+/// decrease.
+pub fn resolve_color_stops<D>(
+    items: &[GradientItem<D>],
+    interpolation: &ColorInterpolationMethod,
+    current_color: Color,
+    to_fraction: impl Fn(&D) -> f32,
+) -> Vec<(f32, RGBA)> {
+    let mut positions = Vec::new();
+    let mut colors = Vec::new();
+    // The hint following each color stop, if any.
+    let mut hints = Vec::new();
+
+    for item in items {
+        match item {
+            GradientItem::ColorStop(stop) => {
+                positions.push(stop.position.as_ref().map(&to_fraction));
+                colors.push(stop.color.resolve(current_color).get_rgba());
+                hints.push(None);
+            }
+
+            GradientItem::Hint(hint) => {
+                if let Some(last) = hints.last_mut() {
+                    *last = Some(to_fraction(hint));
+                }
+            }
+        }
+    }
+
+    if colors.is_empty() {
+        return Vec::new();
+    }
+
+    let last = positions.len() - 1;
+    positions[0].get_or_insert(0.0);
+    positions[last].get_or_insert(1.0);
+
+    // A stop cannot be positioned before any stop preceding it.
+    let mut max = f32::MIN;
+    for position in positions.iter_mut().flatten() {
+        max = max.max(*position);
+        *position = max;
+    }
+
+    let mut resolved = Vec::with_capacity(positions.len());
+    let mut index = 0;
+    while index < positions.len() {
+        match positions[index] {
+            Some(position) => {
+                resolved.push(position);
+                index += 1;
+            }
+
+            None => {
+                // Spread a run of stops without positions evenly between the stops either side.
+                let before = resolved[index - 1];
+                let end = (index..positions.len()).find(|&i| positions[i].is_some()).unwrap();
+                let after = positions[end].unwrap();
+                let count = (end - index + 1) as f32;
+                for i in index..end {
+                    resolved.push(before + (after - before) * (i - index + 1) as f32 / count);
+                }
+
+                index = end;
+            }
+        }
+    }
+
+    let mut stops = Vec::with_capacity(resolved.len());
+    for i in 0..resolved.len() {
+        stops.push((resolved[i], colors[i]));
+
+        if i == last || resolved[i + 1] <= resolved[i] {
+            continue;
+        }
+
+        let (start, end) = (resolved[i], resolved[i + 1]);
+        let hint = hints[i].map(|hint| (hint - start) / (end - start));
+
+        if hint.is_none() && interpolation.space == ColorSpace::Srgb {
+            continue;
+        }
+
+        for segment in 1..TRANSITION_SEGMENTS {
+            let t = segment as f32 / TRANSITION_SEGMENTS as f32;
+            let weight = match hint {
+                Some(hint) if hint <= 0.0 => 1.0,
+                Some(hint) if hint >= 1.0 => 0.0,
+                Some(hint) => t.powf(0.5f32.ln() / hint.ln()),
+                None => t,
+            };
+
+            stops.push((
+                start + (end - start) * t,
+                interpolation.mix(colors[i], colors[i + 1], weight),
+            ));
+        }
+    }
+
+    stops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::assert_parse, HueInterpolationMethod};
+
+    fn stop(color: Color, position: Option<f32>) -> GradientItem<LengthOrPercentage> {
+        GradientItem::ColorStop(ColorStop {
+            color,
+            position: position.map(LengthOrPercentage::Percentage),
+        })
+    }
+
+    assert_parse! {
+        Gradient, assert_gradient,
+
+        custom {
+            success {
+                "linear-gradient(red, blue)" => Gradient::Linear(LinearGradient {
+                    direction: LineDirection::Vertical(VerticalPositionKeyword::Bottom),
+                    interpolation: ColorInterpolationMethod::default(),
+                    items: vec![stop(Color::rgb(255, 0, 0), None), stop(Color::rgb(0, 0, 255), None)],
+                }),
+                "repeating-linear-gradient(45deg in oklch longer hue, red, blue 20%)" => Gradient::RepeatingLinear(LinearGradient {
+                    direction: LineDirection::Angle(Angle::Deg(45.0)),
+                    interpolation: ColorInterpolationMethod::new(ColorSpace::Oklch, HueInterpolationMethod::Longer),
+                    items: vec![stop(Color::rgb(255, 0, 0), None), stop(Color::rgb(0, 0, 255), Some(20.0))],
+                }),
+                "linear-gradient(in oklab to right, red, 30%, blue)" => Gradient::Linear(LinearGradient {
+                    direction: LineDirection::Horizontal(HorizontalPositionKeyword::Right),
+                    interpolation: ColorSpace::Oklab.into(),
+                    items: vec![
+                        stop(Color::rgb(255, 0, 0), None),
+                        GradientItem::Hint(LengthOrPercentage::Percentage(30.0)),
+                        stop(Color::rgb(0, 0, 255), None),
+                    ],
+                }),
+                "radial-gradient(at center, red, blue)" => Gradient::Radial(RadialGradient {
+                    position: Position::center(),
+                    interpolation: ColorInterpolationMethod::default(),
+                    items: vec![stop(Color::rgb(255, 0, 0), None), stop(Color::rgb(0, 0, 255), None)],
+                }),
+                "repeating-radial-gradient(red, blue 10%)" => Gradient::RepeatingRadial(RadialGradient {
+                    position: Position::center(),
+                    interpolation: ColorInterpolationMethod::default(),
+                    items: vec![stop(Color::rgb(255, 0, 0), None), stop(Color::rgb(0, 0, 255), Some(10.0))],
+                }),
+                "conic-gradient(from 90deg at center, red, blue 50%, red)" => Gradient::Conic(ConicGradient {
+                    angle: Angle::Deg(90.0),
+                    position: Position::center(),
+                    interpolation: ColorInterpolationMethod::default(),
+                    items: vec![
+                        Color::rgb(255, 0, 0).into(),
+                        GradientItem::ColorStop(ColorStop { color: Color::rgb(0, 0, 255), position: Some(AngleOrPercentage::Percentage(50.0)) }),
+                        Color::rgb(255, 0, 0).into(),
+                    ],
+                }),
+                "repeating-conic-gradient(red 0deg 10deg, blue 10deg 20deg)" => Gradient::RepeatingConic(ConicGradient {
+                    angle: Angle::Deg(0.0),
+                    position: Position::center(),
+                    interpolation: ColorInterpolationMethod::default(),
+                    items: vec![
+                        GradientItem::ColorStop(ColorStop { color: Color::rgb(255, 0, 0), position: Some(AngleOrPercentage::Angle(Angle::Deg(0.0))) }),
+                        GradientItem::ColorStop(ColorStop { color: Color::rgb(255, 0, 0), position: Some(AngleOrPercentage::Angle(Angle::Deg(10.0))) }),
+                        GradientItem::ColorStop(ColorStop { color: Color::rgb(0, 0, 255), position: Some(AngleOrPercentage::Angle(Angle::Deg(10.0))) }),
+                        GradientItem::ColorStop(ColorStop { color: Color::rgb(0, 0, 255), position: Some(AngleOrPercentage::Angle(Angle::Deg(20.0))) }),
+                    ],
+                }),
+            }
+
+            failure {
+                "linear-gradient()",
+                "linear-gradient(to right)",
+                "linear-gradient(red, 30%)",
+                "linear-gradient(30%, red)",
+                "conic-gradient(from 10px, red, blue)",
+                "elliptic-gradient(red, blue)",
+            }
+        }
+    }
+
+    #[test]
+    fn resolve_positions() {
+        let items = vec![
+            stop(Color::rgb(255, 0, 0), None),
+            stop(Color::rgb(0, 255, 0), None),
+            stop(Color::rgb(0, 0, 255), Some(80.0)),
+            stop(Color::rgb(0, 0, 0), Some(40.0)),
+            stop(Color::rgb(255, 255, 255), None),
+        ];
+
+        let stops = resolve_color_stops(
+            &items,
+            &ColorInterpolationMethod::default(),
+            Color::black(),
+            |position| position.to_pixels(1.0, 1.0),
+        );
+
+        let positions = stops.iter().map(|(position, _)| *position).collect::<Vec<_>>();
+        assert_eq!(positions, vec![0.0, 0.4, 0.8, 0.8, 1.0]);
+        assert_eq!(stops[1].1, RGBA::rgb(0, 255, 0));
+    }
+
+    #[test]
+    fn resolve_hint() {
+        let items = vec![
+            stop(Color::rgb(0, 0, 0), None),
+            GradientItem::Hint(LengthOrPercentage::Percentage(25.0)),
+            stop(Color::rgb(255, 255, 255), None),
+        ];
+
+        let stops = resolve_color_stops(
+            &items,
+            &ColorInterpolationMethod::default(),
+            Color::black(),
+            |position| position.to_pixels(1.0, 1.0),
+        );
+
+        assert_eq!(stops.len(), TRANSITION_SEGMENTS + 1);
+        assert_eq!(stops.first(), Some(&(0.0, RGBA::rgb(0, 0, 0))));
+        assert_eq!(stops.last(), Some(&(1.0, RGBA::rgb(255, 255, 255))));
+
+        // The color is halfway between the stops at the hint.
+        let (position, color) = stops[TRANSITION_SEGMENTS / 4];
+        assert_eq!(position, 0.25);
+        assert!((127..=128).contains(&color.red), "{:?}", color);
+    }
+
+    #[test]
+    fn resolve_color_space() {
+        let items = vec![stop(Color::rgb(255, 0, 0), None), stop(Color::rgb(0, 0, 255), None)];
+
+        let srgb = resolve_color_stops(
+            &items,
+            &ColorInterpolationMethod::default(),
+            Color::black(),
+            |position| position.to_pixels(1.0, 1.0),
+        );
+        assert_eq!(srgb.len(), 2);
+
+        let oklab =
+            resolve_color_stops(&items, &ColorSpace::Oklab.into(), Color::black(), |position| {
+                position.to_pixels(1.0, 1.0)
+            });
+        assert_eq!(oklab.len(), TRANSITION_SEGMENTS + 1);
+    }
+}
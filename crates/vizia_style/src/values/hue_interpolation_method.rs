@@ -0,0 +1,71 @@
+use crate::{define_enum, Parse};
+
+define_enum! {
+    /// Determines which way around the hue wheel hues are interpolated in a polar color space.
+    #[derive(Default)]
+    pub enum HueInterpolationMethod {
+        /// Interpolates along the shorter arc between the hues.
+        #[default]
+        "shorter": Shorter,
+        /// Interpolates along the longer arc between the hues.
+        "longer": Longer,
+        /// Interpolates with increasing hue values.
+        "increasing": Increasing,
+        /// Interpolates with decreasing hue values.
+        "decreasing": Decreasing,
+    }
+}
+
+impl HueInterpolationMethod {
+    /// Adjusts a pair of hues, in degrees, so that linearly interpolating between them follows this method.
+    pub fn fixup(&self, start: f32, end: f32) -> (f32, f32) {
+        let (mut start, mut end) = (start.rem_euclid(360.0), end.rem_euclid(360.0));
+        let delta = end - start;
+
+        match self {
+            HueInterpolationMethod::Shorter => {
+                if delta > 180.0 {
+                    start += 360.0;
+                } else if delta < -180.0 {
+                    end += 360.0;
+                }
+            }
+
+            HueInterpolationMethod::Longer => {
+                if 0.0 < delta && delta < 180.0 {
+                    start += 360.0;
+                } else if -180.0 < delta && delta <= 0.0 {
+                    end += 360.0;
+                }
+            }
+
+            HueInterpolationMethod::Increasing => {
+                if delta < 0.0 {
+                    end += 360.0;
+                }
+            }
+
+            HueInterpolationMethod::Decreasing => {
+                if delta > 0.0 {
+                    start += 360.0;
+                }
+            }
+        }
+
+        (start, end)
+    }
+}
+
+#[cfg(test)]
+mod tests_fixup {
+    use super::*;
+
+    #[test]
+    fn fixup() {
+        assert_eq!(HueInterpolationMethod::Shorter.fixup(350.0, 10.0), (350.0, 370.0));
+        assert_eq!(HueInterpolationMethod::Shorter.fixup(10.0, 350.0), (370.0, 350.0));
+        assert_eq!(HueInterpolationMethod::Longer.fixup(10.0, 30.0), (370.0, 30.0));
+        assert_eq!(HueInterpolationMethod::Increasing.fixup(30.0, 10.0), (30.0, 370.0));
+        assert_eq!(HueInterpolationMethod::Decreasing.fixup(10.0, 30.0), (370.0, 30.0));
+    }
+}
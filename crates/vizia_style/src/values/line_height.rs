@@ -0,0 +1,115 @@
+use cssparser::*;
+
+use crate::{macros::impl_parse, Length, LengthOrPercentage, LengthValue, Parse};
+
+/// The height of a line of text.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum LineHeight {
+    /// The default line height of the font.
+    #[default]
+    Normal,
+    /// A multiple of the font size.
+    Number(f32),
+    /// A length, or a percentage of the font size.
+    Length(LengthOrPercentage),
+}
+
+impl LineHeight {
+    /// Returns the line height as a multiple of the given font size, or `None` for the default line height of the
+    /// font.
+    pub fn to_factor(&self, font_size: f32) -> Option<f32> {
+        match self {
+            LineHeight::Normal => None,
+            LineHeight::Number(number) => Some(*number),
+            LineHeight::Length(LengthOrPercentage::Percentage(percentage)) => {
+                Some(percentage / 100.0)
+            }
+            LineHeight::Length(LengthOrPercentage::Length(Length::Value(LengthValue::Em(em)))) => {
+                Some(*em)
+            }
+            LineHeight::Length(LengthOrPercentage::Length(length)) => {
+                length.to_px().filter(|_| font_size > 0.0).map(|px| px / font_size)
+            }
+        }
+    }
+}
+
+impl_parse! {
+    LineHeight,
+
+    custom {
+        |input| {
+            if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+                return Ok(LineHeight::Normal);
+            }
+
+            if let Ok(number) = input.try_parse(f32::parse) {
+                return Ok(LineHeight::Number(number));
+            }
+
+            Ok(LineHeight::Length(LengthOrPercentage::parse(input)?))
+        }
+    }
+}
+
+impl From<f32> for LineHeight {
+    fn from(number: f32) -> Self {
+        LineHeight::Number(number)
+    }
+}
+
+impl From<Length> for LineHeight {
+    fn from(length: Length) -> Self {
+        LineHeight::Length(length.into())
+    }
+}
+
+impl From<LengthOrPercentage> for LineHeight {
+    fn from(length: LengthOrPercentage) -> Self {
+        LineHeight::Length(length)
+    }
+}
+
+impl From<&str> for LineHeight {
+    fn from(s: &str) -> Self {
+        let mut input = ParserInput::new(s);
+        let mut parser = Parser::new(&mut input);
+        LineHeight::parse(&mut parser).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::assert_parse;
+
+    assert_parse! {
+        LineHeight, assert_line_height,
+
+        custom {
+            success {
+                "normal" => LineHeight::Normal,
+                "1.5" => LineHeight::Number(1.5),
+                "20px" => LineHeight::Length(LengthOrPercentage::px(20.0)),
+                "150%" => LineHeight::Length(LengthOrPercentage::Percentage(150.0)),
+                "2em" => LineHeight::Length(LengthOrPercentage::Length(Length::Value(LengthValue::Em(2.0)))),
+            }
+
+            failure {
+                "test",
+                "auto",
+            }
+        }
+    }
+
+    #[test]
+    fn line_height_to_factor() {
+        assert_eq!(LineHeight::Normal.to_factor(16.0), None);
+        assert_eq!(LineHeight::Number(1.5).to_factor(16.0), Some(1.5));
+        assert_eq!(LineHeight::Length(LengthOrPercentage::px(24.0)).to_factor(16.0), Some(1.5));
+        assert_eq!(
+            LineHeight::Length(LengthOrPercentage::Percentage(200.0)).to_factor(16.0),
+            Some(2.0)
+        );
+    }
+}
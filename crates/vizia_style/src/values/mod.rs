@@ -1,7 +1,11 @@
 pub mod alignment;
 pub mod alpha;
 pub mod angle;
-pub mod backdrop_filter;
+pub mod angle_or_percentage;
+pub mod animation;
+pub mod animation_direction;
+pub mod animation_fill_mode;
+pub mod animation_iteration_count;
 pub mod background_size;
 pub mod basic;
 pub mod blend_mode;
@@ -9,6 +13,10 @@ pub mod border;
 pub mod calc;
 pub mod clip;
 pub mod color;
+pub mod color_interpolation;
+pub mod color_space;
+pub mod container;
+pub mod content;
 pub mod cursor_icon;
 pub mod custom;
 pub mod dimension_percentage;
@@ -16,7 +24,9 @@ pub mod direction;
 pub mod display;
 pub mod duration;
 pub mod easing;
+pub mod filter;
 pub mod font_family;
+pub mod font_feature_settings;
 pub mod font_size;
 pub mod font_size_keyword;
 pub mod font_slant;
@@ -26,12 +36,14 @@ pub mod font_weight_keyword;
 pub mod font_width;
 pub mod gradient;
 pub mod horizontal_position_keyword;
+pub mod hue_interpolation_method;
 pub mod image;
 pub mod keywords;
 pub mod layout_type;
 pub mod length;
 pub mod length_or_percentage;
 pub mod length_percentage_auto;
+pub mod line_height;
 pub mod matrix;
 pub mod number_or_percentage;
 pub mod opacity;
@@ -44,11 +56,14 @@ pub mod rect;
 pub mod rotate;
 pub mod scale;
 pub mod shadow;
+pub mod spacing;
 pub mod stretch;
 pub mod text_align;
 pub mod text_decoration;
 pub mod text_overflow;
+pub mod text_shadow;
 pub mod text_stroke;
+pub mod text_transform;
 pub mod transform;
 pub mod transition;
 pub mod translate;
@@ -60,7 +75,11 @@ pub mod visibility;
 pub use alignment::*;
 pub use alpha::*;
 pub use angle::*;
-pub use backdrop_filter::*;
+pub use angle_or_percentage::*;
+pub use animation::*;
+pub use animation_direction::*;
+pub use animation_fill_mode::*;
+pub use animation_iteration_count::*;
 pub use background_size::*;
 pub use basic::*;
 pub use blend_mode::*;
@@ -68,6 +87,10 @@ pub use border::*;
 pub use calc::*;
 pub use clip::*;
 pub use color::*;
+pub use color_interpolation::*;
+pub use color_space::*;
+pub use container::*;
+pub use content::*;
 pub use cursor_icon::*;
 pub use custom::*;
 pub use dimension_percentage::*;
@@ -75,7 +98,9 @@ pub use direction::*;
 pub use display::*;
 pub use duration::*;
 pub use easing::*;
+pub use filter::*;
 pub use font_family::*;
+pub use font_feature_settings::*;
 pub use font_size::*;
 pub use font_size_keyword::*;
 pub use font_slant::*;
@@ -85,12 +110,14 @@ pub use font_weight_keyword::*;
 pub use font_width::*;
 pub use gradient::*;
 pub use horizontal_position_keyword::*;
+pub use hue_interpolation_method::*;
 pub use image::*;
 pub use keywords::*;
 pub use layout_type::*;
 pub use length::*;
 pub use length_or_percentage::*;
 pub use length_percentage_auto::*;
+pub use line_height::*;
 pub use matrix::*;
 pub use number_or_percentage::*;
 pub use opacity::*;
@@ -103,11 +130,14 @@ pub use rect::*;
 pub use rotate::*;
 pub use scale::*;
 pub use shadow::*;
+pub use spacing::*;
 pub use stretch::*;
 pub use text_align::*;
 pub use text_decoration::*;
 pub use text_overflow::*;
+pub use text_shadow::*;
 pub use text_stroke::*;
+pub use text_transform::*;
 pub use transform::*;
 pub use transition::*;
 pub use translate::*;
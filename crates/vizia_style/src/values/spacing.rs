@@ -0,0 +1,74 @@
+use cssparser::*;
+
+use crate::{macros::impl_parse, Length, Parse};
+
+/// The additional space between letters or words of text.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum Spacing {
+    /// No additional space.
+    #[default]
+    Normal,
+    /// A length added to the default spacing, which may be negative.
+    Length(Length),
+}
+
+impl_parse! {
+    Spacing,
+
+    custom {
+        |input| {
+            if input.try_parse(|input| input.expect_ident_matching("normal")).is_ok() {
+                return Ok(Spacing::Normal);
+            }
+
+            Ok(Spacing::Length(Length::parse(input)?))
+        }
+    }
+}
+
+impl From<Spacing> for Length {
+    fn from(spacing: Spacing) -> Self {
+        match spacing {
+            Spacing::Normal => Length::zero(),
+            Spacing::Length(length) => length,
+        }
+    }
+}
+
+impl From<Length> for Spacing {
+    fn from(length: Length) -> Self {
+        Spacing::Length(length)
+    }
+}
+
+impl From<&str> for Spacing {
+    fn from(s: &str) -> Self {
+        let mut input = ParserInput::new(s);
+        let mut parser = Parser::new(&mut input);
+        Spacing::parse(&mut parser).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::assert_parse, LengthValue};
+
+    assert_parse! {
+        Spacing, assert_spacing,
+
+        custom {
+            success {
+                "normal" => Spacing::Normal,
+                "2px" => Spacing::Length(Length::px(2.0)),
+                "-1px" => Spacing::Length(Length::px(-1.0)),
+                "0.1em" => Spacing::Length(Length::Value(LengthValue::Em(0.1))),
+            }
+
+            failure {
+                "test",
+                "auto",
+            }
+        }
+    }
+}
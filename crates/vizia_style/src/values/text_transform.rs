@@ -0,0 +1,56 @@
+use crate::{define_enum, Parse};
+
+define_enum! {
+    /// Determines the capitalization of text.
+    #[derive(Default)]
+    pub enum TextTransform {
+        /// The text is displayed as it is.
+        #[default]
+        "none": None,
+        /// Every character is displayed in uppercase.
+        "uppercase": Uppercase,
+        /// Every character is displayed in lowercase.
+        "lowercase": Lowercase,
+        /// The first character of each word is displayed in uppercase.
+        "capitalize": Capitalize,
+    }
+}
+
+impl TextTransform {
+    /// Returns the given text with the transform applied.
+    pub fn apply(&self, text: &str) -> String {
+        match self {
+            TextTransform::None => text.to_owned(),
+            TextTransform::Uppercase => text.to_uppercase(),
+            TextTransform::Lowercase => text.to_lowercase(),
+            TextTransform::Capitalize => {
+                let mut capitalized = String::with_capacity(text.len());
+                let mut word_start = true;
+                for c in text.chars() {
+                    if word_start && c.is_alphanumeric() {
+                        capitalized.extend(c.to_uppercase());
+                    } else {
+                        capitalized.push(c);
+                    }
+
+                    word_start = c.is_whitespace();
+                }
+
+                capitalized
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_apply {
+    use super::*;
+
+    #[test]
+    fn apply_text_transform() {
+        assert_eq!(TextTransform::None.apply("Hello World"), "Hello World");
+        assert_eq!(TextTransform::Uppercase.apply("Hello World"), "HELLO WORLD");
+        assert_eq!(TextTransform::Lowercase.apply("Hello World"), "hello world");
+        assert_eq!(TextTransform::Capitalize.apply("hello big  world"), "Hello Big  World");
+    }
+}
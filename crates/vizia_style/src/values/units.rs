@@ -1,4 +1,7 @@
-use crate::{macros::impl_parse, AutoKeyword, LengthPixels, Parse, Percentage, Stretch};
+use crate::{
+    macros::impl_parse, AutoKeyword, CustomParseError, LengthPixels, Parse, Percentage, Stretch,
+};
+use cssparser::{ParseError, Parser};
 pub use morphorm::Units;
 
 impl_parse! {
@@ -12,6 +15,18 @@ impl_parse! {
     }
 }
 
+/// Parses a space separated list of units, e.g. the tracks of a grid.
+impl<'i> Parse<'i> for Vec<Units> {
+    fn parse<'t>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>> {
+        let mut units = vec![Units::parse(input)?];
+        while let Ok(value) = input.try_parse(Units::parse) {
+            units.push(value);
+        }
+
+        Ok(units)
+    }
+}
+
 impl From<AutoKeyword> for Units {
     fn from(_: AutoKeyword) -> Self {
         Units::Auto
@@ -63,4 +78,21 @@ mod tests {
             "s" => Units::Stretch,
         }
     }
+
+    assert_parse! {
+        Vec<Units>, parse_units_list,
+
+        custom {
+            success {
+                "100px" => vec![Units::Pixels(100.0)],
+                "100px 1s auto" => vec![Units::Pixels(100.0), Units::Stretch(1.0), Units::Auto],
+                "2s 50%" => vec![Units::Stretch(2.0), Units::Percentage(50.0)],
+            }
+
+            failure {
+                "test",
+                ", 100px",
+            }
+        }
+    }
 }
@@ -173,6 +173,14 @@ impl Application {
         self
     }
 
+    /// Reloads styles whenever the file of a stylesheet added to the application changes.
+    ///
+    /// See [`Context::watch_stylesheets()`] for details.
+    pub fn watch_stylesheets(mut self) -> Self {
+        self.cx.context().watch_stylesheets();
+        self
+    }
+
     pub fn should_poll(mut self) -> Self {
         self.control_flow = ControlFlow::Poll;
 
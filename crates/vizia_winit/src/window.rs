@@ -503,7 +503,7 @@ impl View for Window {
             }
 
             WindowEvent::ReloadStyles => {
-                cx.reload_styles().unwrap();
+                cx.reload_styles();
             }
 
             WindowEvent::WindowClose => {
@@ -0,0 +1,48 @@
+use vizia::prelude::*;
+
+const STYLE: &str = r#"
+
+    :root {
+        padding: 1s;
+        layout-type: row;
+        horizontal-gap: 20px;
+    }
+
+    element {
+        size: 100px;
+        background-color: rgb(200, 200, 200);
+    }
+
+    .sides {
+        corner-radius: 20px;
+        border-width: 2px 6px 10px 14px;
+        border-color: red green blue orange;
+    }
+
+    .styles {
+        corner-radius: 10px;
+        border: 4px solid black;
+        border-top-style: dashed;
+        border-bottom-style: dotted;
+    }
+
+    .underline {
+        border-bottom: 3px solid rgb(50, 100, 200);
+    }
+
+    .underline:hover {
+        border-bottom-width: 8px;
+        border-bottom-color: rgb(200, 50, 100);
+        transition: border-bottom 0.2s;
+    }
+"#;
+
+fn main() -> Result<(), ApplicationError> {
+    Application::new(|cx| {
+        cx.add_stylesheet(STYLE).expect("Failed to add stylesheet");
+        Element::new(cx).class("sides");
+        Element::new(cx).class("styles");
+        Element::new(cx).class("underline");
+    })
+    .run()
+}
@@ -0,0 +1,77 @@
+use vizia::prelude::*;
+
+const STYLE: &str = r#"
+
+    :root {
+        padding: 1s;
+        --accent: #3d7eff;
+    }
+
+    .swatches {
+        height: auto;
+        horizontal-gap: 10px;
+    }
+
+    .swatches > element {
+        size: 60px;
+        corner-radius: 8px;
+    }
+
+    .lab {
+        background-color: lab(54 81 70);
+    }
+
+    .oklch {
+        background-color: oklch(70% 0.15 150);
+    }
+
+    .mix {
+        background-color: color-mix(in oklab, var(--accent) 40%, yellow);
+    }
+
+    .relative {
+        background-color: hsl(from var(--accent) calc(h + 180) s l);
+    }
+
+    .translucent {
+        background-color: rgb(from var(--accent) r g b / 50%);
+    }
+
+    button.derived {
+        color: white;
+        background-color: var(--accent);
+        border-width: 2px;
+        border-color: currentcolor;
+        transition: background-color 200ms;
+    }
+
+    button.derived:hover {
+        background-color: color-mix(in oklab, var(--accent), white 20%);
+    }
+
+    button.derived:active {
+        background-color: oklch(from var(--accent) calc(l - 0.1) c h);
+    }
+"#;
+
+fn main() -> Result<(), ApplicationError> {
+    Application::new(|cx| {
+        cx.add_stylesheet(STYLE).expect("Failed to add stylesheet");
+
+        VStack::new(cx, |cx| {
+            HStack::new(cx, |cx| {
+                Element::new(cx).class("lab");
+                Element::new(cx).class("oklch");
+                Element::new(cx).class("mix");
+                Element::new(cx).class("relative");
+                Element::new(cx).class("translucent");
+            })
+            .class("swatches");
+
+            Button::new(cx, |cx| Label::new(cx, "Accent")).class("derived");
+        })
+        .vertical_gap(Pixels(20.0));
+    })
+    .title("Color")
+    .run()
+}
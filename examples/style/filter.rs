@@ -19,6 +19,43 @@ const STYLE: &str = r#"
         padding: 30px;
     }
 
+    .thumbnails {
+        height: auto;
+        top: 1s;
+        bottom: 20px;
+        horizontal-gap: 20px;
+        alignment: center;
+    }
+
+    .thumbnail {
+        width: 120px;
+        height: 90px;
+        background-image: url("sample.png");
+        background-size: cover;
+        corner-radius: 8px;
+        transition: filter 200ms;
+    }
+
+    .grayscale {
+        filter: grayscale(1);
+    }
+
+    .grayscale:hover {
+        filter: grayscale(0);
+    }
+
+    .sepia {
+        filter: sepia(80%) contrast(1.2);
+    }
+
+    .hue-rotate {
+        filter: hue-rotate(120deg) saturate(1.5);
+    }
+
+    .drop-shadow {
+        filter: drop-shadow(4px 4px 8px black);
+    }
+
     label {
         text-wrap: true;
         font-size: 30.0;
@@ -60,7 +97,7 @@ fn main() -> Result<(), ApplicationError> {
 
         FilterElement::new(cx);
     })
-    .title("Backdrop Filter")
+    .title("Filter")
     .inner_size((800, 400))
     .run()
 }
@@ -75,6 +112,13 @@ impl FilterElement {
     pub fn new(cx: &mut Context) -> Handle<Self> {
         Self { left: Units::Pixels(0.0), top: Units::Pixels(0.0) }
             .build(cx, |cx| {
+                HStack::new(cx, |cx| {
+                    for class in ["grayscale", "sepia", "hue-rotate", "drop-shadow"] {
+                        Element::new(cx).class("thumbnail").class(class);
+                    }
+                })
+                .class("thumbnails");
+
                 VStack::new(cx, |_cx| {
                     // Label::new(cx, "This is some text");
                 })
@@ -33,6 +33,23 @@ const STYLE: &str = r#"
     .grad2 {
         background-image: radial-gradient(cyan 0%, transparent 20%, salmon 40%);
     }
+
+    .conic {
+        background-image: conic-gradient(from 45deg, red, yellow, lime, aqua, blue, magenta, red);
+    }
+
+    .conic:hover {
+        background-image: conic-gradient(from 225deg, red, yellow, lime, aqua, blue, magenta, red);
+        transition: background-image 500ms;
+    }
+
+    .stripes {
+        background-image: repeating-linear-gradient(45deg, #3f87a6 0px 10px, #ebf8e1 10px 20px);
+    }
+
+    .oklch {
+        background-image: linear-gradient(to right in oklch, blue, 25%, yellow);
+    }
 "#;
 
 fn main() -> Result<(), ApplicationError> {
@@ -48,6 +65,10 @@ fn main() -> Result<(), ApplicationError> {
             //         .add_stop(Color::blue()),
             // )
             .class("grad2");
+
+        Element::new(cx).class("conic");
+        Element::new(cx).class("stripes");
+        Element::new(cx).class("oklch");
     })
     .title("Gradient")
     .run()
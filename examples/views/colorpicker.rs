@@ -0,0 +1,55 @@
+mod helpers;
+use helpers::*;
+use vizia::prelude::*;
+
+#[derive(Lens)]
+pub struct AppData {
+    color: Color,
+    model: ColorModel,
+}
+
+pub enum AppEvent {
+    SetColor(Color),
+    ToggleModel,
+}
+
+impl Model for AppData {
+    fn event(&mut self, _cx: &mut EventContext, event: &mut Event) {
+        event.map(|app_event, _| match app_event {
+            AppEvent::SetColor(color) => self.color = *color,
+            AppEvent::ToggleModel => {
+                self.model = match self.model {
+                    ColorModel::Hsv => ColorModel::Oklch,
+                    ColorModel::Oklch => ColorModel::Hsv,
+                };
+            }
+        });
+    }
+}
+
+fn main() -> Result<(), ApplicationError> {
+    Application::new(|cx| {
+        AppData { color: Color::rgb(81, 175, 239), model: ColorModel::Hsv }.build(cx);
+
+        ExamplePage::vertical(cx, |cx| {
+            HStack::new(cx, |cx| {
+                Label::new(cx, "OKLCH");
+                Switch::new(cx, AppData::model.map(|model| *model == ColorModel::Oklch))
+                    .on_toggle(|cx| cx.emit(AppEvent::ToggleModel));
+            })
+            .size(Auto)
+            .horizontal_gap(Pixels(8.0))
+            .alignment(Alignment::Center);
+
+            ColorPicker::new(cx, AppData::color)
+                .model(AppData::model)
+                .on_change(|cx, color| cx.emit(AppEvent::SetColor(color)));
+
+            ColorButton::new(cx, AppData::color)
+                .on_change(|cx, color| cx.emit(AppEvent::SetColor(color)));
+        });
+    })
+    .title("Color Picker")
+    .inner_size((400, 600))
+    .run()
+}
@@ -0,0 +1,63 @@
+mod helpers;
+use helpers::*;
+use vizia::prelude::*;
+
+#[derive(Lens)]
+pub struct AppData {
+    dock: DockState,
+}
+
+pub enum AppEvent {
+    SetDock(DockState),
+    ResetLayout,
+}
+
+impl Model for AppData {
+    fn event(&mut self, _: &mut EventContext, event: &mut Event) {
+        event.take(|app_event, _| match app_event {
+            AppEvent::SetDock(dock) => self.dock = dock,
+            AppEvent::ResetLayout => self.dock = default_layout(),
+        });
+    }
+}
+
+fn default_layout() -> DockState {
+    DockState::new(DockLayout::split(
+        Orientation::Horizontal,
+        0.25,
+        DockLayout::tabs(["Explorer", "Search"]),
+        DockLayout::split(
+            Orientation::Vertical,
+            0.7,
+            DockLayout::tabs(["main.rs", "lib.rs"]),
+            DockLayout::tabs(["Terminal", "Output"]),
+        ),
+    ))
+}
+
+fn main() -> Result<(), ApplicationError> {
+    Application::new(|cx| {
+        AppData { dock: default_layout() }.build(cx);
+
+        ExamplePage::vertical(cx, |cx| {
+            Button::new(cx, |cx| Label::new(cx, "Reset Layout"))
+                .on_press(|cx| cx.emit(AppEvent::ResetLayout));
+
+            DockArea::new(cx, AppData::dock, |_, panel| {
+                let name = panel.to_owned();
+                let content = format!("Content of {}", panel);
+                TabPair::new(
+                    move |cx| {
+                        Label::new(cx, name.clone());
+                    },
+                    move |cx| {
+                        Label::new(cx, content.clone()).space(Pixels(8.0));
+                    },
+                )
+            })
+            .on_change(|cx, dock| cx.emit(AppEvent::SetDock(dock.clone())));
+        });
+    })
+    .title("DockArea")
+    .run()
+}
@@ -0,0 +1,38 @@
+mod helpers;
+use helpers::*;
+use vizia::prelude::*;
+
+fn main() -> Result<(), ApplicationError> {
+    Application::new(|cx| {
+        ExamplePage::vertical(cx, |cx| {
+            SplitView::new(
+                cx,
+                Orientation::Horizontal,
+                |cx| {
+                    Label::new(cx, "Sidebar").space(Pixels(8.0));
+                },
+                |cx| {
+                    SplitView::new(
+                        cx,
+                        Orientation::Vertical,
+                        |cx| {
+                            Label::new(cx, "Editor").space(Pixels(8.0));
+                        },
+                        |cx| {
+                            Label::new(cx, "Terminal").space(Pixels(8.0));
+                        },
+                    )
+                    .split(0.7)
+                    .min_sizes(50.0, 50.0)
+                    .collapsible(true);
+                },
+            )
+            .split(0.25)
+            .min_sizes(100.0, 200.0)
+            .max_sizes(400.0, f32::MAX)
+            .collapsible(true);
+        });
+    })
+    .title("SplitView")
+    .run()
+}
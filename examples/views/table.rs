@@ -0,0 +1,95 @@
+mod helpers;
+use helpers::*;
+use vizia::prelude::*;
+
+#[derive(Debug, Clone, Data)]
+pub struct Person {
+    id: usize,
+    name: String,
+    age: u32,
+    city: &'static str,
+}
+
+#[derive(Lens)]
+pub struct AppData {
+    people: Vec<Person>,
+}
+
+impl Model for AppData {}
+
+const NAMES: [&str; 8] = ["Ada", "Grace", "Alan", "Edsger", "Barbara", "Donald", "Frances", "Ken"];
+const CITIES: [&str; 5] = ["London", "Paris", "Berlin", "Tokyo", "Toronto"];
+
+fn main() -> Result<(), ApplicationError> {
+    Application::new(|cx| {
+        AppData {
+            people: (0..100_000)
+                .map(|id| Person {
+                    id,
+                    name: format!("{} {}", NAMES[id % NAMES.len()], id),
+                    age: 18 + (id * 7 % 60) as u32,
+                    city: CITIES[id * 3 % CITIES.len()],
+                })
+                .collect(),
+        }
+        .build(cx);
+
+        ExamplePage::new(cx, |cx| {
+            Table::new(cx, AppData::people, 32.0, |table| {
+                table
+                    .column(
+                        |cx| {
+                            Label::new(cx, "ID");
+                        },
+                        |cx, _, person| {
+                            Label::new(cx, person.map(|person| person.id));
+                        },
+                    )
+                    .width(80.0)
+                    .sort_by_key(|person| person.id);
+
+                table
+                    .column(
+                        |cx| {
+                            Label::new(cx, "Name");
+                        },
+                        |cx, _, person| {
+                            Label::new(cx, person.map(|person| person.name.clone()));
+                        },
+                    )
+                    .width(200.0)
+                    .min_width(100.0)
+                    .sort_by_key(|person| person.name.clone());
+
+                table
+                    .column(
+                        |cx| {
+                            Label::new(cx, "Age");
+                        },
+                        |cx, _, person| {
+                            Label::new(cx, person.map(|person| person.age));
+                        },
+                    )
+                    .width(80.0)
+                    .sort_by_key(|person| person.age);
+
+                table
+                    .column(
+                        |cx| {
+                            Label::new(cx, "City");
+                        },
+                        |cx, _, person| {
+                            Label::new(cx, person.map(|person| person.city));
+                        },
+                    )
+                    .width(150.0)
+                    .max_width(300.0)
+                    .sort_by(|a, b| a.city.cmp(b.city));
+            })
+            .selectable(Selectable::Multi)
+            .size(Pixels(500.0));
+        });
+    })
+    .title("Table")
+    .run()
+}
@@ -0,0 +1,44 @@
+mod helpers;
+use helpers::*;
+use vizia::prelude::*;
+
+fn main() -> Result<(), ApplicationError> {
+    Application::new(|cx| {
+        ExamplePage::vertical(cx, |cx| {
+            Button::new(cx, |cx| Label::new(cx, "Show toast")).on_press(|cx| {
+                cx.emit(ToastEvent::Show(
+                    Toast::new("Document saved").body("All changes have been saved."),
+                ))
+            });
+
+            Button::new(cx, |cx| Label::new(cx, "Show error")).on_press(|cx| {
+                cx.emit(ToastEvent::Show(
+                    Toast::new("Export failed")
+                        .severity(ToastSeverity::Error)
+                        .body("The file could not be written.")
+                        .action("Retry", |cx| {
+                            cx.emit(ToastEvent::Show(Toast::new("Retrying export")))
+                        })
+                        .timeout(None),
+                ))
+            });
+
+            Button::new(cx, |cx| Label::new(cx, "Start background job")).on_press(|cx| {
+                cx.spawn(|cx| {
+                    std::thread::sleep(Duration::from_secs(2));
+                    cx.emit(ToastEvent::Show(
+                        Toast::new("Background job finished").severity(ToastSeverity::Success),
+                    ))
+                    .unwrap();
+                })
+            });
+
+            Button::new(cx, |cx| Label::new(cx, "Dismiss all"))
+                .on_press(|cx| cx.emit(ToastEvent::DismissAll));
+        });
+
+        Toaster::new(cx).placement(Placement::BottomEnd);
+    })
+    .title("Toast")
+    .run()
+}
@@ -0,0 +1,57 @@
+mod helpers;
+use helpers::*;
+use vizia::prelude::*;
+
+#[derive(Debug, Clone, Data)]
+pub struct File {
+    name: String,
+    children: Vec<File>,
+}
+
+impl File {
+    fn new(name: &str, children: Vec<File>) -> Self {
+        Self { name: name.to_owned(), children }
+    }
+}
+
+impl TreeNode for File {
+    fn children(&self) -> &[Self] {
+        &self.children
+    }
+}
+
+#[derive(Lens)]
+pub struct AppData {
+    files: Vec<File>,
+}
+
+impl Model for AppData {}
+
+fn main() -> Result<(), ApplicationError> {
+    Application::new(|cx| {
+        AppData {
+            files: vec![
+                File::new(
+                    "src",
+                    vec![
+                        File::new("main.rs", vec![]),
+                        File::new("views", vec![File::new("mod.rs", vec![])]),
+                    ],
+                ),
+                File::new("Cargo.toml", vec![]),
+                File::new("README.md", vec![]),
+            ],
+        }
+        .build(cx);
+
+        ExamplePage::new(cx, |cx| {
+            TreeView::new(cx, AppData::files, |cx, _, file| {
+                Label::new(cx, file.map(|file| file.name.clone()));
+            })
+            .selectable(Selectable::Single)
+            .size(Pixels(300.0));
+        });
+    })
+    .title("TreeView")
+    .run()
+}